@@ -0,0 +1,155 @@
+//! Benchmarks for the bulk evaluation paths: catalog comparison, coverage
+//! sampling and the placement optimizer
+//!
+//! Run with `cargo bench -p camera-optics`.
+
+use camera_optics::calculations::{calculate_fov, compare_cameras};
+use camera_optics::coverage::evaluate_zone_coverage;
+use camera_optics::placement::optimize_placement;
+use camera_optics::types::{
+    CameraPose, CameraSystem, CandidateCamera, CoverageZone, DoriLevel, MountPoint, Obstacle,
+    PlacedCamera, PlacementRequest, PlanPoint, Scene, ZoneRequirement,
+};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn rectangle(x0: f64, y0: f64, x1: f64, y1: f64) -> Vec<PlanPoint> {
+    vec![
+        PlanPoint { x_m: x0, y_m: y0 },
+        PlanPoint { x_m: x1, y_m: y0 },
+        PlanPoint { x_m: x1, y_m: y1 },
+        PlanPoint { x_m: x0, y_m: y1 },
+    ]
+}
+
+/// A 40 × 20 m warehouse with two racking rows and a stricter entrance zone
+fn warehouse() -> Scene {
+    Scene {
+        room: rectangle(0.0, 0.0, 40.0, 20.0),
+        zones: vec![
+            CoverageZone {
+                name: "Hall".to_string(),
+                polygon: rectangle(0.0, 0.0, 40.0, 20.0),
+                requirement: ZoneRequirement::Dori(DoriLevel::Observation),
+            },
+            CoverageZone {
+                name: "Entrance".to_string(),
+                polygon: rectangle(16.0, 0.0, 24.0, 4.0),
+                requirement: ZoneRequirement::Dori(DoriLevel::Recognition),
+            },
+        ],
+        obstacles: vec![
+            Obstacle {
+                name: Some("Racking A".to_string()),
+                polygon: rectangle(4.0, 6.0, 36.0, 7.0),
+            },
+            Obstacle {
+                name: Some("Racking B".to_string()),
+                polygon: rectangle(4.0, 13.0, 36.0, 14.0),
+            },
+        ],
+    }
+}
+
+/// Catalog of cameras spanning common sensor sizes, resolutions and lenses
+fn catalog(size: usize) -> Vec<CameraSystem> {
+    (0..size)
+        .map(|i| {
+            let sensor_width_mm = 4.8 + (i % 7) as f64;
+            let pixel_width = 1920 + 640 * (i % 5) as u32;
+            CameraSystem::new(
+                sensor_width_mm,
+                sensor_width_mm * 9.0 / 16.0,
+                pixel_width,
+                pixel_width * 9 / 16,
+                2.8 + (i % 11) as f64,
+            )
+        })
+        .collect()
+}
+
+fn placement_request() -> PlacementRequest {
+    let mounts = [
+        (0.0, 0.0),
+        (20.0, 0.0),
+        (40.0, 0.0),
+        (40.0, 10.0),
+        (40.0, 20.0),
+        (20.0, 20.0),
+        (0.0, 20.0),
+        (0.0, 10.0),
+    ]
+    .into_iter()
+    .map(|(x_m, y_m)| MountPoint {
+        x_m,
+        y_m,
+        height_m: 4.0,
+    })
+    .collect();
+    PlacementRequest {
+        scene: warehouse(),
+        mounts,
+        cameras: vec![
+            CandidateCamera {
+                camera: CameraSystem::new(5.6, 3.15, 2560, 1440, 2.8),
+                focal_length_max_mm: Some(12.0),
+                price: None,
+            },
+            CandidateCamera {
+                camera: CameraSystem::new(7.2, 4.05, 3840, 2160, 4.0),
+                focal_length_max_mm: None,
+                price: None,
+            },
+        ],
+        max_cameras: None,
+        grid_spacing_m: Some(0.5),
+    }
+}
+
+fn placed_cameras() -> Vec<PlacedCamera> {
+    [(0.0, 0.0, 45.0), (40.0, 0.0, 315.0), (20.0, 20.0, 180.0)]
+        .into_iter()
+        .map(|(x_m, y_m, pan_deg)| PlacedCamera {
+            camera: CameraSystem::new(5.6, 3.15, 2560, 1440, 4.0),
+            pose: CameraPose {
+                x_m,
+                y_m,
+                height_m: 4.0,
+                pan_deg,
+                tilt_deg: -15.0,
+            },
+        })
+        .collect()
+}
+
+fn bench_fov(c: &mut Criterion) {
+    let camera = CameraSystem::new(5.6, 3.15, 2560, 1440, 4.0);
+    c.bench_function("calculate_fov", |b| {
+        b.iter(|| calculate_fov(black_box(&camera), black_box(10_000.0)))
+    });
+
+    let cameras = catalog(5000);
+    c.bench_function("compare_cameras/5000", |b| {
+        b.iter(|| compare_cameras(black_box(cameras.clone()), black_box(10_000.0)))
+    });
+}
+
+fn bench_coverage(c: &mut Criterion) {
+    let scene = warehouse();
+    let cameras = placed_cameras();
+    c.bench_function("evaluate_zone_coverage/0.1m", |b| {
+        b.iter(|| evaluate_zone_coverage(black_box(&scene), black_box(&cameras), Some(0.1)))
+    });
+}
+
+fn bench_placement(c: &mut Criterion) {
+    let request = placement_request();
+    let mut group = c.benchmark_group("placement");
+    group.sample_size(10);
+    group.bench_function("optimize_placement", |b| {
+        b.iter(|| optimize_placement(black_box(&request)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_fov, bench_coverage, bench_placement);
+criterion_main!(benches);
@@ -0,0 +1,162 @@
+use super::calculations::calculate_fov;
+use super::constants::DEFAULT_ALTITUDE_CEILING_M;
+use super::types::{FlightAltitude, FlightAltitudeParams, SurveyParams, SurveyPlan};
+
+/// Solve the flight altitude that gives a required ground sample distance
+///
+/// # Formula
+/// GSD = pixel_pitch × altitude / focal_length, so altitude = GSD × focal_length / pixel_pitch
+///
+/// The coarser pixel direction sets the altitude so the GSD is met along both axes.
+/// The camera is assumed to point straight down over flat ground, with its long
+/// side across the flight direction.
+pub fn solve_flight_altitude(params: &FlightAltitudeParams) -> FlightAltitude {
+    let camera = &params.camera;
+    let (h_pitch_um, v_pitch_um) = camera.pixel_pitch_um();
+    let pixel_pitch_mm = h_pitch_um.max(v_pitch_um) / 1000.0;
+    let gsd_m = params.gsd_cm / 100.0;
+
+    let altitude_m = gsd_m * camera.focal_length_mm / pixel_pitch_mm;
+    let swath_width_m = camera.sensor_width_mm * altitude_m / camera.focal_length_mm;
+    let swath_length_m = camera.sensor_height_mm * altitude_m / camera.focal_length_mm;
+    let max_altitude_m = params.max_altitude_m.unwrap_or(DEFAULT_ALTITUDE_CEILING_M);
+
+    FlightAltitude {
+        gsd_cm: params.gsd_cm,
+        altitude_m,
+        swath_width_m,
+        swath_length_m,
+        area_per_image_m2: swath_width_m * swath_length_m,
+        max_altitude_m,
+        exceeds_ceiling: altitude_m > max_altitude_m,
+    }
+}
+
+/// Plan exposure and flight-line spacing for a photogrammetry survey
+///
+/// The image footprint at the altitude comes from the FOV calculation. Forward
+/// overlap sets the distance between exposures (and with the ground speed, the
+/// shutter interval); side overlap sets the spacing between flight lines. With a
+/// survey area, the outer lines and first/last exposures sit on the area's edges
+/// and the counts are rounded up so the overlap is never less than requested.
+///
+/// # Returns
+/// `None` if either overlap is outside 0-100 % (at 100 % the camera never advances)
+pub fn plan_survey(params: &SurveyParams) -> Option<SurveyPlan> {
+    let valid_overlap = |percent: f64| (0.0..100.0).contains(&percent);
+    if !valid_overlap(params.forward_overlap_percent) || !valid_overlap(params.side_overlap_percent)
+    {
+        return None;
+    }
+
+    let fov = calculate_fov(&params.camera, params.altitude_m * 1000.0);
+    let footprint_width_m = fov.horizontal_fov_m;
+    let footprint_length_m = fov.vertical_fov_m;
+
+    let photo_base_m = footprint_length_m * (1.0 - params.forward_overlap_percent / 100.0);
+    let line_spacing_m = footprint_width_m * (1.0 - params.side_overlap_percent / 100.0);
+
+    // Steps needed to span the rest of the extent once the first footprint covers one edge
+    let count = |extent_m: f64, footprint_m: f64, step_m: f64| {
+        (((extent_m - footprint_m).max(0.0) / step_m).ceil() as u32).saturating_add(1)
+    };
+    let flight_lines = params
+        .area
+        .as_ref()
+        .map(|area| count(area.width_m, footprint_width_m, line_spacing_m));
+    let images_per_line = params
+        .area
+        .as_ref()
+        .map(|area| count(area.length_m, footprint_length_m, photo_base_m));
+
+    Some(SurveyPlan {
+        gsd_cm: 100.0 / fov.horizontal_ppm.min(fov.vertical_ppm),
+        footprint_width_m,
+        footprint_length_m,
+        photo_base_m,
+        shutter_interval_s: photo_base_m / params.ground_speed_m_s,
+        line_spacing_m,
+        flight_lines,
+        images_per_line,
+        total_images: flight_lines
+            .zip(images_per_line)
+            .map(|(lines, images)| lines.saturating_mul(images)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{CameraSystem, SurveyArea};
+
+    // 1" 20 MP survey camera with an 8.8 mm lens (2.4 µm pixels)
+    fn params(gsd_cm: f64) -> FlightAltitudeParams {
+        FlightAltitudeParams {
+            camera: CameraSystem::new(13.2, 8.8, 5500, 3667, 8.8),
+            gsd_cm,
+            max_altitude_m: None,
+        }
+    }
+
+    #[test]
+    fn test_altitude_and_swath() {
+        let flight = solve_flight_altitude(&params(2.0));
+
+        assert!((flight.altitude_m - 73.3).abs() < 0.1);
+        assert!((flight.swath_width_m - 110.0).abs() < 0.1);
+        assert!((flight.area_per_image_m2 - 110.0 * 73.3).abs() < 15.0);
+        assert!(flight.validate().is_empty());
+    }
+
+    // 110 × 73.3 m footprint at 73.3 m over a 500 × 400 m area
+    fn survey(forward_overlap_percent: f64, side_overlap_percent: f64) -> SurveyParams {
+        SurveyParams {
+            camera: params(2.0).camera,
+            altitude_m: 73.333_333_333,
+            ground_speed_m_s: 5.0,
+            forward_overlap_percent,
+            side_overlap_percent,
+            area: Some(SurveyArea {
+                width_m: 500.0,
+                length_m: 400.0,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_survey_plan() {
+        let plan = plan_survey(&survey(80.0, 70.0)).unwrap();
+
+        assert!((plan.gsd_cm - 2.0).abs() < 1e-3);
+        assert!((plan.photo_base_m - 14.67).abs() < 0.01);
+        assert!((plan.shutter_interval_s - 2.93).abs() < 0.01);
+        assert!((plan.line_spacing_m - 33.0).abs() < 1e-6);
+        // Lines: (500 - 110) / 33 = 11.8 -> 12 steps, 13 lines
+        // Images: (400 - 73.3) / 14.67 = 22.3 -> 23 steps, 24 per line
+        assert_eq!(plan.flight_lines, Some(13));
+        assert_eq!(plan.images_per_line, Some(24));
+        assert_eq!(plan.total_images, Some(13 * 24));
+    }
+
+    #[test]
+    fn test_full_overlap_is_rejected() {
+        assert!(plan_survey(&survey(100.0, 70.0)).is_none());
+        assert!(plan_survey(&survey(80.0, 100.0)).is_none());
+        assert!(plan_survey(&survey(-10.0, 70.0)).is_none());
+        // Just under 100 % still plans, with a huge but bounded image count
+        let plan = plan_survey(&survey(99.999_999, 99.999_999)).unwrap();
+        assert!(plan.total_images.unwrap() > 1_000_000);
+    }
+
+    #[test]
+    fn test_ceiling_warning() {
+        let flight = solve_flight_altitude(&params(4.0));
+
+        assert!(flight.exceeds_ceiling);
+        assert_eq!(flight.validate().len(), 1);
+
+        let mut params = params(4.0);
+        params.max_altitude_m = Some(150.0);
+        assert!(!solve_flight_altitude(&params).exceeds_ceiling);
+    }
+}
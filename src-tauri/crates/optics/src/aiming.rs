@@ -0,0 +1,239 @@
+use super::calculations::calculate_dori_distances;
+use super::geometry::{bearing_deg, camera_axes, plan_distance};
+use super::types::{
+    AimingRequest, AimingResult, CameraPose, CameraSystem, DoriLevel, ImagePoint, PlanPoint,
+    TargetEvaluation, TargetSize, WorldPoint,
+};
+
+/// Project the image corners of an aimed camera onto the plane of interest
+fn frame_footprint(request: &AimingRequest, pose: &CameraPose) -> Vec<PlanPoint> {
+    let camera = &request.camera;
+    let [forward, right, up] = camera_axes(pose.pan_deg, pose.tilt_deg);
+
+    let half_width = camera.sensor_width_mm / (2.0 * camera.focal_length_mm);
+    let half_height = camera.sensor_height_mm / (2.0 * camera.focal_length_mm);
+    let max_range_m = calculate_dori_distances(camera).detection_m;
+
+    [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)]
+        .iter()
+        .map(|&(sx, sy)| {
+            let ray: Vec<f64> = (0..3)
+                .map(|i| forward[i] + sx * half_width * right[i] + sy * half_height * up[i])
+                .collect();
+            let horizontal = ray[0].hypot(ray[1]);
+            let range_m = if ray[2] < 0.0 {
+                (pose.height_m / -ray[2] * horizontal).min(max_range_m)
+            } else {
+                max_range_m
+            };
+            PlanPoint {
+                x_m: pose.x_m + range_m * ray[0] / horizontal,
+                y_m: pose.y_m + range_m * ray[1] / horizontal,
+            }
+        })
+        .collect()
+}
+
+/// Pan and tilt that centre a target point in the image
+///
+/// Pan is the bearing from the mount to the target, clockwise from north; tilt
+/// is negative when looking down. The pixel density and DORI level are those at
+/// the slant distance to the target. A target directly below the mount gives a
+/// pan of 0° and a tilt of -90°.
+pub fn aim_camera(request: &AimingRequest) -> AimingResult {
+    let mount = &request.mount;
+    let origin = PlanPoint {
+        x_m: mount.x_m,
+        y_m: mount.y_m,
+    };
+    let distance_m = plan_distance(origin, request.target);
+    let drop_m = mount.height_m - request.target_height_m;
+
+    let pose = CameraPose {
+        x_m: mount.x_m,
+        y_m: mount.y_m,
+        height_m: mount.height_m,
+        pan_deg: if distance_m > 0.0 {
+            bearing_deg(origin, request.target)
+        } else {
+            0.0
+        },
+        tilt_deg: -drop_m.atan2(distance_m).to_degrees(),
+    };
+
+    let camera = &request.camera;
+    let slant_distance_m = distance_m.hypot(drop_m);
+    let target_ppm = camera.pixel_width as f64 * camera.focal_length_mm
+        / (camera.sensor_width_mm * slant_distance_m);
+    let achieved_level = DoriLevel::highest_met(target_ppm);
+
+    AimingResult {
+        footprint: frame_footprint(request, &pose),
+        pose,
+        slant_distance_m,
+        target_ppm,
+        achieved_level,
+        meets_requirement: request
+            .required_level
+            .map(|level| target_ppm >= level.px_per_m()),
+    }
+}
+
+/// Pixel density, DORI level and pixels on a target seen by a placed camera
+///
+/// The target stands upright and faces the camera. Pixel densities follow the
+/// depth along the optical axis, so they match [`aim_camera`] for a centred target
+/// and rise towards the image edges. The target height is foreshortened by the
+/// angle at which the camera looks down onto it.
+///
+/// # Arguments
+/// * `camera` - The camera system specification
+/// * `pose` - Position and orientation of the camera
+/// * `target_position` - Centre of the target in site coordinates
+/// * `target_size` - Width and height of the target
+pub fn evaluate_target(
+    camera: &CameraSystem,
+    pose: &CameraPose,
+    target_position: WorldPoint,
+    target_size: TargetSize,
+) -> TargetEvaluation {
+    let [forward, right, up] = camera_axes(pose.pan_deg, pose.tilt_deg);
+    let offset = [
+        target_position.x_m - pose.x_m,
+        target_position.y_m - pose.y_m,
+        target_position.z_m - pose.height_m,
+    ];
+    let dot = |a: [f64; 3], b: [f64; 3]| a[0] * b[0] + a[1] * b[1] + a[2] * b[2];
+    let depth_m = dot(offset, forward);
+
+    let slant_distance_m = dot(offset, offset).sqrt();
+    let incidence_deg = (-offset[2]).atan2(offset[0].hypot(offset[1])).to_degrees();
+
+    let (horizontal_ppm, vertical_ppm, image_point) = if depth_m > 0.0 {
+        let width = camera.pixel_width as f64;
+        let height = camera.pixel_height as f64;
+        let focal_x_px = camera.focal_length_mm * width / camera.sensor_width_mm;
+        let focal_y_px = camera.focal_length_mm * height / camera.sensor_height_mm;
+        let point = ImagePoint {
+            x_px: width / 2.0 + focal_x_px * dot(offset, right) / depth_m,
+            y_px: height / 2.0 - focal_y_px * dot(offset, up) / depth_m,
+        };
+        (focal_x_px / depth_m, focal_y_px / depth_m, Some(point))
+    } else {
+        (0.0, 0.0, None)
+    };
+    let in_view = image_point.is_some_and(|p| {
+        (0.0..=camera.pixel_width as f64).contains(&p.x_px)
+            && (0.0..=camera.pixel_height as f64).contains(&p.y_px)
+    });
+
+    TargetEvaluation {
+        slant_distance_m,
+        horizontal_ppm,
+        vertical_ppm,
+        achieved_level: DoriLevel::highest_met(horizontal_ppm).filter(|_| in_view),
+        pixels_horizontal: target_size.width_m * horizontal_ppm,
+        pixels_vertical: target_size.height_m * vertical_ppm * incidence_deg.to_radians().cos(),
+        incidence_deg,
+        image_point,
+        in_view,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::MountPoint;
+
+    fn request(target: PlanPoint) -> AimingRequest {
+        AimingRequest {
+            // Identification (250 px/m) out to ~11 m slant
+            camera: CameraSystem::new(5.6, 3.15, 1920, 1080, 8.0),
+            mount: MountPoint {
+                x_m: 0.0,
+                y_m: 0.0,
+                height_m: 3.1,
+            },
+            target,
+            target_height_m: 1.6,
+            required_level: Some(DoriLevel::Identification),
+        }
+    }
+
+    #[test]
+    fn test_angles_and_dori_at_target() {
+        let near = aim_camera(&request(PlanPoint { x_m: 6.0, y_m: 6.0 }));
+        assert!((near.pose.pan_deg - 45.0).abs() < 1e-9);
+        // 1.5 m drop over 8.49 m
+        assert!((near.pose.tilt_deg + 10.02).abs() < 0.01);
+        assert!((near.slant_distance_m - 8.62).abs() < 0.01);
+        assert_eq!(near.achieved_level, Some(DoriLevel::Identification));
+        assert_eq!(near.meets_requirement, Some(true));
+
+        let far = aim_camera(&request(PlanPoint {
+            x_m: -20.0,
+            y_m: 0.0,
+        }));
+        assert!((far.pose.pan_deg - 270.0).abs() < 1e-9);
+        // ~137 px/m at 20 m
+        assert_eq!(far.achieved_level, Some(DoriLevel::Recognition));
+        assert_eq!(far.meets_requirement, Some(false));
+
+        let below = aim_camera(&request(PlanPoint { x_m: 0.0, y_m: 0.0 }));
+        assert_eq!((below.pose.pan_deg, below.pose.tilt_deg), (0.0, -90.0));
+    }
+
+    #[test]
+    fn test_footprint_surrounds_target() {
+        let result = aim_camera(&request(PlanPoint {
+            x_m: 0.0,
+            y_m: 10.0,
+        }));
+        let [bottom_left, bottom_right, top_right, top_left] = result.footprint[..] else {
+            panic!("expected four corners");
+        };
+
+        // Symmetric about the line of sight, wider at the far edge
+        assert!((bottom_left.x_m + bottom_right.x_m).abs() < 1e-9);
+        assert!(top_right.x_m - top_left.x_m > bottom_right.x_m - bottom_left.x_m);
+        // The point on the plane below the target is inside the frame
+        assert!(bottom_left.y_m < 10.0 && top_left.y_m > 10.0);
+        // The top edge looks above the horizon and is cut off at the detection distance
+        assert!((top_left.x_m.hypot(top_left.y_m) - 109.7).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_evaluate_target() {
+        let request = request(PlanPoint { x_m: 6.0, y_m: 6.0 });
+        let aimed = aim_camera(&request);
+        let person = TargetSize {
+            width_m: 0.5,
+            height_m: 1.7,
+        };
+        let at = |x_m, y_m| WorldPoint { x_m, y_m, z_m: 1.6 };
+
+        // Centred on the aimed target, the densities match the aiming result
+        let centred = evaluate_target(&request.camera, &aimed.pose, at(6.0, 6.0), person);
+        assert!(centred.in_view);
+        assert!((centred.horizontal_ppm - aimed.target_ppm).abs() < 1e-9);
+        assert!((centred.slant_distance_m - aimed.slant_distance_m).abs() < 1e-9);
+        assert_eq!(centred.achieved_level, Some(DoriLevel::Identification));
+        let image_point = centred.image_point.unwrap();
+        assert!((image_point.x_px - 960.0).abs() < 1e-6);
+        assert!((image_point.y_px - 540.0).abs() < 1e-6);
+        assert!((centred.incidence_deg + aimed.pose.tilt_deg).abs() < 1e-9);
+        assert!((centred.pixels_horizontal - 0.5 * centred.horizontal_ppm).abs() < 1e-9);
+        assert!(centred.pixels_vertical < 1.7 * centred.vertical_ppm);
+
+        // Off to the side of the frame: density is still defined but the target is not seen
+        let outside = evaluate_target(&request.camera, &aimed.pose, at(8.0, -2.0), person);
+        assert!(outside.image_point.is_some());
+        assert!(!outside.in_view);
+        assert_eq!(outside.achieved_level, None);
+
+        // Behind the camera
+        let behind = evaluate_target(&request.camera, &aimed.pose, at(-6.0, -6.0), person);
+        assert!(behind.image_point.is_none());
+        assert_eq!(behind.horizontal_ppm, 0.0);
+    }
+}
@@ -0,0 +1,139 @@
+use super::constants::ALPR_MAX_BLUR_PX;
+use super::exposure::angle_from_shutter;
+use super::types::{AlprParams, AlprRequirement, AlprResult, CameraSystem, MotionBlurCheck};
+
+/// Pixel count and sensor size along the axis a requirement is measured on
+fn requirement_axis(camera: &CameraSystem, requirement: AlprRequirement) -> (f64, f64) {
+    match requirement {
+        // Character height is vertical
+        AlprRequirement::PixelsPerCharacter(_) => {
+            (camera.pixel_height as f64, camera.sensor_height_mm)
+        }
+        AlprRequirement::PixelDensity(_) => (camera.pixel_width as f64, camera.sensor_width_mm),
+    }
+}
+
+/// Maximum plate capture distance, required focal length and motion blur for ALPR
+///
+/// Plate reading thresholds are set per engine and region rather than by the
+/// person-based DORI levels, so the requirement is given either in pixels per
+/// character height or as a pixel density on the plate. Motion blur assumes the
+/// vehicle moves across the image, the worst case, and is limited to 1 px. The
+/// exposure can be given as a shutter time or as a shutter angle at a frame rate.
+pub fn calculate_alpr(params: &AlprParams) -> AlprResult {
+    let camera = &params.camera;
+    let required_ppm = match params.requirement {
+        AlprRequirement::PixelsPerCharacter(pixels) => {
+            pixels / (params.plate.character_height_mm / 1000.0)
+        }
+        AlprRequirement::PixelDensity(ppm) => ppm,
+    };
+
+    // ppm = pixels × focal length / (sensor size × distance)
+    let (pixels, sensor_mm) = requirement_axis(camera, params.requirement);
+    let max_distance_m = pixels * camera.focal_length_mm / (sensor_mm * required_ppm);
+    let required_focal_length_mm = params
+        .capture_distance_m
+        .map(|distance_m| required_ppm * sensor_mm * distance_m / pixels);
+
+    let distance_m = params.capture_distance_m.unwrap_or(max_distance_m);
+    let horizontal_ppm =
+        camera.pixel_width as f64 * camera.focal_length_mm / (camera.sensor_width_mm * distance_m);
+    let vertical_ppm = camera.pixel_height as f64 * camera.focal_length_mm
+        / (camera.sensor_height_mm * distance_m);
+
+    let motion_blur = params.vehicle_speed_kmh.map(|speed_kmh| {
+        let speed_px_per_s = speed_kmh / 3.6 * horizontal_ppm;
+        let shutter_s = params
+            .shutter_angle
+            .map(|angle| angle.shutter_s())
+            .or(params.shutter_s);
+        let blur_px = shutter_s.map(|shutter_s| speed_px_per_s * shutter_s);
+        let max_shutter_s = ALPR_MAX_BLUR_PX / speed_px_per_s;
+        MotionBlurCheck {
+            blur_px,
+            max_shutter_s,
+            passes: blur_px.map(|blur_px| blur_px <= ALPR_MAX_BLUR_PX),
+            max_shutter_angle_deg: params
+                .shutter_angle
+                .map(|angle| angle_from_shutter(max_shutter_s, angle.frame_rate_fps)),
+        }
+    });
+
+    AlprResult {
+        required_ppm,
+        max_distance_m,
+        required_focal_length_mm,
+        plate_width_px: params
+            .capture_distance_m
+            .map(|_| params.plate.width_mm / 1000.0 * horizontal_ppm),
+        character_height_px: params
+            .capture_distance_m
+            .map(|_| params.plate.character_height_mm / 1000.0 * vertical_ppm),
+        motion_blur,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{PlateRegion, ShutterAngle};
+
+    fn params() -> AlprParams {
+        AlprParams {
+            // 2.9 µm square pixels
+            camera: CameraSystem::new(5.6, 3.15, 1920, 1080, 12.0),
+            plate: PlateRegion::Eu.plate(),
+            requirement: AlprRequirement::PixelsPerCharacter(20.0),
+            capture_distance_m: None,
+            vehicle_speed_kmh: Some(50.0),
+            shutter_s: Some(0.001),
+            shutter_angle: None,
+        }
+    }
+
+    #[test]
+    fn test_max_distance_and_blur() {
+        let result = calculate_alpr(&params());
+
+        // 20 px over 75 mm characters
+        assert!((result.required_ppm - 266.67).abs() < 0.01);
+        assert!((result.max_distance_m - 15.43).abs() < 0.01);
+        assert!(result.required_focal_length_mm.is_none());
+
+        // 13.9 m/s at ~267 px/m smears a 1/1000 s exposure over ~3.7 px
+        let blur = result.motion_blur.unwrap();
+        assert!((blur.blur_px.unwrap() - 3.70).abs() < 0.01);
+        assert_eq!(blur.passes, Some(false));
+        assert!((blur.max_shutter_s - 1.0 / 3703.7).abs() < 1e-7);
+    }
+
+    #[test]
+    fn test_blur_from_shutter_angle() {
+        let mut params = params();
+        params.shutter_s = None;
+        params.shutter_angle = Some(ShutterAngle {
+            angle_deg: 180.0,
+            frame_rate_fps: 24.0,
+        });
+        let blur = calculate_alpr(&params).motion_blur.unwrap();
+
+        // 1/48 s is ~21× the 1/1000 s blur
+        assert!((blur.blur_px.unwrap() - 3.70 * 1000.0 / 48.0).abs() < 0.2);
+        // 1/3704 s at 24 fps is a ~2.3° shutter
+        assert!((blur.max_shutter_angle_deg.unwrap() - 2.33).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_required_focal_length_at_capture_distance() {
+        let mut params = params();
+        params.capture_distance_m = Some(20.0);
+        params.requirement = AlprRequirement::PixelDensity(250.0);
+        let result = calculate_alpr(&params);
+
+        assert!((result.required_focal_length_mm.unwrap() - 14.58).abs() < 0.01);
+        // 12 mm at 20 m gives ~206 px/m: 107 px across the plate
+        assert!((result.plate_width_px.unwrap() - 107.0).abs() < 0.1);
+        assert!((result.max_distance_m - 16.46).abs() < 0.01);
+    }
+}
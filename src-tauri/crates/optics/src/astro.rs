@@ -0,0 +1,129 @@
+use super::calculations::calculate_fov;
+use super::constants::{
+    ARCSEC_PER_RAD, ASTRO_MAX_PIXELS_PER_FWHM, ASTRO_MIN_PIXELS_PER_FWHM, FULL_FRAME_DIAGONAL_MM,
+};
+use super::types::{AstroSampling, CameraSystem, ParameterRange, StarTrailLimit};
+
+/// Calculate the pixel scale and sky field of a camera for astrophotography
+///
+/// Pixel scale = 206265 × pixel_pitch / focal_length arcsec/px. Stars are well
+/// sampled with 2-3 pixels across the seeing FWHM; coarser pixels make blocky
+/// stars, finer ones spread the light without resolving more detail.
+///
+/// # Arguments
+/// * `camera` - Camera with the telescope or lens focal length
+/// * `seeing_arcsec` - Seeing FWHM at the site, typically 1.5-4"
+pub fn calculate_astro_sampling(camera: &CameraSystem, seeing_arcsec: f64) -> AstroSampling {
+    let pixel_pitch_mm = camera.sensor_width_mm / camera.pixel_width as f64;
+    let pixel_scale_arcsec = pixel_pitch_mm / camera.focal_length_mm * ARCSEC_PER_RAD;
+    let pixels_per_fwhm = seeing_arcsec / pixel_scale_arcsec;
+
+    // Focal length for a given pixel scale: f = 206265 × pitch / scale
+    let focal_for = |pixels: f64| pixel_pitch_mm * ARCSEC_PER_RAD * pixels / seeing_arcsec;
+
+    // Angular FOV doesn't depend on distance
+    let fov = calculate_fov(camera, 1.0);
+    let diagonal_mm = camera.sensor_width_mm.hypot(camera.sensor_height_mm);
+
+    AstroSampling {
+        pixel_scale_arcsec,
+        seeing_arcsec,
+        pixels_per_fwhm,
+        undersampled: pixels_per_fwhm < ASTRO_MIN_PIXELS_PER_FWHM,
+        oversampled: pixels_per_fwhm > ASTRO_MAX_PIXELS_PER_FWHM,
+        optimal_focal_length_mm: ParameterRange {
+            min: focal_for(ASTRO_MIN_PIXELS_PER_FWHM),
+            max: focal_for(ASTRO_MAX_PIXELS_PER_FWHM),
+            recommended: None,
+        },
+        horizontal_fov_deg: fov.horizontal_fov_deg,
+        vertical_fov_deg: fov.vertical_fov_deg,
+        diagonal_fov_deg: 2.0
+            * (diagonal_mm / (2.0 * camera.focal_length_mm))
+                .atan()
+                .to_degrees(),
+        horizontal_fov_arcmin: fov.horizontal_fov_deg * 60.0,
+        vertical_fov_arcmin: fov.vertical_fov_deg * 60.0,
+    }
+}
+
+/// Calculate the longest untrailed exposure for a camera on a fixed tripod
+///
+/// The 500 rule only scales with the focal length, which lets stars trail visibly on
+/// today's small pixels. The NPF rule (Michaud) also accounts for the pixel pitch and
+/// the aperture, whose blur masks some of the motion. Stars away from the celestial
+/// equator move more slowly across the sky, so both limits are divided by cos δ;
+/// close to the celestial poles they grow without bound.
+///
+/// # Arguments
+/// * `camera` - The camera system specification
+/// * `f_number` - Lens aperture (f-number)
+/// * `declination_deg` - Declination of the stars in degrees
+pub fn calculate_star_trail_limit(
+    camera: &CameraSystem,
+    f_number: f64,
+    declination_deg: f64,
+) -> StarTrailLimit {
+    let crop_factor =
+        FULL_FRAME_DIAGONAL_MM / camera.sensor_width_mm.hypot(camera.sensor_height_mm);
+    let pixel_pitch_um = camera.sensor_width_mm / camera.pixel_width as f64 * 1000.0;
+    let declination_factor = declination_deg.to_radians().cos();
+
+    StarTrailLimit {
+        declination_deg,
+        crop_factor,
+        pixel_pitch_um,
+        rule_500_s: 500.0 / (camera.focal_length_mm * crop_factor * declination_factor),
+        npf_s: (35.0 * f_number + 30.0 * pixel_pitch_um)
+            / (camera.focal_length_mm * declination_factor),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pixel_scale_and_sampling() {
+        // 3.76 µm pixels at 600 mm: 1.29"/px
+        let camera = CameraSystem::new(23.5, 15.7, 6250, 4176, 600.0);
+        let sampling = calculate_astro_sampling(&camera, 3.0);
+
+        assert!((sampling.pixel_scale_arcsec - 1.2926).abs() < 1e-3);
+        assert!(!sampling.undersampled && !sampling.oversampled);
+        assert!(sampling.validate().is_empty());
+        assert!((sampling.horizontal_fov_arcmin - 134.6).abs() < 0.1);
+
+        // At the optimal range ends the FWHM spans exactly 2 and 3 pixels
+        let range = &sampling.optimal_focal_length_mm;
+        let with_focal = |f: f64| CameraSystem::new(23.5, 15.7, 6250, 4176, f);
+        let at_min = calculate_astro_sampling(&with_focal(range.min), 3.0);
+        let at_max = calculate_astro_sampling(&with_focal(range.max), 3.0);
+        assert!((at_min.pixels_per_fwhm - 2.0).abs() < 1e-9);
+        assert!((at_max.pixels_per_fwhm - 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_star_trail_limits() {
+        // Full frame, 24 MP (6 µm pixels), 20 mm at f/2
+        let camera = CameraSystem::new(36.0, 24.0, 6000, 4000, 20.0);
+        let limit = calculate_star_trail_limit(&camera, 2.0, 0.0);
+
+        assert!((limit.crop_factor - 1.0).abs() < 1e-3);
+        assert!((limit.rule_500_s - 25.0).abs() < 0.05);
+        assert!((limit.npf_s - 12.5).abs() < 1e-9);
+
+        // Stars at 60° move half as fast
+        let high = calculate_star_trail_limit(&camera, 2.0, 60.0);
+        assert!((high.npf_s - 2.0 * limit.npf_s).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_wide_lens_undersamples() {
+        let camera = CameraSystem::new(36.0, 24.0, 6000, 4000, 50.0);
+        let sampling = calculate_astro_sampling(&camera, 2.0);
+
+        assert!(sampling.undersampled);
+        assert_eq!(sampling.validate().len(), 1);
+    }
+}
@@ -0,0 +1,183 @@
+use super::calculations::calculate_dori_distances;
+use super::constants::KOSCHMIEDER_CONSTANT;
+use super::types::{
+    AtmosphericConditions, AtmosphericDoriResult, CameraSystem, DoriDeratingFactors, DoriDistances,
+};
+
+/// Calculate the fog/haze extinction coefficient from meteorological visibility
+///
+/// Koschmieder's law: σ = 3.912 / V, where V is the distance at which
+/// the contrast of a black target drops to 2%.
+///
+/// # Returns
+/// Extinction coefficient in 1/km
+pub fn fog_extinction_per_km(visibility_m: f64) -> f64 {
+    KOSCHMIEDER_CONSTANT / (visibility_m / 1000.0)
+}
+
+/// Calculate the rain extinction coefficient from rain rate
+///
+/// Empirical relation for visible wavelengths: σ = 1.076 × R^0.67
+///
+/// # Returns
+/// Extinction coefficient in 1/km
+pub fn rain_extinction_per_km(rain_rate_mm_h: f64) -> f64 {
+    1.076 * rain_rate_mm_h.powf(0.67)
+}
+
+/// Fog and rain extinction coefficients (1/km) for a set of conditions
+pub(super) fn conditions_extinction_per_km(conditions: &AtmosphericConditions) -> (f64, f64) {
+    let fog = conditions
+        .visibility_m
+        .map(fog_extinction_per_km)
+        .unwrap_or(0.0);
+    let rain = conditions
+        .rain_rate_mm_h
+        .map(rain_extinction_per_km)
+        .unwrap_or(0.0);
+    (fog, rain)
+}
+
+/// Derate a single clear-weather distance for atmospheric extinction
+///
+/// Contrast falls off as T(d) = exp(-σ × d). Requiring the contrast-weighted pixel
+/// density to still meet the tier threshold gives d × exp(σ × d) = d_clear, which is
+/// solved with Newton's method. The result is additionally capped at the visibility
+/// range (3.912 / σ) beyond which nothing can be seen.
+pub(super) fn derate_distance(clear_distance_m: f64, extinction_per_m: f64) -> f64 {
+    if extinction_per_m <= 0.0 {
+        return clear_distance_m;
+    }
+
+    // f(d) = d × e^(σd) - D is convex, so Newton from d = D converges from above
+    let mut d = clear_distance_m;
+    for _ in 0..50 {
+        let e = (extinction_per_m * d).exp();
+        let step = (d * e - clear_distance_m) / (e * (1.0 + extinction_per_m * d));
+        d -= step;
+        if step.abs() < 1e-9 {
+            break;
+        }
+    }
+
+    d.min(KOSCHMIEDER_CONSTANT / extinction_per_m)
+}
+
+/// Calculate DORI distances derated for fog, haze and rain
+///
+/// # Arguments
+/// * `camera` - The camera system specification
+/// * `conditions` - Visibility and rain rate
+///
+/// # Returns
+/// Theoretical and effective DORI distances with the derating factor for each tier
+pub fn calculate_atmospheric_dori(
+    camera: &CameraSystem,
+    conditions: &AtmosphericConditions,
+) -> AtmosphericDoriResult {
+    let (fog, rain) = conditions_extinction_per_km(conditions);
+    let extinction_per_km = fog + rain;
+    let extinction_per_m = extinction_per_km / 1000.0;
+
+    let theoretical = calculate_dori_distances(camera);
+    let effective = DoriDistances {
+        detection_m: derate_distance(theoretical.detection_m, extinction_per_m),
+        observation_m: derate_distance(theoretical.observation_m, extinction_per_m),
+        recognition_m: derate_distance(theoretical.recognition_m, extinction_per_m),
+        identification_m: derate_distance(theoretical.identification_m, extinction_per_m),
+    };
+
+    let derating_factors = DoriDeratingFactors {
+        detection: effective.detection_m / theoretical.detection_m,
+        observation: effective.observation_m / theoretical.observation_m,
+        recognition: effective.recognition_m / theoretical.recognition_m,
+        identification: effective.identification_m / theoretical.identification_m,
+    };
+
+    AtmosphericDoriResult {
+        fog_extinction_per_km: fog,
+        rain_extinction_per_km: rain,
+        extinction_per_km,
+        theoretical,
+        effective,
+        derating_factors,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clear_weather_is_not_derated() {
+        let camera = CameraSystem::new(6.4, 4.8, 1920, 1080, 4.0);
+        let conditions = AtmosphericConditions {
+            visibility_m: None,
+            rain_rate_mm_h: None,
+        };
+        let result = calculate_atmospheric_dori(&camera, &conditions);
+
+        assert_eq!(result.extinction_per_km, 0.0);
+        assert!((result.effective.detection_m - result.theoretical.detection_m).abs() < 1e-9);
+        assert!((result.derating_factors.identification - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_fog_extinction() {
+        // 1 km visibility gives σ = 3.912 / km
+        assert!((fog_extinction_per_km(1000.0) - 3.912).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_fog_derates_far_tiers_more() {
+        let camera = CameraSystem::new(6.4, 4.8, 1920, 1080, 12.0);
+        let conditions = AtmosphericConditions {
+            visibility_m: Some(500.0),
+            rain_rate_mm_h: None,
+        };
+        let result = calculate_atmospheric_dori(&camera, &conditions);
+
+        let f = &result.derating_factors;
+        assert!(f.detection < f.observation);
+        assert!(f.observation < f.recognition);
+        assert!(f.recognition < f.identification);
+        assert!(f.identification < 1.0);
+
+        // The effective distance satisfies d × e^(σd) = d_clear
+        let sigma = result.extinction_per_km / 1000.0;
+        let d = result.effective.recognition_m;
+        assert!((d * (sigma * d).exp() - result.theoretical.recognition_m).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_rain_adds_extinction() {
+        let camera = CameraSystem::new(6.4, 4.8, 1920, 1080, 4.0);
+        let conditions = AtmosphericConditions {
+            visibility_m: Some(5000.0),
+            rain_rate_mm_h: Some(10.0),
+        };
+        let result = calculate_atmospheric_dori(&camera, &conditions);
+
+        assert!(result.rain_extinction_per_km > 0.0);
+        assert!(
+            (result.extinction_per_km
+                - result.fog_extinction_per_km
+                - result.rain_extinction_per_km)
+                .abs()
+                < 1e-9
+        );
+    }
+
+    #[test]
+    fn test_effective_distance_capped_at_visibility() {
+        // Very long lens: geometric detection far exceeds the 200 m visibility
+        let camera = CameraSystem::new(6.4, 4.8, 3840, 2160, 300.0);
+        let conditions = AtmosphericConditions {
+            visibility_m: Some(200.0),
+            rain_rate_mm_h: None,
+        };
+        let result = calculate_atmospheric_dori(&camera, &conditions);
+
+        assert!(result.effective.detection_m <= 200.0 + 1e-6);
+    }
+}
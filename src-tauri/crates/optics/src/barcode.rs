@@ -0,0 +1,99 @@
+use super::constants::{LINEAR_CODE_PIXELS_PER_MODULE, MATRIX_CODE_PIXELS_PER_MODULE};
+use super::types::{BarcodeParams, BarcodeResult, CameraSystem, CodeSymbology};
+
+/// Pixel density at 1 m along the axes a code is read on, in px/m
+fn resolving_density(camera: &CameraSystem, symbology: CodeSymbology) -> f64 {
+    let horizontal = camera.pixel_width as f64 * camera.focal_length_mm / camera.sensor_width_mm;
+    let vertical = camera.pixel_height as f64 * camera.focal_length_mm / camera.sensor_height_mm;
+    match symbology {
+        // Bars are read across, in the orientation the code is mounted: assume horizontal
+        CodeSymbology::Linear => horizontal,
+        // Cells must be resolved in both directions, so the coarser axis limits
+        CodeSymbology::Matrix => horizontal.min(vertical),
+    }
+}
+
+/// Working-distance window in which a 1D or 2D code can be decoded
+///
+/// The far limit is where a module spans the required number of pixels. When
+/// the code size is given, the near limit is where the code just fills the
+/// frame; otherwise it is 0.
+pub fn calculate_barcode_reading(params: &BarcodeParams) -> BarcodeResult {
+    let camera = &params.camera;
+    let required_pixels_per_module = params.pixels_per_module.unwrap_or(match params.symbology {
+        CodeSymbology::Linear => LINEAR_CODE_PIXELS_PER_MODULE,
+        CodeSymbology::Matrix => MATRIX_CODE_PIXELS_PER_MODULE,
+    });
+
+    let density = resolving_density(camera, params.symbology);
+    let far_distance_m = params.module_size_mm / 1000.0 * density / required_pixels_per_module;
+
+    // Frame size at distance d is sensor size × d / focal length
+    let fits = |code_mm: Option<f64>, sensor_mm: f64| {
+        code_mm.map_or(0.0, |code_mm| {
+            code_mm * camera.focal_length_mm / sensor_mm / 1000.0
+        })
+    };
+    let near_distance_m = fits(params.code_width_mm, camera.sensor_width_mm)
+        .max(fits(params.code_height_mm, camera.sensor_height_mm));
+
+    let pixels_per_module = params
+        .distance_m
+        .map(|distance_m| params.module_size_mm / 1000.0 * density / distance_m);
+
+    BarcodeResult {
+        required_pixels_per_module,
+        near_distance_m,
+        far_distance_m,
+        pixels_per_module,
+        decodable: params
+            .distance_m
+            .map(|distance_m| distance_m >= near_distance_m && distance_m <= far_distance_m),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(symbology: CodeSymbology) -> BarcodeParams {
+        BarcodeParams {
+            // 5486 px/m at 1 m: a 0.5 mm module spans 2.7 px
+            camera: CameraSystem::new(5.6, 3.15, 1920, 1080, 16.0),
+            symbology,
+            module_size_mm: 0.5,
+            pixels_per_module: None,
+            code_width_mm: Some(40.0),
+            code_height_mm: Some(40.0),
+            distance_m: Some(1.0),
+        }
+    }
+
+    #[test]
+    fn test_reading_window() {
+        let linear = calculate_barcode_reading(&params(CodeSymbology::Linear));
+        assert_eq!(linear.required_pixels_per_module, 2.0);
+        assert!((linear.far_distance_m - 1.371).abs() < 0.001);
+        // 40 mm fills the 3.15 mm high frame at 0.2 m
+        assert!((linear.near_distance_m - 0.203).abs() < 0.001);
+        assert!((linear.pixels_per_module.unwrap() - 2.743).abs() < 0.001);
+        assert_eq!(linear.decodable, Some(true));
+
+        let matrix = calculate_barcode_reading(&params(CodeSymbology::Matrix));
+        assert!((matrix.far_distance_m - 0.914).abs() < 0.001);
+        assert_eq!(matrix.decodable, Some(false));
+    }
+
+    #[test]
+    fn test_custom_threshold_without_code_size() {
+        let mut params = params(CodeSymbology::Matrix);
+        params.pixels_per_module = Some(1.5);
+        params.code_width_mm = None;
+        params.code_height_mm = None;
+        let result = calculate_barcode_reading(&params);
+
+        assert_eq!(result.near_distance_m, 0.0);
+        assert!((result.far_distance_m - 1.829).abs() < 0.001);
+        assert_eq!(result.decodable, Some(true));
+    }
+}
@@ -1,4 +1,15 @@
-use super::types::{CameraSystem, DoriDistances, FovResult};
+use rayon::prelude::*;
+
+use super::equivalence::classify_angle_of_view;
+use super::sensitivity::{
+    calculate_low_light_score, rank_low_light_scores, rank_night_low_light_scores,
+};
+use super::types::{
+    BindingConstraint, CameraSystem, CameraWithResult, DoriDistances, DoriLevel, FovResult,
+    GoverningTarget, MessageKey, NightModeResult, RangeLimit, RangeParameter, RequiredFov,
+    SensorFormat, SolverBounds, StandardLens, StandardResolution, ValidationCode,
+    ValidationSeverity, ValidationWarning,
+};
 
 /// Calculate field of view and spatial resolution for a camera system at a given distance
 ///
@@ -9,6 +20,8 @@ use super::types::{CameraSystem, DoriDistances, FovResult};
 /// # Returns
 /// Field of view results including angular FOV, linear FOV at distance, and spatial resolution
 pub fn calculate_fov(camera: &CameraSystem, distance_mm: f64) -> FovResult {
+    use super::constants::METERS_PER_FOOT;
+
     // Calculate angular field of view using: FOV = 2 * atan(sensor_size / (2 * focal_length))
     let horizontal_fov_rad = 2.0 * (camera.sensor_width_mm / (2.0 * camera.focal_length_mm)).atan();
     let vertical_fov_rad = 2.0 * (camera.sensor_height_mm / (2.0 * camera.focal_length_mm)).atan();
@@ -29,6 +42,22 @@ pub fn calculate_fov(camera: &CameraSystem, distance_mm: f64) -> FovResult {
     let horizontal_ppm = camera.pixel_width as f64 / horizontal_fov_m;
     let vertical_ppm = camera.pixel_height as f64 / vertical_fov_m;
 
+    // Calculate angular size of a single pixel: IFOV = pixel_pitch / focal_length
+    let horizontal_ifov_urad =
+        (camera.sensor_width_mm / camera.pixel_width as f64) / camera.focal_length_mm * 1e6;
+    let vertical_ifov_urad =
+        (camera.sensor_height_mm / camera.pixel_height as f64) / camera.focal_length_mm * 1e6;
+
+    // Derived figures quoted by different industries, so the UI never re-derives them
+    let diagonal_mm = camera.sensor_width_mm.hypot(camera.sensor_height_mm);
+    let diagonal_fov_deg =
+        (2.0 * (diagonal_mm / (2.0 * camera.focal_length_mm)).atan()).to_degrees();
+    let horizontal_px_per_deg = camera.pixel_width as f64 / horizontal_fov_deg;
+    let vertical_px_per_deg = camera.pixel_height as f64 / vertical_fov_deg;
+    let horizontal_ppf = horizontal_ppm * METERS_PER_FOOT;
+    let vertical_ppf = vertical_ppm * METERS_PER_FOOT;
+    let megapixels_per_m2 = horizontal_ppm * vertical_ppm / 1e6;
+
     // Calculate DORI distances
     let dori = calculate_dori_distances(camera);
 
@@ -40,10 +69,54 @@ pub fn calculate_fov(camera: &CameraSystem, distance_mm: f64) -> FovResult {
         horizontal_ppm,
         vertical_ppm,
         distance_m,
+        horizontal_ifov_urad,
+        vertical_ifov_urad,
+        diagonal_fov_deg,
+        horizontal_px_per_deg,
+        vertical_px_per_deg,
+        horizontal_ppf,
+        vertical_ppf,
+        megapixels_per_m2,
         dori: Some(dori),
+        angle_of_view: Some(classify_angle_of_view(camera)),
     }
 }
 
+/// Solve the working distance at which a camera covers a given scene size
+///
+/// The inverse of [`calculate_fov`]: distance = scene_size × focal_length / sensor_size.
+/// When both width and height are given the camera is placed far enough back to
+/// cover both, so one axis will usually have margin to spare.
+///
+/// # Arguments
+/// * `camera` - The camera system specification
+/// * `required_fov_m` - Scene width and/or height to cover in meters
+///
+/// # Returns
+/// The FOV result at the solved distance (including px/m there), or `None` if no
+/// positive scene size was given
+pub fn solve_working_distance(
+    camera: &CameraSystem,
+    required_fov_m: &RequiredFov,
+) -> Option<FovResult> {
+    let by_width = required_fov_m
+        .width_m
+        .map(|width_m| width_m * camera.focal_length_mm / camera.sensor_width_mm);
+    let by_height = required_fov_m
+        .height_m
+        .map(|height_m| height_m * camera.focal_length_mm / camera.sensor_height_mm);
+
+    let distance_m = by_width
+        .into_iter()
+        .chain(by_height)
+        .filter(|d| *d > 0.0)
+        .fold(None, |acc: Option<f64>, d| {
+            Some(acc.map_or(d, |a| a.max(d)))
+        })?;
+
+    Some(calculate_fov(camera, distance_m * 1000.0))
+}
+
 /// Calculate DORI (Detection, Observation, Recognition, Identification) distances
 ///
 /// DORI is a standard metric for surveillance camera performance evaluation based on
@@ -67,23 +140,24 @@ pub fn calculate_fov(camera: &CameraSystem, distance_mm: f64) -> FovResult {
 /// DORI distances in meters for each surveillance task
 pub fn calculate_dori_distances(camera: &CameraSystem) -> DoriDistances {
     // Formula: distance = (focal_length × pixel_width) / (sensor_width × required_px_per_m)
-    // This gives the maximum distance at which the required pixel density is achieved
+    // This gives the maximum distance at which the required pixel density is achieved.
+    // DORI densities are defined horizontally, so only the horizontal pixel pitch counts
+    // and non-square pixels do not shorten the distances
 
     use super::constants::{
         DETECTION_PX_PER_M, IDENTIFICATION_PX_PER_M, OBSERVATION_PX_PER_M, RECOGNITION_PX_PER_M,
     };
 
-    let detection_m = (camera.focal_length_mm * camera.pixel_width as f64)
-        / (camera.sensor_width_mm * DETECTION_PX_PER_M);
+    let (h_pitch_um, _) = camera.pixel_pitch_um();
+    let pitch_mm = h_pitch_um / 1000.0;
+
+    let detection_m = camera.focal_length_mm / (pitch_mm * DETECTION_PX_PER_M);
 
-    let observation_m = (camera.focal_length_mm * camera.pixel_width as f64)
-        / (camera.sensor_width_mm * OBSERVATION_PX_PER_M);
+    let observation_m = camera.focal_length_mm / (pitch_mm * OBSERVATION_PX_PER_M);
 
-    let recognition_m = (camera.focal_length_mm * camera.pixel_width as f64)
-        / (camera.sensor_width_mm * RECOGNITION_PX_PER_M);
+    let recognition_m = camera.focal_length_mm / (pitch_mm * RECOGNITION_PX_PER_M);
 
-    let identification_m = (camera.focal_length_mm * camera.pixel_width as f64)
-        / (camera.sensor_width_mm * IDENTIFICATION_PX_PER_M);
+    let identification_m = camera.focal_length_mm / (pitch_mm * IDENTIFICATION_PX_PER_M);
 
     DoriDistances {
         detection_m,
@@ -137,6 +211,20 @@ pub fn calculate_dori_from_single(distance_m: f64, dori_type: &str) -> DoriDista
     }
 }
 
+/// Check DORI targets and constraints before solving for parameter ranges
+///
+/// Reports a missing target, zero or negative values, FOVs outside 0-180° and fixed
+/// sensor and pixel widths giving an impossible pixel pitch. The solver should only
+/// be run when none of the warnings is an error.
+pub fn validate_dori_request(
+    targets: &super::types::DoriTargets,
+    constraints: &super::types::ParameterConstraint,
+) -> Vec<ValidationWarning> {
+    let mut warnings = targets.validate();
+    warnings.extend(constraints.validate());
+    warnings
+}
+
 /// Calculate ranges of camera parameters that satisfy given DORI distance requirements
 ///
 /// This is the inverse of calculate_dori_distances - given target distances, find what
@@ -150,40 +238,48 @@ pub fn calculate_dori_from_single(distance_m: f64, dori_type: &str) -> DoriDista
 /// * `constraints` - Fixed parameters that narrow the solution space
 ///
 /// # Returns
-/// Ranges for unconstrained parameters that satisfy the requirements
+/// Ranges for unconstrained parameters that satisfy the requirements, or the errors
+/// from [`validate_dori_request`] when the request cannot be solved
 pub fn calculate_dori_parameter_ranges(
     targets: &super::types::DoriTargets,
     constraints: &super::types::ParameterConstraint,
-) -> super::types::DoriParameterRanges {
-    use super::types::{DoriParameterRanges, ParameterRange};
+) -> Result<super::types::DoriParameterRanges, Vec<ValidationWarning>> {
+    calculate_dori_parameter_ranges_with_bounds(targets, constraints, &SolverBounds::default())
+}
 
-    // Standard DORI pixel density requirements
-    const DETECTION_PX_PER_M: f64 = 25.0;
-    const OBSERVATION_PX_PER_M: f64 = 62.5;
-    const RECOGNITION_PX_PER_M: f64 = 125.0;
-    const IDENTIFICATION_PX_PER_M: f64 = 250.0;
+/// Calculate DORI parameter ranges within custom bounds on each parameter
+///
+/// Same as [`calculate_dori_parameter_ranges`], but with the plausible pixel, sensor
+/// and focal length bounds replaced, e.g. for 150 MP sensors or 1000 mm lenses.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(?targets, ?constraints))
+)]
+pub fn calculate_dori_parameter_ranges_with_bounds(
+    targets: &super::types::DoriTargets,
+    constraints: &super::types::ParameterConstraint,
+    bounds: &SolverBounds,
+) -> Result<super::types::DoriParameterRanges, Vec<ValidationWarning>> {
+    use super::types::{DoriParameterRanges, ParameterRange};
 
-    // Reasonable parameter bounds
-    const MIN_PIXEL_WIDTH: u32 = 640;
-    const MAX_PIXEL_WIDTH: u32 = 8192;
-    const MIN_SENSOR_WIDTH_MM: f64 = 3.0;
-    const MAX_SENSOR_WIDTH_MM: f64 = 50.0;
-    const MIN_FOCAL_LENGTH_MM: f64 = 2.0;
-    const MAX_FOCAL_LENGTH_MM: f64 = 400.0;
-
-    // Pick the first specified DORI target (prefer identification as most common/restrictive)
-    // Since DORI values maintain fixed ratios, any single target defines all others
-    let (target_distance, required_px_per_m) = if let Some(id) = targets.identification_m {
-        (id, IDENTIFICATION_PX_PER_M)
-    } else if let Some(rec) = targets.recognition_m {
-        (rec, RECOGNITION_PX_PER_M)
-    } else if let Some(obs) = targets.observation_m {
-        (obs, OBSERVATION_PX_PER_M)
-    } else if let Some(det) = targets.detection_m {
-        (det, DETECTION_PX_PER_M)
-    } else {
-        panic!("At least one DORI target must be specified");
+    let errors: Vec<ValidationWarning> = validate_dori_request(targets, constraints)
+        .into_iter()
+        .filter(|warning| warning.severity == ValidationSeverity::Error)
+        .collect();
+    let constraints = &with_horizontal_fov(constraints, bounds);
+
+    // Every target is a lower bound on distance × pixel density, so the strictest one
+    // alone defines the intersection of their feasible ranges
+    let governing = match reconcile_dori_targets(targets) {
+        Some(governing) if errors.is_empty() => governing,
+        _ => return Err(errors),
     };
+    let (target_distance, target_level) = (governing.distance_m, governing.level);
+    let required_px_per_m = target_level.px_per_m();
+    let target = RangeLimit::DoriTarget(target_level);
+    let pixel_bounds = RangeLimit::ParameterBounds(RangeParameter::PixelWidth);
+    let sensor_bounds = RangeLimit::ParameterBounds(RangeParameter::SensorWidth);
+    let focal_bounds = RangeLimit::ParameterBounds(RangeParameter::FocalLength);
 
     // Calculate ranges based on what's constrained
     let mut ranges = DoriParameterRanges {
@@ -193,7 +289,25 @@ pub fn calculate_dori_parameter_ranges(
         pixel_height: None,
         focal_length_mm: None,
         horizontal_fov_deg: None,
+        inconsistency: None,
+        binding_constraints: Vec::new(),
+        standard_resolutions: Vec::new(),
+        standard_lenses: Vec::new(),
+        standard_sensor_formats: Vec::new(),
+        governing_target: Some(governing),
+        infeasible_parameters: Vec::new(),
     };
+    let mut binding = Vec::new();
+
+    // With FOV, focal length and sensor width all fixed, one of them is recomputed below;
+    // report when the given values disagree rather than overriding one silently
+    if let (Some(fov_deg), Some(focal), Some(sensor_w)) = (
+        constraints.horizontal_fov_deg,
+        constraints.focal_length_mm,
+        constraints.sensor_width_mm,
+    ) {
+        ranges.inconsistency = check_fov_consistency(fov_deg, focal, sensor_w);
+    }
 
     // Helper function to calculate FOV from sensor width and focal length
     let calc_fov_deg = |sensor_mm: f64, focal_mm: f64| -> f64 {
@@ -215,7 +329,13 @@ pub fn calculate_dori_parameter_ranges(
             ranges.sensor_width_mm = Some(ParameterRange {
                 min: sensor_w,
                 max: sensor_w,
+                recommended: None,
             });
+            binding.push(bind(
+                RangeParameter::SensorWidth,
+                RangeLimit::FovConstraint,
+                RangeLimit::FovConstraint,
+            ));
 
             // Now calculate pixel width range based on fixed focal and sensor
             if let Some(_pixels) = constraints.pixel_width {
@@ -225,13 +345,24 @@ pub fn calculate_dori_parameter_ranges(
             } else {
                 // Calculate pixel width range
                 let required_product = target_distance * sensor_w * required_px_per_m / focal;
-                let min_pixels = required_product.max(MIN_PIXEL_WIDTH as f64);
-                let max_pixels = MAX_PIXEL_WIDTH as f64;
+                let min_pixels = required_product.max(bounds.min_pixel_width as f64);
+                let max_pixels = bounds.max_pixel_width as f64;
 
                 ranges.pixel_width = Some(ParameterRange {
                     min: min_pixels,
                     max: max_pixels,
+                    recommended: None,
                 });
+                binding.push(bind(
+                    RangeParameter::PixelWidth,
+                    lower_limit(
+                        required_product,
+                        bounds.min_pixel_width as f64,
+                        target,
+                        pixel_bounds,
+                    ),
+                    pixel_bounds,
+                ));
             }
         } else if let Some(sensor_w) = constraints.sensor_width_mm {
             // FOV and sensor are fixed - focal is determined
@@ -241,7 +372,13 @@ pub fn calculate_dori_parameter_ranges(
             ranges.focal_length_mm = Some(ParameterRange {
                 min: focal,
                 max: focal,
+                recommended: None,
             });
+            binding.push(bind(
+                RangeParameter::FocalLength,
+                RangeLimit::FovConstraint,
+                RangeLimit::FovConstraint,
+            ));
 
             if let Some(_pixels) = constraints.pixel_width {
                 // All three fixed - focal is determined, others are inputs
@@ -250,13 +387,24 @@ pub fn calculate_dori_parameter_ranges(
             } else {
                 // Calculate pixel width range
                 let required_product = target_distance * sensor_w * required_px_per_m / focal;
-                let min_pixels = required_product.max(MIN_PIXEL_WIDTH as f64);
-                let max_pixels = MAX_PIXEL_WIDTH as f64;
+                let min_pixels = required_product.max(bounds.min_pixel_width as f64);
+                let max_pixels = bounds.max_pixel_width as f64;
 
                 ranges.pixel_width = Some(ParameterRange {
                     min: min_pixels,
                     max: max_pixels,
+                    recommended: None,
                 });
+                binding.push(bind(
+                    RangeParameter::PixelWidth,
+                    lower_limit(
+                        required_product,
+                        bounds.min_pixel_width as f64,
+                        target,
+                        pixel_bounds,
+                    ),
+                    pixel_bounds,
+                ));
             }
         } else if let Some(_pixels) = constraints.pixel_width {
             // FOV and pixels are fixed - calculate constrained focal and sensor
@@ -267,16 +415,38 @@ pub fn calculate_dori_parameter_ranges(
             // This means focal cancels out, so we can pick focal range and derive sensor
             // But we need to constrain focal so sensor stays within physical limits
 
-            let min_focal_for_min_sensor = MIN_SENSOR_WIDTH_MM / (2.0 * tan_half_fov);
-            let max_focal_for_max_sensor = MAX_SENSOR_WIDTH_MM / (2.0 * tan_half_fov);
+            let min_focal_for_min_sensor = bounds.min_sensor_width_mm / (2.0 * tan_half_fov);
+            let max_focal_for_max_sensor = bounds.max_sensor_width_mm / (2.0 * tan_half_fov);
 
-            let min_focal = min_focal_for_min_sensor.max(MIN_FOCAL_LENGTH_MM);
-            let max_focal = max_focal_for_max_sensor.min(MAX_FOCAL_LENGTH_MM);
+            let min_focal = min_focal_for_min_sensor.max(bounds.min_focal_length_mm);
+            let max_focal = max_focal_for_max_sensor.min(bounds.max_focal_length_mm);
 
             ranges.focal_length_mm = Some(ParameterRange {
                 min: min_focal,
                 max: max_focal,
+                recommended: None,
             });
+            let focal_limits = bind(
+                RangeParameter::FocalLength,
+                lower_limit(
+                    min_focal_for_min_sensor,
+                    bounds.min_focal_length_mm,
+                    sensor_bounds,
+                    focal_bounds,
+                ),
+                upper_limit(
+                    max_focal_for_max_sensor,
+                    bounds.max_focal_length_mm,
+                    sensor_bounds,
+                    focal_bounds,
+                ),
+            );
+            // Sensor follows from focal through the FOV, so the same bounds limit it
+            binding.push(BindingConstraint {
+                parameter: RangeParameter::SensorWidth,
+                ..focal_limits.clone()
+            });
+            binding.push(focal_limits);
 
             // Sensor is determined by FOV and focal
             let min_sensor = 2.0 * min_focal * tan_half_fov;
@@ -285,6 +455,7 @@ pub fn calculate_dori_parameter_ranges(
             ranges.sensor_width_mm = Some(ParameterRange {
                 min: min_sensor,
                 max: max_sensor,
+                recommended: None,
             });
         } else {
             // Only FOV is fixed - give ranges for focal, sensor follows from FOV
@@ -292,17 +463,39 @@ pub fn calculate_dori_parameter_ranges(
             // sensor = 2 × focal × tan(FOV/2)
             // Therefore: focal = sensor / (2 × tan(FOV/2))
 
-            let min_focal_for_min_sensor = MIN_SENSOR_WIDTH_MM / (2.0 * tan_half_fov);
-            let max_focal_for_max_sensor = MAX_SENSOR_WIDTH_MM / (2.0 * tan_half_fov);
+            let min_focal_for_min_sensor = bounds.min_sensor_width_mm / (2.0 * tan_half_fov);
+            let max_focal_for_max_sensor = bounds.max_sensor_width_mm / (2.0 * tan_half_fov);
 
             // Constrain focal range to stay within both focal and sensor limits
-            let min_focal = min_focal_for_min_sensor.max(MIN_FOCAL_LENGTH_MM);
-            let max_focal = max_focal_for_max_sensor.min(MAX_FOCAL_LENGTH_MM);
+            let min_focal = min_focal_for_min_sensor.max(bounds.min_focal_length_mm);
+            let max_focal = max_focal_for_max_sensor.min(bounds.max_focal_length_mm);
 
             ranges.focal_length_mm = Some(ParameterRange {
                 min: min_focal,
                 max: max_focal,
+                recommended: None,
+            });
+            let focal_limits = bind(
+                RangeParameter::FocalLength,
+                lower_limit(
+                    min_focal_for_min_sensor,
+                    bounds.min_focal_length_mm,
+                    sensor_bounds,
+                    focal_bounds,
+                ),
+                upper_limit(
+                    max_focal_for_max_sensor,
+                    bounds.max_focal_length_mm,
+                    sensor_bounds,
+                    focal_bounds,
+                ),
+            );
+            // Sensor follows from focal through the FOV, so the same bounds limit it
+            binding.push(BindingConstraint {
+                parameter: RangeParameter::SensorWidth,
+                ..focal_limits.clone()
             });
+            binding.push(focal_limits);
 
             // Now sensor is correctly determined by constrained focal and FOV
             let min_sensor = 2.0 * min_focal * tan_half_fov;
@@ -311,6 +504,7 @@ pub fn calculate_dori_parameter_ranges(
             ranges.sensor_width_mm = Some(ParameterRange {
                 min: min_sensor,
                 max: max_sensor,
+                recommended: None,
             });
 
             // Calculate pixel width range based on FOV constraint
@@ -321,49 +515,27 @@ pub fn calculate_dori_parameter_ranges(
             // Therefore: pixels = distance × 2 × tan(FOV/2) × px_per_m
 
             let calculated_pixels = target_distance * 2.0 * tan_half_fov * required_px_per_m;
-            let min_pixels = calculated_pixels.max(MIN_PIXEL_WIDTH as f64);
-            let max_pixels = MAX_PIXEL_WIDTH as f64;
+            let min_pixels = calculated_pixels.max(bounds.min_pixel_width as f64);
+            let max_pixels = bounds.max_pixel_width as f64;
 
             ranges.pixel_width = Some(ParameterRange {
                 min: min_pixels,
                 max: max_pixels,
+                recommended: None,
             });
+            binding.push(bind(
+                RangeParameter::PixelWidth,
+                lower_limit(
+                    calculated_pixels,
+                    bounds.min_pixel_width as f64,
+                    target,
+                    pixel_bounds,
+                ),
+                pixel_bounds,
+            ));
         }
 
-        // Calculate height dimensions before returning (FOV branch exits early)
-        const STANDARD_ASPECT_RATIO: f64 = 4.0 / 3.0;
-
-        if constraints.sensor_height_mm.is_none() {
-            if let Some(sensor_width_range) = &ranges.sensor_width_mm {
-                ranges.sensor_height_mm = Some(ParameterRange {
-                    min: sensor_width_range.min / STANDARD_ASPECT_RATIO,
-                    max: sensor_width_range.max / STANDARD_ASPECT_RATIO,
-                });
-            } else if let Some(sensor_w) = constraints.sensor_width_mm {
-                let sensor_h = sensor_w / STANDARD_ASPECT_RATIO;
-                ranges.sensor_height_mm = Some(ParameterRange {
-                    min: sensor_h,
-                    max: sensor_h,
-                });
-            }
-        }
-
-        if constraints.pixel_height.is_none() {
-            if let Some(pixel_width_range) = &ranges.pixel_width {
-                ranges.pixel_height = Some(ParameterRange {
-                    min: pixel_width_range.min / STANDARD_ASPECT_RATIO,
-                    max: pixel_width_range.max / STANDARD_ASPECT_RATIO,
-                });
-            } else if let Some(pixels_w) = constraints.pixel_width {
-                let pixels_h = pixels_w as f64 / STANDARD_ASPECT_RATIO;
-                ranges.pixel_height = Some(ParameterRange {
-                    min: pixels_h,
-                    max: pixels_h,
-                });
-            }
-        }
-
-        return ranges; // FOV is fixed, so we handle it completely here
+        return Ok(finish_ranges(ranges, binding, constraints, bounds)); // FOV is fixed, so we handle it completely here
     }
 
     // If focal length is fixed, calculate pixel width and sensor width ranges
@@ -371,17 +543,37 @@ pub fn calculate_dori_parameter_ranges(
         if let Some(sensor_w) = constraints.sensor_width_mm {
             // Both focal and sensor are fixed - calculate pixel width range and FOV
             let required_product = target_distance * sensor_w * required_px_per_m / focal;
-            let min_pixels = required_product.max(MIN_PIXEL_WIDTH as f64);
-            let max_pixels = MAX_PIXEL_WIDTH as f64;
+            let min_pixels = required_product.max(bounds.min_pixel_width as f64);
+            let max_pixels = bounds.max_pixel_width as f64;
 
             ranges.pixel_width = Some(ParameterRange {
                 min: min_pixels,
                 max: max_pixels,
+                recommended: None,
             });
+            binding.push(bind(
+                RangeParameter::PixelWidth,
+                lower_limit(
+                    required_product,
+                    bounds.min_pixel_width as f64,
+                    target,
+                    pixel_bounds,
+                ),
+                pixel_bounds,
+            ));
 
             // Calculate determined FOV value
             let fov = calc_fov_deg(sensor_w, focal);
-            ranges.horizontal_fov_deg = Some(ParameterRange { min: fov, max: fov });
+            ranges.horizontal_fov_deg = Some(ParameterRange {
+                min: fov,
+                max: fov,
+                recommended: None,
+            });
+            binding.push(bind(
+                RangeParameter::HorizontalFov,
+                RangeLimit::FixedParameters,
+                RangeLimit::FixedParameters,
+            ));
 
             // Calculate aspect ratio constraint for pixel height
             if let Some(sensor_h) = constraints.sensor_height_mm {
@@ -389,6 +581,7 @@ pub fn calculate_dori_parameter_ranges(
                 ranges.pixel_height = Some(ParameterRange {
                     min: min_pixels * aspect,
                     max: max_pixels * aspect,
+                    recommended: None,
                 });
             }
         } else if let Some(pixels) = constraints.pixel_width {
@@ -399,17 +592,27 @@ pub fn calculate_dori_parameter_ranges(
             ranges.sensor_width_mm = Some(ParameterRange {
                 min: sensor,
                 max: sensor,
+                recommended: None,
             });
+            binding.push(bind(RangeParameter::SensorWidth, target, target));
         } else {
             // Only focal is fixed - give ranges for both sensor and pixels
             ranges.sensor_width_mm = Some(ParameterRange {
-                min: MIN_SENSOR_WIDTH_MM,
-                max: MAX_SENSOR_WIDTH_MM,
+                min: bounds.min_sensor_width_mm,
+                max: bounds.max_sensor_width_mm,
+                recommended: None,
             });
             ranges.pixel_width = Some(ParameterRange {
-                min: MIN_PIXEL_WIDTH as f64,
-                max: MAX_PIXEL_WIDTH as f64,
+                min: bounds.min_pixel_width as f64,
+                max: bounds.max_pixel_width as f64,
+                recommended: None,
             });
+            binding.push(bind(
+                RangeParameter::SensorWidth,
+                sensor_bounds,
+                sensor_bounds,
+            ));
+            binding.push(bind(RangeParameter::PixelWidth, pixel_bounds, pixel_bounds));
         }
     } else if let Some(sensor_w) = constraints.sensor_width_mm {
         // Sensor width is fixed but focal isn't
@@ -418,19 +621,33 @@ pub fn calculate_dori_parameter_ranges(
             let min_focal = (target_distance * sensor_w * required_px_per_m) / pixels as f64;
 
             ranges.focal_length_mm = Some(ParameterRange {
-                min: min_focal.max(MIN_FOCAL_LENGTH_MM),
-                max: MAX_FOCAL_LENGTH_MM,
+                min: min_focal.max(bounds.min_focal_length_mm),
+                max: bounds.max_focal_length_mm,
+                recommended: None,
             });
+            binding.push(bind(
+                RangeParameter::FocalLength,
+                lower_limit(min_focal, bounds.min_focal_length_mm, target, focal_bounds),
+                focal_bounds,
+            ));
         } else {
             // Only sensor is fixed - give ranges for focal and pixels
             ranges.focal_length_mm = Some(ParameterRange {
-                min: MIN_FOCAL_LENGTH_MM,
-                max: MAX_FOCAL_LENGTH_MM,
+                min: bounds.min_focal_length_mm,
+                max: bounds.max_focal_length_mm,
+                recommended: None,
             });
             ranges.pixel_width = Some(ParameterRange {
-                min: MIN_PIXEL_WIDTH as f64,
-                max: MAX_PIXEL_WIDTH as f64,
+                min: bounds.min_pixel_width as f64,
+                max: bounds.max_pixel_width as f64,
+                recommended: None,
             });
+            binding.push(bind(
+                RangeParameter::FocalLength,
+                focal_bounds,
+                focal_bounds,
+            ));
+            binding.push(bind(RangeParameter::PixelWidth, pixel_bounds, pixel_bounds));
         }
     } else if let Some(pixels) = constraints.pixel_width {
         // Only pixels are fixed - calculate constrained ranges for focal and sensor
@@ -439,40 +656,78 @@ pub fn calculate_dori_parameter_ranges(
         // Therefore: focal = (distance × sensor × px_per_m) / pixels
 
         // For minimum focal length, use minimum sensor width
-        let min_focal = (target_distance * MIN_SENSOR_WIDTH_MM * required_px_per_m) / pixels as f64;
+        let min_focal =
+            (target_distance * bounds.min_sensor_width_mm * required_px_per_m) / pixels as f64;
         // For maximum focal length, use maximum sensor width
-        let max_focal = (target_distance * MAX_SENSOR_WIDTH_MM * required_px_per_m) / pixels as f64;
+        let max_focal =
+            (target_distance * bounds.max_sensor_width_mm * required_px_per_m) / pixels as f64;
 
         ranges.focal_length_mm = Some(ParameterRange {
-            min: min_focal.max(MIN_FOCAL_LENGTH_MM),
-            max: max_focal.min(MAX_FOCAL_LENGTH_MM),
+            min: min_focal.max(bounds.min_focal_length_mm),
+            max: max_focal.min(bounds.max_focal_length_mm),
+            recommended: None,
         });
+        binding.push(bind(
+            RangeParameter::FocalLength,
+            lower_limit(min_focal, bounds.min_focal_length_mm, target, focal_bounds),
+            upper_limit(max_focal, bounds.max_focal_length_mm, target, focal_bounds),
+        ));
 
         // For minimum sensor width, use minimum focal length
         let min_sensor =
-            (MIN_FOCAL_LENGTH_MM * pixels as f64) / (target_distance * required_px_per_m);
+            (bounds.min_focal_length_mm * pixels as f64) / (target_distance * required_px_per_m);
         // For maximum sensor width, use maximum focal length
         let max_sensor =
-            (MAX_FOCAL_LENGTH_MM * pixels as f64) / (target_distance * required_px_per_m);
+            (bounds.max_focal_length_mm * pixels as f64) / (target_distance * required_px_per_m);
 
         ranges.sensor_width_mm = Some(ParameterRange {
-            min: min_sensor.max(MIN_SENSOR_WIDTH_MM),
-            max: max_sensor.min(MAX_SENSOR_WIDTH_MM),
+            min: min_sensor.max(bounds.min_sensor_width_mm),
+            max: max_sensor.min(bounds.max_sensor_width_mm),
+            recommended: None,
         });
+        binding.push(bind(
+            RangeParameter::SensorWidth,
+            lower_limit(
+                min_sensor,
+                bounds.min_sensor_width_mm,
+                target,
+                sensor_bounds,
+            ),
+            upper_limit(
+                max_sensor,
+                bounds.max_sensor_width_mm,
+                target,
+                sensor_bounds,
+            ),
+        ));
     } else {
         // Nothing is fixed - give all ranges
         ranges.focal_length_mm = Some(ParameterRange {
-            min: MIN_FOCAL_LENGTH_MM,
-            max: MAX_FOCAL_LENGTH_MM,
+            min: bounds.min_focal_length_mm,
+            max: bounds.max_focal_length_mm,
+            recommended: None,
         });
         ranges.sensor_width_mm = Some(ParameterRange {
-            min: MIN_SENSOR_WIDTH_MM,
-            max: MAX_SENSOR_WIDTH_MM,
+            min: bounds.min_sensor_width_mm,
+            max: bounds.max_sensor_width_mm,
+            recommended: None,
         });
         ranges.pixel_width = Some(ParameterRange {
-            min: MIN_PIXEL_WIDTH as f64,
-            max: MAX_PIXEL_WIDTH as f64,
+            min: bounds.min_pixel_width as f64,
+            max: bounds.max_pixel_width as f64,
+            recommended: None,
         });
+        binding.push(bind(
+            RangeParameter::FocalLength,
+            focal_bounds,
+            focal_bounds,
+        ));
+        binding.push(bind(
+            RangeParameter::SensorWidth,
+            sensor_bounds,
+            sensor_bounds,
+        ));
+        binding.push(bind(RangeParameter::PixelWidth, pixel_bounds, pixel_bounds));
     }
 
     // Calculate FOV range if not constrained
@@ -489,7 +744,15 @@ pub fn calculate_dori_parameter_ranges(
             ranges.horizontal_fov_deg = Some(ParameterRange {
                 min: min_fov,
                 max: max_fov,
+                recommended: None,
             });
+            // The focal length end is reported, as it usually spans the wider ratio
+            let focal_limits = limits_of(&binding, RangeParameter::FocalLength);
+            binding.push(bind(
+                RangeParameter::HorizontalFov,
+                focal_limits.max_limited_by,
+                focal_limits.min_limited_by,
+            ));
         } else if let Some(focal) = constraints.focal_length_mm {
             // Focal is fixed, sensor has range
             if let Some(sensor_range) = &ranges.sensor_width_mm {
@@ -499,6 +762,12 @@ pub fn calculate_dori_parameter_ranges(
                 ranges.horizontal_fov_deg = Some(ParameterRange {
                     min: min_fov,
                     max: max_fov,
+                    recommended: None,
+                });
+                let sensor_limits = limits_of(&binding, RangeParameter::SensorWidth);
+                binding.push(BindingConstraint {
+                    parameter: RangeParameter::HorizontalFov,
+                    ..sensor_limits
                 });
             }
         } else if let Some(sensor_w) = constraints.sensor_width_mm {
@@ -510,58 +779,514 @@ pub fn calculate_dori_parameter_ranges(
                 ranges.horizontal_fov_deg = Some(ParameterRange {
                     min: min_fov,
                     max: max_fov,
+                    recommended: None,
                 });
+                let focal_limits = limits_of(&binding, RangeParameter::FocalLength);
+                binding.push(bind(
+                    RangeParameter::HorizontalFov,
+                    focal_limits.max_limited_by,
+                    focal_limits.min_limited_by,
+                ));
             }
         }
     }
 
-    // Calculate sensor_height and pixel_height based on standard 4:3 aspect ratio
-    // if not already constrained
-    const STANDARD_ASPECT_RATIO: f64 = 4.0 / 3.0;
+    Ok(finish_ranges(ranges, binding, constraints, bounds))
+}
 
-    if constraints.sensor_height_mm.is_none() {
-        if let Some(sensor_width_range) = &ranges.sensor_width_mm {
-            ranges.sensor_height_mm = Some(ParameterRange {
-                min: sensor_width_range.min / STANDARD_ASPECT_RATIO,
-                max: sensor_width_range.max / STANDARD_ASPECT_RATIO,
-            });
-        } else if let Some(sensor_w) = constraints.sensor_width_mm {
-            // Width is fixed, calculate height
-            let sensor_h = sensor_w / STANDARD_ASPECT_RATIO;
-            ranges.sensor_height_mm = Some(ParameterRange {
-                min: sensor_h,
-                max: sensor_h,
-            });
+/// Attach heights, range limits, recommended values and standard parts to the ranges
+fn finish_ranges(
+    mut ranges: super::types::DoriParameterRanges,
+    binding: Vec<BindingConstraint>,
+    constraints: &super::types::ParameterConstraint,
+    bounds: &SolverBounds,
+) -> super::types::DoriParameterRanges {
+    fill_heights(
+        &mut ranges,
+        constraints,
+        implied_aspect_ratio(constraints, bounds),
+    );
+    ranges.binding_constraints = with_height_limits(binding, constraints);
+    recommend_range_values(&mut ranges, constraints);
+    if let Some(pixel_width) = &ranges.pixel_width {
+        ranges.standard_resolutions = standard_resolutions_in(pixel_width);
+    }
+    if let Some(focal_length) = &ranges.focal_length_mm {
+        ranges.standard_lenses = standard_lenses_in(focal_length);
+    }
+    if let Some(sensor_width) = &ranges.sensor_width_mm {
+        ranges.standard_sensor_formats = sensor_formats_in(sensor_width);
+    }
+    ranges.infeasible_parameters = [
+        (&ranges.sensor_width_mm, RangeParameter::SensorWidth),
+        (&ranges.sensor_height_mm, RangeParameter::SensorHeight),
+        (&ranges.pixel_width, RangeParameter::PixelWidth),
+        (&ranges.pixel_height, RangeParameter::PixelHeight),
+        (&ranges.focal_length_mm, RangeParameter::FocalLength),
+        (&ranges.horizontal_fov_deg, RangeParameter::HorizontalFov),
+    ]
+    .into_iter()
+    .filter(|(range, _)| range.as_ref().is_some_and(|range| range.min > range.max))
+    .map(|(_, parameter)| parameter)
+    .collect();
+    ranges
+}
+
+/// Find the DORI target that sets the requirement when several are given
+///
+/// Each target needs distance × pixel density; the largest product is the strictest
+/// and governs, with ties going to the higher level. Targets whose product falls short
+/// of it by more than the tolerance do not follow the standard DORI ratios and are
+/// exceeded by any camera meeting the governing one. Returns `None` when no target
+/// is given.
+pub fn reconcile_dori_targets(targets: &super::types::DoriTargets) -> Option<GoverningTarget> {
+    use super::constants::DORI_TARGET_RATIO_TOLERANCE_PERCENT;
+
+    let given: Vec<(DoriLevel, f64)> = DoriLevel::ALL
+        .into_iter()
+        .filter_map(|level| Some((level, targets.distance_m(level)?)))
+        .collect();
+    // max_by keeps the last of equal elements, and levels run from detection up
+    let &(level, distance_m) = given.iter().max_by(|(a_level, a_m), (b_level, b_m)| {
+        (a_m * a_level.px_per_m()).total_cmp(&(b_m * b_level.px_per_m()))
+    })?;
+
+    let required = distance_m * level.px_per_m();
+    let exceeded = given
+        .iter()
+        .filter(|(other, other_m)| {
+            other_m * other.px_per_m()
+                < required * (1.0 - DORI_TARGET_RATIO_TOLERANCE_PERCENT / 100.0)
+        })
+        .map(|&(other, _)| other)
+        .collect();
+
+    Some(GoverningTarget {
+        level,
+        distance_m,
+        exceeded,
+    })
+}
+
+/// Standard optical formats whose width lies inside a sensor width range
+pub fn sensor_formats_in(range: &super::types::ParameterRange) -> Vec<SensorFormat> {
+    use super::constants::STANDARD_SENSOR_FORMATS;
+
+    STANDARD_SENSOR_FORMATS
+        .iter()
+        .filter(|&&(_, width, _)| (range.min..=range.max).contains(&width))
+        .map(|&(name, sensor_width_mm, sensor_height_mm)| SensorFormat {
+            name: name.to_string(),
+            sensor_width_mm,
+            sensor_height_mm,
+        })
+        .collect()
+}
+
+/// Standard fixed lenses inside a focal length range, then varifocal lenses overlapping it
+pub fn standard_lenses_in(range: &super::types::ParameterRange) -> Vec<StandardLens> {
+    use super::constants::{STANDARD_FOCAL_LENGTHS_MM, STANDARD_VARIFOCAL_RANGES_MM};
+
+    let fixed = STANDARD_FOCAL_LENGTHS_MM
+        .iter()
+        .filter(|&&focal| (range.min..=range.max).contains(&focal))
+        .map(|&focal| StandardLens {
+            name: format!("{} mm", focal),
+            min_focal_length_mm: focal,
+            max_focal_length_mm: focal,
+            varifocal: false,
+        });
+    let varifocal = STANDARD_VARIFOCAL_RANGES_MM
+        .iter()
+        .filter(|&&(wide, tele)| wide <= range.max && tele >= range.min)
+        .map(|&(wide, tele)| StandardLens {
+            name: format!("{}-{} mm", wide, tele),
+            min_focal_length_mm: wide,
+            max_focal_length_mm: tele,
+            varifocal: true,
+        });
+    fixed.chain(varifocal).collect()
+}
+
+/// Constraints with a vertical or diagonal FOV turned into the equivalent horizontal FOV
+///
+/// tan(h/2) = tan(v/2) × aspect and tan(h/2) = tan(d/2) × aspect / √(1 + aspect²).
+/// A given horizontal FOV takes precedence.
+fn with_horizontal_fov(
+    constraints: &super::types::ParameterConstraint,
+    bounds: &SolverBounds,
+) -> super::types::ParameterConstraint {
+    let aspect_ratio = implied_aspect_ratio(constraints, bounds);
+    let tan_half_horizontal = if constraints.horizontal_fov_deg.is_some() {
+        None
+    } else if let Some(vertical) = constraints.vertical_fov_deg {
+        Some((vertical.to_radians() / 2.0).tan() * aspect_ratio)
+    } else {
+        constraints.diagonal_fov_deg.map(|diagonal| {
+            (diagonal.to_radians() / 2.0).tan() * aspect_ratio
+                / (1.0 + aspect_ratio * aspect_ratio).sqrt()
+        })
+    };
+
+    let mut constraints = constraints.clone();
+    if let Some(tan_half) = tan_half_horizontal {
+        constraints.horizontal_fov_deg = Some(2.0 * tan_half.atan().to_degrees());
+    }
+    constraints
+}
+
+/// Width-to-height ratio of the constrained camera
+///
+/// A fixed pixel width and height give the ratio of the image, else a fixed sensor
+/// width and height, else the solver's default.
+fn implied_aspect_ratio(
+    constraints: &super::types::ParameterConstraint,
+    bounds: &SolverBounds,
+) -> f64 {
+    if let (Some(width), Some(height)) = (constraints.pixel_width, constraints.pixel_height) {
+        if height > 0 {
+            return width as f64 / height as f64;
+        }
+    }
+    if let (Some(width), Some(height)) = (constraints.sensor_width_mm, constraints.sensor_height_mm)
+    {
+        if height > 0.0 {
+            return width / height;
         }
     }
+    bounds.default_aspect_ratio
+}
 
-    if constraints.pixel_height.is_none() {
-        if let Some(pixel_width_range) = &ranges.pixel_width {
-            ranges.pixel_height = Some(ParameterRange {
-                min: pixel_width_range.min / STANDARD_ASPECT_RATIO,
-                max: pixel_width_range.max / STANDARD_ASPECT_RATIO,
-            });
-        } else if let Some(pixels_w) = constraints.pixel_width {
+/// Derive sensor and pixel heights that are not constrained from their widths
+fn fill_heights(
+    ranges: &mut super::types::DoriParameterRanges,
+    constraints: &super::types::ParameterConstraint,
+    aspect_ratio: f64,
+) {
+    use super::types::ParameterRange;
+
+    let height_of = |width: &Option<ParameterRange>, fixed_width: Option<f64>| {
+        if let Some(width) = width {
+            Some(ParameterRange {
+                min: width.min / aspect_ratio,
+                max: width.max / aspect_ratio,
+                recommended: None,
+            })
+        } else {
             // Width is fixed, calculate height
-            let pixels_h = pixels_w as f64 / STANDARD_ASPECT_RATIO;
-            ranges.pixel_height = Some(ParameterRange {
-                min: pixels_h,
-                max: pixels_h,
+            fixed_width.map(|width| ParameterRange {
+                min: width / aspect_ratio,
+                max: width / aspect_ratio,
+                recommended: None,
+            })
+        }
+    };
+
+    if constraints.sensor_height_mm.is_none() {
+        ranges.sensor_height_mm = height_of(&ranges.sensor_width_mm, constraints.sensor_width_mm);
+    }
+    if constraints.pixel_height.is_none() {
+        ranges.pixel_height = height_of(
+            &ranges.pixel_width,
+            constraints.pixel_width.map(|width| width as f64),
+        );
+    }
+}
+
+/// Standard resolutions whose width lies inside a pixel width range
+pub fn standard_resolutions_in(range: &super::types::ParameterRange) -> Vec<StandardResolution> {
+    use super::constants::STANDARD_RESOLUTIONS;
+
+    STANDARD_RESOLUTIONS
+        .iter()
+        .filter(|&&(_, width, _)| (range.min..=range.max).contains(&(width as f64)))
+        .map(|&(name, pixel_width, pixel_height)| StandardResolution {
+            name: name.to_string(),
+            pixel_width,
+            pixel_height,
+        })
+        .collect()
+}
+
+/// Fill in a recommended value for each returned range
+///
+/// Sensor width, pixel width and focal length take a 20% margin over the minimum,
+/// snapped up to the next standard value that still fits the range. Heights keep
+/// the width's aspect ratio, and the FOV follows from the recommended sensor and lens.
+fn recommend_range_values(
+    ranges: &mut super::types::DoriParameterRanges,
+    constraints: &super::types::ParameterConstraint,
+) {
+    use super::constants::{
+        STANDARD_FOCAL_LENGTHS_MM, STANDARD_RESOLUTIONS, STANDARD_SENSOR_FORMATS,
+    };
+
+    let sensor_widths: Vec<f64> = STANDARD_SENSOR_FORMATS
+        .iter()
+        .map(|&(_, width, _)| width)
+        .collect();
+
+    let pixel_widths: Vec<f64> = STANDARD_RESOLUTIONS
+        .iter()
+        .map(|&(_, width, _)| width as f64)
+        .collect();
+
+    for (range, standard) in [
+        (&mut ranges.sensor_width_mm, &sensor_widths[..]),
+        (&mut ranges.pixel_width, &pixel_widths[..]),
+        (&mut ranges.focal_length_mm, STANDARD_FOCAL_LENGTHS_MM),
+    ] {
+        // An empty range has nothing to recommend
+        if let Some(range) = range.as_mut().filter(|range| range.min <= range.max) {
+            range.recommended = Some(recommend_in_range(range, standard));
+        }
+    }
+
+    for (height, width) in [
+        (&mut ranges.sensor_height_mm, &ranges.sensor_width_mm),
+        (&mut ranges.pixel_height, &ranges.pixel_width),
+    ] {
+        if let (Some(height), Some(width)) = (height, width) {
+            height.recommended = width
+                .recommended
+                .map(|w| (w * height.min / width.min).clamp(height.min, height.max));
+        }
+    }
+
+    let sensor_w = constraints
+        .sensor_width_mm
+        .or_else(|| ranges.sensor_width_mm.as_ref()?.recommended);
+    let focal = constraints
+        .focal_length_mm
+        .or_else(|| ranges.focal_length_mm.as_ref()?.recommended);
+    if let (Some(fov), Some(sensor_w), Some(focal)) = (
+        ranges
+            .horizontal_fov_deg
+            .as_mut()
+            .filter(|fov| fov.min <= fov.max),
+        sensor_w,
+        focal,
+    ) {
+        let recommended = 2.0 * (sensor_w / (2.0 * focal)).atan().to_degrees();
+        fov.recommended = Some(recommended.clamp(fov.min, fov.max));
+    }
+}
+
+/// A margin over the range's minimum, snapped up to a standard value inside the range
+///
+/// Falls back to the largest standard value in the range, then to the unsnapped margin.
+fn recommend_in_range(range: &super::types::ParameterRange, standard: &[f64]) -> f64 {
+    use super::constants::RECOMMENDED_RANGE_MARGIN;
+
+    let target = (range.min * RECOMMENDED_RANGE_MARGIN).min(range.max);
+    let in_range = |value: &&f64| **value >= range.min && **value <= range.max;
+    standard
+        .iter()
+        .filter(in_range)
+        .find(|&&value| value >= target)
+        .or_else(|| standard.iter().rev().find(in_range))
+        .copied()
+        .unwrap_or(target)
+}
+
+fn bind(
+    parameter: RangeParameter,
+    min_limited_by: RangeLimit,
+    max_limited_by: RangeLimit,
+) -> BindingConstraint {
+    BindingConstraint {
+        parameter,
+        min_limited_by,
+        max_limited_by,
+    }
+}
+
+/// The computed minimum unless it was raised to the parameter's plausible minimum
+fn lower_limit(computed: f64, bound: f64, limit: RangeLimit, bounds: RangeLimit) -> RangeLimit {
+    if computed >= bound {
+        limit
+    } else {
+        bounds
+    }
+}
+
+/// The computed maximum unless it was lowered to the parameter's plausible maximum
+fn upper_limit(computed: f64, bound: f64, limit: RangeLimit, bounds: RangeLimit) -> RangeLimit {
+    if computed <= bound {
+        limit
+    } else {
+        bounds
+    }
+}
+
+fn limits_of(binding: &[BindingConstraint], parameter: RangeParameter) -> BindingConstraint {
+    binding
+        .iter()
+        .find(|limits| limits.parameter == parameter)
+        .cloned()
+        .expect("limits are recorded for every returned range")
+}
+
+/// Heights follow from the widths at the implied aspect ratio, so the width limits apply
+fn with_height_limits(
+    mut binding: Vec<BindingConstraint>,
+    constraints: &super::types::ParameterConstraint,
+) -> Vec<BindingConstraint> {
+    for (width, height, height_fixed) in [
+        (
+            RangeParameter::SensorWidth,
+            RangeParameter::SensorHeight,
+            constraints.sensor_height_mm.is_some(),
+        ),
+        (
+            RangeParameter::PixelWidth,
+            RangeParameter::PixelHeight,
+            constraints.pixel_height.is_some(),
+        ),
+    ] {
+        if height_fixed {
+            continue;
+        }
+        if let Some(limits) = binding.iter().find(|limits| limits.parameter == width) {
+            binding.push(BindingConstraint {
+                parameter: height,
+                ..limits.clone()
             });
         }
     }
+    binding
+}
 
-    ranges
+/// Check that a horizontal FOV, focal length and sensor width describe the same camera
+///
+/// FOV = 2 × atan(sensor / (2 × focal)) ties the three together, so fixing all of them
+/// over-constrains the design. Returns `None` when the given FOV is within 0.5% of the
+/// implied one, otherwise the discrepancy and the value each parameter would need,
+/// changing only that parameter, to restore consistency.
+pub fn check_fov_consistency(
+    horizontal_fov_deg: f64,
+    focal_length_mm: f64,
+    sensor_width_mm: f64,
+) -> Option<super::types::ConstraintInconsistency> {
+    use super::constants::FOV_CONSISTENCY_TOLERANCE_PERCENT;
+    use super::types::ConstraintInconsistency;
+
+    let implied_fov_deg = 2.0
+        * (sensor_width_mm / (2.0 * focal_length_mm))
+            .atan()
+            .to_degrees();
+    let discrepancy_deg = horizontal_fov_deg - implied_fov_deg;
+    let discrepancy_percent = discrepancy_deg / implied_fov_deg * 100.0;
+    if discrepancy_percent.abs() <= FOV_CONSISTENCY_TOLERANCE_PERCENT {
+        return None;
+    }
+
+    let tan_half_fov = (horizontal_fov_deg.to_radians() / 2.0).tan();
+    Some(ConstraintInconsistency {
+        implied_fov_deg,
+        discrepancy_deg,
+        discrepancy_percent,
+        corrected_fov_deg: implied_fov_deg,
+        corrected_focal_length_mm: sensor_width_mm / (2.0 * tan_half_fov),
+        corrected_sensor_width_mm: 2.0 * focal_length_mm * tan_half_fov,
+    })
+}
+
+/// Check a camera against a single DORI target and suggest the focal length to use
+///
+/// Returns a warning with the focal length needed when the camera falls short of the
+/// target, or an info with the shortest focal length that still reaches it (for the
+/// widest field of view) when it does not.
+///
+/// # Arguments
+/// * `camera` - The camera system specification
+/// * `level` - DORI level the target must be reached at
+/// * `distance_m` - Distance at which the level is required
+pub fn advise_dori_target(
+    camera: &CameraSystem,
+    level: DoriLevel,
+    distance_m: f64,
+) -> ValidationWarning {
+    // Rearranged from: distance = (focal_length × pixel_width) / (sensor_width × required_px_per_m)
+    // with the horizontal pixel pitch, as in calculate_dori_distances
+    let (h_pitch_um, _) = camera.pixel_pitch_um();
+    let required_focal_mm = distance_m * h_pitch_um / 1000.0 * level.px_per_m();
+    let reached_m = calculate_dori_distances(camera).distance_m(level);
+    let level_term = format!("dori.level.{:?}", level).to_lowercase();
+
+    let (code, severity, key) = if camera.focal_length_mm < required_focal_mm {
+        (
+            ValidationCode::DoriTargetMissed,
+            ValidationSeverity::Warning,
+            "dori.target_missed",
+        )
+    } else {
+        (
+            ValidationCode::DoriTargetMet,
+            ValidationSeverity::Info,
+            "dori.target_met",
+        )
+    };
+    let message = MessageKey::new(key)
+        .with_term("level", level_term.as_str())
+        .with_number("reached_m", reached_m)
+        .with_number("distance_m", distance_m);
+    let suggestion = MessageKey::new(format!("{}.suggestion", key))
+        .with_number("focal_length_mm", required_focal_mm)
+        .with_term("level", level_term)
+        .with_number("distance_m", distance_m);
+
+    ValidationWarning::new(code, severity, message)
+        .with_suggestion(suggestion)
+        .with_field("focal_length_mm", camera.focal_length_mm)
+        .with_field("distance_m", distance_m)
 }
 
-/// Calculate FOV for multiple camera systems
+/// Calculate FOV for multiple camera systems, in parallel
 pub fn calculate_multiple_fov(cameras: &[CameraSystem], distance_mm: f64) -> Vec<FovResult> {
     cameras
-        .iter()
+        .par_iter()
         .map(|camera| calculate_fov(camera, distance_mm))
         .collect()
 }
 
+/// Calculate FOV for multiple camera systems and rank them against each other
+///
+/// In addition to the FOV result, each camera gets a low-light score (when an
+/// f-number is known) ranked relative to the other cameras in the comparison.
+/// Day/night cameras also get their night mode results, ranked among the night modes.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(cameras = cameras.len(), distance_mm))
+)]
+pub fn compare_cameras(cameras: Vec<CameraSystem>, distance_mm: f64) -> Vec<CameraWithResult> {
+    // Cameras are evaluated in parallel; ranking needs them all, so it runs afterwards
+    let mut results: Vec<CameraWithResult> = cameras
+        .into_par_iter()
+        .map(|camera| {
+            let result = calculate_fov(&camera, distance_mm);
+            let low_light = calculate_low_light_score(&camera);
+            let night = camera.night().map(|night_camera| NightModeResult {
+                result: calculate_fov(&night_camera, distance_mm),
+                low_light: calculate_low_light_score(&night_camera),
+                camera: night_camera,
+            });
+            CameraWithResult {
+                camera,
+                result,
+                low_light,
+                suitability: None,
+                isolation: None,
+                night,
+            }
+        })
+        .collect();
+
+    rank_low_light_scores(&mut results);
+    rank_night_low_light_scores(&mut results);
+    results
+}
+
 /// Calculate hyperfocal distance for a given camera system and aperture
 /// H = (f² / (N × c)) + f
 /// where f = focal length, N = f-number, c = circle of confusion
@@ -570,12 +1295,30 @@ pub fn calculate_hyperfocal(focal_length_mm: f64, f_number: f64, coc_mm: f64) ->
 }
 
 /// Calculate depth of field given object distance, focal length, f-number, and circle of confusion
+///
+/// Without a pupil magnification the lens is treated as symmetric. Retrofocus
+/// wide-angles (pupil magnification > 1) and telephotos (< 1) have entrance and
+/// exit pupils of different sizes away from the principal planes, which shifts the
+/// limits measurably at close distances; pass the pupil magnification (exit / entrance
+/// pupil diameter) to trace the blur from the exit pupil instead. Distances are then
+/// measured from the entrance pupil.
 pub fn calculate_dof(
     object_distance_mm: f64,
     focal_length_mm: f64,
     f_number: f64,
     coc_mm: f64,
+    pupil_magnification: Option<f64>,
 ) -> (f64, f64, f64) {
+    if let Some(pupil_magnification) = pupil_magnification {
+        return calculate_asymmetric_dof(
+            object_distance_mm,
+            focal_length_mm,
+            f_number,
+            coc_mm,
+            pupil_magnification,
+        );
+    }
+
     let hyperfocal = calculate_hyperfocal(focal_length_mm, f_number, coc_mm);
 
     // Near limit: Dn = (H × s) / (H + (s - f))
@@ -594,6 +1337,45 @@ pub fn calculate_dof(
     (near, far, total_dof)
 }
 
+/// Depth of field of a lens whose pupils differ in size (pupil magnification P)
+///
+/// With object distances x from the front principal plane and image distances x'
+/// from the rear one, the entrance pupil sits at f(1 - 1/P) in front of the front
+/// principal plane and the exit pupil at f(1 - P) behind the rear one. A point whose
+/// image lands at x' is blurred on the sensor at x'_s by D_x × |x' - x'_s| / (x' - e),
+/// where D_x = P × f / N is the exit pupil diameter and e its position; setting the
+/// blur to the circle of confusion gives the image-side limits.
+fn calculate_asymmetric_dof(
+    object_distance_mm: f64,
+    focal_length_mm: f64,
+    f_number: f64,
+    coc_mm: f64,
+    pupil_magnification: f64,
+) -> (f64, f64, f64) {
+    let f = focal_length_mm;
+    let entrance_pupil_mm = f * (1.0 - 1.0 / pupil_magnification);
+    let exit_pupil_mm = f * (1.0 - pupil_magnification);
+    let exit_pupil_diameter_mm = pupil_magnification * f / f_number;
+
+    let thin_lens = |x: f64| f * x / (x - f);
+    let sensor_mm = thin_lens(object_distance_mm + entrance_pupil_mm);
+
+    let near_image = (exit_pupil_diameter_mm * sensor_mm - coc_mm * exit_pupil_mm)
+        / (exit_pupil_diameter_mm - coc_mm);
+    let far_image = (exit_pupil_diameter_mm * sensor_mm + coc_mm * exit_pupil_mm)
+        / (exit_pupil_diameter_mm + coc_mm);
+
+    let near = thin_lens(near_image) - entrance_pupil_mm;
+    // Images at or inside the focal length come from beyond infinity
+    let far = if far_image > f {
+        thin_lens(far_image) - entrance_pupil_mm
+    } else {
+        f64::INFINITY
+    };
+
+    (near, far, far - near)
+}
+
 /// Calculate focal length from field of view and sensor size
 /// focal_length = (sensor_size / 2) / tan(fov / 2)
 pub fn calculate_focal_length_from_fov(sensor_size_mm: f64, fov_deg: f64) -> f64 {
@@ -621,6 +1403,61 @@ mod tests {
         assert!((result.distance_m - 5.0).abs() < 0.01);
     }
 
+    #[test]
+    fn test_fov_ifov() {
+        // 6 µm pixels behind a 50mm lens: 0.006 / 50 = 120 µrad
+        let camera = CameraSystem::new(36.0, 24.0, 6000, 4000, 50.0);
+        let result = calculate_fov(&camera, 5000.0);
+
+        assert!((result.horizontal_ifov_urad - 120.0).abs() < 1e-9);
+        assert!((result.vertical_ifov_urad - 120.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_fov_derived_metrics() {
+        // 36 × 27 mm behind a 36 mm lens: 1:1 on the horizontal, 45 mm diagonal
+        let camera = CameraSystem::new(36.0, 27.0, 4000, 3000, 36.0);
+        let result = calculate_fov(&camera, 10_000.0);
+
+        let diagonal = (2.0 * (45.0_f64 / 72.0).atan()).to_degrees();
+        assert!((result.diagonal_fov_deg - diagonal).abs() < 1e-9);
+        assert!((result.horizontal_px_per_deg - 4000.0 / result.horizontal_fov_deg).abs() < 1e-9);
+        // 10 m wide at 10 m: 400 px/m, 121.92 px/ft
+        assert!((result.horizontal_ppm - 400.0).abs() < 1e-9);
+        assert!((result.horizontal_ppf - 121.92).abs() < 1e-9);
+        // 400 × 400 px on a square metre
+        assert!((result.megapixels_per_m2 - 0.16).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_solve_working_distance() {
+        let camera = CameraSystem::new(6.4, 4.8, 1920, 1080, 8.0);
+
+        let width_only = RequiredFov {
+            width_m: Some(4.0),
+            height_m: None,
+        };
+        let result = solve_working_distance(&camera, &width_only).unwrap();
+        assert!((result.distance_m - 5.0).abs() < 1e-9);
+        assert!((result.horizontal_fov_m - 4.0).abs() < 1e-9);
+        assert!((result.horizontal_ppm - 480.0).abs() < 1e-9);
+
+        // 4 m wide needs 5 m, 4 m high needs 6.67 m; the farther one covers both
+        let both = RequiredFov {
+            width_m: Some(4.0),
+            height_m: Some(4.0),
+        };
+        let result = solve_working_distance(&camera, &both).unwrap();
+        assert!((result.vertical_fov_m - 4.0).abs() < 1e-9);
+        assert!(result.horizontal_fov_m > 4.0);
+
+        let neither = RequiredFov {
+            width_m: None,
+            height_m: None,
+        };
+        assert!(solve_working_distance(&camera, &neither).is_none());
+    }
+
     #[test]
     fn test_hyperfocal_calculation() {
         // 50mm lens, f/8, 0.03mm CoC (full frame standard)
@@ -630,6 +1467,27 @@ mod tests {
         assert!((hyperfocal - 10416.7).abs() < 100.0);
     }
 
+    #[test]
+    fn test_dof_with_pupil_magnification() {
+        // 24 mm at f/8 focused at 300 mm
+        let (near, far, _) = calculate_dof(300.0, 24.0, 8.0, 0.03, None);
+        let (sym_near, sym_far, sym_total) = calculate_dof(300.0, 24.0, 8.0, 0.03, Some(1.0));
+        assert!((sym_near - near).abs() < 0.5);
+        assert!((sym_far - far).abs() < 0.5);
+
+        // Retrofocus lenses gain DOF at close range, telephotos lose it,
+        // following 2Nc(1 + m/P) / m²
+        let (_, _, retrofocus) = calculate_dof(300.0, 24.0, 8.0, 0.03, Some(2.0));
+        let (_, _, telephoto) = calculate_dof(300.0, 24.0, 8.0, 0.03, Some(0.5));
+        assert!((retrofocus - 73.05).abs() < 0.01);
+        assert!((telephoto - 63.70).abs() < 0.01);
+        assert!(retrofocus > sym_total && sym_total > telephoto);
+
+        // Far limit reaches infinity beyond the hyperfocal distance
+        let (_, far, _) = calculate_dof(10000.0, 24.0, 8.0, 0.03, Some(2.0));
+        assert!(far.is_infinite());
+    }
+
     #[test]
     fn test_focal_length_from_fov() {
         // Full frame sensor (36mm width), 39.6° horizontal FOV
@@ -691,6 +1549,61 @@ mod tests {
         assert!((dori.identification_m - 14.4).abs() < 0.2);
     }
 
+    #[test]
+    fn test_multiple_fov_keeps_catalog_order() {
+        // Large enough to be split across threads
+        let cameras: Vec<CameraSystem> = (1..=500)
+            .map(|i| CameraSystem::new(6.4, 4.8, 1920, 1080, i as f64 * 0.1))
+            .collect();
+        let results = calculate_multiple_fov(&cameras, 10_000.0);
+
+        assert_eq!(results.len(), cameras.len());
+        for (camera, result) in cameras.iter().zip(&results) {
+            let expected = calculate_fov(camera, 10_000.0);
+            assert_eq!(result.horizontal_fov_deg, expected.horizontal_fov_deg);
+        }
+        let compared = compare_cameras(cameras.clone(), 10_000.0);
+        assert_eq!(
+            compared[499].camera.focal_length_mm,
+            cameras[499].focal_length_mm
+        );
+    }
+
+    #[test]
+    fn test_non_square_pixels() {
+        // 1280x1080 on a 6.4x4.8 mm sensor: 5.0 µm wide, 4.44 µm tall pixels
+        let camera = CameraSystem::new(6.4, 4.8, 1280, 1080, 8.0);
+        assert!((camera.pixel_aspect_ratio() - 1.125).abs() < 1e-9);
+
+        let fov = calculate_fov(&camera, 10_000.0);
+        assert!((fov.horizontal_ppm - 160.0).abs() < 0.01);
+        assert!((fov.vertical_ppm - 180.0).abs() < 0.01);
+
+        // DORI follows the horizontal pitch only
+        let dori = calculate_dori_distances(&camera);
+        assert!((dori.detection_m - 64.0).abs() < 1e-9);
+
+        // The default circle of confusion spans two of the wider pixels
+        assert!((camera.default_coc_mm() - 0.010).abs() < 1e-9);
+        assert!(camera
+            .summary()
+            .render(crate::Locale::En)
+            .contains("pixel aspect 1.12"));
+    }
+
+    #[test]
+    fn test_camera_from_pixel_pitch() {
+        // Sony IMX264: 2448x2048 at 3.45 µm
+        let camera = CameraSystem::from_pixel_pitch(2448, 2048, 3.45, 16.0);
+        assert!((camera.sensor_width_mm - 8.4456).abs() < 1e-9);
+        assert!((camera.sensor_height_mm - 7.0656).abs() < 1e-9);
+
+        let (h_pitch, v_pitch) = camera.pixel_pitch_um();
+        assert!((h_pitch - 3.45).abs() < 1e-9);
+        assert!((v_pitch - 3.45).abs() < 1e-9);
+        assert!(camera.validate().is_empty());
+    }
+
     #[test]
     fn test_dori_from_single_identification() {
         // If identification is at 5m, calculate all others
@@ -747,9 +1660,28 @@ mod tests {
         assert!((from_det.detection_m - 80.0).abs() < 0.01);
     }
 
+    #[test]
+    fn test_advise_dori_target() {
+        // 1/3" sensor, 1920 px: identification at 10 m needs 10 × 5.6 × 250 / 1920 = 7.29 mm
+        let short = CameraSystem::new(5.6, 3.15, 1920, 1080, 4.0);
+        let advice = advise_dori_target(&short, DoriLevel::Identification, 10.0);
+        assert_eq!(advice.code, ValidationCode::DoriTargetMissed);
+        assert_eq!(advice.severity, ValidationSeverity::Warning);
+        assert_eq!(
+            advice.suggestion.as_deref(),
+            Some("Increase focal length to ≥ 7.3 mm to reach identification at 10 m")
+        );
+
+        let long = CameraSystem::new(5.6, 3.15, 1920, 1080, 8.0);
+        let advice = advise_dori_target(&long, DoriLevel::Identification, 10.0);
+        assert_eq!(advice.code, ValidationCode::DoriTargetMet);
+        assert_eq!(advice.severity, ValidationSeverity::Info);
+        assert!(advice.suggestion.unwrap().contains("7.3 mm"));
+    }
+
     #[test]
     fn test_dori_ranges_with_fov_constraint() {
-        use crate::optics::types::{DoriTargets, ParameterConstraint};
+        use crate::types::{DoriTargets, ParameterConstraint};
 
         // Test with FOV constraint only
         let targets = DoriTargets {
@@ -766,9 +1698,11 @@ mod tests {
             pixel_height: None,
             focal_length_mm: None,
             horizontal_fov_deg: Some(60.0),
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
         };
 
-        let ranges = calculate_dori_parameter_ranges(&targets, &constraints);
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
 
         // With FOV fixed, focal and sensor should have ranges
         assert!(ranges.focal_length_mm.is_some());
@@ -826,7 +1760,7 @@ mod tests {
 
     #[test]
     fn test_dori_ranges_fov_and_pixel_constraint() {
-        use crate::optics::types::{DoriTargets, ParameterConstraint};
+        use crate::types::{DoriTargets, ParameterConstraint};
 
         // Test with both FOV and pixel width constrained
         let targets = DoriTargets {
@@ -843,9 +1777,11 @@ mod tests {
             pixel_height: None,
             focal_length_mm: None,
             horizontal_fov_deg: Some(90.0),
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
         };
 
-        let ranges = calculate_dori_parameter_ranges(&targets, &constraints);
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
 
         // With both FOV and pixels fixed, focal and sensor should still have ranges
         // but they're related by the FOV constraint
@@ -872,7 +1808,7 @@ mod tests {
 
     #[test]
     fn test_dori_ranges_no_fov_constraint() {
-        use crate::optics::types::{DoriTargets, ParameterConstraint};
+        use crate::types::{DoriTargets, ParameterConstraint};
 
         // Test without FOV constraint - FOV range should be calculated
         let targets = DoriTargets {
@@ -889,9 +1825,11 @@ mod tests {
             pixel_height: None,
             focal_length_mm: None,
             horizontal_fov_deg: None,
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
         };
 
-        let ranges = calculate_dori_parameter_ranges(&targets, &constraints);
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
 
         // Without FOV constraint, FOV should have a range
         assert!(ranges.horizontal_fov_deg.is_some());
@@ -906,9 +1844,9 @@ mod tests {
 
     #[test]
     fn test_dori_ranges_calculates_height_dimensions() {
-        use crate::optics::types::{DoriTargets, ParameterConstraint};
+        use crate::types::{DoriTargets, ParameterConstraint};
 
-        // Test that sensor_height and pixel_height are calculated with 4:3 aspect ratio
+        // Test that sensor_height and pixel_height follow the default aspect ratio
         let targets = DoriTargets {
             identification_m: Some(20.0),
             observation_m: None,
@@ -923,9 +1861,11 @@ mod tests {
             pixel_height: None,
             focal_length_mm: None,
             horizontal_fov_deg: None,
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
         };
 
-        let ranges = calculate_dori_parameter_ranges(&targets, &constraints);
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
 
         // Both width and height should have ranges
         assert!(ranges.sensor_width_mm.is_some());
@@ -965,11 +1905,29 @@ mod tests {
                 "Max pixel aspect ratio should be 4:3"
             );
         }
+
+        // Another default ratio carries through to the heights and their recommendations
+        let bounds = SolverBounds {
+            default_aspect_ratio: 16.0 / 9.0,
+            ..SolverBounds::default()
+        };
+        let ranges =
+            calculate_dori_parameter_ranges_with_bounds(&targets, &constraints, &bounds).unwrap();
+        for (width, height) in [
+            (ranges.sensor_width_mm, ranges.sensor_height_mm),
+            (ranges.pixel_width, ranges.pixel_height),
+        ] {
+            let (width, height) = (width.unwrap(), height.unwrap());
+            assert!((width.min / height.min - 16.0 / 9.0).abs() < 1e-9);
+            assert!((width.max / height.max - 16.0 / 9.0).abs() < 1e-9);
+            let recommended = width.recommended.unwrap() / height.recommended.unwrap();
+            assert!((recommended - 16.0 / 9.0).abs() < 1e-9);
+        }
     }
 
     #[test]
     fn test_dori_ranges_height_with_fixed_width() {
-        use crate::optics::types::{DoriTargets, ParameterConstraint};
+        use crate::types::{DoriTargets, ParameterConstraint};
 
         // Test that when width is fixed, height is calculated from it
         let targets = DoriTargets {
@@ -986,9 +1944,11 @@ mod tests {
             pixel_height: None,
             focal_length_mm: None,
             horizontal_fov_deg: None,
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
         };
 
-        let ranges = calculate_dori_parameter_ranges(&targets, &constraints);
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
 
         // Height should be calculated with fixed value (same min/max)
         if let Some(sensor_h) = &ranges.sensor_height_mm {
@@ -1006,7 +1966,7 @@ mod tests {
 
     #[test]
     fn test_dori_ranges_fov_with_sensor_determines_focal() {
-        use crate::optics::types::{DoriTargets, ParameterConstraint};
+        use crate::types::{DoriTargets, ParameterConstraint};
 
         // Test case from screenshot: FOV=8°, sensor=4.2mm should give focal≈30mm
         let targets = DoriTargets {
@@ -1023,9 +1983,11 @@ mod tests {
             pixel_height: None,
             focal_length_mm: None,
             horizontal_fov_deg: Some(8.0),
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
         };
 
-        let ranges = calculate_dori_parameter_ranges(&targets, &constraints);
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
 
         // Focal length should be determined (fixed value)
         assert!(
@@ -1102,7 +2064,7 @@ mod tests {
 
     #[test]
     fn test_dori_ranges_fov_with_focal_determines_sensor() {
-        use crate::optics::types::{DoriTargets, ParameterConstraint};
+        use crate::types::{DoriTargets, ParameterConstraint};
 
         // Test: FOV=60°, focal=25mm should give sensor≈43.3mm
         let targets = DoriTargets {
@@ -1119,9 +2081,11 @@ mod tests {
             pixel_height: None,
             focal_length_mm: Some(25.0),
             horizontal_fov_deg: Some(60.0),
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
         };
 
-        let ranges = calculate_dori_parameter_ranges(&targets, &constraints);
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
 
         // Sensor width should be determined (fixed value)
         assert!(
@@ -1154,9 +2118,465 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_dori_ranges_report_inconsistent_fov_focal_and_sensor() {
+        use crate::types::{DoriTargets, ParameterConstraint, ValidationCode};
+
+        let targets = DoriTargets {
+            identification_m: Some(10.0),
+            observation_m: None,
+            recognition_m: None,
+            detection_m: None,
+        };
+        // 20 mm behind 25 mm gives 43.6°, not 60°
+        let constraints = ParameterConstraint {
+            sensor_width_mm: Some(20.0),
+            sensor_height_mm: None,
+            pixel_width: Some(4000),
+            pixel_height: None,
+            focal_length_mm: Some(25.0),
+            horizontal_fov_deg: Some(60.0),
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
+        };
+
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
+        let inconsistency = ranges.inconsistency.as_ref().unwrap();
+        assert!((inconsistency.implied_fov_deg - 43.60).abs() < 0.01);
+        assert!((inconsistency.discrepancy_deg - 16.40).abs() < 0.01);
+        assert!((inconsistency.corrected_fov_deg - 43.60).abs() < 0.01);
+        // 20 / (2 × tan 30°) and 2 × 25 × tan 30°
+        assert!((inconsistency.corrected_focal_length_mm - 17.32).abs() < 0.01);
+        assert!((inconsistency.corrected_sensor_width_mm - 28.87).abs() < 0.01);
+
+        let warnings = ranges.validate();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, ValidationCode::ConstraintsInconsistent);
+
+        // Rounding in user input stays within tolerance
+        assert!(check_fov_consistency(43.6, 25.0, 20.0).is_none());
+    }
+
+    #[test]
+    fn test_dori_ranges_report_binding_constraints() {
+        use crate::types::{DoriTargets, ParameterConstraint};
+
+        let targets = DoriTargets {
+            identification_m: Some(20.0),
+            observation_m: None,
+            recognition_m: Some(40.0),
+            detection_m: None,
+        };
+        let constraints = ParameterConstraint {
+            sensor_width_mm: Some(6.0),
+            sensor_height_mm: None,
+            pixel_width: Some(1920),
+            pixel_height: None,
+            focal_length_mm: None,
+            horizontal_fov_deg: None,
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
+        };
+
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
+        let focal = limits_of(&ranges.binding_constraints, RangeParameter::FocalLength);
+        // Identification is the strictest target and sets the shortest usable lens
+        assert_eq!(
+            focal.min_limited_by,
+            RangeLimit::DoriTarget(DoriLevel::Identification)
+        );
+        assert_eq!(
+            focal.max_limited_by,
+            RangeLimit::ParameterBounds(RangeParameter::FocalLength)
+        );
+        // Widest FOV comes from the shortest lens
+        let fov = limits_of(&ranges.binding_constraints, RangeParameter::HorizontalFov);
+        assert_eq!(fov.max_limited_by, focal.min_limited_by);
+    }
+
+    #[test]
+    fn test_dori_ranges_recommend_standard_values() {
+        use crate::types::{DoriTargets, ParameterConstraint};
+
+        let targets = DoriTargets {
+            identification_m: Some(20.0),
+            observation_m: None,
+            recognition_m: None,
+            detection_m: None,
+        };
+        let constraints = ParameterConstraint {
+            sensor_width_mm: Some(6.0),
+            sensor_height_mm: None,
+            pixel_width: Some(1920),
+            pixel_height: None,
+            focal_length_mm: None,
+            horizontal_fov_deg: None,
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
+        };
+
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
+        // 15.6 mm minimum + 20% is 18.75 mm, snapped up to a 25 mm lens
+        let focal = ranges.focal_length_mm.unwrap();
+        assert!((focal.min - 15.625).abs() < 1e-9);
+        assert_eq!(focal.recommended, Some(25.0));
+        // FOV of the 6 mm sensor behind the recommended lens
+        let fov = ranges.horizontal_fov_deg.unwrap().recommended.unwrap();
+        assert!((fov - 13.69).abs() < 0.01);
+
+        // A determined value is its own recommendation
+        let range = crate::types::ParameterRange {
+            min: 7.0,
+            max: 7.0,
+            recommended: None,
+        };
+        assert_eq!(recommend_in_range(&range, &[6.0, 8.0]), 7.0);
+    }
+
+    #[test]
+    fn test_dori_ranges_with_custom_bounds() {
+        use crate::types::{DoriTargets, ParameterConstraint};
+
+        let targets = DoriTargets {
+            identification_m: Some(200.0),
+            observation_m: None,
+            recognition_m: None,
+            detection_m: None,
+        };
+        let constraints = ParameterConstraint {
+            sensor_width_mm: Some(6.0),
+            sensor_height_mm: None,
+            pixel_width: Some(1920),
+            pixel_height: None,
+            focal_length_mm: None,
+            horizontal_fov_deg: None,
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
+        };
+        let bounds = SolverBounds {
+            max_focal_length_mm: 1000.0,
+            ..SolverBounds::default()
+        };
+
+        let default = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
+        assert_eq!(default.focal_length_mm.unwrap().max, 400.0);
+
+        let ranges =
+            calculate_dori_parameter_ranges_with_bounds(&targets, &constraints, &bounds).unwrap();
+        let focal = ranges.focal_length_mm.unwrap();
+        assert!((focal.min - 156.25).abs() < 1e-9);
+        assert_eq!(focal.max, 1000.0);
+    }
+
+    #[test]
+    fn test_dori_ranges_heights_follow_constrained_aspect_ratio() {
+        use crate::types::{DoriTargets, ParameterConstraint};
+
+        let targets = DoriTargets {
+            identification_m: Some(10.0),
+            observation_m: None,
+            recognition_m: None,
+            detection_m: None,
+        };
+        let constraints = ParameterConstraint {
+            sensor_width_mm: Some(5.6),
+            sensor_height_mm: None,
+            pixel_width: Some(1920),
+            pixel_height: Some(1080),
+            focal_length_mm: None,
+            horizontal_fov_deg: None,
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
+        };
+
+        // 1920×1080 makes it a 16:9 camera, not 4:3
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
+        let sensor_height = ranges.sensor_height_mm.unwrap();
+        assert!((sensor_height.min - 3.15).abs() < 1e-9);
+
+        let bounds = SolverBounds {
+            default_aspect_ratio: 3.0 / 2.0,
+            ..SolverBounds::default()
+        };
+        let constraints = ParameterConstraint {
+            pixel_height: None,
+            ..constraints
+        };
+        let ranges =
+            calculate_dori_parameter_ranges_with_bounds(&targets, &constraints, &bounds).unwrap();
+        assert_eq!(ranges.pixel_height.unwrap().min, 1280.0);
+    }
+
+    #[test]
+    fn test_dori_ranges_accept_vertical_and_diagonal_fov() {
+        use crate::types::{DoriTargets, ParameterConstraint};
+
+        let targets = DoriTargets {
+            identification_m: Some(10.0),
+            observation_m: None,
+            recognition_m: None,
+            detection_m: None,
+        };
+        let horizontal = ParameterConstraint {
+            sensor_width_mm: None,
+            sensor_height_mm: None,
+            pixel_width: Some(1920),
+            pixel_height: Some(1080),
+            focal_length_mm: Some(4.0),
+            horizontal_fov_deg: Some(70.0),
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
+        };
+        let expected = calculate_dori_parameter_ranges(&targets, &horizontal)
+            .unwrap()
+            .sensor_width_mm
+            .unwrap();
+
+        // The same 16:9 camera described by its vertical and its diagonal FOV
+        let tan_half_h = 35f64.to_radians().tan();
+        let vertical = 2.0 * (tan_half_h * 9.0 / 16.0).atan().to_degrees();
+        let diagonal = 2.0 * (tan_half_h * (337f64).sqrt() / 16.0).atan().to_degrees();
+        for constraints in [
+            ParameterConstraint {
+                horizontal_fov_deg: None,
+                vertical_fov_deg: Some(vertical),
+                ..horizontal.clone()
+            },
+            ParameterConstraint {
+                horizontal_fov_deg: None,
+                diagonal_fov_deg: Some(diagonal),
+                ..horizontal.clone()
+            },
+        ] {
+            let sensor = calculate_dori_parameter_ranges(&targets, &constraints)
+                .unwrap()
+                .sensor_width_mm
+                .unwrap();
+            assert!((sensor.min - expected.min).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_dori_ranges_reconcile_multiple_targets() {
+        use crate::types::{DoriTargets, ParameterConstraint, ValidationCode};
+
+        // Standard ratios: identification at 10 m implies detection at 100 m
+        let consistent = DoriTargets {
+            identification_m: Some(10.0),
+            observation_m: None,
+            recognition_m: None,
+            detection_m: Some(100.0),
+        };
+        let governing = reconcile_dori_targets(&consistent).unwrap();
+        assert_eq!(governing.level, DoriLevel::Identification);
+        assert!(governing.exceeded.is_empty());
+
+        // Detection at 150 m needs more than identification at 10 m
+        let targets = DoriTargets {
+            detection_m: Some(150.0),
+            ..consistent
+        };
+        let governing = reconcile_dori_targets(&targets).unwrap();
+        assert_eq!(governing.level, DoriLevel::Detection);
+        assert_eq!(governing.exceeded, [DoriLevel::Identification]);
+
+        let constraints = ParameterConstraint {
+            sensor_width_mm: Some(6.0),
+            sensor_height_mm: None,
+            pixel_width: Some(640),
+            pixel_height: None,
+            focal_length_mm: None,
+            horizontal_fov_deg: None,
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
+        };
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
+        // 150 m × 6 mm × 25 px/m / 640 px
+        assert!((ranges.focal_length_mm.as_ref().unwrap().min - 35.156).abs() < 0.001);
+        assert!(ranges.infeasible_parameters.is_empty());
+        let warnings = ranges.validate();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, ValidationCode::DoriTargetsInconsistent);
+
+        // No lens up to 400 mm reaches detection at 5 km with 640 px
+        let targets = DoriTargets {
+            detection_m: Some(5000.0),
+            ..targets
+        };
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
+        // and the FOV that would follow from such a lens is empty too
+        assert_eq!(
+            ranges.infeasible_parameters,
+            [RangeParameter::FocalLength, RangeParameter::HorizontalFov]
+        );
+        assert_eq!(
+            ranges.validate()[0].message,
+            "No focal length within the parameter bounds reaches detection at 5000.0 m"
+        );
+    }
+
+    #[test]
+    fn test_validate_dori_request() {
+        use crate::types::{DoriTargets, ParameterConstraint, ValidationCode};
+
+        let targets = DoriTargets {
+            identification_m: None,
+            observation_m: None,
+            recognition_m: None,
+            detection_m: None,
+        };
+        let constraints = ParameterConstraint {
+            sensor_width_mm: Some(0.5),
+            sensor_height_mm: None,
+            pixel_width: Some(4000),
+            pixel_height: None,
+            focal_length_mm: Some(-4.0),
+            horizontal_fov_deg: Some(190.0),
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
+        };
+
+        let codes: Vec<ValidationCode> = validate_dori_request(&targets, &constraints)
+            .into_iter()
+            .map(|warning| warning.code)
+            .collect();
+        assert_eq!(
+            codes,
+            [
+                ValidationCode::DoriTargetMissing,
+                ValidationCode::ConstraintNotPositive,
+                ValidationCode::FovOutOfRange,
+                ValidationCode::PixelPitchTooSmall,
+            ]
+        );
+
+        let targets = DoriTargets {
+            recognition_m: Some(0.0),
+            ..targets
+        };
+        let warnings = validate_dori_request(
+            &targets,
+            &ParameterConstraint {
+                sensor_width_mm: None,
+                focal_length_mm: None,
+                horizontal_fov_deg: None,
+                ..constraints
+            },
+        );
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].message,
+            "The recognition target distance must be greater than zero"
+        );
+        assert_eq!(warnings[0].fields[0].field, "recognition_m");
+    }
+
+    #[test]
+    fn test_solver_rejects_request_without_targets() {
+        use crate::types::{DoriTargets, ParameterConstraint, ValidationCode};
+
+        let targets = DoriTargets {
+            identification_m: None,
+            observation_m: None,
+            recognition_m: None,
+            detection_m: None,
+        };
+        let constraints = ParameterConstraint {
+            sensor_width_mm: None,
+            sensor_height_mm: None,
+            pixel_width: None,
+            pixel_height: None,
+            focal_length_mm: None,
+            horizontal_fov_deg: None,
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
+        };
+
+        assert!(reconcile_dori_targets(&targets).is_none());
+        let errors = calculate_dori_parameter_ranges(&targets, &constraints).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, ValidationCode::DoriTargetMissing);
+    }
+
+    #[test]
+    fn test_sensor_formats_in_sensor_range() {
+        let range = crate::types::ParameterRange {
+            min: 5.0,
+            max: 7.0,
+            recommended: None,
+        };
+
+        let names: Vec<String> = sensor_formats_in(&range)
+            .into_iter()
+            .map(|format| format.name)
+            .collect();
+        assert_eq!(names, ["1/2.8\"", "1/2.5\"", "1/2\""]);
+    }
+
+    #[test]
+    fn test_standard_lenses_in_focal_range() {
+        let range = crate::types::ParameterRange {
+            min: 7.0,
+            max: 14.0,
+            recommended: None,
+        };
+
+        let names: Vec<String> = standard_lenses_in(&range)
+            .into_iter()
+            .map(|lens| lens.name)
+            .collect();
+        // Fixed lenses first, then every zoom reaching into 7-14 mm
+        assert_eq!(
+            names,
+            [
+                "8 mm",
+                "12 mm",
+                "2.7-13.5 mm",
+                "2.8-12 mm",
+                "3.6-10 mm",
+                "5-50 mm",
+                "8-32 mm",
+                "10-40 mm",
+                "12-50 mm"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dori_ranges_list_standard_resolutions() {
+        use crate::types::{DoriTargets, ParameterConstraint};
+
+        let targets = DoriTargets {
+            identification_m: Some(10.0),
+            observation_m: None,
+            recognition_m: None,
+            detection_m: None,
+        };
+        let constraints = ParameterConstraint {
+            sensor_width_mm: None,
+            sensor_height_mm: None,
+            pixel_width: None,
+            pixel_height: None,
+            focal_length_mm: None,
+            horizontal_fov_deg: Some(90.0),
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
+        };
+
+        // 10 m × 2 × tan 45° × 250 px/m needs at least 5000 px across
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
+        let names: Vec<&str> = ranges
+            .standard_resolutions
+            .iter()
+            .map(|resolution| resolution.name.as_str())
+            .collect();
+        assert_eq!(names, ["20 MP", "8K"]);
+        assert_eq!(ranges.standard_resolutions[0].pixel_width, 5472);
+    }
+
     #[test]
     fn test_dori_ranges_sensor_with_focal_determines_fov() {
-        use crate::optics::types::{DoriTargets, ParameterConstraint};
+        use crate::types::{DoriTargets, ParameterConstraint};
 
         // Test case from screenshot: sensor=5mm, focal=75mm should give FOV≈3.82°
         let targets = DoriTargets {
@@ -1173,9 +2593,11 @@ mod tests {
             pixel_height: None,
             focal_length_mm: Some(75.0),
             horizontal_fov_deg: None,
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
         };
 
-        let ranges = calculate_dori_parameter_ranges(&targets, &constraints);
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
 
         // FOV should be determined (fixed value)
         assert!(
@@ -1217,7 +2639,7 @@ mod tests {
 
     #[test]
     fn test_combo_none_none_none_none() {
-        use crate::optics::types::{DoriTargets, ParameterConstraint};
+        use crate::types::{DoriTargets, ParameterConstraint};
 
         // Case 0000: All unconstrained - should give full ranges for all parameters
         let targets = DoriTargets {
@@ -1234,9 +2656,11 @@ mod tests {
             pixel_height: None,
             focal_length_mm: None,
             horizontal_fov_deg: None,
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
         };
 
-        let ranges = calculate_dori_parameter_ranges(&targets, &constraints);
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
 
         // All should have ranges
         assert!(
@@ -1269,7 +2693,7 @@ mod tests {
 
     #[test]
     fn test_combo_sensor_none_none_none() {
-        use crate::optics::types::{DoriTargets, ParameterConstraint};
+        use crate::types::{DoriTargets, ParameterConstraint};
 
         // Case 1000: Only sensor constrained
         let targets = DoriTargets {
@@ -1286,9 +2710,11 @@ mod tests {
             pixel_height: None,
             focal_length_mm: None,
             horizontal_fov_deg: None,
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
         };
 
-        let ranges = calculate_dori_parameter_ranges(&targets, &constraints);
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
 
         // Sensor should not have range (it's fixed)
         assert!(
@@ -1319,7 +2745,7 @@ mod tests {
 
     #[test]
     fn test_combo_none_pixel_none_none() {
-        use crate::optics::types::{DoriTargets, ParameterConstraint};
+        use crate::types::{DoriTargets, ParameterConstraint};
 
         // Case 0100: Only pixel_width constrained
         let targets = DoriTargets {
@@ -1336,9 +2762,11 @@ mod tests {
             pixel_height: None,
             focal_length_mm: None,
             horizontal_fov_deg: None,
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
         };
 
-        let ranges = calculate_dori_parameter_ranges(&targets, &constraints);
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
 
         // Pixel should not have range (it's fixed)
         assert!(
@@ -1369,7 +2797,7 @@ mod tests {
 
     #[test]
     fn test_combo_sensor_pixel_none_none() {
-        use crate::optics::types::{DoriTargets, ParameterConstraint};
+        use crate::types::{DoriTargets, ParameterConstraint};
 
         // Case 1100: Sensor and pixel constrained
         let targets = DoriTargets {
@@ -1386,9 +2814,11 @@ mod tests {
             pixel_height: None,
             focal_length_mm: None,
             horizontal_fov_deg: None,
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
         };
 
-        let ranges = calculate_dori_parameter_ranges(&targets, &constraints);
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
 
         // Sensor and pixel should not have ranges (fixed inputs)
         assert!(
@@ -1418,7 +2848,7 @@ mod tests {
 
     #[test]
     fn test_combo_none_none_focal_none() {
-        use crate::optics::types::{DoriTargets, ParameterConstraint};
+        use crate::types::{DoriTargets, ParameterConstraint};
 
         // Case 0010: Only focal constrained
         let targets = DoriTargets {
@@ -1435,9 +2865,11 @@ mod tests {
             pixel_height: None,
             focal_length_mm: Some(50.0),
             horizontal_fov_deg: None,
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
         };
 
-        let ranges = calculate_dori_parameter_ranges(&targets, &constraints);
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
 
         // Focal should not have range (it's fixed)
         assert!(
@@ -1458,7 +2890,7 @@ mod tests {
 
     #[test]
     fn test_combo_sensor_none_focal_none() {
-        use crate::optics::types::{DoriTargets, ParameterConstraint};
+        use crate::types::{DoriTargets, ParameterConstraint};
 
         // Case 1010: Sensor and focal constrained - FOV is determined
         let targets = DoriTargets {
@@ -1475,9 +2907,11 @@ mod tests {
             pixel_height: None,
             focal_length_mm: Some(35.0),
             horizontal_fov_deg: None,
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
         };
 
-        let ranges = calculate_dori_parameter_ranges(&targets, &constraints);
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
 
         // Sensor and focal should not have ranges (fixed inputs)
         assert!(
@@ -1516,7 +2950,7 @@ mod tests {
 
     #[test]
     fn test_combo_none_pixel_focal_none() {
-        use crate::optics::types::{DoriTargets, ParameterConstraint};
+        use crate::types::{DoriTargets, ParameterConstraint};
 
         // Case 0110: Pixel and focal constrained
         let targets = DoriTargets {
@@ -1533,9 +2967,11 @@ mod tests {
             pixel_height: None,
             focal_length_mm: Some(25.0),
             horizontal_fov_deg: None,
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
         };
 
-        let ranges = calculate_dori_parameter_ranges(&targets, &constraints);
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
 
         // Pixel and focal should not have ranges (fixed inputs)
         assert!(ranges.pixel_width.is_none(), "Pixel width should be None");
@@ -1553,7 +2989,7 @@ mod tests {
 
     #[test]
     fn test_combo_sensor_pixel_focal_none() {
-        use crate::optics::types::{DoriTargets, ParameterConstraint};
+        use crate::types::{DoriTargets, ParameterConstraint};
 
         // Case 1110: Sensor, pixel, and focal constrained
         // Note: When sensor and focal are fixed, the code still calculates
@@ -1572,9 +3008,11 @@ mod tests {
             pixel_height: None,
             focal_length_mm: Some(16.0),
             horizontal_fov_deg: None,
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
         };
 
-        let ranges = calculate_dori_parameter_ranges(&targets, &constraints);
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
 
         // When sensor + focal are fixed, pixel still gets a range (requirement range)
         // This tells us what pixel widths would meet the DORI requirement
@@ -1606,7 +3044,7 @@ mod tests {
 
     #[test]
     fn test_combo_none_none_none_fov() {
-        use crate::optics::types::{DoriTargets, ParameterConstraint};
+        use crate::types::{DoriTargets, ParameterConstraint};
 
         // Case 0001: Only FOV constrained
         let targets = DoriTargets {
@@ -1623,9 +3061,11 @@ mod tests {
             pixel_height: None,
             focal_length_mm: None,
             horizontal_fov_deg: Some(45.0),
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
         };
 
-        let ranges = calculate_dori_parameter_ranges(&targets, &constraints);
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
 
         // FOV should not have range (it's fixed)
         assert!(
@@ -1657,7 +3097,7 @@ mod tests {
 
     #[test]
     fn test_combo_sensor_none_none_fov() {
-        use crate::optics::types::{DoriTargets, ParameterConstraint};
+        use crate::types::{DoriTargets, ParameterConstraint};
 
         // Case 1001: Sensor and FOV constrained - focal is determined
         let targets = DoriTargets {
@@ -1674,9 +3114,11 @@ mod tests {
             pixel_height: None,
             focal_length_mm: None,
             horizontal_fov_deg: Some(30.0),
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
         };
 
-        let ranges = calculate_dori_parameter_ranges(&targets, &constraints);
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
 
         // Sensor and FOV should not have ranges (fixed inputs)
         assert!(
@@ -1710,7 +3152,7 @@ mod tests {
 
     #[test]
     fn test_combo_none_pixel_none_fov() {
-        use crate::optics::types::{DoriTargets, ParameterConstraint};
+        use crate::types::{DoriTargets, ParameterConstraint};
 
         // Case 0101: Pixel and FOV constrained
         let targets = DoriTargets {
@@ -1727,9 +3169,11 @@ mod tests {
             pixel_height: None,
             focal_length_mm: None,
             horizontal_fov_deg: Some(60.0),
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
         };
 
-        let ranges = calculate_dori_parameter_ranges(&targets, &constraints);
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
 
         // Pixel and FOV should not have ranges (fixed inputs)
         assert!(ranges.pixel_width.is_none(), "Pixel width should be None");
@@ -1747,7 +3191,7 @@ mod tests {
 
     #[test]
     fn test_combo_sensor_pixel_none_fov() {
-        use crate::optics::types::{DoriTargets, ParameterConstraint};
+        use crate::types::{DoriTargets, ParameterConstraint};
 
         // Case 1101: Sensor, pixel, and FOV constrained - focal is determined
         let targets = DoriTargets {
@@ -1764,9 +3208,11 @@ mod tests {
             pixel_height: None,
             focal_length_mm: None,
             horizontal_fov_deg: Some(50.0),
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
         };
 
-        let ranges = calculate_dori_parameter_ranges(&targets, &constraints);
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
 
         // Sensor, pixel, and FOV should not have ranges (fixed inputs)
         assert!(
@@ -1797,7 +3243,7 @@ mod tests {
 
     #[test]
     fn test_combo_none_none_focal_fov() {
-        use crate::optics::types::{DoriTargets, ParameterConstraint};
+        use crate::types::{DoriTargets, ParameterConstraint};
 
         // Case 0011: Focal and FOV constrained - sensor is determined
         let targets = DoriTargets {
@@ -1814,9 +3260,11 @@ mod tests {
             pixel_height: None,
             focal_length_mm: Some(50.0),
             horizontal_fov_deg: Some(40.0),
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
         };
 
-        let ranges = calculate_dori_parameter_ranges(&targets, &constraints);
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
 
         // Focal and FOV should not have ranges (fixed inputs)
         assert!(
@@ -1850,7 +3298,7 @@ mod tests {
 
     #[test]
     fn test_combo_sensor_none_focal_fov() {
-        use crate::optics::types::{DoriTargets, ParameterConstraint};
+        use crate::types::{DoriTargets, ParameterConstraint};
 
         // Case 1011: Sensor, focal, and FOV constrained - over-constrained system
         // When FOV + focal are constrained, the code calculates what sensor SHOULD be
@@ -1870,9 +3318,11 @@ mod tests {
             pixel_height: None,
             focal_length_mm: Some(50.0),
             horizontal_fov_deg: Some(39.6),
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
         };
 
-        let ranges = calculate_dori_parameter_ranges(&targets, &constraints);
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
 
         // FOV branch calculates sensor from focal + FOV, even if sensor is also constrained
         // This allows validation that the three parameters are consistent
@@ -1911,7 +3361,7 @@ mod tests {
 
     #[test]
     fn test_combo_none_pixel_focal_fov() {
-        use crate::optics::types::{DoriTargets, ParameterConstraint};
+        use crate::types::{DoriTargets, ParameterConstraint};
 
         // Case 0111: Pixel, focal, and FOV constrained - sensor is determined
         let targets = DoriTargets {
@@ -1928,9 +3378,11 @@ mod tests {
             pixel_height: None,
             focal_length_mm: Some(28.0),
             horizontal_fov_deg: Some(65.0),
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
         };
 
-        let ranges = calculate_dori_parameter_ranges(&targets, &constraints);
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
 
         // Pixel, focal, and FOV should not have ranges (fixed inputs)
         assert!(ranges.pixel_width.is_none(), "Pixel width should be None");
@@ -1961,7 +3413,7 @@ mod tests {
 
     #[test]
     fn test_combo_sensor_pixel_focal_fov() {
-        use crate::optics::types::{DoriTargets, ParameterConstraint};
+        use crate::types::{DoriTargets, ParameterConstraint};
 
         // Case 1111: All constrained - fully determined system
         // Similar to previous case: FOV+focal causes sensor to be calculated for validation
@@ -1980,9 +3432,11 @@ mod tests {
             pixel_height: None,
             focal_length_mm: Some(4.0),
             horizontal_fov_deg: Some(84.0),
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
         };
 
-        let ranges = calculate_dori_parameter_ranges(&targets, &constraints);
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
 
         // When FOV is constrained, it enters the FOV branch
         // FOV + focal determines sensor, even if sensor+pixel are also constrained
@@ -2027,7 +3481,7 @@ mod tests {
 
     #[test]
     fn test_height_sensor_height_only() {
-        use crate::optics::types::{DoriTargets, ParameterConstraint};
+        use crate::types::{DoriTargets, ParameterConstraint};
 
         // Test with only sensor_height constrained (unusual but valid)
         let targets = DoriTargets {
@@ -2044,9 +3498,11 @@ mod tests {
             pixel_height: None,
             focal_length_mm: None,
             horizontal_fov_deg: None,
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
         };
 
-        let ranges = calculate_dori_parameter_ranges(&targets, &constraints);
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
 
         // Sensor height is fixed, should not have range
         assert!(
@@ -2070,7 +3526,7 @@ mod tests {
 
     #[test]
     fn test_height_pixel_height_only() {
-        use crate::optics::types::{DoriTargets, ParameterConstraint};
+        use crate::types::{DoriTargets, ParameterConstraint};
 
         // Test with only pixel_height constrained
         let targets = DoriTargets {
@@ -2087,9 +3543,11 @@ mod tests {
             pixel_height: Some(1080),
             focal_length_mm: None,
             horizontal_fov_deg: None,
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
         };
 
-        let ranges = calculate_dori_parameter_ranges(&targets, &constraints);
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
 
         // Pixel height is fixed, should not have range
         assert!(
@@ -2113,7 +3571,7 @@ mod tests {
 
     #[test]
     fn test_height_sensor_width_and_height() {
-        use crate::optics::types::{DoriTargets, ParameterConstraint};
+        use crate::types::{DoriTargets, ParameterConstraint};
 
         // Test with both sensor width and height constrained (custom aspect ratio)
         let targets = DoriTargets {
@@ -2130,9 +3588,11 @@ mod tests {
             pixel_height: None,
             focal_length_mm: None,
             horizontal_fov_deg: None,
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
         };
 
-        let ranges = calculate_dori_parameter_ranges(&targets, &constraints);
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
 
         // Both sensor dimensions are fixed
         assert!(
@@ -2156,7 +3616,7 @@ mod tests {
 
     #[test]
     fn test_height_pixel_width_and_height() {
-        use crate::optics::types::{DoriTargets, ParameterConstraint};
+        use crate::types::{DoriTargets, ParameterConstraint};
 
         // Test with both pixel width and height constrained (custom aspect ratio)
         let targets = DoriTargets {
@@ -2173,9 +3633,11 @@ mod tests {
             pixel_height: Some(1080), // 16:9 aspect ratio
             focal_length_mm: None,
             horizontal_fov_deg: None,
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
         };
 
-        let ranges = calculate_dori_parameter_ranges(&targets, &constraints);
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
 
         // Both pixel dimensions are fixed
         assert!(
@@ -2199,7 +3661,7 @@ mod tests {
 
     #[test]
     fn test_height_sensor_and_focal_with_sensor_height() {
-        use crate::optics::types::{DoriTargets, ParameterConstraint};
+        use crate::types::{DoriTargets, ParameterConstraint};
 
         // Test sensor width + height + focal (determines aspect ratio)
         let targets = DoriTargets {
@@ -2216,9 +3678,11 @@ mod tests {
             pixel_height: None,
             focal_length_mm: Some(25.0),
             horizontal_fov_deg: None,
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
         };
 
-        let ranges = calculate_dori_parameter_ranges(&targets, &constraints);
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
 
         // Sensor dimensions and focal are fixed
         assert!(
@@ -2258,7 +3722,7 @@ mod tests {
 
     #[test]
     fn test_height_all_pixels_constrained() {
-        use crate::optics::types::{DoriTargets, ParameterConstraint};
+        use crate::types::{DoriTargets, ParameterConstraint};
 
         // Test with all pixel dimensions constrained along with sensor
         let targets = DoriTargets {
@@ -2275,9 +3739,11 @@ mod tests {
             pixel_height: Some(1440), // 4:3
             focal_length_mm: None,
             horizontal_fov_deg: None,
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
         };
 
-        let ranges = calculate_dori_parameter_ranges(&targets, &constraints);
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
 
         // All dimensions are fixed, only focal should have range
         assert!(
@@ -2298,7 +3764,7 @@ mod tests {
 
     #[test]
     fn test_height_mismatched_aspect_ratios() {
-        use crate::optics::types::{DoriTargets, ParameterConstraint};
+        use crate::types::{DoriTargets, ParameterConstraint};
 
         // Test with mismatched aspect ratios (sensor 4:3, pixels 16:9)
         let targets = DoriTargets {
@@ -2315,9 +3781,11 @@ mod tests {
             pixel_height: Some(1080), // 16:9 aspect
             focal_length_mm: None,
             horizontal_fov_deg: None,
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
         };
 
-        let ranges = calculate_dori_parameter_ranges(&targets, &constraints);
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
 
         // All dimensions fixed despite mismatched aspect ratios
         assert!(
@@ -2339,7 +3807,7 @@ mod tests {
 
     #[test]
     fn test_height_fov_with_heights() {
-        use crate::optics::types::{DoriTargets, ParameterConstraint};
+        use crate::types::{DoriTargets, ParameterConstraint};
 
         // Test FOV constraint with height dimensions
         let targets = DoriTargets {
@@ -2356,9 +3824,11 @@ mod tests {
             pixel_height: None,
             focal_length_mm: None,
             horizontal_fov_deg: Some(45.0),
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
         };
 
-        let ranges = calculate_dori_parameter_ranges(&targets, &constraints);
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
 
         // Sensor width, height, and FOV are fixed - focal should be determined
         assert!(
@@ -2387,7 +3857,7 @@ mod tests {
 
     #[test]
     fn test_height_vertical_fov_implications() {
-        use crate::optics::types::{DoriTargets, ParameterConstraint};
+        use crate::types::{DoriTargets, ParameterConstraint};
 
         // Test that vertical FOV is implied by horizontal FOV and aspect ratio
         let targets = DoriTargets {
@@ -2404,9 +3874,11 @@ mod tests {
             pixel_height: Some(1440), // 4:3
             focal_length_mm: Some(50.0),
             horizontal_fov_deg: None,
+            vertical_fov_deg: None,
+            diagonal_fov_deg: None,
         };
 
-        let ranges = calculate_dori_parameter_ranges(&targets, &constraints);
+        let ranges = calculate_dori_parameter_ranges(&targets, &constraints).unwrap();
 
         // Everything is fixed - FOV should be calculated
         assert!(
@@ -0,0 +1,111 @@
+use super::calculations::calculate_fov;
+use super::constants::{
+    CHARUCO_MARKER_RATIO, DEFAULT_CALIBRATION_FOV_FRACTION, DEFAULT_PIXELS_PER_SQUARE,
+    MIN_CALIBRATION_SQUARES,
+};
+use super::types::{CalibrationPattern, CalibrationTarget, CalibrationTargetParams};
+
+/// Recommend a checkerboard or ChArUco board for calibrating a camera at a distance
+///
+/// The square size gives the requested pixels per square at the working distance,
+/// rounded to a whole millimeter for printing. The board then takes as many squares
+/// as fit in the requested fraction of the frame. Checkerboards get an even number
+/// of squares on one side and an odd number on the other, so the pattern has no
+/// 180° symmetry and detectors can tell its orientation.
+///
+/// # Returns
+/// `None` if fewer than three squares fit along either side
+pub fn size_calibration_target(params: &CalibrationTargetParams) -> Option<CalibrationTarget> {
+    let pixels_per_square = params
+        .pixels_per_square
+        .unwrap_or(DEFAULT_PIXELS_PER_SQUARE);
+    let fov_fraction = params
+        .fov_fraction
+        .unwrap_or(DEFAULT_CALIBRATION_FOV_FRACTION);
+
+    let fov = calculate_fov(&params.camera, params.working_distance_mm);
+    let ppmm = fov.horizontal_ppm.min(fov.vertical_ppm) / 1000.0;
+    let square_size_mm = (pixels_per_square / ppmm).round().max(1.0);
+
+    let frame_width_mm = fov.horizontal_fov_m * 1000.0;
+    let frame_height_mm = fov.vertical_fov_m * 1000.0;
+    let columns = (frame_width_mm * fov_fraction / square_size_mm).floor() as u32;
+    let mut rows = (frame_height_mm * fov_fraction / square_size_mm).floor() as u32;
+    if params.pattern == CalibrationPattern::Checkerboard && columns % 2 == rows % 2 {
+        // No room for a single row leaves nothing to drop
+        rows = rows.checked_sub(1)?;
+    }
+    if columns < MIN_CALIBRATION_SQUARES || rows < MIN_CALIBRATION_SQUARES {
+        return None;
+    }
+
+    let board_width_mm = columns as f64 * square_size_mm;
+    let board_height_mm = rows as f64 * square_size_mm;
+    Some(CalibrationTarget {
+        square_size_mm,
+        marker_size_mm: (params.pattern == CalibrationPattern::Charuco)
+            .then_some(square_size_mm * CHARUCO_MARKER_RATIO),
+        columns,
+        rows,
+        inner_corners: (columns - 1, rows - 1),
+        board_width_mm,
+        board_height_mm,
+        pixels_per_square: square_size_mm * ppmm,
+        width_fraction: board_width_mm / frame_width_mm,
+        height_fraction: board_height_mm / frame_height_mm,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::CameraSystem;
+
+    fn params(pattern: CalibrationPattern) -> CalibrationTargetParams {
+        // 6.4 × 4.8 m frame at 8 m: 0.25 px/mm, so 25 px squares are 100 mm
+        CalibrationTargetParams {
+            camera: CameraSystem::new(6.4, 4.8, 1600, 1200, 8.0),
+            pattern,
+            working_distance_mm: 8000.0,
+            pixels_per_square: None,
+            fov_fraction: None,
+        }
+    }
+
+    #[test]
+    fn test_checkerboard_is_asymmetric() {
+        let target = size_calibration_target(&params(CalibrationPattern::Checkerboard)).unwrap();
+
+        assert!((target.square_size_mm - 100.0).abs() < 1e-9);
+        assert!((target.pixels_per_square - 25.0).abs() < 1e-9);
+        // 32 × 24 squares fit in half the frame; 24 rows drop to 23
+        assert_eq!((target.columns, target.rows), (32, 23));
+        assert_eq!(target.inner_corners, (31, 22));
+        assert!(target.marker_size_mm.is_none());
+    }
+
+    #[test]
+    fn test_charuco_keeps_squares_and_sizes_markers() {
+        let target = size_calibration_target(&params(CalibrationPattern::Charuco)).unwrap();
+
+        assert_eq!((target.columns, target.rows), (32, 24));
+        assert!((target.marker_size_mm.unwrap() - 75.0).abs() < 1e-9);
+        assert!((target.height_fraction - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_too_few_squares() {
+        let mut params = params(CalibrationPattern::Charuco);
+        params.pixels_per_square = Some(400.0);
+        assert!(size_calibration_target(&params).is_none());
+    }
+
+    #[test]
+    fn test_no_rows_on_wide_frame() {
+        // 6.4 × 1.8 m frame at 8 m: 1.5 m squares fit 2 columns and no rows
+        let mut params = params(CalibrationPattern::Checkerboard);
+        params.camera = CameraSystem::new(6.4, 1.8, 3840, 1080, 8.0);
+        params.pixels_per_square = Some(900.0);
+        assert!(size_calibration_target(&params).is_none());
+    }
+}
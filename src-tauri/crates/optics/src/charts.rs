@@ -0,0 +1,114 @@
+use super::types::{ChartFraming, TestChart, TestChartParams, TestChartSetup, UsafElement};
+
+impl UsafElement {
+    /// Finest element at or below a resolution in line pairs per mm
+    ///
+    /// Elements step by 2^(1/6): lp/mm = 2^(group + (element - 1) / 6). Standard
+    /// targets span groups -2 to 7, so results outside that need a special target.
+    pub fn finest_within(line_pairs_per_mm: f64) -> Self {
+        let step = (line_pairs_per_mm.log2() * 6.0 + 1e-9).floor() as i32;
+        let group = step.div_euclid(6);
+        let element = step.rem_euclid(6) as u32 + 1;
+        Self {
+            group,
+            element,
+            line_pairs_per_mm: 2f64.powf(group as f64 + (element - 1) as f64 / 6.0),
+        }
+    }
+}
+
+/// Calculate where to place a resolution test chart to verify a camera in the lab
+///
+/// The magnification follows from the framing, and the working distance from the
+/// thin-lens equation, u = f × (1 + 1/m). The sensor's Nyquist limit, 1 / (2 ×
+/// pixel pitch), is projected onto the chart so the measured limit can be compared
+/// with the theoretical one.
+///
+/// ISO 12233 charts are scaled for the chart height filling the picture height;
+/// when it fills less, readings are multiplied by `iso_reading_scale`.
+///
+/// # Returns
+/// `None` if the chart size or framing isn't positive
+pub fn calculate_test_chart_setup(params: &TestChartParams) -> Option<TestChartSetup> {
+    let camera = &params.camera;
+    if params.chart_width_mm <= 0.0 || params.chart_height_mm <= 0.0 {
+        return None;
+    }
+
+    let magnification = match params.framing {
+        ChartFraming::Coverage(fraction) => {
+            fraction
+                * (camera.sensor_width_mm / params.chart_width_mm)
+                    .min(camera.sensor_height_mm / params.chart_height_mm)
+        }
+        ChartFraming::Magnification(magnification) => magnification,
+    };
+    if magnification <= 0.0 {
+        return None;
+    }
+
+    let pixel_pitch_mm = camera.sensor_width_mm / camera.pixel_width as f64;
+    let chart_nyquist_lp_mm = magnification / (2.0 * pixel_pitch_mm);
+    let height_fraction = params.chart_height_mm * magnification / camera.sensor_height_mm;
+
+    Some(TestChartSetup {
+        working_distance_mm: camera.focal_length_mm * (1.0 + 1.0 / magnification),
+        magnification,
+        width_fraction: params.chart_width_mm * magnification / camera.sensor_width_mm,
+        height_fraction,
+        chart_nyquist_lp_mm,
+        usaf_limit: (params.chart == TestChart::Usaf1951)
+            .then(|| UsafElement::finest_within(chart_nyquist_lp_mm)),
+        iso_reading_scale: (params.chart == TestChart::Iso12233).then(|| 1.0 / height_fraction),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::CameraSystem;
+
+    // 4 µm pixels: Nyquist at 125 lp/mm on the sensor
+    fn camera() -> CameraSystem {
+        CameraSystem::new(6.4, 4.8, 1600, 1200, 8.0)
+    }
+
+    #[test]
+    fn test_iso_chart_filling_frame() {
+        let setup = calculate_test_chart_setup(&TestChartParams {
+            camera: camera(),
+            chart: TestChart::Iso12233,
+            chart_width_mm: 400.0,
+            chart_height_mm: 300.0,
+            framing: ChartFraming::Coverage(1.0),
+        })
+        .unwrap();
+
+        assert!((setup.magnification - 0.016).abs() < 1e-12);
+        assert!((setup.working_distance_mm - 508.0).abs() < 1e-9);
+        assert!((setup.chart_nyquist_lp_mm - 2.0).abs() < 1e-9);
+        assert!((setup.iso_reading_scale.unwrap() - 1.0).abs() < 1e-9);
+        assert!(setup.usaf_limit.is_none());
+    }
+
+    #[test]
+    fn test_usaf_limit_at_unit_magnification() {
+        let setup = calculate_test_chart_setup(&TestChartParams {
+            camera: camera(),
+            chart: TestChart::Usaf1951,
+            chart_width_mm: 50.8,
+            chart_height_mm: 50.8,
+            framing: ChartFraming::Magnification(1.0),
+        })
+        .unwrap();
+
+        assert!((setup.working_distance_mm - 16.0).abs() < 1e-9);
+        // 125 lp/mm falls between group 6 element 6 (114 lp/mm) and group 7 element 1
+        let limit = setup.usaf_limit.unwrap();
+        assert_eq!((limit.group, limit.element), (6, 6));
+        assert!((limit.line_pairs_per_mm - 114.0).abs() < 0.1);
+
+        let exact = UsafElement::finest_within(2.0);
+        assert_eq!((exact.group, exact.element), (1, 1));
+    }
+}
@@ -0,0 +1,183 @@
+pub(super) const DETECTION_PX_PER_M: f64 = 25.0;
+pub(super) const OBSERVATION_PX_PER_M: f64 = 62.5;
+pub(super) const RECOGNITION_PX_PER_M: f64 = 125.0;
+pub(super) const IDENTIFICATION_PX_PER_M: f64 = 250.0;
+
+/// Incident-light meter calibration constant (ISO 2720, flat receptor)
+pub(super) const INCIDENT_METER_CALIBRATION: f64 = 250.0;
+
+/// Koschmieder constant: ln(1 / 0.02) for a 2% contrast threshold
+pub(super) const KOSCHMIEDER_CONSTANT: f64 = 3.912;
+
+/// Default wavelength for diffraction calculations (green, peak photopic sensitivity)
+pub(super) const DEFAULT_WAVELENGTH_NM: f64 = 550.0;
+
+/// Default spacing of the coverage sample grid in metres
+pub(super) const DEFAULT_GRID_SPACING_M: f64 = 0.5;
+
+/// Upper limit on coverage samples per zone; the grid is coarsened beyond this
+pub(super) const MAX_ZONE_SAMPLES: usize = 20_000;
+
+/// Steps between progress reports of long-running computations
+pub(super) const PROGRESS_REPORT_INTERVAL: usize = 500;
+
+/// Pan increment tried by the placement optimizer in degrees
+pub(super) const PLACEMENT_PAN_STEP_DEG: f64 = 15.0;
+
+/// Rays cast across the horizontal FOV when tracing a camera's visible footprint
+pub(super) const FOOTPRINT_RAYS: usize = 64;
+
+/// Typical face height above the ground in metres
+pub(super) const FACE_HEIGHT_M: f64 = 1.6;
+
+/// Steepest vertical angle onto a face that still allows facial identification, in degrees
+pub(super) const MAX_FACE_INCIDENCE_DEG: f64 = 30.0;
+
+/// Largest motion blur in pixels at which plate characters stay legible
+pub(super) const ALPR_MAX_BLUR_PX: f64 = 1.0;
+
+/// Default decoding thresholds in pixels per module for 1D and 2D codes
+pub(super) const LINEAR_CODE_PIXELS_PER_MODULE: f64 = 2.0;
+pub(super) const MATRIX_CODE_PIXELS_PER_MODULE: f64 = 3.0;
+
+/// Default pixels across the smallest feature for machine-vision inspection
+pub(super) const DEFAULT_PIXELS_PER_FEATURE: f64 = 3.0;
+
+/// Default pixels across a calibration square, mid-range of the usual 20-30 px
+pub(super) const DEFAULT_PIXELS_PER_SQUARE: f64 = 25.0;
+
+/// Default fraction of the frame a calibration board fills
+pub(super) const DEFAULT_CALIBRATION_FOV_FRACTION: f64 = 0.5;
+
+/// ArUco marker size as a fraction of the ChArUco square size
+pub(super) const CHARUCO_MARKER_RATIO: f64 = 0.75;
+
+/// Smallest number of squares along either side of a usable calibration board
+pub(super) const MIN_CALIBRATION_SQUARES: u32 = 3;
+
+/// Arcseconds per radian
+pub(super) const ARCSEC_PER_RAD: f64 = 206_264.806;
+
+/// Pixels across the seeing FWHM for well-sampled star images
+pub(super) const ASTRO_MIN_PIXELS_PER_FWHM: f64 = 2.0;
+pub(super) const ASTRO_MAX_PIXELS_PER_FWHM: f64 = 3.0;
+
+/// Full-frame (36 × 24 mm) sensor diagonal, the reference for crop factors
+pub(super) const FULL_FRAME_DIAGONAL_MM: f64 = 43.27;
+
+/// Pupil diameter of a dark-adapted eye in millimeters
+pub(super) const DARK_ADAPTED_PUPIL_MM: f64 = 7.0;
+
+/// Highest useful telescope magnification per millimeter of aperture
+pub(super) const MAX_MAGNIFICATION_PER_MM: f64 = 2.0;
+
+/// Dawes limit constant in arcsecond-millimeters
+pub(super) const DAWES_CONSTANT: f64 = 116.0;
+
+/// Default drone altitude ceiling in metres (EU open category; US Part 107 is 400 ft)
+pub(super) const DEFAULT_ALTITUDE_CEILING_M: f64 = 120.0;
+
+/// Samples from the image centre to the corner in an illumination falloff profile
+pub(super) const VIGNETTING_SAMPLES: usize = 11;
+
+/// Default lowest acceptable corner illumination in percent of the centre (1 stop)
+pub(super) const DEFAULT_MIN_CORNER_ILLUMINATION_PERCENT: f64 = 50.0;
+
+/// Dynamic range headroom in dB (1 stop) a camera needs over a scene to pass,
+/// as vendor WDR figures are measured under ideal conditions
+pub(super) const WDR_HEADROOM_DB: f64 = 6.0;
+
+/// Photons per second per µm² for an illuminance of 1 lux at 555 nm
+pub(super) const PHOTONS_PER_LUX_SECOND_UM2: f64 = 4.09e3;
+
+/// Reflectance of a mid-grey scene
+pub(super) const DEFAULT_SCENE_REFLECTANCE: f64 = 0.18;
+
+/// Largest difference between a given and the implied FOV still treated as consistent, in percent
+pub(super) const FOV_CONSISTENCY_TOLERANCE_PERCENT: f64 = 0.5;
+
+/// Margin over the minimum of a DORI parameter range for its recommended value (20%)
+pub(super) const RECOMMENDED_RANGE_MARGIN: f64 = 1.2;
+
+/// Common fixed focal lengths of CCTV and photographic lenses in mm
+pub(super) const STANDARD_FOCAL_LENGTHS_MM: &[f64] = &[
+    2.8, 3.6, 4.0, 6.0, 8.0, 12.0, 16.0, 25.0, 35.0, 50.0, 75.0, 100.0, 135.0, 200.0, 300.0, 400.0,
+];
+
+/// Common CCTV varifocal zoom ranges as (wide, tele) focal lengths in mm
+pub(super) const STANDARD_VARIFOCAL_RANGES_MM: &[(f64, f64)] = &[
+    (2.7, 13.5),
+    (2.8, 12.0),
+    (3.6, 10.0),
+    (5.0, 50.0),
+    (8.0, 32.0),
+    (10.0, 40.0),
+    (12.0, 50.0),
+];
+
+/// Common surveillance and machine-vision resolutions as (name, width, height) in pixels
+pub(super) const STANDARD_RESOLUTIONS: &[(&str, u32, u32)] = &[
+    ("VGA", 640, 480),
+    ("720p", 1280, 720),
+    ("1080p", 1920, 1080),
+    ("3 MP", 2048, 1536),
+    ("4 MP", 2560, 1440),
+    ("5 MP", 2592, 1944),
+    ("6 MP", 3072, 2048),
+    ("4K", 3840, 2160),
+    ("12 MP", 4000, 3000),
+    ("20 MP", 5472, 3648),
+    ("8K", 7680, 4320),
+];
+
+/// Common optical formats as (name, width, height) in mm, 1/4" to full frame
+pub(super) const STANDARD_SENSOR_FORMATS: &[(&str, f64, f64)] = &[
+    ("1/4\"", 3.6, 2.7),
+    ("1/3\"", 4.8, 3.6),
+    ("1/2.8\"", 5.6, 3.15),
+    ("1/2.5\"", 5.76, 4.29),
+    ("1/2\"", 6.4, 4.8),
+    ("1/1.8\"", 7.18, 5.32),
+    ("2/3\"", 8.8, 6.6),
+    ("1\"", 13.2, 8.8),
+    ("Four Thirds", 17.3, 13.0),
+    ("APS-C", 23.5, 15.6),
+    ("Full frame", 36.0, 24.0),
+];
+
+/// Typical smartphone camera modules as (name, sensor width, sensor height in mm,
+/// pixel width, pixel height, focal length in mm, f-number). Resolutions are the
+/// binned 12 MP output most phones record at; names give the 35 mm equivalent.
+pub(super) const SMARTPHONE_CAMERA_MODULES: &[(&str, f64, f64, u32, u32, f64, f64)] = &[
+    ("Phone main - 24mm eq.", 9.8, 7.35, 4032, 3024, 6.86, 1.8),
+    (
+        "Phone ultrawide - 14mm eq.",
+        5.6,
+        4.2,
+        4032,
+        3024,
+        2.22,
+        2.2,
+    ),
+    ("Phone tele 3x - 78mm eq.", 4.0, 3.0, 4032, 3024, 9.0, 2.8),
+    (
+        "Phone periscope 5x - 120mm eq.",
+        4.7,
+        3.52,
+        4032,
+        3024,
+        16.3,
+        2.8,
+    ),
+];
+
+/// Largest shortfall of a DORI target's distance × pixel density below the strictest
+/// target still treated as following the standard ratios, in percent
+pub(super) const DORI_TARGET_RATIO_TOLERANCE_PERCENT: f64 = 1.0;
+
+/// Metres per international foot
+pub(super) const METERS_PER_FOOT: f64 = 0.3048;
+
+/// 35 mm-equivalent focal lengths at which each angle-of-view class ends, from
+/// ultra-wide up to tele; anything longer is super-tele
+pub(super) const ANGLE_OF_VIEW_LIMITS_EQUIVALENT_MM: [f64; 5] = [24.0, 40.0, 70.0, 135.0, 300.0];
@@ -0,0 +1,98 @@
+use super::types::{CameraSystem, CorridorSolution};
+
+/// Minimum number of cameras that cover a straight run at a pixel density
+///
+/// All cameras face the same way along the run, so the blind spot below each
+/// camera is covered by the one behind it. Each camera is tilted so the top edge
+/// of its vertical FOV reaches the point where the pixel density falls to
+/// `required_ppm`; the bottom edge then sets the blind spot. The first camera is
+/// set back from the start of the run by its blind spot, and cameras are spaced
+/// evenly so the run is covered from start to end.
+///
+/// # Returns
+/// `None` if the camera does not reach `required_ppm` even straight below it
+pub fn solve_corridor_coverage(
+    camera: &CameraSystem,
+    length_m: f64,
+    mounting_height_m: f64,
+    required_ppm: f64,
+) -> Option<CorridorSolution> {
+    // Slant range at which the density falls to the requirement, projected onto the run
+    let slant_m = camera.pixel_width as f64 * camera.focal_length_mm
+        / (camera.sensor_width_mm * required_ppm);
+    if slant_m <= mounting_height_m {
+        return None;
+    }
+    let reach_m = (slant_m * slant_m - mounting_height_m * mounting_height_m).sqrt();
+
+    let vertical_fov_rad = 2.0 * (camera.sensor_height_mm / (2.0 * camera.focal_length_mm)).atan();
+    let far_depression_rad = mounting_height_m.atan2(reach_m);
+    let near_depression_rad = far_depression_rad + vertical_fov_rad;
+    let blind_spot_m = if near_depression_rad >= std::f64::consts::FRAC_PI_2 {
+        0.0
+    } else {
+        mounting_height_m / near_depression_rad.tan()
+    };
+
+    // The bottom edge always lands nearer than the top edge, so each camera covers some length
+    let camera_count = (length_m / (reach_m - blind_spot_m)).ceil().max(1.0) as u32;
+    let spacing_m = length_m / camera_count as f64;
+    Some(CorridorSolution {
+        camera_count,
+        spacing_m,
+        tilt_deg: -(far_depression_rad + vertical_fov_rad / 2.0).to_degrees(),
+        positions_m: (0..camera_count)
+            .map(|i| i as f64 * spacing_m - blind_spot_m)
+            .collect(),
+        blind_spot_m,
+        reach_m,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn camera() -> CameraSystem {
+        // ~22° vertical FOV, observation (62.5 px/m) out to ~43.9 m slant
+        CameraSystem::new(5.6, 3.15, 1920, 1080, 8.0)
+    }
+
+    #[test]
+    fn test_corridor_camera_count_and_spacing() {
+        let solution = solve_corridor_coverage(&camera(), 100.0, 3.0, 62.5).unwrap();
+
+        assert!((solution.reach_m - 43.78).abs() < 0.01);
+        // Bottom edge at 3.9° + 22.3° below horizontal
+        assert!((solution.blind_spot_m - 6.09).abs() < 0.01);
+        assert!((solution.tilt_deg + 15.06).abs() < 0.01);
+        // ~37.7 m covered per camera
+        assert_eq!(solution.camera_count, 3);
+        assert!((solution.spacing_m - 100.0 / 3.0).abs() < 1e-9);
+        assert_eq!(solution.positions_m.len(), 3);
+        assert_eq!(solution.positions_m[0], -solution.blind_spot_m);
+
+        // Each camera covers up to the next one's blind spot, the last past the end
+        for pair in solution.positions_m.windows(2) {
+            assert!(pair[0] + solution.reach_m >= pair[1] + solution.blind_spot_m);
+        }
+        assert!(solution.positions_m[2] + solution.reach_m >= 100.0);
+    }
+
+    #[test]
+    fn test_short_run_needs_one_camera() {
+        let solution = solve_corridor_coverage(&camera(), 10.0, 3.0, 62.5).unwrap();
+        assert_eq!(solution.camera_count, 1);
+        assert_eq!(solution.spacing_m, 10.0);
+    }
+
+    #[test]
+    fn test_requirement_out_of_reach() {
+        // Identification only reaches ~11 m slant
+        assert!(solve_corridor_coverage(&camera(), 50.0, 12.0, 250.0).is_none());
+        // From 10 m up it reaches only ~4.5 m along the run, looking almost straight down
+        let steep = solve_corridor_coverage(&camera(), 50.0, 10.0, 250.0).unwrap();
+        assert!(steep.blind_spot_m < 0.5);
+        assert_eq!(steep.camera_count, 13);
+    }
+}
@@ -0,0 +1,530 @@
+use rayon::prelude::*;
+
+use super::constants::{
+    DEFAULT_GRID_SPACING_M, FOOTPRINT_RAYS, MAX_ZONE_SAMPLES, PROGRESS_REPORT_INTERVAL,
+};
+use super::geometry::{
+    bearing_deg, grid_samples, line_of_sight, plan_distance, polygon_area, polygon_contains,
+    polygon_edges, ray_hit_distance,
+};
+use super::tolerance::band;
+use super::types::{
+    CoverageZone, DoriLevel, PlacedCamera, PlanPoint, Scene, TierCoverage, ZoneCoverage,
+    ZoneStatistics,
+};
+
+/// Plan position of a placed camera
+pub(crate) fn camera_origin(placed: &PlacedCamera) -> PlanPoint {
+    PlanPoint {
+        x_m: placed.pose.x_m,
+        y_m: placed.pose.y_m,
+    }
+}
+
+/// Wall segments that block the view: the room outline plus every obstacle outline
+pub(crate) fn wall_segments(scene: &Scene) -> Vec<(PlanPoint, PlanPoint)> {
+    let mut walls: Vec<_> = polygon_edges(&scene.room).collect();
+    for obstacle in &scene.obstacles {
+        match obstacle.polygon.as_slice() {
+            // A two-point wall would otherwise be traced there and back
+            [a, b] => walls.push((*a, *b)),
+            polygon => walls.extend(polygon_edges(polygon)),
+        }
+    }
+    walls
+}
+
+/// Half the horizontal field of view in degrees
+fn half_fov_deg(placed: &PlacedCamera) -> f64 {
+    let camera = &placed.camera;
+    (camera.sensor_width_mm / (2.0 * camera.focal_length_mm))
+        .atan()
+        .to_degrees()
+}
+
+/// The constants of [`pixel_density_at`] for one placed camera
+///
+/// Bulk evaluation computes these once per camera instead of once per sample
+/// point; the trigonometry of the FOV is the costly part.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DensityModel {
+    pub(crate) origin: PlanPoint,
+    height_m: f64,
+    pan_deg: f64,
+    half_fov_deg: f64,
+    /// pixel_width × focal_length, the numerator of the density
+    pixel_focal: f64,
+    sensor_width_mm: f64,
+}
+
+impl DensityModel {
+    pub(crate) fn new(placed: &PlacedCamera) -> Self {
+        let camera = &placed.camera;
+        Self {
+            origin: camera_origin(placed),
+            height_m: placed.pose.height_m,
+            pan_deg: placed.pose.pan_deg,
+            half_fov_deg: half_fov_deg(placed),
+            pixel_focal: camera.pixel_width as f64 * camera.focal_length_mm,
+            sensor_width_mm: camera.sensor_width_mm,
+        }
+    }
+
+    /// Whether a bearing from the camera lies within its horizontal FOV
+    pub(crate) fn in_view(&self, bearing_deg: f64) -> bool {
+        let off_axis_deg = (bearing_deg - self.pan_deg + 180.0).rem_euclid(360.0) - 180.0;
+        off_axis_deg.abs() <= self.half_fov_deg
+    }
+
+    /// Bearings at the left and right edge of the horizontal FOV
+    ///
+    /// Either may lie outside [0, 360) when the FOV spans north.
+    pub(crate) fn view_window(&self) -> (f64, f64) {
+        (
+            self.pan_deg - self.half_fov_deg,
+            self.pan_deg + self.half_fov_deg,
+        )
+    }
+
+    /// Density at a slant distance, ignoring the FOV
+    pub(crate) fn density_on_axis(&self, slant_m: f64) -> f64 {
+        // px/m = pixel_width / frame width, with frame width = slant × sensor_width / focal_length
+        self.pixel_focal / (self.sensor_width_mm * slant_m)
+    }
+
+    /// Density at a point given by its bearing and slant distance from the camera
+    ///
+    /// The point must not be directly below the camera.
+    pub(crate) fn density(&self, bearing_deg: f64, slant_m: f64) -> Option<f64> {
+        self.in_view(bearing_deg)
+            .then(|| self.density_on_axis(slant_m))
+    }
+
+    /// Density at a point on the site plan, see [`pixel_density_at`]
+    pub(crate) fn density_at(&self, point: PlanPoint) -> Option<f64> {
+        let distance_m = plan_distance(self.origin, point);
+        if distance_m < 1e-9 {
+            return None;
+        }
+        self.density(
+            bearing_deg(self.origin, point),
+            distance_m.hypot(self.height_m),
+        )
+    }
+}
+
+/// Horizontal pixel density a placed camera achieves at a point on the site plan
+///
+/// The camera is modelled in plan view: a point is seen when its bearing lies within
+/// the horizontal FOV around the pan direction, and the pixel density follows from the
+/// slant distance between the mounting height and the plane of interest.
+///
+/// # Returns
+/// `None` when the point is outside the horizontal FOV or directly below the camera
+pub fn pixel_density_at(placed: &PlacedCamera, point: PlanPoint) -> Option<f64> {
+    DensityModel::new(placed).density_at(point)
+}
+
+/// Outline of the area a camera sees at or above a pixel density
+///
+/// Rays are cast across the horizontal FOV and each stops at the range where the
+/// density drops below `required_ppm` or at the first wall or obstacle, whichever
+/// is nearer. The first point of the outline is the camera position.
+pub fn camera_footprint(placed: &PlacedCamera, required_ppm: f64, scene: &Scene) -> Vec<PlanPoint> {
+    let camera = &placed.camera;
+    let origin = camera_origin(placed);
+    let walls = wall_segments(scene);
+
+    // Slant range at which the density falls to the requirement, projected onto the plan
+    let slant_m = camera.pixel_width as f64 * camera.focal_length_mm
+        / (camera.sensor_width_mm * required_ppm);
+    let range_m = (slant_m * slant_m - placed.pose.height_m * placed.pose.height_m)
+        .max(0.0)
+        .sqrt();
+
+    let half_fov = half_fov_deg(placed);
+    let mut outline = vec![origin];
+    for i in 0..=FOOTPRINT_RAYS {
+        let bearing =
+            placed.pose.pan_deg - half_fov + 2.0 * half_fov * i as f64 / FOOTPRINT_RAYS as f64;
+        let reach_m =
+            ray_hit_distance(origin, bearing, &walls).map_or(range_m, |hit| hit.min(range_m));
+        outline.push(PlanPoint {
+            x_m: origin.x_m + reach_m * bearing.to_radians().sin(),
+            y_m: origin.y_m + reach_m * bearing.to_radians().cos(),
+        });
+    }
+    outline
+}
+
+/// Grid spacing to use for a zone, coarsened so it stays within the sample limit
+fn zone_spacing(zone: &CoverageZone, spacing_m: Option<f64>) -> f64 {
+    let spacing_m = spacing_m.unwrap_or(DEFAULT_GRID_SPACING_M);
+    let min_spacing_m = (polygon_area(&zone.polygon) / MAX_ZONE_SAMPLES as f64).sqrt();
+    spacing_m.max(min_spacing_m)
+}
+
+/// Coverage sample points of each zone, clipped to the room outline
+pub(crate) fn zone_samples(scene: &Scene, spacing_m: Option<f64>) -> Vec<Vec<PlanPoint>> {
+    scene
+        .zones
+        .iter()
+        .map(|zone| {
+            grid_samples(&zone.polygon, zone_spacing(zone, spacing_m))
+                .into_iter()
+                .filter(|&point| polygon_contains(&scene.room, point))
+                .collect()
+        })
+        .collect()
+}
+
+/// Highest pixel density any camera with a clear line of sight achieves at a point
+fn best_density(
+    models: &[DensityModel],
+    walls: &[(PlanPoint, PlanPoint)],
+    point: PlanPoint,
+) -> f64 {
+    models
+        .iter()
+        .filter(|model| line_of_sight(model.origin, point, walls))
+        .filter_map(|model| model.density_at(point))
+        .fold(0.0, f64::max)
+}
+
+/// Highest pixel density any camera with a clear line of sight achieves at each point
+fn best_densities(
+    cameras: &[PlacedCamera],
+    walls: &[(PlanPoint, PlanPoint)],
+    samples: &[PlanPoint],
+) -> Vec<f64> {
+    let models: Vec<_> = cameras.iter().map(DensityModel::new).collect();
+    samples
+        .par_iter()
+        .map(|&point| best_density(&models, walls, point))
+        .collect()
+}
+
+/// Best pixel density at each sample point of every zone
+///
+/// `progress` is told how many samples over all zones are done; returning
+/// `false` cancels and gives `None`.
+fn zone_densities(
+    scene: &Scene,
+    cameras: &[PlacedCamera],
+    spacing_m: Option<f64>,
+    progress: &mut dyn FnMut(usize, usize) -> bool,
+) -> Option<Vec<Vec<f64>>> {
+    let walls = wall_segments(scene);
+    let samples = zone_samples(scene, spacing_m);
+    let total = samples.iter().map(Vec::len).sum();
+
+    // Each chunk of samples is evaluated in parallel, with progress reported between chunks
+    let mut done = 0;
+    let mut zones = Vec::with_capacity(samples.len());
+    for points in samples {
+        let mut densities = Vec::with_capacity(points.len());
+        for chunk in points.chunks(PROGRESS_REPORT_INTERVAL) {
+            densities.extend(best_densities(cameras, &walls, chunk));
+            done += chunk.len();
+            if done < total && !progress(done, total) {
+                return None;
+            }
+        }
+        zones.push(densities);
+    }
+    progress(total, total).then_some(zones)
+}
+
+/// Evaluate which zones of a scene meet their requirement, and by how much
+///
+/// Each sample point takes the highest pixel density of any camera with a clear
+/// line of sight to it past the room walls and obstacles. Zones are sampled on a
+/// grid of `spacing_m` (default 0.5 m).
+pub fn evaluate_zone_coverage(
+    scene: &Scene,
+    cameras: &[PlacedCamera],
+    spacing_m: Option<f64>,
+) -> Vec<ZoneCoverage> {
+    evaluate_zone_coverage_with_progress(scene, cameras, spacing_m, &mut |_, _| true)
+        .expect("evaluation without cancellation always completes")
+}
+
+/// [`evaluate_zone_coverage`] reporting the sample points done so far to `progress`
+///
+/// # Returns
+/// `None` if `progress` returned `false` to cancel
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(zones = scene.zones.len(), cameras = cameras.len(), ?spacing_m))
+)]
+pub fn evaluate_zone_coverage_with_progress(
+    scene: &Scene,
+    cameras: &[PlacedCamera],
+    spacing_m: Option<f64>,
+    progress: &mut dyn FnMut(usize, usize) -> bool,
+) -> Option<Vec<ZoneCoverage>> {
+    let densities = zone_densities(scene, cameras, spacing_m, progress)?;
+    let coverage = scene
+        .zones
+        .iter()
+        .zip(densities)
+        .map(|(zone, best_ppm)| {
+            let samples = best_ppm.len();
+            let required_ppm = zone.requirement.px_per_m();
+
+            let covered = best_ppm.iter().filter(|&&ppm| ppm >= required_ppm).count();
+            let worst_ppm = best_ppm.iter().copied().reduce(f64::min).unwrap_or(0.0);
+            ZoneCoverage {
+                name: zone.name.clone(),
+                required_ppm,
+                covered_percent: if samples == 0 {
+                    0.0
+                } else {
+                    covered as f64 / samples as f64 * 100.0
+                },
+                worst_ppm,
+                margin_percent: (worst_ppm / required_ppm - 1.0) * 100.0,
+                meets_requirement: samples > 0 && covered == samples,
+            }
+        })
+        .collect();
+    Some(coverage)
+}
+
+/// Pixel density percentiles and DORI level coverage over each zone of a scene
+///
+/// Uses the same sampling and line-of-sight rules as [`evaluate_zone_coverage`],
+/// so compliance can be stated as a share of the area, e.g. the p5 density is
+/// reached over 95% of the zone.
+pub fn zone_coverage_statistics(
+    scene: &Scene,
+    cameras: &[PlacedCamera],
+    spacing_m: Option<f64>,
+) -> Vec<ZoneStatistics> {
+    zone_coverage_statistics_with_progress(scene, cameras, spacing_m, &mut |_, _| true)
+        .expect("statistics without cancellation always complete")
+}
+
+/// [`zone_coverage_statistics`] reporting the sample points done so far to `progress`
+///
+/// # Returns
+/// `None` if `progress` returned `false` to cancel
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(zones = scene.zones.len(), cameras = cameras.len(), ?spacing_m))
+)]
+pub fn zone_coverage_statistics_with_progress(
+    scene: &Scene,
+    cameras: &[PlacedCamera],
+    spacing_m: Option<f64>,
+    progress: &mut dyn FnMut(usize, usize) -> bool,
+) -> Option<Vec<ZoneStatistics>> {
+    let densities = zone_densities(scene, cameras, spacing_m, progress)?;
+    let statistics = scene
+        .zones
+        .iter()
+        .zip(densities)
+        .map(|(zone, best_ppm)| {
+            let samples = best_ppm.len();
+            let tiers = DoriLevel::ALL
+                .into_iter()
+                .map(|level| {
+                    let covered = best_ppm
+                        .iter()
+                        .filter(|&&ppm| ppm >= level.px_per_m())
+                        .count();
+                    TierCoverage {
+                        level,
+                        covered_percent: if samples == 0 {
+                            0.0
+                        } else {
+                            covered as f64 / samples as f64 * 100.0
+                        },
+                    }
+                })
+                .collect();
+            ZoneStatistics {
+                name: zone.name.clone(),
+                samples: samples as u32,
+                ppm: (samples > 0).then(|| band(best_ppm)),
+                tiers,
+            }
+        })
+        .collect();
+    Some(statistics)
+}
+
+/// Zone sample points that no camera covers at the zone's requirement
+///
+/// Uses the same sampling and line-of-sight rules as [`evaluate_zone_coverage`].
+pub fn blind_spots(
+    scene: &Scene,
+    cameras: &[PlacedCamera],
+    spacing_m: Option<f64>,
+) -> Vec<PlanPoint> {
+    let walls = wall_segments(scene);
+    scene
+        .zones
+        .iter()
+        .zip(zone_samples(scene, spacing_m))
+        .flat_map(|(zone, samples)| {
+            let required_ppm = zone.requirement.px_per_m();
+            let best_ppm = best_densities(cameras, &walls, &samples);
+            samples
+                .into_iter()
+                .zip(best_ppm)
+                .filter(move |&(_, ppm)| ppm < required_ppm)
+                .map(|(point, _)| point)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{CameraPose, CameraSystem, DoriLevel, Obstacle, ZoneRequirement};
+
+    fn point(x_m: f64, y_m: f64) -> PlanPoint {
+        PlanPoint { x_m, y_m }
+    }
+
+    fn rectangle(x0: f64, y0: f64, x1: f64, y1: f64) -> Vec<PlanPoint> {
+        vec![point(x0, y0), point(x1, y0), point(x1, y1), point(x0, y1)]
+    }
+
+    fn placed(pan_deg: f64) -> PlacedCamera {
+        PlacedCamera {
+            // ~70° horizontal FOV
+            camera: CameraSystem::new(5.6, 3.15, 1920, 1080, 4.0),
+            pose: CameraPose {
+                x_m: 0.0,
+                y_m: 0.0,
+                height_m: 3.0,
+                pan_deg,
+                tilt_deg: -10.0,
+            },
+        }
+    }
+
+    fn zone(name: &str, polygon: Vec<PlanPoint>, requirement: ZoneRequirement) -> CoverageZone {
+        CoverageZone {
+            name: name.to_string(),
+            polygon,
+            requirement,
+        }
+    }
+
+    #[test]
+    fn test_pixel_density_matches_fov_at_slant_distance() {
+        let target = point(0.0, 4.0);
+        let ppm = pixel_density_at(&placed(0.0), target).unwrap();
+        let fov = crate::calculate_fov(&placed(0.0).camera, 5000.0);
+        assert!((ppm - fov.horizontal_ppm).abs() < 1e-9);
+
+        // Behind the camera and outside the FOV
+        assert!(pixel_density_at(&placed(180.0), target).is_none());
+        assert!(pixel_density_at(&placed(90.0), target).is_none());
+    }
+
+    #[test]
+    fn test_zone_coverage_pass_and_fail() {
+        let ahead = rectangle(-1.0, 1.0, 1.0, 11.0);
+        let scene = Scene {
+            room: rectangle(-20.0, 0.0, 20.0, 20.0),
+            zones: vec![
+                zone(
+                    "Ahead",
+                    ahead.clone(),
+                    ZoneRequirement::Dori(DoriLevel::Observation),
+                ),
+                zone("Close-up", ahead, ZoneRequirement::PixelDensity(250.0)),
+            ],
+            obstacles: Vec::new(),
+        };
+        let coverage = evaluate_zone_coverage(&scene, &[placed(0.0)], None);
+
+        // Observation reaches ~22 m slant, identification only ~5.5 m (4.6 m from the mast)
+        assert_eq!(coverage[0].required_ppm, 62.5);
+        assert_eq!(coverage[0].covered_percent, 100.0);
+        assert!(coverage[0].meets_requirement && coverage[0].margin_percent > 0.0);
+        assert!(coverage[1].covered_percent > 30.0 && coverage[1].covered_percent < 40.0);
+        assert!(!coverage[1].meets_requirement);
+        // The far end of the zone is ~11 m slant away: about half the required density
+        assert!(coverage[1].margin_percent < -40.0 && coverage[1].margin_percent > -60.0);
+    }
+
+    #[test]
+    fn test_zone_coverage_statistics() {
+        let scene = Scene {
+            room: rectangle(-20.0, 0.0, 20.0, 20.0),
+            zones: vec![
+                zone(
+                    "Ahead",
+                    rectangle(-1.0, 1.0, 1.0, 11.0),
+                    ZoneRequirement::Dori(DoriLevel::Observation),
+                ),
+                zone(
+                    "Outside",
+                    rectangle(30.0, 30.0, 31.0, 31.0),
+                    ZoneRequirement::Dori(DoriLevel::Detection),
+                ),
+            ],
+            obstacles: Vec::new(),
+        };
+        let stats = zone_coverage_statistics(&scene, &[placed(0.0)], None);
+        let coverage = evaluate_zone_coverage(&scene, &[placed(0.0)], None);
+
+        let ahead = &stats[0];
+        assert_eq!(ahead.samples, 80);
+        let ppm = ahead.ppm.as_ref().unwrap();
+        assert_eq!(ppm.min, coverage[0].worst_ppm);
+        assert!(ppm.min <= ppm.p5 && ppm.p5 <= ppm.p50 && ppm.p50 <= ppm.max);
+
+        // Each level covers no more than the one below it; observation matches the zone check
+        let covered: Vec<f64> = ahead.tiers.iter().map(|t| t.covered_percent).collect();
+        assert!(covered.windows(2).all(|pair| pair[0] >= pair[1]));
+        assert_eq!(ahead.tiers[1].level, DoriLevel::Observation);
+        assert_eq!(covered[1], coverage[0].covered_percent);
+        assert!(covered[3] > 30.0 && covered[3] < 40.0);
+
+        // Nothing of the second zone lies inside the room
+        assert_eq!(stats[1].samples, 0);
+        assert!(stats[1].ppm.is_none());
+        assert_eq!(stats[1].tiers[0].covered_percent, 0.0);
+    }
+
+    #[test]
+    fn test_obstacle_shadows_coverage_and_footprint() {
+        // A wall across the view, 5 m ahead, 2 m wide
+        let wall = Obstacle {
+            name: Some("Racking".to_string()),
+            polygon: vec![point(-1.0, 5.0), point(1.0, 5.0)],
+        };
+        let mut scene = Scene {
+            room: rectangle(-20.0, 0.0, 20.0, 40.0),
+            zones: vec![zone(
+                "Behind racking",
+                rectangle(-0.5, 6.0, 0.5, 8.0),
+                ZoneRequirement::Dori(DoriLevel::Detection),
+            )],
+            obstacles: Vec::new(),
+        };
+
+        let open = evaluate_zone_coverage(&scene, &[placed(0.0)], None);
+        scene.obstacles.push(wall);
+        let blocked = evaluate_zone_coverage(&scene, &[placed(0.0)], None);
+        assert_eq!(open[0].covered_percent, 100.0);
+        assert_eq!(blocked[0].covered_percent, 0.0);
+        assert_eq!(blocked[0].worst_ppm, 0.0);
+        // Every 0.5 m cell of the 1 × 2 m zone behind the wall
+        assert_eq!(blind_spots(&scene, &[placed(0.0)], None).len(), 8);
+
+        // The central ray stops at the wall, the outer rays reach the detection range
+        let outline = camera_footprint(&placed(0.0), 25.0, &scene);
+        assert_eq!(outline.len(), FOOTPRINT_RAYS + 2);
+        let centre = outline[1 + FOOTPRINT_RAYS / 2];
+        assert!((centre.y_m - 5.0).abs() < 1e-9);
+        let edge = outline[1];
+        assert!(plan_distance(outline[0], edge) > 30.0);
+    }
+}
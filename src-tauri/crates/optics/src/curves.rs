@@ -0,0 +1,134 @@
+use super::calculations::{calculate_dori_distances, calculate_fov};
+use super::types::{CameraSystem, PpmCurve, ZoomCurve};
+
+/// Evenly spaced values from `min` to `max` (inclusive), at least two points
+fn linspace(min: f64, max: f64, steps: usize) -> Vec<f64> {
+    let steps = steps.max(2);
+    let increment = (max - min) / (steps - 1) as f64;
+    (0..steps).map(|i| min + increment * i as f64).collect()
+}
+
+/// Generate a pixel-density-vs-distance series for plotting
+///
+/// Pixel density falls off as 1/distance, so the curve crosses each DORI threshold
+/// exactly once at the distances returned in `dori`.
+///
+/// # Arguments
+/// * `camera` - The camera system specification
+/// * `distance_min_m` / `distance_max_m` - Distance range in meters; non-positive distances are skipped
+/// * `steps` - Number of points in the series (at least 2)
+pub fn generate_ppm_curve(
+    camera: &CameraSystem,
+    distance_min_m: f64,
+    distance_max_m: f64,
+    steps: usize,
+) -> PpmCurve {
+    let mut curve = PpmCurve {
+        distances_m: Vec::new(),
+        horizontal_ppm: Vec::new(),
+        vertical_ppm: Vec::new(),
+        dori: calculate_dori_distances(camera),
+    };
+
+    for distance_m in linspace(distance_min_m, distance_max_m, steps) {
+        if distance_m <= 0.0 {
+            continue;
+        }
+        let fov = calculate_fov(camera, distance_m * 1000.0);
+        curve.distances_m.push(distance_m);
+        curve.horizontal_ppm.push(fov.horizontal_ppm);
+        curve.vertical_ppm.push(fov.vertical_ppm);
+    }
+
+    curve
+}
+
+/// Generate FOV and DORI series across a varifocal lens's zoom range
+///
+/// Each step evaluates the camera with the focal length replaced, giving the
+/// coverage (FOV) versus reach (DORI distance) tradeoff of the lens.
+///
+/// # Arguments
+/// * `camera` - The camera system specification; its focal length is ignored
+/// * `focal_min_mm` / `focal_max_mm` - Zoom range in millimeters, e.g. 2.8–12
+/// * `steps` - Number of points in the series (at least 2)
+pub fn generate_zoom_curve(
+    camera: &CameraSystem,
+    focal_min_mm: f64,
+    focal_max_mm: f64,
+    steps: usize,
+) -> ZoomCurve {
+    let mut curve = ZoomCurve {
+        focal_lengths_mm: Vec::new(),
+        horizontal_fov_deg: Vec::new(),
+        vertical_fov_deg: Vec::new(),
+        detection_m: Vec::new(),
+        observation_m: Vec::new(),
+        recognition_m: Vec::new(),
+        identification_m: Vec::new(),
+    };
+
+    for focal_length_mm in linspace(focal_min_mm, focal_max_mm, steps) {
+        let zoomed = CameraSystem {
+            focal_length_mm,
+            ..camera.clone()
+        };
+        // Angular FOV and DORI distances do not depend on the working distance
+        let fov = calculate_fov(&zoomed, 1000.0);
+        let dori = calculate_dori_distances(&zoomed);
+
+        curve.focal_lengths_mm.push(focal_length_mm);
+        curve.horizontal_fov_deg.push(fov.horizontal_fov_deg);
+        curve.vertical_fov_deg.push(fov.vertical_fov_deg);
+        curve.detection_m.push(dori.detection_m);
+        curve.observation_m.push(dori.observation_m);
+        curve.recognition_m.push(dori.recognition_m);
+        curve.identification_m.push(dori.identification_m);
+    }
+
+    curve
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ppm_curve_matches_fov() {
+        let camera = CameraSystem::new(5.6, 3.15, 1920, 1080, 4.0);
+        let curve = generate_ppm_curve(&camera, 5.0, 50.0, 10);
+
+        assert_eq!(curve.distances_m.len(), 10);
+        assert_eq!(curve.distances_m[0], 5.0);
+        assert!((curve.distances_m[9] - 50.0).abs() < 1e-9);
+
+        let fov = calculate_fov(&camera, 20_000.0);
+        assert!((curve.horizontal_ppm[3] - fov.horizontal_ppm).abs() < 1e-9);
+        // Density at the detection distance equals the detection threshold
+        let at_detection = calculate_fov(&camera, curve.dori.detection_m * 1000.0);
+        assert!((at_detection.horizontal_ppm - 25.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_ppm_curve_skips_zero_distance() {
+        let camera = CameraSystem::new(36.0, 24.0, 6000, 4000, 50.0);
+        let curve = generate_ppm_curve(&camera, 0.0, 10.0, 11);
+
+        assert_eq!(curve.distances_m.len(), 10);
+        assert!(curve.horizontal_ppm.iter().all(|ppm| ppm.is_finite()));
+    }
+
+    #[test]
+    fn test_zoom_curve_tradeoff() {
+        let camera = CameraSystem::new(5.6, 3.15, 2560, 1440, 2.8);
+        let curve = generate_zoom_curve(&camera, 2.8, 12.0, 24);
+
+        assert_eq!(curve.focal_lengths_mm.len(), 24);
+        assert!((curve.focal_lengths_mm[23] - 12.0).abs() < 1e-9);
+
+        // Zooming in narrows coverage and extends reach
+        assert!(curve.horizontal_fov_deg[23] < curve.horizontal_fov_deg[0]);
+        let ratio = curve.identification_m[23] / curve.identification_m[0];
+        assert!((ratio - 12.0 / 2.8).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,179 @@
+use super::atmosphere::{conditions_extinction_per_km, derate_distance};
+use super::calculations::calculate_dori_distances;
+use super::types::{
+    AppliedDerating, AtmosphericConditions, CameraSystem, DeratingFactor, DoriDeratingFactors,
+    DoriDistances, EffectiveDoriDistances,
+};
+
+impl DeratingFactor {
+    /// Create a custom derating stage
+    pub fn new(
+        label: impl Into<String>,
+        required_ppm_multiplier: f64,
+        resolution_multiplier: f64,
+    ) -> Self {
+        Self {
+            label: label.into(),
+            required_ppm_multiplier,
+            resolution_multiplier,
+        }
+    }
+
+    /// IR night mode: higher gain and IR focus shift cost about 30% of the resolution
+    pub fn night_mode() -> Self {
+        Self::new("Night mode (IR)", 1.0, 0.7)
+    }
+
+    /// Heavy compression: block artefacts raise the required pixel density by 25%
+    pub fn heavy_compression() -> Self {
+        Self::new("Heavy compression", 1.25, 1.0)
+    }
+
+    /// WDR off: backlit subjects lose detail, raising the required pixel density by 20%
+    pub fn wdr_off() -> Self {
+        Self::new("WDR off", 1.2, 1.0)
+    }
+
+    /// Dirty or scratched dome: scattering costs about 15% of the resolution
+    pub fn dirty_dome() -> Self {
+        Self::new("Dirty dome", 1.0, 0.85)
+    }
+
+    /// Factor by which this stage scales DORI distances
+    ///
+    /// DORI distance is proportional to resolution and inversely proportional to
+    /// the required px/m, so: factor = resolution_multiplier / required_ppm_multiplier
+    pub fn distance_factor(&self) -> f64 {
+        self.resolution_multiplier / self.required_ppm_multiplier
+    }
+}
+
+/// Calculate effective DORI distances after applying a derating stack
+///
+/// Stages are applied in order and multiply together. Atmospheric conditions, if
+/// given, are applied last since their effect depends on the absolute distance.
+///
+/// # Arguments
+/// * `camera` - The camera system specification
+/// * `stages` - Derating stages to apply
+/// * `conditions` - Optional atmospheric conditions
+///
+/// # Returns
+/// Theoretical and effective DORI distances with the contribution of each stage
+pub fn calculate_effective_dori(
+    camera: &CameraSystem,
+    stages: &[DeratingFactor],
+    conditions: Option<&AtmosphericConditions>,
+) -> EffectiveDoriDistances {
+    let theoretical = calculate_dori_distances(camera);
+
+    let mut cumulative_factor = 1.0;
+    let applied: Vec<AppliedDerating> = stages
+        .iter()
+        .map(|stage| {
+            let distance_factor = stage.distance_factor();
+            cumulative_factor *= distance_factor;
+            AppliedDerating {
+                label: stage.label.clone(),
+                distance_factor,
+                cumulative_factor,
+            }
+        })
+        .collect();
+
+    let mut effective = DoriDistances {
+        detection_m: theoretical.detection_m * cumulative_factor,
+        observation_m: theoretical.observation_m * cumulative_factor,
+        recognition_m: theoretical.recognition_m * cumulative_factor,
+        identification_m: theoretical.identification_m * cumulative_factor,
+    };
+
+    let atmospheric_factors = conditions.map(|conditions| {
+        let (fog, rain) = conditions_extinction_per_km(conditions);
+        let extinction_per_m = (fog + rain) / 1000.0;
+
+        let derated = DoriDistances {
+            detection_m: derate_distance(effective.detection_m, extinction_per_m),
+            observation_m: derate_distance(effective.observation_m, extinction_per_m),
+            recognition_m: derate_distance(effective.recognition_m, extinction_per_m),
+            identification_m: derate_distance(effective.identification_m, extinction_per_m),
+        };
+        let factors = DoriDeratingFactors {
+            detection: derated.detection_m / effective.detection_m,
+            observation: derated.observation_m / effective.observation_m,
+            recognition: derated.recognition_m / effective.recognition_m,
+            identification: derated.identification_m / effective.identification_m,
+        };
+
+        effective = derated;
+        factors
+    });
+
+    EffectiveDoriDistances {
+        theoretical,
+        effective,
+        stages: applied,
+        atmospheric_factors,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_stages_matches_theoretical() {
+        let camera = CameraSystem::new(6.4, 4.8, 1920, 1080, 4.0);
+        let result = calculate_effective_dori(&camera, &[], None);
+
+        assert!((result.effective.detection_m - result.theoretical.detection_m).abs() < 1e-9);
+        assert!(result.stages.is_empty());
+        assert!(result.atmospheric_factors.is_none());
+    }
+
+    #[test]
+    fn test_stages_multiply() {
+        let camera = CameraSystem::new(6.4, 4.8, 1920, 1080, 4.0);
+        let stages = vec![
+            DeratingFactor::night_mode(),
+            DeratingFactor::heavy_compression(),
+        ];
+        let result = calculate_effective_dori(&camera, &stages, None);
+
+        // 0.7 resolution / 1.25 required density = 0.56
+        let expected = 0.7 / 1.25;
+        assert!((result.stages[0].distance_factor - 0.7).abs() < 1e-9);
+        assert!((result.stages[1].cumulative_factor - expected).abs() < 1e-9);
+        assert!(
+            (result.effective.identification_m - result.theoretical.identification_m * expected)
+                .abs()
+                < 1e-9
+        );
+    }
+
+    #[test]
+    fn test_custom_stage() {
+        let stage = DeratingFactor::new("Motion blur", 2.0, 0.5);
+        assert!((stage.distance_factor() - 0.25).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_atmosphere_applied_after_stages() {
+        let camera = CameraSystem::new(6.4, 4.8, 1920, 1080, 12.0);
+        let conditions = AtmosphericConditions {
+            visibility_m: Some(500.0),
+            rain_rate_mm_h: None,
+        };
+        let stages = vec![DeratingFactor::dirty_dome()];
+        let result = calculate_effective_dori(&camera, &stages, Some(&conditions));
+
+        let factors = result.atmospheric_factors.unwrap();
+        assert!(factors.detection < 1.0);
+        assert!(
+            (result.effective.detection_m
+                - result.theoretical.detection_m * 0.85 * factors.detection)
+                .abs()
+                < 1e-6
+        );
+    }
+}
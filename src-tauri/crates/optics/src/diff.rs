@@ -0,0 +1,140 @@
+use super::calculations::{calculate_dof, calculate_fov};
+use super::types::{CameraDiff, CameraSystem, FovResult, MetricDelta};
+
+/// Compare every derived metric of two cameras at the same working distance
+///
+/// Each change is relative to camera `a`, so going from a 4K to a 1080p camera
+/// with `a` as the 4K one shows the pixel densities and DORI distances dropping
+/// by about half. Depth of field is only compared when both cameras have an
+/// f-number, each with its own default circle of confusion.
+///
+/// # Arguments
+/// * `a` - Reference camera
+/// * `b` - Camera compared against the reference
+/// * `distance_mm` - Working distance in millimeters
+pub fn calculate_camera_diff(a: &CameraSystem, b: &CameraSystem, distance_mm: f64) -> CameraDiff {
+    let (fov_a, fov_b) = (calculate_fov(a, distance_mm), calculate_fov(b, distance_mm));
+    let mut metrics: Vec<MetricDelta> = fov_metrics(&fov_a)
+        .into_iter()
+        .zip(fov_metrics(&fov_b))
+        .map(|((metric, a), (_, b))| MetricDelta::new(metric, a, b))
+        .collect();
+
+    if let (Some(dof_a), Some(dof_b)) = (dof_metrics(a, distance_mm), dof_metrics(b, distance_mm)) {
+        metrics.extend(
+            dof_a
+                .into_iter()
+                .zip(dof_b)
+                .map(|((metric, a), (_, b))| MetricDelta::new(metric, a, b)),
+        );
+    }
+
+    CameraDiff {
+        distance_m: distance_mm / 1000.0,
+        metrics,
+    }
+}
+
+/// Named FOV, resolution and DORI figures of a result, in a fixed order
+fn fov_metrics(fov: &FovResult) -> Vec<(&'static str, f64)> {
+    let mut metrics = vec![
+        ("horizontal_fov_deg", fov.horizontal_fov_deg),
+        ("vertical_fov_deg", fov.vertical_fov_deg),
+        ("diagonal_fov_deg", fov.diagonal_fov_deg),
+        ("horizontal_fov_m", fov.horizontal_fov_m),
+        ("vertical_fov_m", fov.vertical_fov_m),
+        ("horizontal_ppm", fov.horizontal_ppm),
+        ("vertical_ppm", fov.vertical_ppm),
+        ("horizontal_px_per_deg", fov.horizontal_px_per_deg),
+        ("megapixels_per_m2", fov.megapixels_per_m2),
+        ("horizontal_ifov_urad", fov.horizontal_ifov_urad),
+    ];
+    if let Some(dori) = &fov.dori {
+        metrics.extend([
+            ("detection_m", dori.detection_m),
+            ("observation_m", dori.observation_m),
+            ("recognition_m", dori.recognition_m),
+            ("identification_m", dori.identification_m),
+        ]);
+    }
+    metrics
+}
+
+/// Depth of field limits in meters, if the camera has an f-number
+fn dof_metrics(camera: &CameraSystem, distance_mm: f64) -> Option<[(&'static str, f64); 3]> {
+    let f_number = camera.f_number?;
+    let (near_mm, far_mm, total_mm) = calculate_dof(
+        distance_mm,
+        camera.focal_length_mm,
+        f_number,
+        camera.default_coc_mm(),
+        None,
+    );
+    Some([
+        ("dof_near_m", near_mm / 1000.0),
+        ("dof_far_m", far_mm / 1000.0),
+        ("dof_total_m", total_mm / 1000.0),
+    ])
+}
+
+impl MetricDelta {
+    fn new(metric: &str, a: f64, b: f64) -> Self {
+        // No meaningful percentage from a zero or an unbounded reference
+        let change_percent =
+            (a != 0.0 && a.is_finite() && b.is_finite()).then(|| (b - a) / a * 100.0);
+        Self {
+            metric: metric.to_string(),
+            a,
+            b,
+            change_percent,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn delta<'a>(diff: &'a CameraDiff, metric: &str) -> &'a MetricDelta {
+        diff.metrics.iter().find(|m| m.metric == metric).unwrap()
+    }
+
+    #[test]
+    fn test_4k_to_1080p_halves_pixel_density() {
+        let uhd = CameraSystem::new(6.4, 3.6, 3840, 2160, 4.0);
+        let full_hd = CameraSystem::new(6.4, 3.6, 1920, 1080, 4.0);
+        let diff = calculate_camera_diff(&uhd, &full_hd, 20_000.0);
+
+        // Same sensor and lens: the view is unchanged, the detail halves
+        assert!(
+            delta(&diff, "horizontal_fov_deg")
+                .change_percent
+                .unwrap()
+                .abs()
+                < 1e-9
+        );
+        for metric in [
+            "horizontal_ppm",
+            "identification_m",
+            "horizontal_px_per_deg",
+        ] {
+            assert!((delta(&diff, metric).change_percent.unwrap() + 50.0).abs() < 1e-9);
+        }
+        assert!((delta(&diff, "megapixels_per_m2").change_percent.unwrap() + 75.0).abs() < 1e-9);
+        // Neither camera has an f-number
+        assert!(diff.metrics.iter().all(|m| !m.metric.starts_with("dof_")));
+    }
+
+    #[test]
+    fn test_dof_beyond_hyperfocal_has_no_percentage() {
+        let wide = CameraSystem::new(6.4, 3.6, 1920, 1080, 2.8).with_f_number(2.0);
+        let tele = CameraSystem::new(6.4, 3.6, 1920, 1080, 12.0).with_f_number(2.0);
+        let diff = calculate_camera_diff(&wide, &tele, 20_000.0);
+
+        // The wide lens is focused past its hyperfocal distance
+        let far = delta(&diff, "dof_far_m");
+        assert!(far.a.is_infinite());
+        assert!(far.change_percent.is_none());
+        assert!(delta(&diff, "dof_near_m").change_percent.unwrap() > 0.0);
+    }
+}
@@ -0,0 +1,124 @@
+use super::constants::{ANGLE_OF_VIEW_LIMITS_EQUIVALENT_MM, FULL_FRAME_DIAGONAL_MM};
+use super::types::{AngleOfView, CameraSystem, FormatEquivalence};
+
+/// Classify a lens as ultra-wide to super-tele on the camera's sensor format
+///
+/// The focal length is converted to its 35 mm equivalent through the ratio of
+/// sensor diagonals, so the class follows the angle of view rather than the
+/// nominal focal length.
+pub fn classify_angle_of_view(camera: &CameraSystem) -> AngleOfView {
+    const CLASSES: [AngleOfView; 6] = [
+        AngleOfView::UltraWide,
+        AngleOfView::Wide,
+        AngleOfView::Normal,
+        AngleOfView::ShortTele,
+        AngleOfView::Tele,
+        AngleOfView::SuperTele,
+    ];
+    let equivalent_mm = camera.focal_length_mm * FULL_FRAME_DIAGONAL_MM
+        / camera.sensor_width_mm.hypot(camera.sensor_height_mm);
+    CLASSES[ANGLE_OF_VIEW_LIMITS_EQUIVALENT_MM.partition_point(|&limit| equivalent_mm >= limit)]
+}
+
+/// Find the setup on another sensor format that gives the same picture
+///
+/// Scaling focal length and f-number by the ratio of sensor diagonals keeps the
+/// field of view, the entrance pupil, and with it the depth of field and the total
+/// light gathered - and so the shot noise - the same. The ISO scales with the square
+/// of the ratio to keep the exposure time. Formats with different aspect ratios
+/// are matched on the diagonal, so the widths and heights of the FOV differ.
+///
+/// # Arguments
+/// * `camera` - Source camera and lens
+/// * `f_number` - Aperture used on the source camera
+/// * `sensor_width_mm` - Width of the target sensor
+/// * `sensor_height_mm` - Height of the target sensor
+pub fn calculate_format_equivalence(
+    camera: &CameraSystem,
+    f_number: f64,
+    sensor_width_mm: f64,
+    sensor_height_mm: f64,
+) -> FormatEquivalence {
+    let source_diagonal = camera.sensor_width_mm.hypot(camera.sensor_height_mm);
+    let scale_factor = sensor_width_mm.hypot(sensor_height_mm) / source_diagonal;
+    let full_frame_scale = FULL_FRAME_DIAGONAL_MM / source_diagonal;
+
+    let equivalent_focal_length_mm = camera.focal_length_mm * scale_factor;
+    let equivalent_f_number = f_number * scale_factor;
+
+    let mut equivalent = camera.clone();
+    equivalent.sensor_width_mm = sensor_width_mm;
+    equivalent.sensor_height_mm = sensor_height_mm;
+    equivalent.focal_length_mm = equivalent_focal_length_mm;
+    equivalent.f_number = Some(equivalent_f_number);
+    equivalent.t_stop = None;
+
+    FormatEquivalence {
+        scale_factor,
+        equivalent_focal_length_mm,
+        equivalent_f_number,
+        iso_multiplier: scale_factor * scale_factor,
+        entrance_pupil_mm: camera.focal_length_mm / f_number,
+        full_frame_focal_length_mm: camera.focal_length_mm * full_frame_scale,
+        full_frame_f_number: f_number * full_frame_scale,
+        camera: equivalent,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculations::calculate_fov;
+
+    #[test]
+    fn test_angle_of_view_is_format_aware() {
+        let full_frame =
+            |focal_length_mm| CameraSystem::new(36.0, 24.0, 6000, 4000, focal_length_mm);
+        assert_eq!(
+            classify_angle_of_view(&full_frame(16.0)),
+            AngleOfView::UltraWide
+        );
+        assert_eq!(
+            classify_angle_of_view(&full_frame(50.0)),
+            AngleOfView::Normal
+        );
+        assert_eq!(
+            classify_angle_of_view(&full_frame(85.0)),
+            AngleOfView::ShortTele
+        );
+        assert_eq!(
+            classify_angle_of_view(&full_frame(600.0)),
+            AngleOfView::SuperTele
+        );
+
+        // 8 mm on a 1/2.8" (5.6 × 3.15 mm) sensor is about 54 mm equivalent
+        let dome = CameraSystem::new(5.6, 3.15, 1920, 1080, 8.0);
+        assert_eq!(classify_angle_of_view(&dome), AngleOfView::Normal);
+        assert_eq!(
+            calculate_fov(&dome, 10_000.0).angle_of_view,
+            Some(AngleOfView::Normal)
+        );
+    }
+
+    #[test]
+    fn test_full_frame_to_micro_four_thirds() {
+        let full_frame = CameraSystem::new(36.0, 24.0, 6000, 4000, 50.0);
+        let m43 = calculate_format_equivalence(&full_frame, 2.8, 17.3, 13.0);
+
+        // Crop factor ~2: 50 mm f/2.8 on full frame looks like 25 mm f/1.4 on M4/3
+        assert!((m43.scale_factor - 0.5).abs() < 0.005);
+        assert!((m43.equivalent_focal_length_mm - 25.0).abs() < 0.25);
+        assert!((m43.equivalent_f_number - 1.4).abs() < 0.02);
+        assert!((m43.iso_multiplier - 0.25).abs() < 0.005);
+        assert!((m43.full_frame_focal_length_mm - 50.0).abs() < 0.05);
+
+        // Same entrance pupil and diagonal FOV on both formats
+        let pupil = m43.equivalent_focal_length_mm / m43.equivalent_f_number;
+        assert!((pupil - m43.entrance_pupil_mm).abs() < 1e-9);
+        let diagonal = |c: &CameraSystem| {
+            let fov = calculate_fov(c, 10000.0);
+            fov.horizontal_fov_m.hypot(fov.vertical_fov_m)
+        };
+        assert!((diagonal(&m43.camera) - diagonal(&full_frame)).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,193 @@
+use super::constants::INCIDENT_METER_CALIBRATION;
+use super::types::{ExposureEstimate, ShutterAngle};
+
+/// Convert sensor gain in dB to an ISO equivalent
+///
+/// Security cameras usually specify gain instead of ISO. Every 20 dB of gain
+/// is a tenfold increase in signal amplification:
+/// ISO = base_iso × 10^(gain_db / 20)
+pub fn iso_from_gain_db(base_iso: f64, gain_db: f64) -> f64 {
+    base_iso * 10f64.powf(gain_db / 20.0)
+}
+
+/// Convert an f-number to a T-stop for a lens with the given transmission
+///
+/// The T-stop is the f-number of an ideal lens passing the same amount of light:
+/// T = N / √transmission
+///
+/// # Arguments
+/// * `f_number` - Geometric aperture (f-number)
+/// * `transmission` - Fraction of light the lens transmits (0-1)
+pub fn t_stop_from_f_number(f_number: f64, transmission: f64) -> f64 {
+    f_number / transmission.sqrt()
+}
+
+/// Calculate the light transmission of a lens from its f-number and T-stop
+///
+/// This is the inverse of `t_stop_from_f_number`: transmission = (N / T)²
+pub fn lens_transmission(f_number: f64, t_stop: f64) -> f64 {
+    (f_number / t_stop).powi(2)
+}
+
+/// Light lost in the lens, in stops, between the f-number and the T-stop
+pub fn transmission_loss_stops(f_number: f64, t_stop: f64) -> f64 {
+    2.0 * (t_stop / f_number).log2()
+}
+
+/// Convert a shutter angle at a frame rate to an exposure time
+///
+/// The shutter is open for its share of a full rotation of each frame:
+/// t = (angle / 360°) / fps
+pub fn shutter_from_angle(angle_deg: f64, frame_rate_fps: f64) -> f64 {
+    angle_deg / 360.0 / frame_rate_fps
+}
+
+/// Convert an exposure time at a frame rate to a shutter angle in degrees
+///
+/// This is the inverse of `shutter_from_angle`: angle = t × fps × 360°
+pub fn angle_from_shutter(shutter_s: f64, frame_rate_fps: f64) -> f64 {
+    shutter_s * frame_rate_fps * 360.0
+}
+
+impl ShutterAngle {
+    /// Exposure time in seconds
+    pub fn shutter_s(&self) -> f64 {
+        shutter_from_angle(self.angle_deg, self.frame_rate_fps)
+    }
+}
+
+/// Calculate the shutter time required for a correct exposure at a given illuminance
+///
+/// # Formula
+/// Incident-light exposure equation: N² / t = E × S / C
+/// Rearranged: t = (N² × C) / (E × S)
+///
+/// # Arguments
+/// * `illuminance_lux` - Scene illuminance in lux
+/// * `iso` - Sensor sensitivity as ISO equivalent
+/// * `f_number` - Lens aperture (f-number, or the T-stop if the lens specifies one)
+///
+/// # Returns
+/// Shutter time in seconds
+pub fn calculate_shutter_from_illuminance(illuminance_lux: f64, iso: f64, f_number: f64) -> f64 {
+    (f_number * f_number * INCIDENT_METER_CALIBRATION) / (illuminance_lux * iso)
+}
+
+/// Calculate the minimum scene illuminance that allows a given shutter time
+///
+/// This is the inverse of `calculate_shutter_from_illuminance`:
+/// E = (N² × C) / (t × S)
+///
+/// # Arguments
+/// * `shutter_s` - Shutter time in seconds
+/// * `iso` - Sensor sensitivity as ISO equivalent
+/// * `f_number` - Lens aperture (f-number, or the T-stop if the lens specifies one)
+///
+/// # Returns
+/// Required illuminance in lux
+pub fn calculate_illuminance_for_shutter(shutter_s: f64, iso: f64, f_number: f64) -> f64 {
+    (f_number * f_number * INCIDENT_METER_CALIBRATION) / (shutter_s * iso)
+}
+
+/// Estimate exposure settings for a scene illuminance, sensitivity and aperture
+///
+/// A T-stop, when the lens specifies one, sets the light reaching the sensor;
+/// the estimate keeps `f_number` as the geometric aperture and reports both.
+pub fn estimate_exposure(
+    illuminance_lux: f64,
+    iso: f64,
+    f_number: f64,
+    t_stop: Option<f64>,
+) -> ExposureEstimate {
+    let stop = t_stop.unwrap_or(f_number);
+    let shutter_s = calculate_shutter_from_illuminance(illuminance_lux, iso, stop);
+
+    // EV = log2(N² / t)
+    let exposure_value = (stop * stop / shutter_s).log2();
+
+    ExposureEstimate {
+        illuminance_lux,
+        iso,
+        f_number,
+        t_stop,
+        shutter_s,
+        exposure_value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shutter_from_illuminance_night_scene() {
+        // 10 lux, ISO 3200, f/1.6: t = (2.56 × 250) / (10 × 3200) = 0.02 s
+        let shutter = calculate_shutter_from_illuminance(10.0, 3200.0, 1.6);
+        assert!((shutter - 0.02).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_illuminance_shutter_roundtrip() {
+        let lux = calculate_illuminance_for_shutter(1.0 / 100.0, 1600.0, 2.0);
+        let shutter = calculate_shutter_from_illuminance(lux, 1600.0, 2.0);
+
+        assert!((shutter - 0.01).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_sunny_sixteen() {
+        // Sunny 16 rule: f/16 at 1/ISO needs roughly 64,000 lux with C = 250
+        let lux = calculate_illuminance_for_shutter(1.0 / 100.0, 100.0, 16.0);
+        assert!((lux - 64000.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_iso_from_gain() {
+        // +20 dB is a tenfold gain, +6 dB roughly doubles
+        assert!((iso_from_gain_db(100.0, 20.0) - 1000.0).abs() < 1e-9);
+        assert!((iso_from_gain_db(100.0, 6.0) - 199.5).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_estimate_exposure_ev() {
+        // f/1.0 at 1 s is EV 0 by definition
+        let lux = calculate_illuminance_for_shutter(1.0, 100.0, 1.0);
+        let estimate = estimate_exposure(lux, 100.0, 1.0, None);
+
+        assert!((estimate.shutter_s - 1.0).abs() < 1e-9);
+        assert!(estimate.exposure_value.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_estimate_exposure_with_t_stop() {
+        // An f/1.4 lens at T2.0 exposes like a perfect f/2.0 lens
+        let estimate = estimate_exposure(10.0, 3200.0, 1.4, Some(2.0));
+        let shutter = calculate_shutter_from_illuminance(10.0, 3200.0, 2.0);
+
+        assert!((estimate.shutter_s - shutter).abs() < 1e-12);
+        assert_eq!(estimate.f_number, 1.4);
+        assert_eq!(estimate.t_stop, Some(2.0));
+    }
+
+    #[test]
+    fn test_t_stop_transmission_roundtrip() {
+        // An f/1.4 lens passing 85% of the light is roughly T1.52
+        let t_stop = t_stop_from_f_number(1.4, 0.85);
+        assert!((t_stop - 1.5185).abs() < 1e-3);
+        assert!((lens_transmission(1.4, t_stop) - 0.85).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_transmission_loss_stops() {
+        // Half the light is one stop
+        let t_stop = t_stop_from_f_number(2.0, 0.5);
+        assert!((transmission_loss_stops(2.0, t_stop) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_shutter_angle_conversion() {
+        // 180° at 24 fps is the classic 1/48 s
+        assert!((shutter_from_angle(180.0, 24.0) - 1.0 / 48.0).abs() < 1e-12);
+        assert!((angle_from_shutter(1.0 / 50.0, 25.0) - 180.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,229 @@
+use super::types::PlanPoint;
+
+/// Viewing direction, image right and image up of a camera in (east, north, up)
+/// for a pan clockwise from north and a tilt that is negative looking down
+pub(crate) fn camera_axes(pan_deg: f64, tilt_deg: f64) -> [[f64; 3]; 3] {
+    let (pan, tilt) = (pan_deg.to_radians(), tilt_deg.to_radians());
+    [
+        [pan.sin() * tilt.cos(), pan.cos() * tilt.cos(), tilt.sin()],
+        [pan.cos(), -pan.sin(), 0.0],
+        [-pan.sin() * tilt.sin(), -pan.cos() * tilt.sin(), tilt.cos()],
+    ]
+}
+
+/// Whether a point lies inside a polygon (even-odd rule)
+pub fn polygon_contains(polygon: &[PlanPoint], point: PlanPoint) -> bool {
+    let mut inside = false;
+    let mut j = polygon.len().wrapping_sub(1);
+    for (i, a) in polygon.iter().enumerate() {
+        let b = polygon[j];
+        if (a.y_m > point.y_m) != (b.y_m > point.y_m) {
+            let x_cross = a.x_m + (point.y_m - a.y_m) / (b.y_m - a.y_m) * (b.x_m - a.x_m);
+            if point.x_m < x_cross {
+                inside = !inside;
+            }
+        }
+        j = i;
+    }
+    inside
+}
+
+/// Area of a simple polygon in square metres (shoelace formula)
+pub fn polygon_area(polygon: &[PlanPoint]) -> f64 {
+    let twice_area: f64 = polygon_edges(polygon)
+        .map(|(a, b)| a.x_m * b.y_m - b.x_m * a.y_m)
+        .sum();
+    twice_area.abs() / 2.0
+}
+
+/// Centres of the cells of a square grid that fall inside a polygon
+pub fn grid_samples(polygon: &[PlanPoint], spacing_m: f64) -> Vec<PlanPoint> {
+    if polygon.len() < 3 || spacing_m <= 0.0 {
+        return Vec::new();
+    }
+    let min_x = polygon.iter().map(|p| p.x_m).fold(f64::INFINITY, f64::min);
+    let max_x = polygon
+        .iter()
+        .map(|p| p.x_m)
+        .fold(f64::NEG_INFINITY, f64::max);
+    let min_y = polygon.iter().map(|p| p.y_m).fold(f64::INFINITY, f64::min);
+    let max_y = polygon
+        .iter()
+        .map(|p| p.y_m)
+        .fold(f64::NEG_INFINITY, f64::max);
+
+    let columns = ((max_x - min_x) / spacing_m).ceil() as usize;
+    let rows = ((max_y - min_y) / spacing_m).ceil() as usize;
+
+    // Scanline fill: the edge crossings of each row are found once, instead of
+    // testing every cell against every edge. A cell is inside when an odd number
+    // of crossings lies to its right, the same rule as `polygon_contains`.
+    let mut samples = Vec::new();
+    let mut crossings = Vec::with_capacity(polygon.len());
+    for row in 0..rows {
+        let y_m = min_y + (row as f64 + 0.5) * spacing_m;
+        crossings.clear();
+        let mut j = polygon.len() - 1;
+        for (i, a) in polygon.iter().enumerate() {
+            let b = polygon[j];
+            if (a.y_m > y_m) != (b.y_m > y_m) {
+                crossings.push(a.x_m + (y_m - a.y_m) / (b.y_m - a.y_m) * (b.x_m - a.x_m));
+            }
+            j = i;
+        }
+        crossings.sort_by(f64::total_cmp);
+
+        let mut left = 0;
+        for column in 0..columns {
+            let x_m = min_x + (column as f64 + 0.5) * spacing_m;
+            while left < crossings.len() && crossings[left] <= x_m {
+                left += 1;
+            }
+            if (crossings.len() - left) % 2 == 1 {
+                samples.push(PlanPoint { x_m, y_m });
+            }
+        }
+    }
+    samples
+}
+
+/// Horizontal distance between two points in metres
+pub fn plan_distance(from: PlanPoint, to: PlanPoint) -> f64 {
+    (to.x_m - from.x_m).hypot(to.y_m - from.y_m)
+}
+
+/// Bearing from one point to another in degrees clockwise from north, in [0, 360)
+pub fn bearing_deg(from: PlanPoint, to: PlanPoint) -> f64 {
+    (to.x_m - from.x_m)
+        .atan2(to.y_m - from.y_m)
+        .to_degrees()
+        .rem_euclid(360.0)
+}
+
+/// Edges of a closed polygon as (start, end) pairs
+pub fn polygon_edges(polygon: &[PlanPoint]) -> impl Iterator<Item = (PlanPoint, PlanPoint)> + '_ {
+    polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .map(|(&a, &b)| (a, b))
+}
+
+/// Signed area of the triangle a-b-c, positive when counter-clockwise
+fn orientation(a: PlanPoint, b: PlanPoint, c: PlanPoint) -> f64 {
+    (b.x_m - a.x_m) * (c.y_m - a.y_m) - (b.y_m - a.y_m) * (c.x_m - a.x_m)
+}
+
+/// Whether segments a-b and c-d cross
+///
+/// Touching at an endpoint or running along each other does not count, so a
+/// camera mounted on a wall can still see past the wall it is mounted on.
+pub fn segments_cross(a: PlanPoint, b: PlanPoint, c: PlanPoint, d: PlanPoint) -> bool {
+    let o1 = orientation(a, b, c);
+    let o2 = orientation(a, b, d);
+    let o3 = orientation(c, d, a);
+    let o4 = orientation(c, d, b);
+    o1 * o2 < 0.0 && o3 * o4 < 0.0
+}
+
+/// Whether `to` can be seen from `from` without crossing any wall segment
+pub fn line_of_sight(from: PlanPoint, to: PlanPoint, walls: &[(PlanPoint, PlanPoint)]) -> bool {
+    !walls.iter().any(|&(a, b)| segments_cross(from, to, a, b))
+}
+
+/// Distance along a ray to the nearest wall it hits, if any
+///
+/// `bearing_deg` is measured clockwise from north. Hits at the ray origin are
+/// ignored so that rays can start on a wall.
+pub fn ray_hit_distance(
+    origin: PlanPoint,
+    bearing_deg: f64,
+    walls: &[(PlanPoint, PlanPoint)],
+) -> Option<f64> {
+    let (dx, dy) = (
+        bearing_deg.to_radians().sin(),
+        bearing_deg.to_radians().cos(),
+    );
+    walls
+        .iter()
+        .filter_map(|&(a, b)| {
+            let (ex, ey) = (b.x_m - a.x_m, b.y_m - a.y_m);
+            let denominator = dx * ey - dy * ex;
+            if denominator.abs() < 1e-12 {
+                return None;
+            }
+            let (ax, ay) = (a.x_m - origin.x_m, a.y_m - origin.y_m);
+            // Distance along the ray and position along the wall (0..1)
+            let t = (ax * ey - ay * ex) / denominator;
+            let u = (ax * dy - ay * dx) / denominator;
+            (t > 1e-9 && (0.0..=1.0).contains(&u)).then_some(t)
+        })
+        .reduce(f64::min)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(x_m: f64, y_m: f64) -> PlanPoint {
+        PlanPoint { x_m, y_m }
+    }
+
+    /// L-shaped room: 10 × 10 m with the north-east 5 × 5 m quarter missing
+    fn l_shape() -> Vec<PlanPoint> {
+        vec![
+            point(0.0, 0.0),
+            point(10.0, 0.0),
+            point(10.0, 5.0),
+            point(5.0, 5.0),
+            point(5.0, 10.0),
+            point(0.0, 10.0),
+        ]
+    }
+
+    #[test]
+    fn test_polygon_contains_concave() {
+        let room = l_shape();
+        assert!(polygon_contains(&room, point(2.0, 8.0)));
+        assert!(polygon_contains(&room, point(8.0, 2.0)));
+        assert!(!polygon_contains(&room, point(8.0, 8.0)));
+        assert!(!polygon_contains(&room, point(-1.0, 5.0)));
+    }
+
+    #[test]
+    fn test_polygon_area_and_grid() {
+        let room = l_shape();
+        assert!((polygon_area(&room) - 75.0).abs() < 1e-9);
+        // One sample per square metre
+        assert_eq!(grid_samples(&room, 1.0).len(), 75);
+    }
+
+    #[test]
+    fn test_bearing_clockwise_from_north() {
+        let origin = point(0.0, 0.0);
+        assert!((bearing_deg(origin, point(0.0, 1.0)) - 0.0).abs() < 1e-9);
+        assert!((bearing_deg(origin, point(1.0, 0.0)) - 90.0).abs() < 1e-9);
+        assert!((bearing_deg(origin, point(-1.0, 0.0)) - 270.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_line_of_sight_around_corner() {
+        let room = l_shape();
+        let walls: Vec<_> = polygon_edges(&room).collect();
+        // Mounted in the south-west corner: sees both wings, not around the inner corner
+        assert!(line_of_sight(point(0.0, 0.0), point(2.0, 9.0), &walls));
+        assert!(line_of_sight(point(0.0, 0.0), point(9.0, 2.0), &walls));
+        assert!(!line_of_sight(point(9.0, 4.0), point(4.0, 9.0), &walls));
+    }
+
+    #[test]
+    fn test_ray_hits_nearest_wall() {
+        let room = l_shape();
+        let walls: Vec<_> = polygon_edges(&room).collect();
+
+        let east = ray_hit_distance(point(2.0, 7.0), 90.0, &walls).unwrap();
+        assert!((east - 3.0).abs() < 1e-9);
+        // A ray starting on the south wall and running north
+        let north = ray_hit_distance(point(8.0, 0.0), 0.0, &walls).unwrap();
+        assert!((north - 5.0).abs() < 1e-9);
+    }
+}
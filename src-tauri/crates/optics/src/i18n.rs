@@ -0,0 +1,1139 @@
+use super::types::{Locale, MessageArg, MessageKey, MessageValue, ValidationWarning};
+
+impl MessageKey {
+    /// Message without parameters
+    pub fn new(key: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            args: Vec::new(),
+        }
+    }
+
+    /// Add a numeric parameter
+    pub fn with_number(self, name: impl Into<String>, value: f64) -> Self {
+        self.with_arg(name, MessageValue::Number(value))
+    }
+
+    /// Add a text parameter that is inserted untranslated
+    pub fn with_text(self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.with_arg(name, MessageValue::Text(value.into()))
+    }
+
+    /// Add a parameter that is itself a catalog term
+    pub fn with_term(self, name: impl Into<String>, key: impl Into<String>) -> Self {
+        self.with_arg(name, MessageValue::Term(key.into()))
+    }
+
+    fn with_arg(mut self, name: impl Into<String>, value: MessageValue) -> Self {
+        self.args.push(MessageArg {
+            name: name.into(),
+            value,
+        });
+        self
+    }
+
+    /// Render the message in a language
+    ///
+    /// Templates refer to parameters as `{name}`, or `{name:.N}` for a number with
+    /// N decimals. A key missing from the locale's catalog falls back to English,
+    /// and an unknown key renders as the key itself.
+    pub fn render(&self, locale: Locale) -> String {
+        let template = template(locale, &self.key)
+            .or_else(|| template(Locale::En, &self.key))
+            .unwrap_or(&self.key);
+
+        let mut rendered = String::with_capacity(template.len());
+        let mut rest = template;
+        while let Some(start) = rest.find('{') {
+            rendered.push_str(&rest[..start]);
+            let Some(len) = rest[start..].find('}') else {
+                break;
+            };
+            let placeholder = &rest[start + 1..start + len];
+            let (name, decimals) = match placeholder.split_once(":.") {
+                Some((name, decimals)) => (name, decimals.parse().ok()),
+                None => (placeholder, None),
+            };
+            match self.args.iter().find(|arg| arg.name == name) {
+                Some(arg) => rendered.push_str(&arg.value.render(locale, decimals)),
+                None => rendered.push_str(&rest[start..=start + len]),
+            }
+            rest = &rest[start + len + 1..];
+        }
+        rendered.push_str(rest);
+        rendered
+    }
+}
+
+impl MessageValue {
+    fn render(&self, locale: Locale, decimals: Option<usize>) -> String {
+        match self {
+            MessageValue::Number(value) => {
+                let formatted = match decimals {
+                    Some(decimals) => format!("{:.*}", decimals, value),
+                    None => value.to_string(),
+                };
+                match locale {
+                    Locale::En => formatted,
+                    Locale::De | Locale::Fr => formatted.replace('.', ","),
+                }
+            }
+            MessageValue::Text(text) => text.clone(),
+            MessageValue::Term(key) => MessageKey::new(key.as_str()).render(locale),
+        }
+    }
+}
+
+/// Re-render a list of warnings in another language
+pub fn localize_warnings(warnings: &[ValidationWarning], locale: Locale) -> Vec<ValidationWarning> {
+    warnings
+        .iter()
+        .map(|warning| warning.localize(locale))
+        .collect()
+}
+
+fn template(locale: Locale, key: &str) -> Option<&'static str> {
+    let catalog = match locale {
+        Locale::En => EN,
+        Locale::De => DE,
+        Locale::Fr => FR,
+    };
+    catalog
+        .iter()
+        .find(|(catalog_key, _)| *catalog_key == key)
+        .map(|(_, template)| *template)
+}
+
+const EN: &[(&str, &str)] = &[
+    ("severity.info", "Info"),
+    ("severity.warning", "Warning"),
+    ("severity.error", "Error"),
+    ("dori.level.detection", "detection"),
+    ("dori.level.observation", "observation"),
+    ("dori.level.recognition", "recognition"),
+    ("dori.level.identification", "identification"),
+    ("angle_of_view.ultra_wide", "ultra-wide"),
+    ("angle_of_view.wide", "wide"),
+    ("angle_of_view.normal", "normal"),
+    ("angle_of_view.short_tele", "short tele"),
+    ("angle_of_view.tele", "tele"),
+    ("angle_of_view.super_tele", "super tele"),
+    ("angle_of_view.unknown", "unclassified"),
+    ("camera.unnamed", "Unnamed"),
+    (
+        "camera.summary",
+        "{name}: {sensor_width_mm}x{sensor_height_mm} mm sensor, {pixel_width}x{pixel_height} px ({horizontal_pitch_um:.2}x{vertical_pitch_um:.2} µm, pixel aspect {pixel_aspect_ratio:.2}), {focal_length_mm} mm lens",
+    ),
+    (
+        "camera.sensor_width_too_small",
+        "Sensor width ({sensor_width_mm:.2} mm) is unrealistically small",
+    ),
+    (
+        "camera.sensor_width_too_large",
+        "Sensor width ({sensor_width_mm:.2} mm) is unrealistically large",
+    ),
+    (
+        "camera.sensor_height_too_small",
+        "Sensor height ({sensor_height_mm:.2} mm) is unrealistically small",
+    ),
+    (
+        "camera.sensor_height_too_large",
+        "Sensor height ({sensor_height_mm:.2} mm) is unrealistically large",
+    ),
+    (
+        "camera.focal_length_too_short",
+        "Focal length ({focal_length_mm:.2} mm) is unrealistically short",
+    ),
+    (
+        "camera.focal_length_too_long",
+        "Focal length ({focal_length_mm:.0} mm) is extremely long",
+    ),
+    (
+        "camera.pixel_width_too_low",
+        "Pixel width ({pixel_width} px) is unrealistically low",
+    ),
+    (
+        "camera.pixel_width_too_high",
+        "Pixel width ({pixel_width} px) is unrealistically high",
+    ),
+    (
+        "camera.pixel_height_too_low",
+        "Pixel height ({pixel_height} px) is unrealistically low",
+    ),
+    (
+        "camera.pixel_height_too_high",
+        "Pixel height ({pixel_height} px) is unrealistically high",
+    ),
+    (
+        "camera.horizontal_pitch_too_small",
+        "Horizontal pixel pitch ({pitch_um:.2} µm) is unrealistically small",
+    ),
+    (
+        "camera.horizontal_pitch_too_large",
+        "Horizontal pixel pitch ({pitch_um:.2} µm) is unusually large",
+    ),
+    (
+        "camera.vertical_pitch_too_small",
+        "Vertical pixel pitch ({pitch_um:.2} µm) is unrealistically small",
+    ),
+    (
+        "camera.vertical_pitch_too_large",
+        "Vertical pixel pitch ({pitch_um:.2} µm) is unusually large",
+    ),
+    (
+        "camera.t_stop_faster_than_f_number",
+        "T-stop (T{t_stop:.1}) is faster than the f-number (f/{f_number:.1})",
+    ),
+    (
+        "camera.aspect_ratio_mismatch",
+        "Sensor aspect ratio ({sensor_aspect:.3}:1) doesn't match pixel aspect ratio ({pixel_aspect:.3}:1) - difference: {difference_percent:.1}%",
+    ),
+    (
+        "camera.non_square_pixels",
+        "Pixels are not square: horizontal pitch ({horizontal_pitch_um:.2} µm) differs from vertical pitch ({vertical_pitch_um:.2} µm) by {difference_percent:.1}%",
+    ),
+    (
+        "camera.quantum_efficiency_out_of_range",
+        "Quantum efficiency ({quantum_efficiency:.2}) must be between 0 and 1",
+    ),
+    ("camera.read_noise_e_negative", "Read noise cannot be negative"),
+    (
+        "camera.full_well_e_negative",
+        "Full-well capacity cannot be negative",
+    ),
+    (
+        "camera.dark_current_e_s_negative",
+        "Dark current cannot be negative",
+    ),
+    (
+        "camera.read_noise_above_full_well",
+        "Read noise ({read_noise_e:.1} e⁻) is not below the full-well capacity ({full_well_e:.0} e⁻)",
+    ),
+    (
+        "fov.summary",
+        "FOV: {horizontal_fov_deg:.2}° × {vertical_fov_deg:.2}° ({horizontal_fov_m:.3} × {vertical_fov_m:.3} m @ {distance_m:.2} m), {diagonal_fov_deg:.2}° diagonal, {angle_of_view}\nResolution: {horizontal_ppm:.1} × {vertical_ppm:.1} px/m ({horizontal_ppf:.1} × {vertical_ppf:.1} px/ft, {horizontal_px_per_deg:.1} px/°, {megapixels_per_m2:.2} MP/m²)\nIFOV: {horizontal_ifov_urad:.1} × {vertical_ifov_urad:.1} µrad/px",
+    ),
+    (
+        "fov.horizontal_too_wide",
+        "Horizontal FOV ({horizontal_fov_deg:.1}°) exceeds 180° - physically impossible",
+    ),
+    (
+        "fov.horizontal_too_narrow",
+        "Horizontal FOV ({horizontal_fov_deg:.2}°) is extremely narrow - may be unrealistic",
+    ),
+    (
+        "fov.vertical_too_wide",
+        "Vertical FOV ({vertical_fov_deg:.1}°) exceeds 180° - physically impossible",
+    ),
+    (
+        "fov.vertical_too_narrow",
+        "Vertical FOV ({vertical_fov_deg:.2}°) is extremely narrow - may be unrealistic",
+    ),
+    (
+        "fov.ppm_too_high",
+        "Pixels per meter ({horizontal_ppm:.1} × {vertical_ppm:.1} px/m) is unrealistically high",
+    ),
+    (
+        "fov.ppm_too_low",
+        "Pixels per meter ({horizontal_ppm:.6} × {vertical_ppm:.6} px/m) is unrealistically low",
+    ),
+    (
+        "dori.detection_unrealistic",
+        "Detection distance ({detection_m:.0} m) seems unrealistic",
+    ),
+    (
+        "dori.detection_within_observation",
+        "Detection distance should be greater than Observation distance",
+    ),
+    (
+        "dori.observation_within_recognition",
+        "Observation distance should be greater than Recognition distance",
+    ),
+    (
+        "dori.recognition_within_identification",
+        "Recognition distance should be greater than Identification distance",
+    ),
+    (
+        "dori.target_missed",
+        "Reaches {level} out to {reached_m:.1} m, short of the {distance_m:.1} m target",
+    ),
+    (
+        "dori.target_missed.suggestion",
+        "Increase focal length to ≥ {focal_length_mm:.1} mm to reach {level} at {distance_m} m",
+    ),
+    (
+        "dori.target_met",
+        "Reaches {level} out to {reached_m:.1} m, beyond the {distance_m:.1} m target",
+    ),
+    (
+        "dori.target_met.suggestion",
+        "A focal length down to {focal_length_mm:.1} mm still reaches {level} at {distance_m} m, with a wider field of view",
+    ),
+    (
+        "sampling.aliasing",
+        "Lens resolves {lens_resolution_lp_mm:.0} lp/mm, above the sensor Nyquist frequency of {nyquist_lp_mm:.0} lp/mm - aliasing is likely",
+    ),
+    (
+        "sampling.lens_limited",
+        "Lens resolves only {lens_resolution_lp_mm:.0} lp/mm, less than half the sensor Nyquist frequency of {nyquist_lp_mm:.0} lp/mm - the lens cannot feed the sensor",
+    ),
+    (
+        "sampling.lens_limited.suggestion",
+        "Use a lens resolving ≥ {lens_resolution_lp_mm:.0} lp/mm",
+    ),
+    (
+        "astro.undersampled",
+        "{pixel_scale_arcsec:.2}\"/px gives only {pixels_per_fwhm:.1} px across {seeing_arcsec:.1}\" seeing - stars will be undersampled",
+    ),
+    (
+        "astro.undersampled.suggestion",
+        "Increase focal length to ≥ {focal_length_mm:.0} mm for 2 px across the seeing",
+    ),
+    (
+        "astro.oversampled",
+        "{pixel_scale_arcsec:.2}\"/px gives {pixels_per_fwhm:.1} px across {seeing_arcsec:.1}\" seeing - oversampled; consider binning or a shorter focal length",
+    ),
+    (
+        "astro.oversampled.suggestion",
+        "Reduce focal length to ≤ {focal_length_mm:.0} mm for 3 px across the seeing",
+    ),
+    (
+        "aerial.altitude_above_ceiling",
+        "{gsd_cm:.1} cm/px needs {altitude_m:.0} m altitude, above the {max_altitude_m:.0} m ceiling - use a longer lens or a finer GSD",
+    ),
+    (
+        "vignetting.corner_illumination_low",
+        "Corners receive {corner_percent:.0}% of the centre illumination ({corner_falloff_stops:.1} stops), below the {min_corner_percent:.0}% threshold - apply flat-field correction before measuring",
+    ),
+    (
+        "incidence.too_steep",
+        "Camera looks down on faces at {incidence_deg:.1}°, steeper than {max_incidence_deg:.0}° - faces will be seen from above and may not be identifiable even at 250 px/m",
+    ),
+    (
+        "incidence.too_steep.suggestion",
+        "Mount the camera at ≤ {mounting_height_m:.2} m or identify faces from ≥ {distance_m:.1} m away",
+    ),
+    (
+        "onvif.sensor_size_assumed",
+        "Sensor size not reported by device; assuming {sensor_width_mm:.1} mm wide (1/2.8\")",
+    ),
+    (
+        "onvif.focal_length_interpolated",
+        "Focal length interpolated linearly from zoom position; actual lens mapping may differ",
+    ),
+    (
+        "onvif.focal_length_assumed",
+        "Focal length not reported by device; assuming {focal_length_mm:.1} mm",
+    ),
+    (
+        "bandwidth.link_capacity_exceeded",
+        "{name}: average bandwidth ({average_mbps:.1} Mbps) exceeds link capacity ({capacity_mbps:.0} Mbps)",
+    ),
+    (
+        "bandwidth.peak_link_capacity_exceeded",
+        "{name}: peak bandwidth ({peak_mbps:.1} Mbps) exceeds link capacity ({capacity_mbps:.0} Mbps)",
+    ),
+    (
+        "compliance.requirement_not_met",
+        "{requirement}: achieves {achieved:.1} {unit}, requires {required:.1} {unit}",
+    ),
+    (
+        "compliance.requirement_not_evaluated",
+        "{requirement}: no stream settings to check against",
+    ),
+    (
+        "dori_ranges.inconsistent",
+        "Fixed FOV, focal length and sensor width disagree: they give a {implied_fov_deg:.2}° FOV, {discrepancy_deg:.2}° ({discrepancy_percent:.1}%) off the given one",
+    ),
+    (
+        "dori_ranges.inconsistent.suggestion",
+        "Use a {horizontal_fov_deg:.2}° FOV, a {focal_length_mm:.2} mm focal length or a {sensor_width_mm:.2} mm sensor width",
+    ),
+    (
+        "dori_ranges.targets_inconsistent",
+        "DORI targets do not follow the standard ratios: {level} at {distance_m:.1} m is the strictest, so the other targets are exceeded",
+    ),
+    (
+        "dori_ranges.infeasible",
+        "No {parameter} within the parameter bounds reaches {level} at {distance_m:.1} m",
+    ),
+    (
+        "parameter.sensor_width",
+        "sensor width",
+    ),
+    (
+        "parameter.sensor_height",
+        "sensor height",
+    ),
+    (
+        "parameter.pixel_width",
+        "pixel width",
+    ),
+    (
+        "parameter.pixel_height",
+        "pixel height",
+    ),
+    (
+        "parameter.focal_length",
+        "focal length",
+    ),
+    (
+        "parameter.horizontal_fov",
+        "horizontal FOV",
+    ),
+    (
+        "dori_request.no_target",
+        "Give at least one DORI target distance",
+    ),
+    (
+        "dori_request.target_not_positive",
+        "The {level} target distance must be greater than zero",
+    ),
+    (
+        "dori_request.constraint_not_positive",
+        "The fixed {parameter} must be greater than zero",
+    ),
+    (
+        "dori_request.fov_out_of_range",
+        "A field of view must lie between 0° and 180°, not {fov_deg:.1}°",
+    ),
+    (
+        "dori_ground.below_mount",
+        "The {level} range of {slant_m:.1} m does not reach {target_height_m:.1} m targets from a {mounting_height_m:.1} m mount",
+    ),
+    (
+        "dori_ground.below_mount.suggestion",
+        "Mount the camera at ≤ {mounting_height_m:.2} m for {level} at {target_height_m:.1} m",
+    ),
+    (
+        "dori_ground.in_blind_spot",
+        "The {level} range ends {ground_m:.1} m out along the ground, inside the {blind_spot_m:.1} m blind spot below the frame",
+    ),
+    (
+        "dori_ground.in_blind_spot.suggestion",
+        "Tilt the camera down to {tilt_deg:.1}° or lower",
+    ),
+];
+
+const DE: &[(&str, &str)] = &[
+    ("severity.info", "Hinweis"),
+    ("severity.warning", "Warnung"),
+    ("severity.error", "Fehler"),
+    ("dori.level.detection", "Detektion"),
+    ("dori.level.observation", "Beobachtung"),
+    ("dori.level.recognition", "Wiedererkennung"),
+    ("dori.level.identification", "Identifizierung"),
+    ("angle_of_view.ultra_wide", "Ultraweitwinkel"),
+    ("angle_of_view.wide", "Weitwinkel"),
+    ("angle_of_view.normal", "Normalbrennweite"),
+    ("angle_of_view.short_tele", "leichtes Tele"),
+    ("angle_of_view.tele", "Tele"),
+    ("angle_of_view.super_tele", "Supertele"),
+    ("angle_of_view.unknown", "nicht eingeordnet"),
+    ("camera.unnamed", "Unbenannt"),
+    (
+        "camera.summary",
+        "{name}: {sensor_width_mm}x{sensor_height_mm} mm Sensor, {pixel_width}x{pixel_height} px ({horizontal_pitch_um:.2}x{vertical_pitch_um:.2} µm, Pixelseitenverhältnis {pixel_aspect_ratio:.2}), {focal_length_mm} mm Objektiv",
+    ),
+    (
+        "camera.sensor_width_too_small",
+        "Sensorbreite ({sensor_width_mm:.2} mm) ist unrealistisch klein",
+    ),
+    (
+        "camera.sensor_width_too_large",
+        "Sensorbreite ({sensor_width_mm:.2} mm) ist unrealistisch groß",
+    ),
+    (
+        "camera.sensor_height_too_small",
+        "Sensorhöhe ({sensor_height_mm:.2} mm) ist unrealistisch klein",
+    ),
+    (
+        "camera.sensor_height_too_large",
+        "Sensorhöhe ({sensor_height_mm:.2} mm) ist unrealistisch groß",
+    ),
+    (
+        "camera.focal_length_too_short",
+        "Brennweite ({focal_length_mm:.2} mm) ist unrealistisch kurz",
+    ),
+    (
+        "camera.focal_length_too_long",
+        "Brennweite ({focal_length_mm:.0} mm) ist extrem lang",
+    ),
+    (
+        "camera.pixel_width_too_low",
+        "Pixelbreite ({pixel_width} px) ist unrealistisch niedrig",
+    ),
+    (
+        "camera.pixel_width_too_high",
+        "Pixelbreite ({pixel_width} px) ist unrealistisch hoch",
+    ),
+    (
+        "camera.pixel_height_too_low",
+        "Pixelhöhe ({pixel_height} px) ist unrealistisch niedrig",
+    ),
+    (
+        "camera.pixel_height_too_high",
+        "Pixelhöhe ({pixel_height} px) ist unrealistisch hoch",
+    ),
+    (
+        "camera.horizontal_pitch_too_small",
+        "Horizontaler Pixelabstand ({pitch_um:.2} µm) ist unrealistisch klein",
+    ),
+    (
+        "camera.horizontal_pitch_too_large",
+        "Horizontaler Pixelabstand ({pitch_um:.2} µm) ist ungewöhnlich groß",
+    ),
+    (
+        "camera.vertical_pitch_too_small",
+        "Vertikaler Pixelabstand ({pitch_um:.2} µm) ist unrealistisch klein",
+    ),
+    (
+        "camera.vertical_pitch_too_large",
+        "Vertikaler Pixelabstand ({pitch_um:.2} µm) ist ungewöhnlich groß",
+    ),
+    (
+        "camera.t_stop_faster_than_f_number",
+        "T-Blende (T{t_stop:.1}) ist lichtstärker als die Blendenzahl (f/{f_number:.1})",
+    ),
+    (
+        "camera.aspect_ratio_mismatch",
+        "Seitenverhältnis des Sensors ({sensor_aspect:.3}:1) passt nicht zum Pixel-Seitenverhältnis ({pixel_aspect:.3}:1) - Abweichung: {difference_percent:.1} %",
+    ),
+    (
+        "camera.non_square_pixels",
+        "Pixel sind nicht quadratisch: horizontaler Abstand ({horizontal_pitch_um:.2} µm) weicht um {difference_percent:.1} % vom vertikalen Abstand ({vertical_pitch_um:.2} µm) ab",
+    ),
+    (
+        "camera.quantum_efficiency_out_of_range",
+        "Quanteneffizienz ({quantum_efficiency:.2}) muss zwischen 0 und 1 liegen",
+    ),
+    (
+        "camera.read_noise_e_negative",
+        "Ausleserauschen kann nicht negativ sein",
+    ),
+    (
+        "camera.full_well_e_negative",
+        "Full-Well-Kapazität kann nicht negativ sein",
+    ),
+    (
+        "camera.dark_current_e_s_negative",
+        "Dunkelstrom kann nicht negativ sein",
+    ),
+    (
+        "camera.read_noise_above_full_well",
+        "Ausleserauschen ({read_noise_e:.1} e⁻) liegt nicht unter der Full-Well-Kapazität ({full_well_e:.0} e⁻)",
+    ),
+    (
+        "fov.summary",
+        "Sichtfeld: {horizontal_fov_deg:.2}° × {vertical_fov_deg:.2}° ({horizontal_fov_m:.3} × {vertical_fov_m:.3} m bei {distance_m:.2} m), {diagonal_fov_deg:.2}° diagonal, {angle_of_view}\nAuflösung: {horizontal_ppm:.1} × {vertical_ppm:.1} px/m ({horizontal_ppf:.1} × {vertical_ppf:.1} px/ft, {horizontal_px_per_deg:.1} px/°, {megapixels_per_m2:.2} MP/m²)\nIFOV: {horizontal_ifov_urad:.1} × {vertical_ifov_urad:.1} µrad/px",
+    ),
+    (
+        "fov.horizontal_too_wide",
+        "Horizontales Sichtfeld ({horizontal_fov_deg:.1}°) übersteigt 180° - physikalisch unmöglich",
+    ),
+    (
+        "fov.horizontal_too_narrow",
+        "Horizontales Sichtfeld ({horizontal_fov_deg:.2}°) ist extrem schmal - möglicherweise unrealistisch",
+    ),
+    (
+        "fov.vertical_too_wide",
+        "Vertikales Sichtfeld ({vertical_fov_deg:.1}°) übersteigt 180° - physikalisch unmöglich",
+    ),
+    (
+        "fov.vertical_too_narrow",
+        "Vertikales Sichtfeld ({vertical_fov_deg:.2}°) ist extrem schmal - möglicherweise unrealistisch",
+    ),
+    (
+        "fov.ppm_too_high",
+        "Pixel pro Meter ({horizontal_ppm:.1} × {vertical_ppm:.1} px/m) sind unrealistisch hoch",
+    ),
+    (
+        "fov.ppm_too_low",
+        "Pixel pro Meter ({horizontal_ppm:.6} × {vertical_ppm:.6} px/m) sind unrealistisch niedrig",
+    ),
+    (
+        "dori.detection_unrealistic",
+        "Detektionsentfernung ({detection_m:.0} m) erscheint unrealistisch",
+    ),
+    (
+        "dori.detection_within_observation",
+        "Detektionsentfernung sollte größer als die Beobachtungsentfernung sein",
+    ),
+    (
+        "dori.observation_within_recognition",
+        "Beobachtungsentfernung sollte größer als die Wiedererkennungsentfernung sein",
+    ),
+    (
+        "dori.recognition_within_identification",
+        "Wiedererkennungsentfernung sollte größer als die Identifizierungsentfernung sein",
+    ),
+    (
+        "dori.target_missed",
+        "Erreicht {level} bis {reached_m:.1} m, weniger als das Ziel von {distance_m:.1} m",
+    ),
+    (
+        "dori.target_missed.suggestion",
+        "Brennweite auf ≥ {focal_length_mm:.1} mm erhöhen, um {level} bei {distance_m} m zu erreichen",
+    ),
+    (
+        "dori.target_met",
+        "Erreicht {level} bis {reached_m:.1} m, über das Ziel von {distance_m:.1} m hinaus",
+    ),
+    (
+        "dori.target_met.suggestion",
+        "Schon eine Brennweite von {focal_length_mm:.1} mm erreicht {level} bei {distance_m} m, mit breiterem Sichtfeld",
+    ),
+    (
+        "sampling.aliasing",
+        "Objektiv löst {lens_resolution_lp_mm:.0} lp/mm auf, über der Nyquist-Frequenz des Sensors von {nyquist_lp_mm:.0} lp/mm - Aliasing ist wahrscheinlich",
+    ),
+    (
+        "sampling.lens_limited",
+        "Objektiv löst nur {lens_resolution_lp_mm:.0} lp/mm auf, weniger als die halbe Nyquist-Frequenz des Sensors von {nyquist_lp_mm:.0} lp/mm - das Objektiv begrenzt den Sensor",
+    ),
+    (
+        "sampling.lens_limited.suggestion",
+        "Ein Objektiv mit ≥ {lens_resolution_lp_mm:.0} lp/mm Auflösung verwenden",
+    ),
+    (
+        "astro.undersampled",
+        "{pixel_scale_arcsec:.2}\"/px ergibt nur {pixels_per_fwhm:.1} px über {seeing_arcsec:.1}\" Seeing - Sterne werden unterabgetastet",
+    ),
+    (
+        "astro.undersampled.suggestion",
+        "Brennweite auf ≥ {focal_length_mm:.0} mm erhöhen für 2 px über das Seeing",
+    ),
+    (
+        "astro.oversampled",
+        "{pixel_scale_arcsec:.2}\"/px ergibt {pixels_per_fwhm:.1} px über {seeing_arcsec:.1}\" Seeing - überabgetastet; Binning oder eine kürzere Brennweite erwägen",
+    ),
+    (
+        "astro.oversampled.suggestion",
+        "Brennweite auf ≤ {focal_length_mm:.0} mm verringern für 3 px über das Seeing",
+    ),
+    (
+        "aerial.altitude_above_ceiling",
+        "{gsd_cm:.1} cm/px erfordert {altitude_m:.0} m Flughöhe, über der Obergrenze von {max_altitude_m:.0} m - längeres Objektiv oder feinere GSD verwenden",
+    ),
+    (
+        "vignetting.corner_illumination_low",
+        "Die Ecken erhalten {corner_percent:.0} % der Mittenbeleuchtung ({corner_falloff_stops:.1} Blendenstufen), unter dem Schwellwert von {min_corner_percent:.0} % - vor dem Messen eine Flat-Field-Korrektur anwenden",
+    ),
+    (
+        "incidence.too_steep",
+        "Kamera blickt mit {incidence_deg:.1}° auf Gesichter herab, steiler als {max_incidence_deg:.0}° - Gesichter werden von oben gesehen und sind selbst bei 250 px/m eventuell nicht identifizierbar",
+    ),
+    (
+        "incidence.too_steep.suggestion",
+        "Kamera auf ≤ {mounting_height_m:.2} m montieren oder Gesichter aus ≥ {distance_m:.1} m Entfernung identifizieren",
+    ),
+    (
+        "onvif.sensor_size_assumed",
+        "Sensorgröße vom Gerät nicht gemeldet; angenommen werden {sensor_width_mm:.1} mm Breite (1/2,8\")",
+    ),
+    (
+        "onvif.focal_length_interpolated",
+        "Brennweite linear aus der Zoomposition interpoliert; die tatsächliche Objektivkennlinie kann abweichen",
+    ),
+    (
+        "onvif.focal_length_assumed",
+        "Brennweite vom Gerät nicht gemeldet; angenommen werden {focal_length_mm:.1} mm",
+    ),
+    (
+        "bandwidth.link_capacity_exceeded",
+        "{name}: mittlere Bandbreite ({average_mbps:.1} Mbit/s) übersteigt die Leitungskapazität ({capacity_mbps:.0} Mbit/s)",
+    ),
+    (
+        "bandwidth.peak_link_capacity_exceeded",
+        "{name}: Spitzenbandbreite ({peak_mbps:.1} Mbit/s) übersteigt die Leitungskapazität ({capacity_mbps:.0} Mbit/s)",
+    ),
+    (
+        "compliance.requirement_not_met",
+        "{requirement}: erreicht {achieved:.1} {unit}, gefordert {required:.1} {unit}",
+    ),
+    (
+        "compliance.requirement_not_evaluated",
+        "{requirement}: keine Stream-Einstellungen zum Prüfen",
+    ),
+    (
+        "dori_ranges.inconsistent",
+        "Festes Sichtfeld, Brennweite und Sensorbreite widersprechen sich: sie ergeben {implied_fov_deg:.2}° Sichtfeld, {discrepancy_deg:.2}° ({discrepancy_percent:.1} %) abweichend vom vorgegebenen",
+    ),
+    (
+        "dori_ranges.inconsistent.suggestion",
+        "{horizontal_fov_deg:.2}° Sichtfeld, {focal_length_mm:.2} mm Brennweite oder {sensor_width_mm:.2} mm Sensorbreite verwenden",
+    ),
+    (
+        "dori_ranges.targets_inconsistent",
+        "DORI-Ziele folgen nicht den Standardverhältnissen: {level} bei {distance_m:.1} m ist am strengsten, die übrigen Ziele werden übertroffen",
+    ),
+    (
+        "dori_ranges.infeasible",
+        "Keine {parameter} innerhalb der Parametergrenzen erreicht {level} bei {distance_m:.1} m",
+    ),
+    (
+        "parameter.sensor_width",
+        "Sensorbreite",
+    ),
+    (
+        "parameter.sensor_height",
+        "Sensorhöhe",
+    ),
+    (
+        "parameter.pixel_width",
+        "Pixelbreite",
+    ),
+    (
+        "parameter.pixel_height",
+        "Pixelhöhe",
+    ),
+    (
+        "parameter.focal_length",
+        "Brennweite",
+    ),
+    (
+        "parameter.horizontal_fov",
+        "horizontales Sichtfeld",
+    ),
+    (
+        "dori_request.no_target",
+        "Mindestens eine DORI-Zielentfernung angeben",
+    ),
+    (
+        "dori_request.target_not_positive",
+        "Die Zielentfernung für {level} muss größer als null sein",
+    ),
+    (
+        "dori_request.constraint_not_positive",
+        "Die feste {parameter} muss größer als null sein",
+    ),
+    (
+        "dori_request.fov_out_of_range",
+        "Ein Sichtfeld muss zwischen 0° und 180° liegen, nicht {fov_deg:.1}°",
+    ),
+    (
+        "dori_ground.below_mount",
+        "Die Reichweite für {level} von {slant_m:.1} m erreicht Ziele in {target_height_m:.1} m Höhe bei {mounting_height_m:.1} m Montagehöhe nicht",
+    ),
+    (
+        "dori_ground.below_mount.suggestion",
+        "Kamera auf ≤ {mounting_height_m:.2} m montieren für {level} in {target_height_m:.1} m Höhe",
+    ),
+    (
+        "dori_ground.in_blind_spot",
+        "Die Reichweite für {level} endet {ground_m:.1} m entfernt am Boden, innerhalb des toten Winkels von {blind_spot_m:.1} m unter dem Bild",
+    ),
+    (
+        "dori_ground.in_blind_spot.suggestion",
+        "Kamera auf {tilt_deg:.1}° oder tiefer neigen",
+    ),
+];
+
+const FR: &[(&str, &str)] = &[
+    ("severity.info", "Info"),
+    ("severity.warning", "Avertissement"),
+    ("severity.error", "Erreur"),
+    ("dori.level.detection", "détection"),
+    ("dori.level.observation", "observation"),
+    ("dori.level.recognition", "reconnaissance"),
+    ("dori.level.identification", "identification"),
+    ("angle_of_view.ultra_wide", "ultra grand-angle"),
+    ("angle_of_view.wide", "grand-angle"),
+    ("angle_of_view.normal", "standard"),
+    ("angle_of_view.short_tele", "téléobjectif court"),
+    ("angle_of_view.tele", "téléobjectif"),
+    ("angle_of_view.super_tele", "super téléobjectif"),
+    ("angle_of_view.unknown", "non classé"),
+    ("camera.unnamed", "Sans nom"),
+    (
+        "camera.summary",
+        "{name} : capteur {sensor_width_mm}x{sensor_height_mm} mm, {pixel_width}x{pixel_height} px ({horizontal_pitch_um:.2}x{vertical_pitch_um:.2} µm, format de pixel {pixel_aspect_ratio:.2}), objectif {focal_length_mm} mm",
+    ),
+    (
+        "camera.sensor_width_too_small",
+        "La largeur du capteur ({sensor_width_mm:.2} mm) est irréalistement petite",
+    ),
+    (
+        "camera.sensor_width_too_large",
+        "La largeur du capteur ({sensor_width_mm:.2} mm) est irréalistement grande",
+    ),
+    (
+        "camera.sensor_height_too_small",
+        "La hauteur du capteur ({sensor_height_mm:.2} mm) est irréalistement petite",
+    ),
+    (
+        "camera.sensor_height_too_large",
+        "La hauteur du capteur ({sensor_height_mm:.2} mm) est irréalistement grande",
+    ),
+    (
+        "camera.focal_length_too_short",
+        "La focale ({focal_length_mm:.2} mm) est irréalistement courte",
+    ),
+    (
+        "camera.focal_length_too_long",
+        "La focale ({focal_length_mm:.0} mm) est extrêmement longue",
+    ),
+    (
+        "camera.pixel_width_too_low",
+        "La largeur en pixels ({pixel_width} px) est irréalistement faible",
+    ),
+    (
+        "camera.pixel_width_too_high",
+        "La largeur en pixels ({pixel_width} px) est irréalistement élevée",
+    ),
+    (
+        "camera.pixel_height_too_low",
+        "La hauteur en pixels ({pixel_height} px) est irréalistement faible",
+    ),
+    (
+        "camera.pixel_height_too_high",
+        "La hauteur en pixels ({pixel_height} px) est irréalistement élevée",
+    ),
+    (
+        "camera.horizontal_pitch_too_small",
+        "Le pas horizontal des pixels ({pitch_um:.2} µm) est irréalistement petit",
+    ),
+    (
+        "camera.horizontal_pitch_too_large",
+        "Le pas horizontal des pixels ({pitch_um:.2} µm) est inhabituellement grand",
+    ),
+    (
+        "camera.vertical_pitch_too_small",
+        "Le pas vertical des pixels ({pitch_um:.2} µm) est irréalistement petit",
+    ),
+    (
+        "camera.vertical_pitch_too_large",
+        "Le pas vertical des pixels ({pitch_um:.2} µm) est inhabituellement grand",
+    ),
+    (
+        "camera.t_stop_faster_than_f_number",
+        "L'ouverture T (T{t_stop:.1}) est plus lumineuse que l'ouverture f (f/{f_number:.1})",
+    ),
+    (
+        "camera.aspect_ratio_mismatch",
+        "Le format du capteur ({sensor_aspect:.3}:1) ne correspond pas au format en pixels ({pixel_aspect:.3}:1) - écart : {difference_percent:.1} %",
+    ),
+    (
+        "camera.non_square_pixels",
+        "Les pixels ne sont pas carrés : le pas horizontal ({horizontal_pitch_um:.2} µm) diffère du pas vertical ({vertical_pitch_um:.2} µm) de {difference_percent:.1} %",
+    ),
+    (
+        "camera.quantum_efficiency_out_of_range",
+        "L'efficacité quantique ({quantum_efficiency:.2}) doit être comprise entre 0 et 1",
+    ),
+    (
+        "camera.read_noise_e_negative",
+        "Le bruit de lecture ne peut pas être négatif",
+    ),
+    (
+        "camera.full_well_e_negative",
+        "La capacité de puits ne peut pas être négative",
+    ),
+    (
+        "camera.dark_current_e_s_negative",
+        "Le courant d'obscurité ne peut pas être négatif",
+    ),
+    (
+        "camera.read_noise_above_full_well",
+        "Le bruit de lecture ({read_noise_e:.1} e⁻) n'est pas inférieur à la capacité de puits ({full_well_e:.0} e⁻)",
+    ),
+    (
+        "fov.summary",
+        "Champ de vision : {horizontal_fov_deg:.2}° × {vertical_fov_deg:.2}° ({horizontal_fov_m:.3} × {vertical_fov_m:.3} m à {distance_m:.2} m), {diagonal_fov_deg:.2}° en diagonale, {angle_of_view}\nRésolution : {horizontal_ppm:.1} × {vertical_ppm:.1} px/m ({horizontal_ppf:.1} × {vertical_ppf:.1} px/pied, {horizontal_px_per_deg:.1} px/°, {megapixels_per_m2:.2} MP/m²)\nIFOV : {horizontal_ifov_urad:.1} × {vertical_ifov_urad:.1} µrad/px",
+    ),
+    (
+        "fov.horizontal_too_wide",
+        "Le champ horizontal ({horizontal_fov_deg:.1}°) dépasse 180° - physiquement impossible",
+    ),
+    (
+        "fov.horizontal_too_narrow",
+        "Le champ horizontal ({horizontal_fov_deg:.2}°) est extrêmement étroit - peut-être irréaliste",
+    ),
+    (
+        "fov.vertical_too_wide",
+        "Le champ vertical ({vertical_fov_deg:.1}°) dépasse 180° - physiquement impossible",
+    ),
+    (
+        "fov.vertical_too_narrow",
+        "Le champ vertical ({vertical_fov_deg:.2}°) est extrêmement étroit - peut-être irréaliste",
+    ),
+    (
+        "fov.ppm_too_high",
+        "La densité ({horizontal_ppm:.1} × {vertical_ppm:.1} px/m) est irréalistement élevée",
+    ),
+    (
+        "fov.ppm_too_low",
+        "La densité ({horizontal_ppm:.6} × {vertical_ppm:.6} px/m) est irréalistement faible",
+    ),
+    (
+        "dori.detection_unrealistic",
+        "La distance de détection ({detection_m:.0} m) semble irréaliste",
+    ),
+    (
+        "dori.detection_within_observation",
+        "La distance de détection devrait être supérieure à la distance d'observation",
+    ),
+    (
+        "dori.observation_within_recognition",
+        "La distance d'observation devrait être supérieure à la distance de reconnaissance",
+    ),
+    (
+        "dori.recognition_within_identification",
+        "La distance de reconnaissance devrait être supérieure à la distance d'identification",
+    ),
+    (
+        "dori.target_missed",
+        "Atteint le niveau {level} jusqu'à {reached_m:.1} m, en deçà de l'objectif de {distance_m:.1} m",
+    ),
+    (
+        "dori.target_missed.suggestion",
+        "Augmenter la focale à ≥ {focal_length_mm:.1} mm pour atteindre le niveau {level} à {distance_m} m",
+    ),
+    (
+        "dori.target_met",
+        "Atteint le niveau {level} jusqu'à {reached_m:.1} m, au-delà de l'objectif de {distance_m:.1} m",
+    ),
+    (
+        "dori.target_met.suggestion",
+        "Une focale de {focal_length_mm:.1} mm atteint encore le niveau {level} à {distance_m} m, avec un champ plus large",
+    ),
+    (
+        "sampling.aliasing",
+        "L'objectif résout {lens_resolution_lp_mm:.0} pl/mm, au-delà de la fréquence de Nyquist du capteur de {nyquist_lp_mm:.0} pl/mm - repliement de spectre probable",
+    ),
+    (
+        "sampling.lens_limited",
+        "L'objectif ne résout que {lens_resolution_lp_mm:.0} pl/mm, moins de la moitié de la fréquence de Nyquist du capteur de {nyquist_lp_mm:.0} pl/mm - l'objectif limite le capteur",
+    ),
+    (
+        "sampling.lens_limited.suggestion",
+        "Utiliser un objectif résolvant ≥ {lens_resolution_lp_mm:.0} pl/mm",
+    ),
+    (
+        "astro.undersampled",
+        "{pixel_scale_arcsec:.2}\"/px ne donne que {pixels_per_fwhm:.1} px sur un seeing de {seeing_arcsec:.1}\" - étoiles sous-échantillonnées",
+    ),
+    (
+        "astro.undersampled.suggestion",
+        "Augmenter la focale à ≥ {focal_length_mm:.0} mm pour 2 px sur le seeing",
+    ),
+    (
+        "astro.oversampled",
+        "{pixel_scale_arcsec:.2}\"/px donne {pixels_per_fwhm:.1} px sur un seeing de {seeing_arcsec:.1}\" - suréchantillonné ; envisager le binning ou une focale plus courte",
+    ),
+    (
+        "astro.oversampled.suggestion",
+        "Réduire la focale à ≤ {focal_length_mm:.0} mm pour 3 px sur le seeing",
+    ),
+    (
+        "aerial.altitude_above_ceiling",
+        "{gsd_cm:.1} cm/px exige {altitude_m:.0} m d'altitude, au-dessus du plafond de {max_altitude_m:.0} m - utiliser un objectif plus long ou une GSD plus fine",
+    ),
+    (
+        "vignetting.corner_illumination_low",
+        "Les coins reçoivent {corner_percent:.0} % de l'éclairement central ({corner_falloff_stops:.1} IL), sous le seuil de {min_corner_percent:.0} % - appliquer une correction de champ plat avant de mesurer",
+    ),
+    (
+        "incidence.too_steep",
+        "La caméra voit les visages sous {incidence_deg:.1}°, plus que {max_incidence_deg:.0}° - les visages sont vus d'en haut et peuvent rester non identifiables même à 250 px/m",
+    ),
+    (
+        "incidence.too_steep.suggestion",
+        "Monter la caméra à ≤ {mounting_height_m:.2} m ou identifier les visages à ≥ {distance_m:.1} m",
+    ),
+    (
+        "onvif.sensor_size_assumed",
+        "Taille du capteur non fournie par l'appareil ; {sensor_width_mm:.1} mm de large supposés (1/2,8\")",
+    ),
+    (
+        "onvif.focal_length_interpolated",
+        "Focale interpolée linéairement à partir de la position du zoom ; la loi réelle de l'objectif peut différer",
+    ),
+    (
+        "onvif.focal_length_assumed",
+        "Focale non fournie par l'appareil ; {focal_length_mm:.1} mm supposés",
+    ),
+    (
+        "bandwidth.link_capacity_exceeded",
+        "{name} : le débit moyen ({average_mbps:.1} Mbit/s) dépasse la capacité du lien ({capacity_mbps:.0} Mbit/s)",
+    ),
+    (
+        "bandwidth.peak_link_capacity_exceeded",
+        "{name} : le débit de pointe ({peak_mbps:.1} Mbit/s) dépasse la capacité du lien ({capacity_mbps:.0} Mbit/s)",
+    ),
+    (
+        "compliance.requirement_not_met",
+        "{requirement} : atteint {achieved:.1} {unit}, exige {required:.1} {unit}",
+    ),
+    (
+        "compliance.requirement_not_evaluated",
+        "{requirement} : aucun paramètre de flux pour la vérification",
+    ),
+    (
+        "dori_ranges.inconsistent",
+        "Le champ, la focale et la largeur du capteur fixés sont incohérents : ils donnent un champ de {implied_fov_deg:.2}°, écart de {discrepancy_deg:.2}° ({discrepancy_percent:.1} %) avec celui fixé",
+    ),
+    (
+        "dori_ranges.inconsistent.suggestion",
+        "Utiliser un champ de {horizontal_fov_deg:.2}°, une focale de {focal_length_mm:.2} mm ou un capteur de {sensor_width_mm:.2} mm de large",
+    ),
+    (
+        "dori_ranges.targets_inconsistent",
+        "Les objectifs DORI ne suivent pas les rapports standard : le niveau {level} à {distance_m:.1} m est le plus strict, les autres objectifs sont dépassés",
+    ),
+    (
+        "dori_ranges.infeasible",
+        "Aucune valeur de {parameter} dans les limites des paramètres n'atteint le niveau {level} à {distance_m:.1} m",
+    ),
+    (
+        "parameter.sensor_width",
+        "largeur de capteur",
+    ),
+    (
+        "parameter.sensor_height",
+        "hauteur de capteur",
+    ),
+    (
+        "parameter.pixel_width",
+        "largeur en pixels",
+    ),
+    (
+        "parameter.pixel_height",
+        "hauteur en pixels",
+    ),
+    (
+        "parameter.focal_length",
+        "focale",
+    ),
+    (
+        "parameter.horizontal_fov",
+        "champ horizontal",
+    ),
+    (
+        "dori_request.no_target",
+        "Indiquer au moins une distance cible DORI",
+    ),
+    (
+        "dori_request.target_not_positive",
+        "La distance cible du niveau {level} doit être supérieure à zéro",
+    ),
+    (
+        "dori_request.constraint_not_positive",
+        "La valeur fixe de {parameter} doit être supérieure à zéro",
+    ),
+    (
+        "dori_request.fov_out_of_range",
+        "Un champ de vision doit être compris entre 0° et 180°, pas {fov_deg:.1}°",
+    ),
+    (
+        "dori_ground.below_mount",
+        "La portée de {level} de {slant_m:.1} m n'atteint pas les cibles à {target_height_m:.1} m depuis un montage à {mounting_height_m:.1} m",
+    ),
+    (
+        "dori_ground.below_mount.suggestion",
+        "Monter la caméra à ≤ {mounting_height_m:.2} m pour la {level} à {target_height_m:.1} m",
+    ),
+    (
+        "dori_ground.in_blind_spot",
+        "La portée de {level} se termine à {ground_m:.1} m au sol, dans l'angle mort de {blind_spot_m:.1} m sous l'image",
+    ),
+    (
+        "dori_ground.in_blind_spot.suggestion",
+        "Incliner la caméra vers le bas à {tilt_deg:.1}° ou plus",
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_with_decimals_and_locale_separator() {
+        let message =
+            MessageKey::new("camera.sensor_width_too_small").with_number("sensor_width_mm", 0.5);
+
+        assert_eq!(
+            message.render(Locale::En),
+            "Sensor width (0.50 mm) is unrealistically small"
+        );
+        assert_eq!(
+            message.render(Locale::De),
+            "Sensorbreite (0,50 mm) ist unrealistisch klein"
+        );
+        assert_eq!(
+            message.render(Locale::Fr),
+            "La largeur du capteur (0,50 mm) est irréalistement petite"
+        );
+    }
+
+    #[test]
+    fn test_render_terms_and_fallbacks() {
+        let message = MessageKey::new("dori.target_missed.suggestion")
+            .with_number("focal_length_mm", 7.29)
+            .with_term("level", "dori.level.identification")
+            .with_number("distance_m", 10.0);
+        assert_eq!(
+            message.render(Locale::De),
+            "Brennweite auf ≥ 7,3 mm erhöhen, um Identifizierung bei 10 m zu erreichen"
+        );
+
+        // Unknown keys render as themselves; missing parameters stay as placeholders
+        assert_eq!(
+            MessageKey::new("no.such.key").render(Locale::Fr),
+            "no.such.key"
+        );
+        assert_eq!(
+            MessageKey::new("camera.pixel_width_too_low").render(Locale::En),
+            "Pixel width ({pixel_width} px) is unrealistically low"
+        );
+    }
+
+    #[test]
+    fn test_localize_warnings() {
+        use crate::types::{CameraSystem, ValidationCode};
+
+        let mut camera = CameraSystem::new(5.6, 3.15, 1920, 1080, 4.0);
+        camera.t_stop = Some(1.2);
+        camera.f_number = Some(1.4);
+        let warnings = localize_warnings(&camera.validate(), Locale::Fr);
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, ValidationCode::TStopFasterThanFNumber);
+        assert_eq!(
+            warnings[0].message,
+            "L'ouverture T (T1,2) est plus lumineuse que l'ouverture f (f/1,4)"
+        );
+        // Re-rendering in English restores the original text
+        assert_eq!(
+            warnings[0].localize(Locale::En).message,
+            "T-stop (T1.2) is faster than the f-number (f/1.4)"
+        );
+    }
+
+    #[test]
+    fn test_catalogs_cover_the_same_keys_and_parameters() {
+        let placeholders = |template: &str| {
+            let mut names: Vec<String> = template
+                .split('{')
+                .skip(1)
+                .filter_map(|part| part.split_once('}'))
+                .map(|(name, _)| name.split(':').next().unwrap().to_string())
+                .collect();
+            names.sort();
+            names.dedup();
+            names
+        };
+
+        for catalog in [DE, FR] {
+            assert_eq!(catalog.len(), EN.len());
+            for (key, english) in EN {
+                let translated = catalog
+                    .iter()
+                    .find(|(catalog_key, _)| catalog_key == key)
+                    .unwrap_or_else(|| panic!("{} is not translated", key))
+                    .1;
+                assert_eq!(placeholders(translated), placeholders(english), "{}", key);
+            }
+        }
+    }
+}
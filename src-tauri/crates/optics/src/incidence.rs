@@ -0,0 +1,202 @@
+use super::calculations::calculate_dori_distances;
+use super::constants::{FACE_HEIGHT_M, MAX_FACE_INCIDENCE_DEG};
+use super::types::{
+    CameraSystem, DoriLevel, FaceIncidenceAnalysis, GroundDoriDistances, GroundDoriTier,
+    MountingHeightLimit,
+};
+
+/// Vertical incidence angle onto a face at a distance from the camera
+///
+/// Facial identification needs a view of the face, not the top of the head, so
+/// the line of sight should be no steeper than about 30° below horizontal.
+///
+/// # Arguments
+/// * `mounting_height_m` - Camera height above the ground
+/// * `distance_m` - Horizontal distance from the camera to the person
+/// * `face_height_m` - Face height above the ground, defaults to 1.6 m
+pub fn calculate_face_incidence(
+    mounting_height_m: f64,
+    distance_m: f64,
+    face_height_m: Option<f64>,
+) -> FaceIncidenceAnalysis {
+    let face_height_m = face_height_m.unwrap_or(FACE_HEIGHT_M);
+    let incidence_deg = (mounting_height_m - face_height_m)
+        .atan2(distance_m)
+        .to_degrees();
+
+    FaceIncidenceAnalysis {
+        mounting_height_m,
+        face_height_m,
+        distance_m,
+        incidence_deg,
+        max_incidence_deg: MAX_FACE_INCIDENCE_DEG,
+        exceeds_limit: incidence_deg > MAX_FACE_INCIDENCE_DEG,
+    }
+}
+
+/// Maximum mounting height that keeps the view onto faces within the identification limit
+///
+/// The inverse of [`calculate_face_incidence`]: a camera mounted higher looks down
+/// onto faces at `distance_m` more steeply than 30°.
+///
+/// # Arguments
+/// * `distance_m` - Horizontal distance at which faces must be identified
+/// * `face_height_m` - Face height above the ground, defaults to 1.6 m
+pub fn calculate_max_mounting_height(
+    distance_m: f64,
+    face_height_m: Option<f64>,
+) -> MountingHeightLimit {
+    let face_height_m = face_height_m.unwrap_or(FACE_HEIGHT_M);
+
+    MountingHeightLimit {
+        distance_m,
+        face_height_m,
+        max_incidence_deg: MAX_FACE_INCIDENCE_DEG,
+        max_mounting_height_m: face_height_m
+            + distance_m * MAX_FACE_INCIDENCE_DEG.to_radians().tan(),
+        tilt_deg: -MAX_FACE_INCIDENCE_DEG,
+    }
+}
+
+/// DORI distances measured along the floor plan for a mounted, tilted camera
+///
+/// DORI distances are straight-line (slant) distances from the lens. With the camera
+/// `mounting_height_m` up and the target at `target_height_m`, each slant distance is
+/// projected onto the ground. A tier is unreachable when its slant distance is shorter
+/// than the height difference, or when it ends inside the blind spot below the bottom
+/// edge of the frame.
+///
+/// # Arguments
+/// * `camera` - The camera system specification
+/// * `mounting_height_m` - Camera height above the ground
+/// * `tilt_deg` - Camera tilt, negative is looking down
+/// * `target_height_m` - Height of the observed target above the ground, defaults to 1.6 m
+pub fn calculate_ground_dori_distances(
+    camera: &CameraSystem,
+    mounting_height_m: f64,
+    tilt_deg: f64,
+    target_height_m: Option<f64>,
+) -> GroundDoriDistances {
+    let target_height_m = target_height_m.unwrap_or(FACE_HEIGHT_M);
+    let drop_m = mounting_height_m - target_height_m;
+    let vertical_fov_deg = 2.0
+        * (camera.sensor_height_mm / (2.0 * camera.focal_length_mm))
+            .atan()
+            .to_degrees();
+
+    // Ground distance at which the bottom edge of the frame reaches target height
+    let bottom_depression_deg = vertical_fov_deg / 2.0 - tilt_deg;
+    let blind_spot_m = if drop_m <= 0.0 || bottom_depression_deg >= 90.0 {
+        Some(0.0)
+    } else if bottom_depression_deg > 0.0 {
+        Some(drop_m / bottom_depression_deg.to_radians().tan())
+    } else {
+        None
+    };
+
+    let dori = calculate_dori_distances(camera);
+    let tiers = DoriLevel::ALL
+        .iter()
+        .map(|&level| {
+            let slant_m = dori.distance_m(level);
+            let ground_m =
+                (slant_m >= drop_m.abs()).then(|| (slant_m * slant_m - drop_m * drop_m).sqrt());
+            GroundDoriTier {
+                level,
+                slant_m,
+                ground_m,
+                reachable: matches!((ground_m, blind_spot_m), (Some(g), Some(b)) if g >= b),
+            }
+        })
+        .collect();
+
+    GroundDoriDistances {
+        mounting_height_m,
+        target_height_m,
+        tilt_deg,
+        vertical_fov_deg,
+        blind_spot_m,
+        tiers,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ValidationCode;
+
+    #[test]
+    fn test_face_incidence_angle() {
+        // 1.6 m drop over 2.77 m is 30°
+        let at_limit = calculate_face_incidence(3.2, 1.6 * 3f64.sqrt(), None);
+        assert!((at_limit.incidence_deg - 30.0).abs() < 1e-9);
+
+        let far = calculate_face_incidence(3.2, 6.0, None);
+        assert!(!far.exceeds_limit);
+        assert!(far.validate().is_empty());
+
+        // Right above the door: looking at the scalp
+        let steep = calculate_face_incidence(3.2, 1.0, Some(1.7));
+        assert!((steep.incidence_deg - 56.31).abs() < 0.01);
+        assert!(steep.exceeds_limit);
+        let warnings = steep.validate();
+        assert_eq!(warnings.len(), 1);
+        // Either mount at ≤ 1.7 + tan(30°) m or stand back ≥ 1.5 / tan(30°) m
+        assert_eq!(
+            warnings[0].suggestion.as_deref(),
+            Some("Mount the camera at ≤ 2.28 m or identify faces from ≥ 2.6 m away")
+        );
+    }
+
+    #[test]
+    fn test_max_mounting_height_inverts_incidence() {
+        let limit = calculate_max_mounting_height(5.0, None);
+        assert!((limit.max_mounting_height_m - (1.6 + 5.0 / 3f64.sqrt())).abs() < 1e-9);
+        assert_eq!(limit.tilt_deg, -30.0);
+
+        let check = calculate_face_incidence(limit.max_mounting_height_m, 5.0, None);
+        assert!((check.incidence_deg - 30.0).abs() < 1e-9);
+        assert!(!check.exceeds_limit);
+    }
+
+    #[test]
+    fn test_ground_dori_distances() {
+        // Identification at 4.8 m slant, detection at 48 m
+        let camera = CameraSystem::new(6.4, 4.8, 1920, 1080, 4.0);
+
+        // 4.6 m mount looking at faces 3 m below: 4.8 m slant is ~3.83 m along the floor
+        let ground = calculate_ground_dori_distances(&camera, 4.6, -30.0, None);
+        let identification = &ground.tiers[3];
+        assert_eq!(identification.level, DoriLevel::Identification);
+        assert!((identification.ground_m.unwrap() - (4.8f64 * 4.8 - 9.0).sqrt()).abs() < 1e-9);
+        assert!(identification.reachable);
+        assert!(ground.validate().is_empty());
+
+        // Mounted 6.6 m up the identification range never reaches face height
+        let high = calculate_ground_dori_distances(&camera, 6.6, -30.0, None);
+        assert_eq!(high.tiers[3].ground_m, None);
+        assert!(!high.tiers[3].reachable);
+        assert!(high.tiers[2].reachable);
+        let warnings = high.validate();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, ValidationCode::DoriTierUnreachable);
+        assert_eq!(
+            warnings[0].suggestion.as_deref(),
+            Some("Mount the camera at ≤ 6.40 m for identification at 1.6 m")
+        );
+
+        // Looking at the horizon, the frame bottom only reaches face height 5 m out
+        let level = calculate_ground_dori_distances(&camera, 4.6, 0.0, None);
+        let blind_spot_m = level.blind_spot_m.unwrap();
+        assert!(
+            (blind_spot_m - 3.0 / (level.vertical_fov_deg / 2.0).to_radians().tan()).abs() < 1e-9
+        );
+        assert!(!level.tiers[3].reachable);
+        assert!(level.tiers[2].reachable);
+        assert!(level.validate()[0]
+            .suggestion
+            .as_deref()
+            .unwrap()
+            .starts_with("Tilt the camera down to -"));
+    }
+}
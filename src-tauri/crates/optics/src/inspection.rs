@@ -0,0 +1,144 @@
+use super::constants::DEFAULT_PIXELS_PER_FEATURE;
+use super::types::{CameraSystem, InspectionOption, InspectionRequirements, InspectionSolution};
+
+/// The camera with a given lens, framing the inspected area at the magnification
+/// where it just fits the sensor
+fn inspection_option(
+    camera: &CameraSystem,
+    focal_length_mm: f64,
+    requirements: &InspectionRequirements,
+) -> InspectionOption {
+    let magnification = requirements
+        .field_height_mm
+        .map_or(f64::INFINITY, |height_mm| {
+            camera.sensor_height_mm / height_mm
+        })
+        .min(camera.sensor_width_mm / requirements.field_width_mm);
+
+    let field_width_mm = camera.sensor_width_mm / magnification;
+    let field_height_mm = camera.sensor_height_mm / magnification;
+    let pixels_per_feature = (camera.pixel_width as f64 / field_width_mm)
+        .min(camera.pixel_height as f64 / field_height_mm)
+        * requirements.feature_size_mm;
+
+    let mut camera = camera.clone();
+    camera.focal_length_mm = focal_length_mm;
+    InspectionOption {
+        camera,
+        // Thin lens: object distance u = f × (1 + 1/m)
+        working_distance_mm: focal_length_mm * (1.0 + 1.0 / magnification),
+        magnification,
+        field_width_mm,
+        field_height_mm,
+        pixels_per_feature,
+    }
+}
+
+/// Sensor resolution, lens and working distance combinations that detect a feature size
+///
+/// The inverse of the DORI solver for inspection: the smallest feature must span
+/// `pixels_per_feature` pixels while the inspected area fits in the frame. This
+/// sets the minimum pixel count independently of the lens; each camera with
+/// enough pixels is then paired with every focal length, at the working
+/// distance where the area fills the frame.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(cameras = cameras.len(), focal_lengths = focal_lengths_mm.len()))
+)]
+pub fn solve_inspection(
+    requirements: &InspectionRequirements,
+    cameras: &[CameraSystem],
+    focal_lengths_mm: &[f64],
+) -> InspectionSolution {
+    let pixels_per_feature = requirements
+        .pixels_per_feature
+        .unwrap_or(DEFAULT_PIXELS_PER_FEATURE);
+    let pixels_across =
+        |field_mm: f64| (field_mm / requirements.feature_size_mm * pixels_per_feature).ceil();
+
+    let mut options: Vec<InspectionOption> = cameras
+        .iter()
+        .flat_map(|camera| {
+            focal_lengths_mm.iter().map(move |&focal_length_mm| {
+                inspection_option(camera, focal_length_mm, requirements)
+            })
+        })
+        .filter(|option| option.pixels_per_feature >= pixels_per_feature)
+        .filter(|option| {
+            requirements
+                .working_distance_mm
+                .as_ref()
+                .is_none_or(|range| (range.min..=range.max).contains(&option.working_distance_mm))
+        })
+        .collect();
+    options.sort_by(|a, b| a.working_distance_mm.total_cmp(&b.working_distance_mm));
+
+    InspectionSolution {
+        pixels_per_feature,
+        required_pixel_width: pixels_across(requirements.field_width_mm) as u32,
+        required_pixel_height: requirements
+            .field_height_mm
+            .map(|height_mm| pixels_across(height_mm) as u32),
+        options,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ParameterRange;
+
+    fn requirements() -> InspectionRequirements {
+        InspectionRequirements {
+            feature_size_mm: 0.1,
+            pixels_per_feature: None,
+            field_width_mm: 100.0,
+            field_height_mm: Some(50.0),
+            working_distance_mm: None,
+        }
+    }
+
+    fn cameras() -> Vec<CameraSystem> {
+        vec![
+            // 2/3" 5 MP: 2448 px over 100 mm is only 2.4 px per 0.1 mm
+            CameraSystem::new(8.45, 7.07, 2448, 2048, 16.0).with_name("5 MP"),
+            // 1.1" 12 MP
+            CameraSystem::new(14.1, 10.3, 4096, 3000, 16.0).with_name("12 MP"),
+        ]
+    }
+
+    #[test]
+    fn test_required_resolution_and_options() {
+        let solution = solve_inspection(&requirements(), &cameras(), &[16.0, 25.0, 35.0]);
+
+        assert_eq!(solution.required_pixel_width, 3000);
+        assert_eq!(solution.required_pixel_height, Some(1500));
+        assert_eq!(solution.options.len(), 3);
+        assert!(solution
+            .options
+            .iter()
+            .all(|o| o.camera.pixel_width == 4096));
+
+        // 14.1 mm sensor over a 100 mm field: m = 0.141
+        let closest = &solution.options[0];
+        assert_eq!(closest.camera.focal_length_mm, 16.0);
+        assert!((closest.magnification - 0.141).abs() < 1e-9);
+        assert!((closest.working_distance_mm - 16.0 * (1.0 + 1.0 / 0.141)).abs() < 1e-9);
+        assert!((closest.pixels_per_feature - 4.096).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_working_distance_limits_lenses() {
+        let mut requirements = requirements();
+        requirements.working_distance_mm = Some(ParameterRange {
+            min: 150.0,
+            max: 250.0,
+            recommended: None,
+        });
+        let solution = solve_inspection(&requirements, &cameras(), &[16.0, 25.0, 35.0]);
+
+        // Only 25 mm works: 16 mm sits at 129 mm, 35 mm at 283 mm
+        assert_eq!(solution.options.len(), 1);
+        assert_eq!(solution.options[0].camera.focal_length_mm, 25.0);
+    }
+}
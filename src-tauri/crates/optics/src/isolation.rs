@@ -0,0 +1,99 @@
+use super::scoring::relative_percent;
+use super::types::{CameraSystem, CameraWithResult, IsolationScenario, SubjectIsolation};
+
+/// Blur disc diameter on the sensor of a background point, in millimeters
+///
+/// # Formula
+/// b = f² × (B - S) / (N × B × (S - f)), which tends to f² / (N × (S - f)) for a
+/// background at infinity
+pub fn calculate_background_blur(
+    focal_length_mm: f64,
+    f_number: f64,
+    scenario: &IsolationScenario,
+) -> f64 {
+    let subject_mm = scenario.subject_distance_mm;
+    let depth_factor = scenario
+        .background_distance_mm
+        .map_or(1.0, |background_mm| {
+            (background_mm - subject_mm).abs() / background_mm
+        });
+    focal_length_mm * focal_length_mm / (f_number * (subject_mm - focal_length_mm)) * depth_factor
+}
+
+/// Compare how strongly each camera separates a subject from the background
+///
+/// The blur disc is expressed as a share of the image width so sensor formats can
+/// be compared directly, then scored 0-100 relative to the most blurred camera.
+/// Cameras without an f-number are left without an isolation result.
+pub fn score_subject_isolation(results: &mut [CameraWithResult], scenario: &IsolationScenario) {
+    let blur = |camera: &CameraSystem| {
+        camera.f_number.map(|f_number| {
+            let blur_disc_mm =
+                calculate_background_blur(camera.focal_length_mm, f_number, scenario);
+            (blur_disc_mm, blur_disc_mm / camera.sensor_width_mm * 100.0)
+        })
+    };
+    let blurs: Vec<Option<(f64, f64)>> = results.iter().map(|r| blur(&r.camera)).collect();
+    let scores = relative_percent(
+        &blurs
+            .iter()
+            .map(|b| b.map_or(0.0, |(_, percent)| percent))
+            .collect::<Vec<_>>(),
+    );
+
+    for ((result, blur), score) in results.iter_mut().zip(blurs).zip(scores) {
+        result.isolation = blur.map(|(blur_disc_mm, blur_percent_of_width)| SubjectIsolation {
+            blur_disc_mm,
+            blur_percent_of_width,
+            score,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculations::compare_cameras;
+
+    #[test]
+    fn test_background_blur_at_infinity() {
+        let scenario = IsolationScenario {
+            subject_distance_mm: 2050.0,
+            background_distance_mm: None,
+        };
+        // 50² / (2 × 2000) = 0.625 mm
+        assert!((calculate_background_blur(50.0, 2.0, &scenario) - 0.625).abs() < 1e-12);
+
+        let near_background = IsolationScenario {
+            background_distance_mm: Some(4100.0),
+            ..scenario
+        };
+        assert!((calculate_background_blur(50.0, 2.0, &near_background) - 0.3125).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_equivalent_setups_score_the_same() {
+        // Full frame 50 mm f/2 and Micro 4/3 25 mm f/1 frame alike with the same pupil
+        let mut results = compare_cameras(
+            vec![
+                CameraSystem::new(36.0, 24.0, 6000, 4000, 50.0).with_f_number(2.0),
+                CameraSystem::new(18.0, 12.0, 6000, 4000, 25.0).with_f_number(1.0),
+                CameraSystem::new(18.0, 12.0, 6000, 4000, 25.0).with_f_number(2.8),
+                CameraSystem::new(18.0, 12.0, 6000, 4000, 25.0),
+            ],
+            3000.0,
+        );
+        let scenario = IsolationScenario {
+            subject_distance_mm: 3000.0,
+            background_distance_mm: None,
+        };
+        score_subject_isolation(&mut results, &scenario);
+
+        let score = |i: usize| results[i].isolation.as_ref().map(|iso| iso.score);
+        assert_eq!(score(0), Some(100.0));
+        // Only differs through the small (S - f) term
+        assert!((score(1).unwrap() - 100.0).abs() < 1.0);
+        assert!(score(2).unwrap() < 40.0);
+        assert_eq!(score(3), None);
+    }
+}
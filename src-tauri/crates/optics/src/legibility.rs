@@ -0,0 +1,50 @@
+use super::types::{LegibilityParams, LegibilityResult};
+
+/// Maximum read distance and minimum focal length for text of a given height
+///
+/// Used for labels, signage and gauges: the character height must span
+/// `pixels_per_character` vertical pixels.
+pub fn calculate_legibility(params: &LegibilityParams) -> LegibilityResult {
+    let camera = &params.camera;
+    let required_ppm = params.pixels_per_character / (params.character_height_mm / 1000.0);
+
+    // ppm = pixel_height × focal length / (sensor height × distance)
+    let pixels = camera.pixel_height as f64;
+    let max_distance_m = pixels * camera.focal_length_mm / (camera.sensor_height_mm * required_ppm);
+
+    LegibilityResult {
+        required_ppm,
+        max_distance_m,
+        min_focal_length_mm: params
+            .read_distance_m
+            .map(|distance_m| required_ppm * camera.sensor_height_mm * distance_m / pixels),
+        character_height_px: params.read_distance_m.map(|distance_m| {
+            params.character_height_mm / 1000.0 * pixels * camera.focal_length_mm
+                / (camera.sensor_height_mm * distance_m)
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::CameraSystem;
+
+    #[test]
+    fn test_read_distance_and_focal_length() {
+        let params = LegibilityParams {
+            camera: CameraSystem::new(5.6, 3.15, 1920, 1080, 8.0),
+            character_height_mm: 10.0,
+            pixels_per_character: 16.0,
+            read_distance_m: Some(2.0),
+        };
+        let result = calculate_legibility(&params);
+
+        // 16 px over 10 mm
+        assert_eq!(result.required_ppm, 1600.0);
+        assert!((result.max_distance_m - 1.714).abs() < 0.001);
+        assert!((result.min_focal_length_mm.unwrap() - 9.33).abs() < 0.01);
+        // Short of the 16 px needed, as 2 m is beyond the maximum distance
+        assert!((result.character_height_px.unwrap() - 13.71).abs() < 0.01);
+    }
+}
@@ -0,0 +1,86 @@
+//! Optical calculations for camera system design: FOV, DOF, DORI and related analyses.
+//!
+//! This crate has no Tauri dependency and can be used from any Rust project.
+
+pub mod aerial;
+pub mod aiming;
+pub mod alpr;
+pub mod astro;
+pub mod atmosphere;
+pub mod barcode;
+pub mod calculations;
+pub mod calibration;
+pub mod charts;
+mod constants;
+pub mod corridor;
+pub mod coverage;
+pub mod curves;
+pub mod derating;
+pub mod diff;
+pub mod equivalence;
+pub mod exposure;
+pub mod geometry;
+pub mod i18n;
+pub mod incidence;
+pub mod inspection;
+pub mod isolation;
+pub mod legibility;
+pub mod masking;
+pub mod microscopy;
+pub mod panorama;
+pub mod placement;
+pub mod presets;
+pub mod privacy;
+pub mod resolution;
+pub mod scoring;
+pub mod selection;
+pub mod sensitivity;
+pub mod spec;
+pub mod telescope;
+pub mod tilt;
+pub mod tolerance;
+pub mod types;
+pub mod uncertainty;
+pub mod vignetting;
+pub mod wdr;
+
+pub use aerial::*;
+pub use aiming::*;
+pub use alpr::*;
+pub use astro::*;
+pub use atmosphere::*;
+pub use barcode::*;
+pub use calculations::*;
+pub use calibration::*;
+pub use charts::*;
+pub use corridor::*;
+pub use coverage::*;
+pub use curves::*;
+pub use derating::*;
+pub use diff::*;
+pub use equivalence::*;
+pub use exposure::*;
+pub use geometry::*;
+pub use i18n::*;
+pub use incidence::*;
+pub use inspection::*;
+pub use isolation::*;
+pub use legibility::*;
+pub use masking::*;
+pub use microscopy::*;
+pub use panorama::*;
+pub use placement::*;
+pub use presets::*;
+pub use privacy::*;
+pub use resolution::*;
+pub use scoring::*;
+pub use selection::*;
+pub use sensitivity::*;
+pub use spec::*;
+pub use telescope::*;
+pub use tilt::*;
+pub use tolerance::*;
+pub use types::*;
+pub use uncertainty::*;
+pub use vignetting::*;
+pub use wdr::*;
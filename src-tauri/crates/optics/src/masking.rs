@@ -0,0 +1,185 @@
+use super::geometry::camera_axes;
+use super::types::{ExclusionZone, ImagePoint, PlacedCamera, PrivacyMask, PrivacyMaskSet};
+
+/// Closest depth in front of the camera that is projected, in metres
+const NEAR_PLANE_M: f64 = 0.01;
+
+/// Keep the part of a polygon where `inside` holds (Sutherland-Hodgman), with
+/// `cross` giving the point where an edge crosses the boundary
+fn clip<P: Copy>(
+    polygon: &[P],
+    inside: impl Fn(&P) -> bool,
+    cross: impl Fn(&P, &P) -> P,
+) -> Vec<P> {
+    let mut clipped = Vec::with_capacity(polygon.len() + 4);
+    for (i, current) in polygon.iter().enumerate() {
+        let previous = &polygon[(i + polygon.len() - 1) % polygon.len()];
+        match (inside(previous), inside(current)) {
+            (true, true) => clipped.push(*current),
+            (true, false) => clipped.push(cross(previous, current)),
+            (false, true) => {
+                clipped.push(cross(previous, current));
+                clipped.push(*current);
+            }
+            (false, false) => {}
+        }
+    }
+    clipped
+}
+
+/// Point where the edge a-b reaches `value` along the coordinate picked by `axis`
+fn crossing<const N: usize>(a: &[f64; N], b: &[f64; N], axis: usize, value: f64) -> [f64; N] {
+    let t = (value - a[axis]) / (b[axis] - a[axis]);
+    std::array::from_fn(|i| a[i] + t * (b[i] - a[i]))
+}
+
+/// Project an exclusion zone into the image of a placed camera
+///
+/// The zone is clipped to the space in front of the camera before projecting,
+/// then to the image rectangle. Returns an empty polygon when the zone is out of view.
+fn project_zone(placed: &PlacedCamera, zone: &ExclusionZone) -> Vec<ImagePoint> {
+    let camera = &placed.camera;
+    let pose = &placed.pose;
+    let [forward, right, up] = camera_axes(pose.pan_deg, pose.tilt_deg);
+    let dot = |a: [f64; 3], b: [f64; 3]| a[0] * b[0] + a[1] * b[1] + a[2] * b[2];
+
+    // Camera coordinates: right, up, depth
+    let in_camera: Vec<[f64; 3]> = zone
+        .polygon
+        .iter()
+        .map(|p| {
+            let offset = [p.x_m - pose.x_m, p.y_m - pose.y_m, p.z_m - pose.height_m];
+            [dot(offset, right), dot(offset, up), dot(offset, forward)]
+        })
+        .collect();
+    let in_front = clip(
+        &in_camera,
+        |p| p[2] >= NEAR_PLANE_M,
+        |a, b| crossing(a, b, 2, NEAR_PLANE_M),
+    );
+
+    // Pinhole projection with the focal length in pixels
+    let width = camera.pixel_width as f64;
+    let height = camera.pixel_height as f64;
+    let focal_px = camera.focal_length_mm * width / camera.sensor_width_mm;
+    let mut image: Vec<[f64; 2]> = in_front
+        .iter()
+        .map(|p| {
+            [
+                width / 2.0 + focal_px * p[0] / p[2],
+                height / 2.0 - focal_px * p[1] / p[2],
+            ]
+        })
+        .collect();
+
+    for (axis, min, max) in [(0, 0.0, width), (1, 0.0, height)] {
+        image = clip(&image, |p| p[axis] >= min, |a, b| crossing(a, b, axis, min));
+        image = clip(&image, |p| p[axis] <= max, |a, b| crossing(a, b, axis, max));
+    }
+
+    image
+        .into_iter()
+        .map(|[x_px, y_px]| ImagePoint { x_px, y_px })
+        .collect()
+}
+
+/// Privacy mask polygons in image pixels for exclusion zones given in site coordinates
+///
+/// Zones that are entirely out of view are left out.
+pub fn generate_privacy_masks(placed: &PlacedCamera, zones: &[ExclusionZone]) -> PrivacyMaskSet {
+    PrivacyMaskSet {
+        image_width: placed.camera.pixel_width,
+        image_height: placed.camera.pixel_height,
+        masks: zones
+            .iter()
+            .filter_map(|zone| {
+                let polygon = project_zone(placed, zone);
+                (polygon.len() >= 3).then(|| PrivacyMask {
+                    name: zone.name.clone(),
+                    polygon,
+                })
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{CameraPose, CameraSystem, WorldPoint};
+
+    fn placed() -> PlacedCamera {
+        PlacedCamera {
+            // 2742.9 px focal length
+            camera: CameraSystem::new(5.6, 3.15, 1920, 1080, 8.0),
+            pose: CameraPose {
+                x_m: 0.0,
+                y_m: 0.0,
+                height_m: 3.0,
+                pan_deg: 0.0,
+                tilt_deg: 0.0,
+            },
+        }
+    }
+
+    fn zone(name: &str, corners: &[(f64, f64, f64)]) -> ExclusionZone {
+        ExclusionZone {
+            name: name.to_string(),
+            polygon: corners
+                .iter()
+                .map(|&(x_m, y_m, z_m)| WorldPoint { x_m, y_m, z_m })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_window_projects_around_image_centre() {
+        // 2 × 1 m window, 10 m ahead, centred at camera height
+        let window = zone(
+            "Window",
+            &[
+                (-1.0, 10.0, 2.5),
+                (1.0, 10.0, 2.5),
+                (1.0, 10.0, 3.5),
+                (-1.0, 10.0, 3.5),
+            ],
+        );
+        let set = generate_privacy_masks(&placed(), &[window]);
+        let polygon = &set.masks[0].polygon;
+
+        assert_eq!((set.image_width, set.image_height), (1920, 1080));
+        let half_width = 8.0 * 1920.0 / 5.6 / 10.0;
+        assert!((polygon[0].x_px - (960.0 - half_width)).abs() < 1e-9);
+        // Bottom of the window is lower in the scene, so further down the image
+        assert!((polygon[0].y_px - (540.0 + half_width / 2.0)).abs() < 1e-9);
+        assert!((polygon[2].x_px - (960.0 + half_width)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_masks_clipped_to_view() {
+        // Footpath running from behind the camera to far ahead
+        let footpath = zone(
+            "Footpath",
+            &[
+                (-2.0, -5.0, 0.0),
+                (2.0, -5.0, 0.0),
+                (2.0, 50.0, 0.0),
+                (-2.0, 50.0, 0.0),
+            ],
+        );
+        let behind = zone(
+            "Behind",
+            &[(-1.0, -5.0, 0.0), (1.0, -5.0, 0.0), (0.0, -8.0, 0.0)],
+        );
+        let set = generate_privacy_masks(&placed(), &[footpath, behind]);
+
+        assert_eq!(set.masks.len(), 1);
+        let polygon = &set.masks[0].polygon;
+        assert!(polygon.len() >= 3);
+        assert!(polygon
+            .iter()
+            .all(|p| { (0.0..=1920.0).contains(&p.x_px) && (0.0..=1080.0).contains(&p.y_px) }));
+        // The near part of the path fills the bottom edge of the image
+        assert!(polygon.iter().any(|p| p.y_px == 1080.0));
+    }
+}
@@ -0,0 +1,87 @@
+use super::constants::DEFAULT_WAVELENGTH_NM;
+use super::types::{CameraSystem, MicroscopeObjective, MicroscopyAnalysis};
+
+/// Pair a camera with a microscope objective
+///
+/// # Formula
+/// Abbe limit: d = λ / (2 NA); Rayleigh limit: d = 0.61 λ / NA
+/// Pixel size on the sample: pixel_pitch / (objective × coupler magnification)
+///
+/// Nyquist sampling needs two pixels across the Rayleigh limit. Coarser pixels
+/// waste the objective's resolution; much finer ones only cut the field of view
+/// and the signal per pixel. The camera's own focal length is not used.
+///
+/// # Arguments
+/// * `camera` - The camera system specification
+/// * `objective` - Objective magnification, NA and camera coupler
+/// * `wavelength_nm` - Wavelength in nanometers (default: 550 nm)
+pub fn analyze_microscope(
+    camera: &CameraSystem,
+    objective: &MicroscopeObjective,
+    wavelength_nm: Option<f64>,
+) -> MicroscopyAnalysis {
+    let wavelength_nm = wavelength_nm.unwrap_or(DEFAULT_WAVELENGTH_NM);
+    let wavelength_um = wavelength_nm / 1000.0;
+    let total_magnification =
+        objective.magnification * objective.coupler_magnification.unwrap_or(1.0);
+
+    let abbe_resolution_um = wavelength_um / (2.0 * objective.numerical_aperture);
+    let rayleigh_resolution_um = 0.61 * wavelength_um / objective.numerical_aperture;
+
+    // Use the coarser pixel direction so non-square pixels aren't overstated
+    let (h_pitch, v_pitch) = camera.pixel_pitch_um();
+    let sample_pixel_size_um = h_pitch.max(v_pitch) / total_magnification;
+
+    MicroscopyAnalysis {
+        total_magnification,
+        wavelength_nm,
+        abbe_resolution_um,
+        rayleigh_resolution_um,
+        field_width_mm: camera.sensor_width_mm / total_magnification,
+        field_height_mm: camera.sensor_height_mm / total_magnification,
+        sample_pixel_size_um,
+        max_pixel_pitch_um: rayleigh_resolution_um / 2.0 * total_magnification,
+        nyquist_satisfied: sample_pixel_size_um <= rayleigh_resolution_um / 2.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 6.5 µm pixels, typical of scientific CMOS
+    fn camera() -> CameraSystem {
+        CameraSystem::new(13.312, 13.312, 2048, 2048, 50.0)
+    }
+
+    #[test]
+    fn test_resolution_and_field() {
+        let objective = MicroscopeObjective {
+            magnification: 40.0,
+            numerical_aperture: 0.95,
+            coupler_magnification: None,
+        };
+        let analysis = analyze_microscope(&camera(), &objective, Some(500.0));
+
+        assert!((analysis.abbe_resolution_um - 0.263).abs() < 1e-3);
+        assert!((analysis.rayleigh_resolution_um - 0.321).abs() < 1e-3);
+        assert!((analysis.field_width_mm - 0.3328).abs() < 1e-9);
+        assert!((analysis.sample_pixel_size_um - 0.1625).abs() < 1e-9);
+        // 0.1625 µm is just over half of 0.321 µm
+        assert!(!analysis.nyquist_satisfied);
+        assert!((analysis.max_pixel_pitch_um - 6.42).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_coupler_magnification_restores_nyquist() {
+        let objective = MicroscopeObjective {
+            magnification: 40.0,
+            numerical_aperture: 0.95,
+            coupler_magnification: Some(1.5),
+        };
+        let analysis = analyze_microscope(&camera(), &objective, Some(500.0));
+
+        assert!((analysis.total_magnification - 60.0).abs() < 1e-9);
+        assert!(analysis.nyquist_satisfied);
+    }
+}
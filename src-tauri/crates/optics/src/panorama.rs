@@ -0,0 +1,99 @@
+use super::types::{LensPupils, NoParallaxParams, NoParallaxPoint};
+
+/// Distance of the entrance pupil in front of the sensor, in millimeters
+///
+/// The entrance pupil sits f × (1 - 1/P) in front of the front principal plane and
+/// the sensor at the image distance behind the rear one, so focusing closer moves
+/// the pupil forward as the lens extends.
+///
+/// # Arguments
+/// * `focal_length_mm` - Lens focal length
+/// * `pupils` - Pupil magnification and principal plane separation
+/// * `focus_distance_mm` - Focus distance from the entrance pupil, `None` for infinity
+pub fn entrance_pupil_from_sensor_mm(
+    focal_length_mm: f64,
+    pupils: &LensPupils,
+    focus_distance_mm: Option<f64>,
+) -> f64 {
+    let f = focal_length_mm;
+    let pupil_in_front_mm = f * (1.0 - 1.0 / pupils.pupil_magnification.unwrap_or(1.0));
+    let image_mm = focus_distance_mm.map_or(f, |distance_mm| {
+        let object_mm = distance_mm + pupil_in_front_mm;
+        f * object_mm / (object_mm - f)
+    });
+    image_mm + pupils.principal_plane_separation_mm.unwrap_or(0.0) + pupil_in_front_mm
+}
+
+/// Locate the no-parallax point and the parallax from rotating about the sensor instead
+///
+/// Rotating about the entrance pupil keeps near and far objects aligned between
+/// panorama frames. Rotating by θ about the sensor plane, a distance e behind the
+/// pupil, shifts the pupil sideways by e × sin θ, which displaces a subject at
+/// distance d against the background at infinity by atan(e × sin θ / d).
+pub fn calculate_no_parallax_point(params: &NoParallaxParams) -> NoParallaxPoint {
+    let camera = &params.camera;
+    let pupil_mm = entrance_pupil_from_sensor_mm(
+        camera.focal_length_mm,
+        &params.pupils,
+        params.focus_distance_mm,
+    );
+
+    let shift_mm = pupil_mm * params.rotation_deg.to_radians().sin();
+    let parallax_rad = (shift_mm / params.subject_distance_mm).atan();
+    let (h_pitch_um, _) = camera.pixel_pitch_um();
+
+    NoParallaxPoint {
+        entrance_pupil_from_sensor_mm: pupil_mm,
+        entrance_pupil_at_infinity_mm: entrance_pupil_from_sensor_mm(
+            camera.focal_length_mm,
+            &params.pupils,
+            None,
+        ),
+        parallax_deg: parallax_rad.to_degrees(),
+        parallax_px: parallax_rad * camera.focal_length_mm / (h_pitch_um / 1000.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::CameraSystem;
+
+    #[test]
+    fn test_entrance_pupil_position() {
+        let thin = LensPupils {
+            pupil_magnification: None,
+            principal_plane_separation_mm: None,
+        };
+        assert!((entrance_pupil_from_sensor_mm(50.0, &thin, None) - 50.0).abs() < 1e-9);
+        // Focused at 1 m the lens extends to 52.6 mm
+        assert!((entrance_pupil_from_sensor_mm(50.0, &thin, Some(1000.0)) - 52.632).abs() < 1e-3);
+
+        // Retrofocus wide-angle: pupil well forward of the focal length
+        let retrofocus = LensPupils {
+            pupil_magnification: Some(3.0),
+            principal_plane_separation_mm: Some(20.0),
+        };
+        let offset = entrance_pupil_from_sensor_mm(15.0, &retrofocus, None);
+        assert!((offset - 45.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_parallax_when_rotating_about_sensor() {
+        let point = calculate_no_parallax_point(&NoParallaxParams {
+            camera: CameraSystem::new(36.0, 24.0, 6000, 4000, 50.0),
+            pupils: LensPupils {
+                pupil_magnification: None,
+                principal_plane_separation_mm: None,
+            },
+            focus_distance_mm: None,
+            subject_distance_mm: 2000.0,
+            rotation_deg: 30.0,
+        });
+
+        // 50 mm × sin 30° = 25 mm shift against a subject at 2 m
+        assert!((point.parallax_deg - (25.0f64 / 2000.0).atan().to_degrees()).abs() < 1e-9);
+        // 0.0125 rad × 50 mm / 6 µm
+        assert!((point.parallax_px - 104.16).abs() < 0.05);
+    }
+}
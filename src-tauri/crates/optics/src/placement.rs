@@ -0,0 +1,397 @@
+use rayon::prelude::*;
+use std::ops::Range;
+
+use super::constants::PLACEMENT_PAN_STEP_DEG;
+use super::coverage::{
+    evaluate_zone_coverage_with_progress, wall_segments, zone_samples, DensityModel,
+};
+use super::geometry::{bearing_deg, line_of_sight, plan_distance};
+use super::types::{
+    CameraPose, CameraSystem, CandidateCamera, MountPoint, PlacedCamera, PlacementRequest,
+    PlacementResult, PlanPoint,
+};
+
+/// Focal lengths tried for a candidate: fixed, or wide/middle/tele of a varifocal lens
+fn focal_options(candidate: &CandidateCamera) -> Vec<f64> {
+    let wide = candidate.camera.focal_length_mm;
+    match candidate.focal_length_max_mm {
+        Some(tele) if tele > wide => vec![wide, (wide * tele).sqrt(), tele],
+        _ => vec![wide],
+    }
+}
+
+/// Ranges of sorted bearings in [0, 360) that lie within a window of bearings,
+/// which may wrap past north on either side
+fn window_ranges(bearings_deg: &[f64], from_deg: f64, to_deg: f64) -> [Range<usize>; 3] {
+    let range = |from: f64, to: f64| {
+        bearings_deg.partition_point(|&b| b < from)..bearings_deg.partition_point(|&b| b <= to)
+    };
+    [
+        range(from_deg, to_deg),
+        range(from_deg + 360.0, f64::INFINITY),
+        range(f64::NEG_INFINITY, to_deg - 360.0),
+    ]
+}
+
+/// Set of sample point indices, one bit per point
+///
+/// Counting the points an installation adds is then a popcount over a few words
+/// rather than a walk over every point it covers.
+#[derive(Clone)]
+struct SampleSet(Vec<u64>);
+
+impl SampleSet {
+    fn new(len: usize) -> Self {
+        Self(vec![0; len.div_ceil(64)])
+    }
+
+    fn insert(&mut self, i: usize) {
+        self.0[i / 64] |= 1 << (i % 64);
+    }
+
+    fn len(&self) -> usize {
+        self.0.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// Number of points in this set that are not in `other`
+    fn count_outside(&self, other: &SampleSet) -> usize {
+        self.0
+            .iter()
+            .zip(&other.0)
+            .map(|(word, other)| (word & !other).count_ones() as usize)
+            .sum()
+    }
+
+    fn union_with(&mut self, other: &SampleSet) {
+        for (word, other) in self.0.iter_mut().zip(&other.0) {
+            *word |= other;
+        }
+    }
+
+    /// Indices in the set, in ascending order
+    fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.0.iter().enumerate().flat_map(|(w, &word)| {
+            (0..64)
+                .filter(move |bit| word & (1 << bit) != 0)
+                .map(move |bit| w * 64 + bit)
+        })
+    }
+}
+
+/// A possible installation and the sample points it covers
+struct Installation {
+    mount: usize,
+    placed: PlacedCamera,
+    covered: SampleSet,
+}
+
+/// The sample points a mount can see, with their bearing and slant distance
+///
+/// Kept as parallel arrays: the inner loop over lenses and pans then only
+/// compares precomputed numbers, without trigonometry or wall tests.
+#[derive(Default)]
+struct MountView {
+    samples: Vec<usize>,
+    bearings_deg: Vec<f64>,
+    slants_m: Vec<f64>,
+    required_ppm: Vec<f64>,
+}
+
+impl MountView {
+    fn new(
+        mount: &MountPoint,
+        samples: &[(PlanPoint, f64)],
+        walls: &[(PlanPoint, PlanPoint)],
+    ) -> Self {
+        let origin = PlanPoint {
+            x_m: mount.x_m,
+            y_m: mount.y_m,
+        };
+        let mut view = Self::default();
+        for (i, &(point, required_ppm)) in samples.iter().enumerate() {
+            let distance_m = plan_distance(origin, point);
+            // Points directly below the camera are never in view
+            if distance_m < 1e-9 || !line_of_sight(origin, point, walls) {
+                continue;
+            }
+            view.samples.push(i);
+            view.bearings_deg.push(bearing_deg(origin, point));
+            view.slants_m.push(distance_m.hypot(mount.height_m));
+            view.required_ppm.push(required_ppm);
+        }
+        view
+    }
+}
+
+/// Choose camera positions, orientations and lenses that cover the required zones
+///
+/// Every candidate camera/lens is tried at every mount, panned in 15° steps.
+/// Installations are then picked greedily, each time taking the one that brings the
+/// most still-uncovered zone area up to its required px/m, until everything is
+/// covered, no installation helps any more, or `max_cameras` is reached.
+/// Each mount holds at most one camera, and points hidden behind the room walls
+/// or obstacles do not count as covered.
+///
+/// The tilt of each chosen camera aims at the mean distance of the area it covers.
+pub fn optimize_placement(request: &PlacementRequest) -> PlacementResult {
+    optimize_placement_with_progress(request, &mut |_, _| true)
+        .expect("placement without cancellation always completes")
+}
+
+/// [`optimize_placement`] reporting progress to `progress`
+///
+/// Progress counts the mount/camera combinations tried, then one final step for
+/// evaluating the chosen cameras.
+///
+/// # Returns
+/// `None` if `progress` returned `false` to cancel
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(mounts = request.mounts.len(), cameras = request.cameras.len()))
+)]
+pub fn optimize_placement_with_progress(
+    request: &PlacementRequest,
+    progress: &mut dyn FnMut(usize, usize) -> bool,
+) -> Option<PlacementResult> {
+    let scene = &request.scene;
+    let samples: Vec<(PlanPoint, f64)> = scene
+        .zones
+        .iter()
+        .zip(zone_samples(scene, request.grid_spacing_m))
+        .flat_map(|(zone, points)| {
+            let required_ppm = zone.requirement.px_per_m();
+            points.into_iter().map(move |p| (p, required_ppm))
+        })
+        .collect();
+
+    let walls = wall_segments(scene);
+    let pans = (360.0 / PLACEMENT_PAN_STEP_DEG) as usize;
+    let total = request.mounts.len() * request.cameras.len() + 1;
+    let mut options = Vec::new();
+    for (mount_index, mount) in request.mounts.iter().enumerate() {
+        // Line of sight, bearing and slant distance depend only on the mount,
+        // not on the lens or pan
+        let view = MountView::new(mount, &samples, &walls);
+
+        for (candidate_index, candidate) in request.cameras.iter().enumerate() {
+            for focal_length_mm in focal_options(candidate) {
+                let placed_at = |pan_deg: f64| PlacedCamera {
+                    camera: CameraSystem {
+                        focal_length_mm,
+                        ..candidate.camera.clone()
+                    },
+                    pose: CameraPose {
+                        x_m: mount.x_m,
+                        y_m: mount.y_m,
+                        height_m: mount.height_m,
+                        pan_deg,
+                        tilt_deg: 0.0,
+                    },
+                };
+                // The density a lens reaches does not depend on the pan, so the
+                // points it resolves well enough are found once for all pans and
+                // sorted by bearing; each pan then covers one or two runs of them
+                let model = DensityModel::new(&placed_at(0.0));
+                let mut resolved: Vec<(f64, usize)> = (0..view.samples.len())
+                    .filter(|&j| model.density_on_axis(view.slants_m[j]) >= view.required_ppm[j])
+                    .map(|j| (view.bearings_deg[j], view.samples[j]))
+                    .collect();
+                if resolved.is_empty() {
+                    continue;
+                }
+                resolved.sort_by(|a, b| a.0.total_cmp(&b.0));
+                let bearings_deg: Vec<f64> = resolved.iter().map(|&(b, _)| b).collect();
+
+                let installations: Vec<Installation> = (0..pans)
+                    .into_par_iter()
+                    .filter_map(|step| {
+                        let placed = placed_at(step as f64 * PLACEMENT_PAN_STEP_DEG);
+                        let (from_deg, to_deg) = DensityModel::new(&placed).view_window();
+                        let mut covered = SampleSet::new(samples.len());
+                        let mut any = false;
+                        for range in window_ranges(&bearings_deg, from_deg, to_deg) {
+                            any |= !range.is_empty();
+                            for &(_, i) in &resolved[range] {
+                                covered.insert(i);
+                            }
+                        }
+                        any.then_some(Installation {
+                            mount: mount_index,
+                            placed,
+                            covered,
+                        })
+                    })
+                    .collect();
+                options.extend(installations);
+            }
+            if !progress(
+                mount_index * request.cameras.len() + candidate_index + 1,
+                total,
+            ) {
+                return None;
+            }
+        }
+    }
+
+    let max_cameras = request.max_cameras.map_or(usize::MAX, |n| n as usize);
+    let mut is_covered = SampleSet::new(samples.len());
+    let mut mount_used = vec![false; request.mounts.len()];
+    let mut cameras = Vec::new();
+
+    while cameras.len() < max_cameras {
+        let gain = |option: &Installation| option.covered.count_outside(&is_covered);
+        let best = options
+            .iter()
+            .filter(|option| !mount_used[option.mount])
+            .max_by_key(|option| gain(option));
+        let Some(best) = best.filter(|option| gain(option) > 0) else {
+            break;
+        };
+
+        let origin = PlanPoint {
+            x_m: best.placed.pose.x_m,
+            y_m: best.placed.pose.y_m,
+        };
+        let mean_distance_m = best
+            .covered
+            .iter()
+            .map(|i| plan_distance(origin, samples[i].0))
+            .sum::<f64>()
+            / best.covered.len() as f64;
+
+        let mut placed = best.placed.clone();
+        placed.pose.tilt_deg = -placed.pose.height_m.atan2(mean_distance_m).to_degrees();
+        is_covered.union_with(&best.covered);
+        mount_used[best.mount] = true;
+        cameras.push(placed);
+    }
+
+    // Evaluating the chosen cameras is the last step, done once all its samples are
+    let zones = evaluate_zone_coverage_with_progress(
+        scene,
+        &cameras,
+        request.grid_spacing_m,
+        &mut |done, of| progress(total - usize::from(done < of), total),
+    )?;
+    Some(PlacementResult { zones, cameras })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{CoverageZone, DoriLevel, Obstacle, Scene, ZoneRequirement};
+
+    fn rectangle(x0: f64, y0: f64, x1: f64, y1: f64) -> Vec<PlanPoint> {
+        vec![
+            PlanPoint { x_m: x0, y_m: y0 },
+            PlanPoint { x_m: x1, y_m: y0 },
+            PlanPoint { x_m: x1, y_m: y1 },
+            PlanPoint { x_m: x0, y_m: y1 },
+        ]
+    }
+
+    fn corner_mounts() -> Vec<MountPoint> {
+        [(0.0, 0.0), (20.0, 0.0), (20.0, 10.0), (0.0, 10.0)]
+            .into_iter()
+            .map(|(x_m, y_m)| MountPoint {
+                x_m,
+                y_m,
+                height_m: 3.0,
+            })
+            .collect()
+    }
+
+    fn request(max_cameras: Option<u32>) -> PlacementRequest {
+        PlacementRequest {
+            scene: Scene {
+                room: rectangle(0.0, 0.0, 20.0, 10.0),
+                zones: vec![
+                    CoverageZone {
+                        name: "Hall".to_string(),
+                        polygon: rectangle(0.0, 0.0, 20.0, 10.0),
+                        requirement: ZoneRequirement::Dori(DoriLevel::Observation),
+                    },
+                    CoverageZone {
+                        name: "Entrance".to_string(),
+                        polygon: rectangle(8.0, 0.0, 12.0, 3.0),
+                        requirement: ZoneRequirement::PixelDensity(125.0),
+                    },
+                ],
+                obstacles: Vec::new(),
+            },
+            mounts: corner_mounts(),
+            cameras: vec![CandidateCamera {
+                camera: CameraSystem::new(5.6, 3.15, 2560, 1440, 2.8),
+                focal_length_max_mm: Some(12.0),
+                price: None,
+            }],
+            max_cameras,
+            grid_spacing_m: Some(1.0),
+        }
+    }
+
+    #[test]
+    fn test_window_ranges_wrap_past_north() {
+        let bearings = [5.0, 40.0, 180.0, 350.0];
+        let covered = |from: f64, to: f64| -> Vec<f64> {
+            window_ranges(&bearings, from, to)
+                .into_iter()
+                .flat_map(|range| bearings[range].to_vec())
+                .collect()
+        };
+
+        assert_eq!(covered(30.0, 190.0), vec![40.0, 180.0]);
+        assert_eq!(covered(-20.0, 20.0), vec![5.0, 350.0]);
+        assert_eq!(covered(340.0, 380.0), vec![350.0, 5.0]);
+    }
+
+    #[test]
+    fn test_placement_covers_room() {
+        let result = optimize_placement(&request(None));
+
+        assert!(!result.cameras.is_empty() && result.cameras.len() <= 4);
+        for zone in &result.zones {
+            assert!(zone.covered_percent > 95.0, "{:?}", zone);
+        }
+        // Cameras look down towards the area they cover
+        assert!(result.cameras.iter().all(|c| c.pose.tilt_deg < 0.0));
+    }
+
+    #[test]
+    fn test_placement_progress_and_cancel() {
+        let mut last = (0, 0);
+        let result = optimize_placement_with_progress(&request(None), &mut |done, total| {
+            assert!(done >= last.0 && done <= total);
+            last = (done, total);
+            true
+        });
+        assert!(result.is_some());
+        assert_eq!(last.0, last.1);
+
+        let cancelled = optimize_placement_with_progress(&request(None), &mut |_, _| false);
+        assert!(cancelled.is_none());
+    }
+
+    #[test]
+    fn test_placement_respects_camera_limit() {
+        let result = optimize_placement(&request(Some(1)));
+
+        assert_eq!(result.cameras.len(), 1);
+        assert!(result.zones[0].covered_percent < 100.0);
+    }
+
+    #[test]
+    fn test_placement_works_around_obstacles() {
+        let mut blocked = request(None);
+        // Racking splitting the hall into two aisles, open at both ends
+        blocked.scene.obstacles.push(Obstacle {
+            name: Some("Racking".to_string()),
+            polygon: rectangle(2.0, 4.5, 18.0, 5.5),
+        });
+        let result = optimize_placement(&blocked);
+
+        assert!(result.cameras.len() >= 2);
+        // The racking itself is not visible floor area, everything else should be
+        assert!(result.zones[0].covered_percent > 85.0, "{:?}", result.zones);
+    }
+}
@@ -0,0 +1,62 @@
+use super::types::CameraSystem;
+
+/// Main, ultrawide and tele modules of a typical smartphone, for checking whether
+/// a phone on a mount could stand in for a dedicated camera
+pub fn smartphone_camera_presets() -> Vec<CameraSystem> {
+    use super::constants::SMARTPHONE_CAMERA_MODULES;
+
+    SMARTPHONE_CAMERA_MODULES
+        .iter()
+        .map(
+            |&(
+                name,
+                sensor_width_mm,
+                sensor_height_mm,
+                pixel_width,
+                pixel_height,
+                focal,
+                f_number,
+            )| {
+                CameraSystem::new(
+                    sensor_width_mm,
+                    sensor_height_mm,
+                    pixel_width,
+                    pixel_height,
+                    focal,
+                )
+                .with_name(name)
+                .with_f_number(f_number)
+            },
+        )
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::equivalence::{calculate_format_equivalence, classify_angle_of_view};
+    use crate::types::AngleOfView;
+
+    #[test]
+    fn test_smartphone_presets_match_their_equivalents() {
+        let presets = smartphone_camera_presets();
+        let classes: Vec<AngleOfView> = presets.iter().map(classify_angle_of_view).collect();
+        assert_eq!(
+            classes,
+            [
+                AngleOfView::Wide,
+                AngleOfView::UltraWide,
+                AngleOfView::ShortTele,
+                AngleOfView::ShortTele
+            ]
+        );
+
+        // The equivalent focal length in each name is the full-frame crop of the module
+        for camera in &presets {
+            let equivalent = calculate_format_equivalence(camera, 2.0, 36.0, 24.0);
+            let name = camera.name.as_deref().unwrap();
+            let quoted = format!("{:.0}mm eq.", equivalent.equivalent_focal_length_mm);
+            assert!(name.ends_with(&quoted), "{} is {}", name, quoted);
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use super::calculations::calculate_dori_distances;
+use super::constants::FOOTPRINT_RAYS;
+use super::coverage::camera_origin;
+use super::types::{DoriLevel, PlacedCamera, PlanPoint, PrivacyBoundary};
+
+/// Distance and site plan boundary beyond which a camera cannot reach a DORI level
+///
+/// The distance is the line-of-sight range at which the pixel density falls to
+/// the level's requirement. It is used as the plan radius of the boundary, which
+/// ignores the mounting height and any walls and so never understates the area
+/// in which the level can be reached.
+pub fn privacy_boundary(placed: &PlacedCamera, level: DoriLevel) -> PrivacyBoundary {
+    let camera = &placed.camera;
+    let distance_m = calculate_dori_distances(camera).distance_m(level);
+
+    let origin = camera_origin(placed);
+    let half_fov_deg = (camera.sensor_width_mm / (2.0 * camera.focal_length_mm))
+        .atan()
+        .to_degrees();
+    let mut polygon = vec![origin];
+    for i in 0..=FOOTPRINT_RAYS {
+        let bearing = (placed.pose.pan_deg - half_fov_deg
+            + 2.0 * half_fov_deg * i as f64 / FOOTPRINT_RAYS as f64)
+            .to_radians();
+        polygon.push(PlanPoint {
+            x_m: origin.x_m + distance_m * bearing.sin(),
+            y_m: origin.y_m + distance_m * bearing.cos(),
+        });
+    }
+
+    PrivacyBoundary {
+        level,
+        distance_m,
+        polygon,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coverage::pixel_density_at;
+    use crate::geometry::plan_distance;
+    use crate::types::{CameraPose, CameraSystem};
+
+    #[test]
+    fn test_boundary_at_identification_distance() {
+        let placed = PlacedCamera {
+            camera: CameraSystem::new(5.6, 3.15, 1920, 1080, 8.0),
+            pose: CameraPose {
+                x_m: 2.0,
+                y_m: 1.0,
+                height_m: 4.0,
+                pan_deg: 90.0,
+                tilt_deg: -10.0,
+            },
+        };
+        let boundary = privacy_boundary(&placed, DoriLevel::Identification);
+
+        // 1920 px × 8 mm / (5.6 mm × 250 px/m)
+        assert!((boundary.distance_m - 10.97).abs() < 0.01);
+        assert_eq!(boundary.polygon.len(), FOOTPRINT_RAYS + 2);
+        let origin = boundary.polygon[0];
+        assert!(boundary.polygon[1..]
+            .iter()
+            .all(|&p| (plan_distance(origin, p) - boundary.distance_m).abs() < 1e-9));
+
+        // Just beyond the boundary the camera falls short of identification
+        let beyond = PlanPoint {
+            x_m: origin.x_m + boundary.distance_m + 0.1,
+            y_m: origin.y_m,
+        };
+        assert!(pixel_density_at(&placed, beyond).unwrap() < 250.0);
+    }
+}
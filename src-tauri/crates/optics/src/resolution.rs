@@ -0,0 +1,202 @@
+use super::constants::DEFAULT_WAVELENGTH_NM;
+use super::types::{
+    CameraSystem, DiffractionLimitResult, LensResolutionRequirement, ResolutionLimit,
+    SamplingAnalysis,
+};
+
+/// Compare the pixel IFOV of a camera against the diffraction limit of its lens
+///
+/// # Formula
+/// Rayleigh criterion: θ = 1.22 × λ / D, where D = focal_length / f_number
+/// Pixel IFOV: θ_px = pixel_pitch / focal_length
+///
+/// When the Rayleigh angle exceeds the pixel IFOV, two points the sensor could
+/// separate are blurred together by diffraction and the system is optics-limited.
+///
+/// # Arguments
+/// * `camera` - The camera system specification
+/// * `f_number` - Lens aperture (f-number)
+/// * `wavelength_nm` - Wavelength in nanometers (default: 550 nm)
+///
+/// # Returns
+/// Pixel IFOV, Rayleigh limit and which one limits the system
+pub fn calculate_diffraction_limit(
+    camera: &CameraSystem,
+    f_number: f64,
+    wavelength_nm: Option<f64>,
+) -> DiffractionLimitResult {
+    let wavelength_nm = wavelength_nm.unwrap_or(DEFAULT_WAVELENGTH_NM);
+    let wavelength_mm = wavelength_nm * 1e-6;
+
+    let aperture_diameter_mm = camera.focal_length_mm / f_number;
+    let rayleigh_limit_urad = 1.22 * wavelength_mm / aperture_diameter_mm * 1e6;
+    let airy_disk_diameter_um = 2.44 * wavelength_nm / 1000.0 * f_number;
+
+    // Use the coarser pixel direction so non-square pixels aren't overstated
+    let (h_pitch, v_pitch) = camera.pixel_pitch_um();
+    let pixel_ifov_urad = h_pitch.max(v_pitch) / camera.focal_length_mm * 1000.0;
+
+    let rayleigh_to_ifov_ratio = rayleigh_limit_urad / pixel_ifov_urad;
+    let limited_by = if rayleigh_to_ifov_ratio > 1.0 {
+        ResolutionLimit::Optics
+    } else {
+        ResolutionLimit::Sensor
+    };
+
+    DiffractionLimitResult {
+        f_number,
+        wavelength_nm,
+        aperture_diameter_mm,
+        pixel_ifov_urad,
+        rayleigh_limit_urad,
+        airy_disk_diameter_um,
+        rayleigh_to_ifov_ratio,
+        limited_by,
+    }
+}
+
+/// Calculate the sensor Nyquist frequency in line pairs per millimeter
+///
+/// One line pair needs two pixels: f_N = 1000 / (2 × pixel_pitch_µm)
+pub fn nyquist_frequency_lp_mm(pixel_pitch_um: f64) -> f64 {
+    1000.0 / (2.0 * pixel_pitch_um)
+}
+
+/// Calculate the lens resolving power required to make use of a sensor
+///
+/// A lens matches a sensor when it still delivers 50% contrast (MTF50) at half the
+/// Nyquist frequency and about 20% contrast at Nyquist itself. Below that, fine detail
+/// the pixels could record is already washed out by the lens.
+///
+/// # Arguments
+/// * `pixel_pitch_um` - Sensor pixel pitch in micrometers
+pub fn calculate_required_lens_resolution(pixel_pitch_um: f64) -> LensResolutionRequirement {
+    let nyquist_lp_mm = nyquist_frequency_lp_mm(pixel_pitch_um);
+
+    LensResolutionRequirement {
+        pixel_pitch_um,
+        nyquist_lp_mm,
+        min_mtf_at_nyquist: 0.2,
+        half_nyquist_lp_mm: nyquist_lp_mm / 2.0,
+        min_mtf_at_half_nyquist: 0.5,
+    }
+}
+
+/// Compare the sensor Nyquist frequency against the lens resolving power
+///
+/// # Formula
+/// Nyquist frequency (lp/mm) = 1000 / (2 × pixel_pitch_µm)
+///
+/// A lens resolving beyond Nyquist produces aliasing (moiré) unless an optical
+/// low-pass filter is fitted. A lens resolving less than half of Nyquist wastes
+/// sensor resolution.
+///
+/// # Arguments
+/// * `camera` - The camera system specification
+/// * `lens_resolution_lp_mm` - Lens resolving power or MTF cutoff in lp/mm
+pub fn calculate_sampling(camera: &CameraSystem, lens_resolution_lp_mm: f64) -> SamplingAnalysis {
+    let (pixel_pitch_um, _) = camera.pixel_pitch_um();
+    let nyquist_lp_mm = nyquist_frequency_lp_mm(pixel_pitch_um);
+    let lens_to_nyquist_ratio = lens_resolution_lp_mm / nyquist_lp_mm;
+
+    SamplingAnalysis {
+        pixel_pitch_um,
+        nyquist_lp_mm,
+        lens_resolution_lp_mm,
+        lens_to_nyquist_ratio,
+        aliasing_likely: lens_to_nyquist_ratio > 1.0,
+        lens_limited: lens_to_nyquist_ratio < 0.5,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ValidationCode;
+
+    #[test]
+    fn test_large_pixels_are_sensor_limited() {
+        // 6 µm pixels, 50mm f/2.8: Rayleigh = 1.22 × 550nm / 17.86mm ≈ 37.6 µrad vs 120 µrad IFOV
+        let camera = CameraSystem::new(36.0, 24.0, 6000, 4000, 50.0);
+        let result = calculate_diffraction_limit(&camera, 2.8, None);
+
+        assert!((result.aperture_diameter_mm - 50.0 / 2.8).abs() < 1e-9);
+        assert!((result.rayleigh_limit_urad - 37.58).abs() < 0.01);
+        assert!((result.pixel_ifov_urad - 120.0).abs() < 1e-9);
+        assert_eq!(result.limited_by, ResolutionLimit::Sensor);
+    }
+
+    #[test]
+    fn test_small_pixels_stopped_down_are_optics_limited() {
+        // 1 µm pixels at f/8: Airy disk ≈ 10.7 µm covers many pixels
+        let camera = CameraSystem::new(4.0, 3.0, 4000, 3000, 4.0);
+        let result = calculate_diffraction_limit(&camera, 8.0, None);
+
+        assert!((result.airy_disk_diameter_um - 10.736).abs() < 0.001);
+        assert!(result.rayleigh_to_ifov_ratio > 1.0);
+        assert_eq!(result.limited_by, ResolutionLimit::Optics);
+    }
+
+    #[test]
+    fn test_wavelength_scales_limit() {
+        let camera = CameraSystem::new(6.4, 4.8, 1920, 1080, 8.0);
+        let green = calculate_diffraction_limit(&camera, 2.0, None);
+        let nir = calculate_diffraction_limit(&camera, 2.0, Some(850.0));
+
+        assert!((nir.rayleigh_limit_urad / green.rayleigh_limit_urad - 850.0 / 550.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_nyquist_frequency() {
+        // 5 µm pixels: Nyquist = 1000 / 10 = 100 lp/mm
+        let camera = CameraSystem::new(10.0, 7.5, 2000, 1500, 25.0);
+        let analysis = calculate_sampling(&camera, 80.0);
+
+        assert!((analysis.nyquist_lp_mm - 100.0).abs() < 1e-9);
+        assert!(!analysis.aliasing_likely);
+        assert!(!analysis.lens_limited);
+        assert!(analysis.validate().is_empty());
+    }
+
+    #[test]
+    fn test_sharp_lens_aliases() {
+        let camera = CameraSystem::new(10.0, 7.5, 2000, 1500, 25.0);
+        let analysis = calculate_sampling(&camera, 150.0);
+
+        assert!(analysis.aliasing_likely);
+        assert_eq!(analysis.validate().len(), 1);
+    }
+
+    #[test]
+    fn test_soft_lens_is_limiting() {
+        // 2 µm pixels (250 lp/mm Nyquist) behind a 100 lp/mm lens
+        let camera = CameraSystem::new(8.0, 6.0, 4000, 3000, 12.0);
+        let analysis = calculate_sampling(&camera, 100.0);
+
+        assert!(analysis.lens_limited);
+        assert!(!analysis.aliasing_likely);
+    }
+
+    #[test]
+    fn test_sampling_included_in_camera_validation() {
+        let camera = CameraSystem::new(8.0, 6.0, 4000, 3000, 12.0).with_lens_resolution(100.0);
+        let warnings = camera.validate();
+
+        let limited = warnings
+            .iter()
+            .find(|w| w.code == ValidationCode::LensLimited)
+            .unwrap();
+        assert_eq!(limited.fields[0].field, "lens_resolution_lp_mm");
+        assert_eq!(limited.fields[0].value, 100.0);
+    }
+
+    #[test]
+    fn test_required_lens_resolution() {
+        // 2.9 µm pixels: Nyquist ≈ 172 lp/mm, MTF50 needed at ≈ 86 lp/mm
+        let requirement = calculate_required_lens_resolution(2.9);
+
+        assert!((requirement.nyquist_lp_mm - 172.41).abs() < 0.01);
+        assert!((requirement.half_nyquist_lp_mm - 86.21).abs() < 0.01);
+        assert!((requirement.min_mtf_at_half_nyquist - 0.5).abs() < 1e-9);
+    }
+}
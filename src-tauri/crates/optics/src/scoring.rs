@@ -0,0 +1,151 @@
+use super::calculations::calculate_dori_distances;
+use super::types::{CameraWithResult, ScoringWeights, SuitabilityScore};
+
+/// Each value as a percentage of the largest one
+pub(crate) fn relative_percent(values: &[f64]) -> Vec<f64> {
+    let best = values.iter().copied().fold(0.0, f64::max);
+    values
+        .iter()
+        .map(|&value| {
+            if best > 0.0 {
+                value / best * 100.0
+            } else {
+                0.0
+            }
+        })
+        .collect()
+}
+
+/// Score and rank compared cameras by a weighting profile
+///
+/// Resolution (total pixel count), horizontal FOV, low-light sensitivity and DORI
+/// reach are each scored 0-100 relative to the best camera in the comparison, then
+/// combined as a weighted mean. Cameras without a low-light score get 0 for that
+/// criterion. Fills in `suitability` for every entry; when all weights are zero the
+/// results are left unscored.
+pub fn score_cameras(results: &mut [CameraWithResult], weights: &ScoringWeights) {
+    let total_weight = weights.resolution + weights.fov + weights.low_light + weights.dori_reach;
+    if total_weight <= 0.0 {
+        return;
+    }
+
+    let criterion = |value: fn(&CameraWithResult) -> f64| {
+        relative_percent(&results.iter().map(value).collect::<Vec<_>>())
+    };
+    let resolution = criterion(|r| r.camera.pixel_width as f64 * r.camera.pixel_height as f64);
+    let fov = criterion(|r| r.result.horizontal_fov_deg);
+    let low_light = criterion(|r| r.low_light.as_ref().map_or(0.0, |l| l.score));
+    // All DORI levels scale with the same pixel density, so one distance ranks them all
+    let dori_reach = criterion(|r| calculate_dori_distances(&r.camera).detection_m);
+
+    let scores: Vec<f64> = (0..results.len())
+        .map(|i| {
+            (weights.resolution * resolution[i]
+                + weights.fov * fov[i]
+                + weights.low_light * low_light[i]
+                + weights.dori_reach * dori_reach[i])
+                / total_weight
+        })
+        .collect();
+
+    let mut order: Vec<usize> = (0..results.len()).collect();
+    order.sort_by(|&a, &b| scores[b].total_cmp(&scores[a]));
+
+    for (rank, i) in order.into_iter().enumerate() {
+        results[i].suitability = Some(SuitabilityScore {
+            score: scores[i],
+            rank: rank as u32 + 1,
+            resolution: resolution[i],
+            fov: fov[i],
+            low_light: low_light[i],
+            dori_reach: dori_reach[i],
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculations::compare_cameras;
+    use crate::types::CameraSystem;
+
+    fn compared() -> Vec<CameraWithResult> {
+        compare_cameras(
+            vec![
+                // Wide 4K, slow lens
+                CameraSystem::new(5.6, 3.15, 3840, 2160, 2.8).with_f_number(2.0),
+                // Tele 1080p, fast lens
+                CameraSystem::new(5.6, 3.15, 1920, 1080, 12.0).with_f_number(1.0),
+            ],
+            10000.0,
+        )
+    }
+
+    fn score(result: &CameraWithResult) -> &SuitabilityScore {
+        result.suitability.as_ref().unwrap()
+    }
+
+    #[test]
+    fn test_weights_change_ranking() {
+        let mut results = compared();
+        score_cameras(
+            &mut results,
+            &ScoringWeights {
+                resolution: 1.0,
+                fov: 1.0,
+                low_light: 0.0,
+                dori_reach: 0.0,
+            },
+        );
+        assert_eq!(score(&results[0]).rank, 1);
+        assert_eq!(score(&results[0]).score, 100.0);
+
+        score_cameras(
+            &mut results,
+            &ScoringWeights {
+                resolution: 0.0,
+                fov: 0.0,
+                low_light: 1.0,
+                dori_reach: 1.0,
+            },
+        );
+        assert_eq!(score(&results[1]).rank, 1);
+        assert_eq!(score(&results[1]).score, 100.0);
+    }
+
+    #[test]
+    fn test_criteria_relative_to_best() {
+        let mut results = compared();
+        score_cameras(&mut results, &ScoringWeights::default());
+
+        let wide = score(&results[0]);
+        let tele = score(&results[1]);
+        assert_eq!(wide.resolution, 100.0);
+        assert!((tele.resolution - 25.0).abs() < 1e-9);
+        // f/2.0 on quarter-size pixels gathers 1/16 of the light of f/1.0
+        assert!((wide.low_light - 6.25).abs() < 1e-9);
+        // 12 mm with half the pixels reaches 12 / (2.8 × 2) ≈ 2.1× as far
+        assert_eq!(tele.dori_reach, 100.0);
+        assert!((wide.dori_reach - 2.8 * 2.0 / 12.0 * 100.0).abs() < 1e-9);
+        assert!(
+            (wide.score - (wide.resolution + wide.fov + wide.low_light + wide.dori_reach) / 4.0)
+                .abs()
+                < 1e-9
+        );
+    }
+
+    #[test]
+    fn test_zero_weights_leave_results_unscored() {
+        let mut results = compared();
+        score_cameras(
+            &mut results,
+            &ScoringWeights {
+                resolution: 0.0,
+                fov: 0.0,
+                low_light: 0.0,
+                dori_reach: 0.0,
+            },
+        );
+        assert!(results.iter().all(|r| r.suitability.is_none()));
+    }
+}
@@ -0,0 +1,396 @@
+use rayon::prelude::*;
+
+use super::calculations::{calculate_dori_distances, calculate_fov};
+use super::constants::{
+    DETECTION_PX_PER_M, IDENTIFICATION_PX_PER_M, OBSERVATION_PX_PER_M, RECOGNITION_PX_PER_M,
+};
+use super::types::{
+    CameraSystem, CandidateCamera, CandidateLens, CoverageDesign, CoverageScene, ParameterRange,
+    RankedSelection, SelectionRequirements, SelectionResult,
+};
+
+/// Focal length bounds (min, max) in millimeters that satisfy the requirements for a sensor
+///
+/// DORI targets and the FOV limit set a minimum focal length:
+/// f ≥ distance × sensor_width × required_px_per_m / pixel_width
+/// f ≥ sensor_width / (2 × tan(max_fov / 2))
+///
+/// The near frame width sets a maximum: f ≤ sensor_width × near_distance / frame_width
+fn focal_bounds(camera: &CameraSystem, requirements: &SelectionRequirements) -> (f64, f64) {
+    let sensor_width = camera.sensor_width_mm;
+    let targets = [
+        (requirements.dori.detection_m, DETECTION_PX_PER_M),
+        (requirements.dori.observation_m, OBSERVATION_PX_PER_M),
+        (requirements.dori.recognition_m, RECOGNITION_PX_PER_M),
+        (requirements.dori.identification_m, IDENTIFICATION_PX_PER_M),
+    ];
+
+    let mut min_focal = targets
+        .iter()
+        .filter_map(|(distance_m, px_per_m)| {
+            distance_m.map(|d| d * sensor_width * px_per_m / camera.pixel_width as f64)
+        })
+        .fold(0.0, f64::max);
+
+    if let Some(max_fov_deg) = requirements.max_horizontal_fov_deg {
+        let focal = sensor_width / (2.0 * (max_fov_deg.to_radians() / 2.0).tan());
+        min_focal = min_focal.max(focal);
+    }
+
+    let max_focal = match (requirements.min_frame_width_m, requirements.near_distance_m) {
+        (Some(width_m), Some(near_m)) => sensor_width * near_m / width_m,
+        _ => f64::INFINITY,
+    };
+
+    (min_focal, max_focal)
+}
+
+/// Requirement bounds plus the part of a lens range that satisfies them
+struct Feasible {
+    bounds: (f64, f64),
+    range: ParameterRange,
+}
+
+/// Intersect a camera's focal length bounds with a lens range
+fn feasible_range(
+    camera: &CameraSystem,
+    lens_range: (f64, f64),
+    requirements: &SelectionRequirements,
+) -> Option<Feasible> {
+    let bounds = focal_bounds(camera, requirements);
+    let low = lens_range.0.max(bounds.0);
+    let high = lens_range.1.min(bounds.1);
+    (low <= high).then_some(Feasible {
+        bounds,
+        range: ParameterRange {
+            min: low,
+            max: high,
+            recommended: None,
+        },
+    })
+}
+
+/// Focal length with the most headroom within the feasible range
+fn balanced_focal(feasible: &Feasible) -> f64 {
+    let (min_focal, max_focal) = feasible.bounds;
+    let range = &feasible.range;
+    // Balance the margins on both sides; with a one-sided requirement, move away from it
+    match (min_focal > 0.0, max_focal.is_finite()) {
+        (true, true) => (min_focal * max_focal).sqrt().clamp(range.min, range.max),
+        (true, false) => range.max,
+        _ => range.min,
+    }
+}
+
+/// Describe a camera/lens combination set to a specific focal length
+fn selection_at(
+    camera: &CameraSystem,
+    lens: Option<&CandidateLens>,
+    focal_length_mm: f64,
+    feasible: &Feasible,
+) -> RankedSelection {
+    let (min_focal, max_focal) = feasible.bounds;
+    let mut margins = Vec::new();
+    if min_focal > 0.0 {
+        margins.push(focal_length_mm / min_focal - 1.0);
+    }
+    if max_focal.is_finite() {
+        margins.push(max_focal / focal_length_mm - 1.0);
+    }
+    let headroom_percent = margins
+        .into_iter()
+        .reduce(f64::min)
+        .map(|margin| margin * 100.0);
+
+    let mut camera = CameraSystem {
+        focal_length_mm,
+        ..camera.clone()
+    };
+    if let Some(f_number) = lens.and_then(|lens| lens.f_number) {
+        camera.f_number = Some(f_number);
+        camera.t_stop = None;
+    }
+    let fov = calculate_fov(&camera, 1000.0);
+
+    RankedSelection {
+        dori: calculate_dori_distances(&camera),
+        horizontal_fov_deg: fov.horizontal_fov_deg,
+        camera,
+        lens: lens.cloned(),
+        focal_range: feasible.range.clone(),
+        headroom_percent,
+    }
+}
+
+/// Camera/lens pairs to evaluate, with the focal length range of each
+///
+/// Each camera is paired with every lens. When no lenses are given,
+/// cameras are evaluated with their own (fixed or varifocal) lens instead.
+fn combinations<'a>(
+    cameras: &'a [CandidateCamera],
+    lenses: &'a [CandidateLens],
+) -> Vec<(&'a CandidateCamera, Option<&'a CandidateLens>, (f64, f64))> {
+    let mut pairs = Vec::new();
+    for candidate in cameras {
+        if lenses.is_empty() {
+            let wide = candidate.camera.focal_length_mm;
+            let tele = candidate.focal_length_max_mm.unwrap_or(wide);
+            pairs.push((candidate, None, (wide, tele)));
+        }
+        for lens in lenses {
+            let tele = lens.focal_length_max_mm.unwrap_or(lens.focal_length_mm);
+            pairs.push((candidate, Some(lens), (lens.focal_length_mm, tele)));
+        }
+    }
+    pairs
+}
+
+/// Find camera/lens combinations that meet a requirement set and rank them
+///
+/// Each camera is paired with every lens in `lenses`. When no lenses are given,
+/// cameras are evaluated with their own (fixed or varifocal) lens instead.
+/// Within a varifocal range the focal length with the most headroom is chosen.
+///
+/// # Returns
+/// Feasible combinations ordered by headroom over the tightest requirement
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(cameras = cameras.len(), lenses = lenses.len()))
+)]
+pub fn select_cameras(
+    requirements: &SelectionRequirements,
+    cameras: &[CandidateCamera],
+    lenses: &[CandidateLens],
+) -> SelectionResult {
+    let pairs = combinations(cameras, lenses);
+    let mut selections: Vec<RankedSelection> = pairs
+        .par_iter()
+        .filter_map(|(candidate, lens, lens_range)| {
+            let feasible = feasible_range(&candidate.camera, *lens_range, requirements)?;
+            Some(selection_at(
+                &candidate.camera,
+                *lens,
+                balanced_focal(&feasible),
+                &feasible,
+            ))
+        })
+        .collect();
+
+    selections.sort_by(|a, b| {
+        let headroom = |s: &RankedSelection| s.headroom_percent.unwrap_or(f64::NEG_INFINITY);
+        headroom(b).total_cmp(&headroom(a))
+    });
+
+    SelectionResult {
+        selections,
+        evaluated: pairs.len(),
+    }
+}
+
+/// Whether design `a` is at least as good as `b` on every objective and better on one
+fn dominates(a: &CoverageDesign, b: &CoverageDesign) -> bool {
+    let no_worse = a.total_cost <= b.total_cost
+        && a.camera_count <= b.camera_count
+        && a.horizontal_ppm >= b.horizontal_ppm;
+    let better = a.total_cost < b.total_cost
+        || a.camera_count < b.camera_count
+        || a.horizontal_ppm > b.horizontal_ppm;
+    no_worse && better
+}
+
+/// Find the cost / camera count / pixel density Pareto front for covering a scene
+///
+/// Every feasible camera/lens combination is tried at the wide end, the balanced
+/// setting and the tele end of its feasible focal range. Enough cameras are used to
+/// span `scene.width_m` at `scene.distance_m`; a missing price counts as zero.
+///
+/// # Arguments
+/// * `max_designs` - Upper limit on designs returned; the cheapest and the
+///   highest-quality designs are always kept, with the rest spread in between
+///
+/// # Returns
+/// Non-dominated designs ordered from cheapest to most expensive
+pub fn optimize_designs(
+    requirements: &SelectionRequirements,
+    scene: &CoverageScene,
+    cameras: &[CandidateCamera],
+    lenses: &[CandidateLens],
+    max_designs: usize,
+) -> Vec<CoverageDesign> {
+    let mut designs = Vec::new();
+
+    for (candidate, lens, lens_range) in combinations(cameras, lenses) {
+        let Some(feasible) = feasible_range(&candidate.camera, lens_range, requirements) else {
+            continue;
+        };
+        let unit_cost = candidate.price.unwrap_or(0.0) + lens.and_then(|l| l.price).unwrap_or(0.0);
+
+        let mut focal_lengths = vec![
+            feasible.range.min,
+            balanced_focal(&feasible),
+            feasible.range.max,
+        ];
+        focal_lengths.dedup();
+
+        for focal_length_mm in focal_lengths {
+            let selection = selection_at(&candidate.camera, lens, focal_length_mm, &feasible);
+            let frame_width_m =
+                selection.camera.sensor_width_mm * scene.distance_m / focal_length_mm;
+            let camera_count = (scene.width_m / frame_width_m).ceil().max(1.0) as u32;
+
+            designs.push(CoverageDesign {
+                horizontal_ppm: selection.camera.pixel_width as f64 / frame_width_m,
+                camera_count,
+                unit_cost,
+                total_cost: unit_cost * camera_count as f64,
+                selection,
+            });
+        }
+    }
+
+    let mut front: Vec<CoverageDesign> = designs
+        .iter()
+        .filter(|d| !designs.iter().any(|other| dominates(other, d)))
+        .cloned()
+        .collect();
+    front.sort_by(|a, b| {
+        a.total_cost
+            .total_cmp(&b.total_cost)
+            .then(b.horizontal_ppm.total_cmp(&a.horizontal_ppm))
+    });
+    // Identical designs (e.g. the same camera listed twice) would otherwise all survive
+    front.dedup_by(|a, b| {
+        a.total_cost == b.total_cost
+            && a.camera_count == b.camera_count
+            && a.horizontal_ppm == b.horizontal_ppm
+    });
+
+    if front.len() > max_designs && max_designs > 1 {
+        let last = front.len() - 1;
+        let keep: Vec<usize> = (0..max_designs)
+            .map(|i| (i * last + (max_designs - 1) / 2) / (max_designs - 1))
+            .collect();
+        front = front
+            .into_iter()
+            .enumerate()
+            .filter(|(i, _)| keep.contains(i))
+            .map(|(_, design)| design)
+            .collect();
+    } else {
+        front.truncate(max_designs);
+    }
+
+    front
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::DoriTargets;
+
+    fn requirements() -> SelectionRequirements {
+        SelectionRequirements {
+            dori: DoriTargets {
+                detection_m: None,
+                observation_m: None,
+                recognition_m: Some(15.0),
+                identification_m: None,
+            },
+            max_horizontal_fov_deg: None,
+            min_frame_width_m: Some(4.0),
+            near_distance_m: Some(5.0),
+        }
+    }
+
+    fn candidate(pixel_width: u32, focal: f64, tele: Option<f64>) -> CandidateCamera {
+        CandidateCamera {
+            camera: CameraSystem::new(5.6, 3.15, pixel_width, 1080, focal),
+            focal_length_max_mm: tele,
+            price: None,
+        }
+    }
+
+    #[test]
+    fn test_selection_filters_and_ranks() {
+        let cameras = [
+            // Too wide to recognize at 15 m
+            candidate(1920, 2.8, None),
+            // Fixed lens meets both requirements
+            candidate(1920, 6.0, None),
+            // Varifocal 4K camera can sit in the middle of the feasible range
+            candidate(3840, 2.8, Some(12.0)),
+        ];
+        let result = select_cameras(&requirements(), &cameras, &[]);
+
+        assert_eq!(result.evaluated, 3);
+        assert_eq!(result.selections.len(), 2);
+        let best = &result.selections[0];
+        assert_eq!(best.camera.pixel_width, 3840);
+        assert!(best.dori.recognition_m >= 15.0);
+        assert!(5.6 * 5.0 / best.camera.focal_length_mm >= 4.0);
+        assert!(best.headroom_percent.unwrap() > result.selections[1].headroom_percent.unwrap());
+    }
+
+    #[test]
+    fn test_selection_pairs_cameras_with_lenses() {
+        let lenses = [
+            CandidateLens {
+                name: Some("Tele".to_string()),
+                focal_length_mm: 25.0,
+                focal_length_max_mm: None,
+                f_number: Some(1.4),
+                price: None,
+            },
+            CandidateLens {
+                name: Some("Standard".to_string()),
+                focal_length_mm: 6.0,
+                focal_length_max_mm: None,
+                f_number: None,
+                price: None,
+            },
+        ];
+        let result = select_cameras(&requirements(), &[candidate(1920, 4.0, None)], &lenses);
+
+        // 25 mm is too narrow for the near frame width
+        assert_eq!(result.evaluated, 2);
+        assert_eq!(result.selections.len(), 1);
+        let lens = result.selections[0].lens.as_ref().unwrap();
+        assert_eq!(lens.name.as_deref(), Some("Standard"));
+    }
+
+    #[test]
+    fn test_pareto_front_trades_cost_for_quality() {
+        let priced = |pixel_width, focal, tele, price| CandidateCamera {
+            price: Some(price),
+            ..candidate(pixel_width, focal, tele)
+        };
+        let cameras = [
+            priced(1920, 6.0, None, 200.0),
+            priced(3840, 2.8, Some(12.0), 500.0),
+            // Same resolution as the first but dearer: always dominated
+            priced(1920, 6.0, None, 300.0),
+        ];
+        let scene = CoverageScene {
+            width_m: 40.0,
+            distance_m: 15.0,
+        };
+        let designs = optimize_designs(&requirements(), &scene, &cameras, &[], 3);
+
+        assert!(designs.len() >= 2 && designs.len() <= 3);
+        assert!(designs
+            .iter()
+            .all(|d| d.selection.camera.pixel_width == 3840 || d.unit_cost == 200.0));
+        // Cheapest first; spending more must buy fewer cameras or more pixels
+        for pair in designs.windows(2) {
+            assert!(pair[0].total_cost <= pair[1].total_cost);
+            assert!(
+                pair[1].camera_count < pair[0].camera_count
+                    || pair[1].horizontal_ppm > pair[0].horizontal_ppm
+            );
+        }
+        for design in &designs {
+            let frame_m = 5.6 * 15.0 / design.selection.camera.focal_length_mm;
+            assert!(design.camera_count as f64 * frame_m >= 40.0);
+        }
+    }
+}
@@ -0,0 +1,320 @@
+use super::constants::{DEFAULT_SCENE_REFLECTANCE, PHOTONS_PER_LUX_SECOND_UM2};
+use super::types::{CameraSystem, CameraWithResult, LowLightScore, SnrEstimate, SnrParams};
+
+/// Calculate a comparative low-light score for a camera system
+///
+/// The light collected by a single pixel is proportional to its area and
+/// inversely proportional to the square of the f-number. A T-stop, when the lens
+/// specifies one, is used instead as it includes the transmission losses. Quantum efficiency
+/// scales how much of that light is converted to signal:
+/// score = pixel_area × QE / N²
+///
+/// The score is relative to a 1 µm² pixel behind an f/1.0 lens with 100% QE,
+/// so it is only meaningful when comparing cameras against each other.
+///
+/// # Returns
+/// `None` if the camera has neither an f-number nor a T-stop specified
+pub fn calculate_low_light_score(camera: &CameraSystem) -> Option<LowLightScore> {
+    let f_number = camera.light_stop()?;
+    let quantum_efficiency = camera.quantum_efficiency.unwrap_or(1.0);
+
+    let (h_pitch, v_pitch) = camera.pixel_pitch_um();
+    let pixel_area_um2 = h_pitch * v_pitch;
+    let score = pixel_area_um2 * quantum_efficiency / (f_number * f_number);
+
+    Some(LowLightScore {
+        pixel_area_um2,
+        f_number,
+        quantum_efficiency,
+        score,
+        relative_percent: None,
+        rank: None,
+    })
+}
+
+/// Estimate the per-pixel signal-to-noise ratio at a given exposure
+///
+/// A first-order photon-noise model. A Lambertian scene of illuminance E and
+/// reflectance R gives an image-plane illuminance of E × R / (4 × N²), using the
+/// T-stop when specified. The photon count at 555 nm is converted to electrons by
+/// the quantum efficiency and clipped at the full well. Dark current D adds its own
+/// shot noise over the exposure:
+/// SNR = S / √(S + D + read_noise²)
+///
+/// Quantum efficiency defaults to 1.0, read noise and dark current to 0 when not specified.
+///
+/// # Returns
+/// `None` if the camera has neither an f-number nor a T-stop specified
+pub fn estimate_snr(camera: &CameraSystem, params: &SnrParams) -> Option<SnrEstimate> {
+    let stop = camera.light_stop()?;
+    let reflectance = params.reflectance.unwrap_or(DEFAULT_SCENE_REFLECTANCE);
+    let sensor_illuminance_lux = params.illuminance_lux * reflectance / (4.0 * stop * stop);
+
+    let (h_pitch, v_pitch) = camera.pixel_pitch_um();
+    let photons =
+        sensor_illuminance_lux * PHOTONS_PER_LUX_SECOND_UM2 * h_pitch * v_pitch * params.shutter_s;
+    let electrons = photons * camera.quantum_efficiency.unwrap_or(1.0);
+    let signal_e = camera
+        .full_well_e
+        .map_or(electrons, |full_well| electrons.min(full_well));
+
+    let read_noise_e = camera.read_noise_e.unwrap_or(0.0);
+    let dark_e = camera.dark_current_e_s.unwrap_or(0.0) * params.shutter_s;
+    let shot_noise_e = signal_e.sqrt();
+    let noise_e = (signal_e + dark_e + read_noise_e * read_noise_e).sqrt();
+    let snr = if noise_e > 0.0 {
+        signal_e / noise_e
+    } else {
+        0.0
+    };
+
+    Some(SnrEstimate {
+        sensor_illuminance_lux,
+        signal_e,
+        shot_noise_e,
+        dark_e,
+        noise_e,
+        snr,
+        snr_db: (snr > 0.0).then(|| 20.0 * snr.log10()),
+        saturated: camera
+            .full_well_e
+            .is_some_and(|full_well| electrons >= full_well),
+    })
+}
+
+/// Rank the low-light scores of compared cameras against each other
+///
+/// Fills in `relative_percent` (100 for the most sensitive camera) and `rank`
+/// for every entry that has a low-light score. Entries without one are left untouched.
+pub fn rank_low_light_scores(results: &mut [CameraWithResult]) {
+    rank_scores(
+        results
+            .iter_mut()
+            .filter_map(|r| r.low_light.as_mut())
+            .collect(),
+    );
+}
+
+/// Rank the night mode low-light scores of compared day/night cameras against each other
+pub fn rank_night_low_light_scores(results: &mut [CameraWithResult]) {
+    rank_scores(
+        results
+            .iter_mut()
+            .filter_map(|r| r.night.as_mut().and_then(|n| n.low_light.as_mut()))
+            .collect(),
+    );
+}
+
+fn rank_scores(mut scores: Vec<&mut LowLightScore>) {
+    let best = scores.iter().map(|s| s.score).fold(0.0, f64::max);
+    scores.sort_by(|a, b| b.score.total_cmp(&a.score));
+
+    for (rank, low_light) in scores.into_iter().enumerate() {
+        low_light.relative_percent = if best > 0.0 {
+            Some(low_light.score / best * 100.0)
+        } else {
+            None
+        };
+        low_light.rank = Some(rank as u32 + 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculations::compare_cameras;
+    use crate::types::{NightMode, ValidationCode};
+
+    #[test]
+    fn test_low_light_score_requires_f_number() {
+        let camera = CameraSystem::new(6.4, 4.8, 1920, 1080, 4.0);
+        assert!(calculate_low_light_score(&camera).is_none());
+    }
+
+    #[test]
+    fn test_low_light_score_aperture_scaling() {
+        // One stop faster aperture collects twice the light
+        let slow = CameraSystem::new(5.6, 3.15, 1920, 1080, 4.0).with_f_number(2.0);
+        let fast = CameraSystem::new(5.6, 3.15, 1920, 1080, 4.0).with_f_number(2.0 / 2f64.sqrt());
+
+        let slow_score = calculate_low_light_score(&slow).unwrap().score;
+        let fast_score = calculate_low_light_score(&fast).unwrap().score;
+
+        assert!((fast_score / slow_score - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_low_light_score_prefers_t_stop() {
+        // A T1.3 cine prime at f/1.2 scores by its transmitted light
+        let camera = CameraSystem::new(5.6, 3.15, 1920, 1080, 4.0)
+            .with_f_number(1.2)
+            .with_t_stop(1.3);
+        let score = calculate_low_light_score(&camera).unwrap();
+
+        assert_eq!(score.f_number, 1.3);
+    }
+
+    #[test]
+    fn test_low_light_score_pixel_area_and_qe() {
+        // 2.9 µm square pixels, f/1.0, QE 0.8
+        let camera = CameraSystem::new(5.568, 3.132, 1920, 1080, 4.0)
+            .with_f_number(1.0)
+            .with_quantum_efficiency(0.8);
+        let score = calculate_low_light_score(&camera).unwrap();
+
+        assert!((score.pixel_area_um2 - 8.41).abs() < 0.01);
+        assert!((score.score - 8.41 * 0.8).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_snr_shot_noise_limited() {
+        // 3 µm pixels at f/2: 100 lux × 0.18 / 16 = 1.125 lux on the sensor,
+        // ≈ 41,400 photons in 1 s, ≈ 20,700 e⁻ at 50% QE
+        let camera = CameraSystem::new(5.76, 3.24, 1920, 1080, 4.0)
+            .with_f_number(2.0)
+            .with_quantum_efficiency(0.5);
+        let params = SnrParams {
+            illuminance_lux: 100.0,
+            shutter_s: 1.0,
+            reflectance: None,
+        };
+        let snr = estimate_snr(&camera, &params).unwrap();
+
+        assert!((snr.sensor_illuminance_lux - 1.125).abs() < 1e-9);
+        assert!((snr.signal_e - 20_706.0).abs() < 1.0);
+        // Without read noise, SNR = √S
+        assert!((snr.snr - snr.signal_e.sqrt()).abs() < 1e-9);
+        assert!(!snr.saturated);
+    }
+
+    #[test]
+    fn test_snr_read_noise_and_full_well() {
+        let camera = CameraSystem::new(5.76, 3.24, 1920, 1080, 4.0)
+            .with_f_number(2.0)
+            .with_read_noise(3.0)
+            .with_full_well(10_000.0);
+        let dim = SnrParams {
+            illuminance_lux: 0.1,
+            shutter_s: 1.0 / 30.0,
+            reflectance: None,
+        };
+        let bright = SnrParams {
+            illuminance_lux: 1000.0,
+            ..dim.clone()
+        };
+
+        // ≈ 1.4 e⁻ of signal is buried in 3 e⁻ of read noise
+        let dim = estimate_snr(&camera, &dim).unwrap();
+        assert!(dim.snr < 1.0);
+        assert!((dim.noise_e - (dim.signal_e + 9.0).sqrt()).abs() < 1e-9);
+
+        let bright = estimate_snr(&camera, &bright).unwrap();
+        assert!(bright.saturated);
+        assert_eq!(bright.signal_e, 10_000.0);
+    }
+
+    #[test]
+    fn test_snr_without_signal() {
+        let camera = CameraSystem::new(5.76, 3.24, 1920, 1080, 4.0)
+            .with_f_number(2.0)
+            .with_read_noise(3.0);
+        let params = SnrParams {
+            illuminance_lux: 0.0,
+            shutter_s: 1.0 / 30.0,
+            reflectance: None,
+        };
+        let snr = estimate_snr(&camera, &params).unwrap();
+
+        assert_eq!(snr.snr, 0.0);
+        assert!(snr.snr_db.is_none());
+        // -∞ dB would not survive a JSON round trip
+        let json = serde_json::to_string(&snr).unwrap();
+        assert!(json.contains("\"snr_db\":null"));
+    }
+
+    #[test]
+    fn test_snr_dark_current_long_exposure() {
+        let camera = CameraSystem::new(5.76, 3.24, 1920, 1080, 4.0)
+            .with_f_number(2.0)
+            .with_dark_current(5.0);
+        let params = SnrParams {
+            illuminance_lux: 0.01,
+            shutter_s: 10.0,
+            reflectance: None,
+        };
+        let snr = estimate_snr(&camera, &params).unwrap();
+
+        assert!((snr.dark_e - 50.0).abs() < 1e-9);
+        assert!((snr.noise_e - (snr.signal_e + 50.0).sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_sensor_characteristics_validation() {
+        let camera = CameraSystem::new(5.76, 3.24, 1920, 1080, 4.0)
+            .with_quantum_efficiency(1.2)
+            .with_read_noise(2.0)
+            .with_full_well(10_000.0)
+            .with_dark_current(-1.0);
+        let codes: Vec<ValidationCode> = camera.validate().iter().map(|w| w.code).collect();
+
+        assert_eq!(
+            codes,
+            [
+                ValidationCode::QuantumEfficiencyOutOfRange,
+                ValidationCode::NegativeSensorValue
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compare_night_mode() {
+        // 2×2 binning at night quadruples the pixel area and halves the DORI reach
+        let day_night = CameraSystem::new(5.6, 3.15, 3840, 2160, 4.0)
+            .with_f_number(1.6)
+            .with_quantum_efficiency(0.5)
+            .with_night_mode(NightMode {
+                binning: 2,
+                quantum_efficiency: Some(0.6),
+            });
+        let results = compare_cameras(
+            vec![
+                day_night,
+                CameraSystem::new(5.6, 3.15, 1920, 1080, 4.0).with_f_number(1.6),
+            ],
+            10000.0,
+        );
+
+        let night = results[0].night.as_ref().unwrap();
+        assert_eq!(night.camera.pixel_width, 1920);
+        assert!(night.camera.night_mode.is_none());
+        let day_identification = results[0].result.dori.as_ref().unwrap().identification_m;
+        let night_identification = night.result.dori.as_ref().unwrap().identification_m;
+        assert!((night_identification * 2.0 - day_identification).abs() < 1e-9);
+
+        let day_score = results[0].low_light.as_ref().unwrap().score;
+        let night_low_light = night.low_light.as_ref().unwrap();
+        assert!((night_low_light.score / day_score - 4.0 * 0.6 / 0.5).abs() < 1e-9);
+        assert_eq!(night_low_light.rank, Some(1));
+        assert!(results[1].night.is_none());
+    }
+
+    #[test]
+    fn test_compare_ranks_low_light() {
+        let cameras = vec![
+            CameraSystem::new(5.6, 3.15, 3840, 2160, 4.0).with_f_number(1.6),
+            CameraSystem::new(5.6, 3.15, 1920, 1080, 4.0).with_f_number(1.6),
+            CameraSystem::new(5.6, 3.15, 1920, 1080, 4.0),
+        ];
+
+        let results = compare_cameras(cameras, 10000.0);
+
+        let low_res = results[1].low_light.as_ref().unwrap();
+        let high_res = results[0].low_light.as_ref().unwrap();
+        assert_eq!(low_res.rank, Some(1));
+        assert_eq!(high_res.rank, Some(2));
+        assert!((low_res.relative_percent.unwrap() - 100.0).abs() < 1e-9);
+        // 4x the pixels on the same sensor means a quarter of the light per pixel
+        assert!((high_res.relative_percent.unwrap() - 25.0).abs() < 1e-6);
+        assert!(results[2].low_light.is_none());
+    }
+}
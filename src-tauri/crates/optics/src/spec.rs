@@ -0,0 +1,258 @@
+//! Camera spec-sheet notation: optical formats, resolutions and lens ranges as
+//! written in datasheets, and the lenient camera input built on them
+
+use serde::{de, Deserialize, Deserializer};
+
+use super::constants::STANDARD_SENSOR_FORMATS;
+use super::types::{CameraSystem, NightMode};
+
+/// Parse the leading number of a spec value, ignoring units such as "mm" or "f/"
+pub fn parse_spec_number(value: &str) -> Option<f64> {
+    let value = value.trim().trim_start_matches(['f', 'F', '/']).trim();
+    let end = value
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(value.len());
+    value[..end].parse().ok()
+}
+
+/// Parse a resolution such as "1920x1080", "1920 × 1080" or "1920*1080"
+pub fn parse_resolution(value: &str) -> Option<(u32, u32)> {
+    let (w, h) = value.split_once(['x', 'X', '×', '*'])?;
+    Some((w.trim().parse().ok()?, parse_spec_number(h)? as u32))
+}
+
+/// Parse a lens range such as "2.8-12mm", "2.8 ~ 12 mm" or a fixed "4mm"
+///
+/// # Returns
+/// The shortest focal length, and the longest for a varifocal lens
+pub fn parse_focal_range(value: &str) -> Option<(f64, Option<f64>)> {
+    match value.split_once(['-', '~', '–']) {
+        Some((min, max)) => Some((parse_spec_number(min)?, Some(parse_spec_number(max)?))),
+        None => Some((parse_spec_number(value)?, None)),
+    }
+}
+
+/// Sensor dimensions from an optical format and the image aspect ratio (height / width)
+///
+/// Inch formats such as `1/2.8"` are nominal tube sizes: the actual diagonal is
+/// about 16 mm per inch for 1/2" and larger, and about 18 mm per inch for smaller
+/// sensors. Named formats such as "APS-C" or "Full frame" take their standard size.
+pub fn sensor_from_optical_format(format: &str, aspect: f64) -> Option<(f64, f64)> {
+    let format = format.trim();
+    if let Some(&(_, width, height)) = STANDARD_SENSOR_FORMATS
+        .iter()
+        .find(|(name, _, _)| !name.ends_with('"') && name.eq_ignore_ascii_case(format))
+    {
+        return Some((width, height));
+    }
+
+    let inches_text = format
+        .trim_end_matches(['"', '”', '\''])
+        .trim_end_matches("inch")
+        .trim();
+    let inches = match inches_text.split_once('/') {
+        Some((num, den)) => parse_spec_number(num)? / parse_spec_number(den)?,
+        None => parse_spec_number(inches_text)?,
+    };
+    let diagonal_mm = if inches >= 0.5 { 16.0 } else { 18.0 } * inches;
+    let width = diagonal_mm / (1.0 + aspect * aspect).sqrt();
+    Some((width, width * aspect))
+}
+
+/// A camera as it may be written: explicit numeric fields, or spec-sheet
+/// notation for the sensor format, resolution and lens
+#[derive(Deserialize)]
+struct CameraSystemInput {
+    sensor_width_mm: Option<f64>,
+    sensor_height_mm: Option<f64>,
+    /// Optical format such as `1/2.8"` or "APS-C"
+    #[serde(alias = "optical_format", alias = "sensor_format")]
+    format: Option<String>,
+    pixel_width: Option<u32>,
+    pixel_height: Option<u32>,
+    /// Resolution such as "1920x1080"
+    resolution: Option<String>,
+    focal_length_mm: Option<f64>,
+    /// Lens such as "4mm" or "2.8-12mm"; a varifocal lens is taken at its wide end
+    lens: Option<String>,
+    name: Option<String>,
+    f_number: Option<f64>,
+    t_stop: Option<f64>,
+    quantum_efficiency: Option<f64>,
+    read_noise_e: Option<f64>,
+    full_well_e: Option<f64>,
+    dark_current_e_s: Option<f64>,
+    lens_resolution_lp_mm: Option<f64>,
+    dynamic_range_db: Option<f64>,
+    night_mode: Option<NightMode>,
+}
+
+/// Missing-field error for a width/height pair that can also come from one spec field,
+/// naming the half of the pair that is actually missing as serde would
+fn missing_field<T>(
+    width: Option<T>,
+    width_field: &str,
+    height_field: &str,
+    alternative: &str,
+) -> String {
+    let field = if width.is_none() {
+        width_field
+    } else {
+        height_field
+    };
+    format!("missing field `{}` or `{}`", field, alternative)
+}
+
+impl TryFrom<CameraSystemInput> for CameraSystem {
+    type Error = String;
+
+    fn try_from(input: CameraSystemInput) -> Result<Self, Self::Error> {
+        let (pixel_width, pixel_height) = match (input.pixel_width, input.pixel_height) {
+            (Some(width), Some(height)) => (width, height),
+            _ => {
+                let resolution = input.resolution.as_deref().ok_or_else(|| {
+                    missing_field(
+                        input.pixel_width,
+                        "pixel_width",
+                        "pixel_height",
+                        "resolution",
+                    )
+                })?;
+                parse_resolution(resolution)
+                    .filter(|&(width, height)| width > 0 && height > 0)
+                    .ok_or_else(|| format!("invalid resolution '{}'", resolution))?
+            }
+        };
+
+        let (sensor_width_mm, sensor_height_mm) =
+            match (input.sensor_width_mm, input.sensor_height_mm) {
+                (Some(width), Some(height)) => (width, height),
+                _ => {
+                    let format = input.format.as_deref().ok_or_else(|| {
+                        missing_field(
+                            input.sensor_width_mm,
+                            "sensor_width_mm",
+                            "sensor_height_mm",
+                            "format",
+                        )
+                    })?;
+                    let aspect = pixel_height as f64 / pixel_width as f64;
+                    sensor_from_optical_format(format, aspect)
+                        .ok_or_else(|| format!("invalid sensor format '{}'", format))?
+                }
+            };
+
+        let focal_length_mm = match input.focal_length_mm {
+            Some(focal_length_mm) => focal_length_mm,
+            None => {
+                let lens = input
+                    .lens
+                    .as_deref()
+                    .ok_or("missing field `focal_length_mm` or `lens`")?;
+                parse_focal_range(lens)
+                    .ok_or_else(|| format!("invalid lens '{}'", lens))?
+                    .0
+            }
+        };
+
+        Ok(CameraSystem {
+            sensor_width_mm,
+            sensor_height_mm,
+            pixel_width,
+            pixel_height,
+            focal_length_mm,
+            name: input.name,
+            f_number: input.f_number,
+            t_stop: input.t_stop,
+            quantum_efficiency: input.quantum_efficiency,
+            read_noise_e: input.read_noise_e,
+            full_well_e: input.full_well_e,
+            dark_current_e_s: input.dark_current_e_s,
+            lens_resolution_lp_mm: input.lens_resolution_lp_mm,
+            dynamic_range_db: input.dynamic_range_db,
+            night_mode: input.night_mode,
+        })
+    }
+}
+
+/// Cameras deserialize from their explicit fields or from spec-sheet notation, so
+/// pasted datasheet JSON such as
+/// `{"format": "1/2.8\"", "resolution": "1920x1080", "lens": "2.8-12mm"}` works.
+/// Explicit fields win when both are given.
+impl<'de> Deserialize<'de> for CameraSystem {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        CameraSystemInput::deserialize(deserializer)?
+            .try_into()
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spec_sheet_camera() {
+        let camera: CameraSystem = serde_json::from_str(
+            r#"{"format": "1/2.8\"", "resolution": "1920x1080", "lens": "2.8-12mm"}"#,
+        )
+        .unwrap();
+
+        assert_eq!((camera.pixel_width, camera.pixel_height), (1920, 1080));
+        assert!((camera.focal_length_mm - 2.8).abs() < 1e-9);
+        // 1/2.8" ≈ 6.43 mm diagonal at 16:9
+        let diagonal = camera.sensor_width_mm.hypot(camera.sensor_height_mm);
+        assert!((diagonal - 18.0 / 2.8).abs() < 1e-9);
+        assert!((camera.sensor_height_mm / camera.sensor_width_mm - 0.5625).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_explicit_fields_and_named_formats() {
+        let camera: CameraSystem = serde_json::from_str(
+            r#"{"sensor_width_mm": 6.4, "sensor_height_mm": 4.8, "format": "1/2\"",
+                "pixel_width": 2048, "pixel_height": 1536, "resolution": "640x480",
+                "focal_length_mm": 8.0, "name": "Explicit"}"#,
+        )
+        .unwrap();
+        assert_eq!(camera.sensor_width_mm, 6.4);
+        assert_eq!(camera.pixel_width, 2048);
+        assert_eq!(camera.name.as_deref(), Some("Explicit"));
+
+        let camera: CameraSystem = serde_json::from_str(
+            r#"{"optical_format": "full frame", "resolution": "6000 × 4000", "lens": "50 mm"}"#,
+        )
+        .unwrap();
+        assert_eq!(camera.sensor_width_mm, 36.0);
+        assert_eq!(camera.focal_length_mm, 50.0);
+    }
+
+    #[test]
+    fn test_invalid_spec_sheet_camera() {
+        let error = serde_json::from_str::<CameraSystem>(
+            r#"{"format": "1/2.8\"", "resolution": "full hd", "lens": "4mm"}"#,
+        )
+        .unwrap_err();
+        assert!(error.to_string().contains("resolution"));
+
+        assert!(serde_json::from_str::<CameraSystem>(r#"{"resolution": "1920x1080"}"#).is_err());
+    }
+
+    #[test]
+    fn test_missing_field_names_the_missing_half() {
+        let error = serde_json::from_str::<CameraSystem>(
+            r#"{"format": "1/2\"", "pixel_width": 1920, "lens": "4mm"}"#,
+        )
+        .unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("missing field `pixel_height` or `resolution`"));
+
+        let error = serde_json::from_str::<CameraSystem>(
+            r#"{"sensor_height_mm": 4.8, "resolution": "1920x1080", "lens": "4mm"}"#,
+        )
+        .unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("missing field `sensor_width_mm` or `format`"));
+    }
+}
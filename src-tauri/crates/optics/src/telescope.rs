@@ -0,0 +1,104 @@
+use super::constants::{DARK_ADAPTED_PUPIL_MM, DAWES_CONSTANT, MAX_MAGNIFICATION_PER_MM};
+use super::types::{CameraSystem, Eyepiece, EyepieceView, Telescope, TelescopeAnalysis};
+
+impl Telescope {
+    /// Focal ratio (f-number) of the telescope
+    pub fn focal_ratio(&self) -> f64 {
+        self.focal_length_mm / self.aperture_mm
+    }
+
+    /// The camera at prime focus, with the telescope as its lens
+    ///
+    /// The result works with every camera calculation, e.g. the astro pixel scale
+    /// or the diffraction limit.
+    pub fn with_camera(&self, camera: &CameraSystem) -> CameraSystem {
+        let mut camera = camera.clone();
+        camera.focal_length_mm = self.focal_length_mm;
+        camera.f_number = Some(self.focal_ratio());
+        camera.t_stop = None;
+        camera
+    }
+}
+
+/// Calculate the focal ratio, useful magnification range and eyepiece view of a telescope
+///
+/// Magnification is the telescope focal length over the eyepiece focal length and
+/// the true field is approximated as the apparent field over the magnification. The
+/// useful range runs from the magnification where the exit pupil matches a 7 mm
+/// dark-adapted pupil up to 2× the aperture in millimeters.
+pub fn analyze_telescope(telescope: &Telescope, eyepiece: Option<&Eyepiece>) -> TelescopeAnalysis {
+    let min_useful_magnification = telescope.aperture_mm / DARK_ADAPTED_PUPIL_MM;
+    let max_useful_magnification = telescope.aperture_mm * MAX_MAGNIFICATION_PER_MM;
+
+    let eyepiece = eyepiece.map(|eyepiece| {
+        let magnification = telescope.focal_length_mm / eyepiece.focal_length_mm;
+        EyepieceView {
+            magnification,
+            true_fov_deg: eyepiece.apparent_fov_deg / magnification,
+            exit_pupil_mm: telescope.aperture_mm / magnification,
+            within_useful_range: (min_useful_magnification..=max_useful_magnification)
+                .contains(&magnification),
+        }
+    });
+
+    TelescopeAnalysis {
+        focal_ratio: telescope.focal_ratio(),
+        min_useful_magnification,
+        max_useful_magnification,
+        dawes_limit_arcsec: DAWES_CONSTANT / telescope.aperture_mm,
+        eyepiece,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 8" f/6 Dobsonian
+    fn telescope() -> Telescope {
+        Telescope {
+            aperture_mm: 200.0,
+            focal_length_mm: 1200.0,
+            name: None,
+        }
+    }
+
+    #[test]
+    fn test_eyepiece_view() {
+        let eyepiece = Eyepiece {
+            focal_length_mm: 25.0,
+            apparent_fov_deg: 52.0,
+        };
+        let analysis = analyze_telescope(&telescope(), Some(&eyepiece));
+
+        assert!((analysis.focal_ratio - 6.0).abs() < 1e-9);
+        assert!((analysis.max_useful_magnification - 400.0).abs() < 1e-9);
+        assert!((analysis.dawes_limit_arcsec - 0.58).abs() < 1e-9);
+
+        let view = analysis.eyepiece.unwrap();
+        assert!((view.magnification - 48.0).abs() < 1e-9);
+        assert!((view.true_fov_deg - 52.0 / 48.0).abs() < 1e-9);
+        assert!((view.exit_pupil_mm - 200.0 / 48.0).abs() < 1e-9);
+        assert!(view.within_useful_range);
+
+        // A 2 mm eyepiece gives 600×, beyond what 200 mm can resolve
+        let short = Eyepiece {
+            focal_length_mm: 2.0,
+            apparent_fov_deg: 60.0,
+        };
+        let view = analyze_telescope(&telescope(), Some(&short))
+            .eyepiece
+            .unwrap();
+        assert!(!view.within_useful_range);
+    }
+
+    #[test]
+    fn test_camera_at_prime_focus() {
+        let camera = CameraSystem::new(23.5, 15.7, 6250, 4176, 50.0);
+        let prime = telescope().with_camera(&camera);
+
+        assert_eq!(prime.focal_length_mm, 1200.0);
+        assert_eq!(prime.f_number, Some(6.0));
+        assert_eq!(prime.pixel_width, 6250);
+    }
+}
@@ -0,0 +1,103 @@
+use super::types::{TiltedFocus, TiltedLensParams};
+
+/// Lens tilt that lays the plane of focus through a hinge line at a given distance
+///
+/// Hinge rule: sin(tilt) = f / J. For a camera looking along a flat surface (a road
+/// or a table top), J is the height of the lens above the surface measured parallel
+/// to the sensor. Returns `None` when the hinge is closer than the focal length.
+pub fn scheimpflug_tilt_deg(focal_length_mm: f64, hinge_distance_mm: f64) -> Option<f64> {
+    let sin_tilt = focal_length_mm / hinge_distance_mm;
+    (sin_tilt > 0.0 && sin_tilt <= 1.0).then(|| sin_tilt.asin().to_degrees())
+}
+
+/// Calculate the plane of focus and depth of field of a tilted lens
+///
+/// In the plane of the tilt, with the lens at the origin and the camera axis along
+/// x, the hinge rule places the hinge line at (0, -J) with J = f / sin(tilt). The
+/// plane of focus and both DOF limits pivot about it, so the DOF is a wedge rather
+/// than a slab. Each plane also passes through its Scheimpflug line, where the lens
+/// plane meets the image plane: the sensor for the plane of focus, and planes
+/// N × c in front of and behind it for the DOF limits.
+///
+/// # Returns
+/// `None` if the lens isn't tilted or the plane of focus can't cross the axis at
+/// the focus distance (it would need an image behind the lens)
+pub fn calculate_tilted_focus(params: &TiltedLensParams) -> Option<TiltedFocus> {
+    let tilt_rad = params.tilt_deg.to_radians();
+    if tilt_rad <= 0.0 {
+        return None;
+    }
+    let hinge_mm = params.focal_length_mm / tilt_rad.sin();
+    let cot_tilt = 1.0 / tilt_rad.tan();
+
+    // Slope of an object plane through the hinge whose image plane is at distance v:
+    // the Scheimpflug line lies at (-v, -v cot(tilt)), giving slope = cot(tilt) - J / v
+    let slope_for_image = |image_mm: f64| cot_tilt - hinge_mm / image_mm;
+
+    // Image distance that puts the plane of focus through the focus distance on axis
+    let focus_slope = hinge_mm / params.focus_distance_mm;
+    let sensor_mm = hinge_mm / (cot_tilt - focus_slope);
+    if sensor_mm <= 0.0 {
+        return None;
+    }
+
+    let defocus_mm = params.f_number * params.coc_mm;
+    let near_slope = slope_for_image(sensor_mm + defocus_mm);
+    let far_slope = slope_for_image(sensor_mm - defocus_mm);
+    let angle = |slope: f64| slope.atan().to_degrees();
+
+    Some(TiltedFocus {
+        hinge_distance_mm: hinge_mm,
+        focus_plane_angle_deg: angle(focus_slope),
+        focus_plane_to_sensor_deg: 90.0 - angle(focus_slope),
+        near_limit_angle_deg: angle(near_slope),
+        far_limit_angle_deg: angle(far_slope),
+        wedge_angle_deg: angle(near_slope) - angle(far_slope),
+        near_limit_on_axis_mm: hinge_mm / near_slope,
+        far_limit_on_axis_mm: (far_slope > 0.0).then(|| hinge_mm / far_slope),
+        depth_at_focus_mm: (near_slope - far_slope) * params.focus_distance_mm,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculations::calculate_dof;
+
+    fn params(tilt_deg: f64) -> TiltedLensParams {
+        TiltedLensParams {
+            focal_length_mm: 90.0,
+            tilt_deg,
+            f_number: 11.0,
+            coc_mm: 0.03,
+            focus_distance_mm: 5000.0,
+        }
+    }
+
+    #[test]
+    fn test_hinge_rule() {
+        let focus = calculate_tilted_focus(&params(5.0)).unwrap();
+
+        assert!((focus.hinge_distance_mm - 90.0 / 5f64.to_radians().sin()).abs() < 1e-9);
+        // Plane of focus runs from the hinge to the focus distance on axis
+        let expected = (focus.hinge_distance_mm / 5000.0).atan().to_degrees();
+        assert!((focus.focus_plane_angle_deg - expected).abs() < 1e-9);
+        assert!(focus.near_limit_angle_deg > focus.focus_plane_angle_deg);
+        assert!(focus.far_limit_angle_deg < focus.focus_plane_angle_deg);
+        assert!(focus.near_limit_on_axis_mm < 5000.0);
+
+        let tilt = scheimpflug_tilt_deg(90.0, focus.hinge_distance_mm).unwrap();
+        assert!((tilt - 5.0).abs() < 1e-9);
+        assert!(scheimpflug_tilt_deg(90.0, 50.0).is_none());
+    }
+
+    #[test]
+    fn test_small_tilt_matches_untilted_dof() {
+        let focus = calculate_tilted_focus(&params(0.001)).unwrap();
+        let (near, far, _) = calculate_dof(5000.0, 90.0, 11.0, 0.03, None);
+
+        assert!((focus.near_limit_on_axis_mm - near).abs() / near < 0.01);
+        assert!((focus.far_limit_on_axis_mm.unwrap() - far).abs() / far < 0.01);
+        assert!(calculate_tilted_focus(&params(0.0)).is_none());
+    }
+}
@@ -0,0 +1,201 @@
+use super::calculations::{calculate_dori_distances, calculate_fov};
+use super::constants::PROGRESS_REPORT_INTERVAL;
+use super::types::{
+    CameraSystem, DoriDistances, FovResult, PercentileBand, Tolerance, ToleranceAnalysisParams,
+    ToleranceAnalysisResult, ToleranceDistribution,
+};
+
+/// Draw a perturbed value around `nominal`
+fn sample(rng: &mut fastrand::Rng, nominal: f64, tolerance: Option<&Tolerance>) -> f64 {
+    let Some(tolerance) = tolerance else {
+        return nominal;
+    };
+    let offset = match tolerance.distribution {
+        ToleranceDistribution::Uniform => (rng.f64() * 2.0 - 1.0) * tolerance.amount,
+        ToleranceDistribution::Normal => {
+            // Box-Muller transform; 1 - u keeps the logarithm finite
+            let u1 = 1.0 - rng.f64();
+            let u2 = rng.f64();
+            (-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos() * tolerance.amount
+        }
+    };
+    nominal + offset
+}
+
+/// Linearly interpolated percentile of sorted values
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = p / 100.0 * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+/// Summarize the spread of a set of samples
+pub(crate) fn band(mut values: Vec<f64>) -> PercentileBand {
+    values.sort_by(f64::total_cmp);
+    let mean = values.iter().sum::<f64>() / values.len() as f64;
+    PercentileBand {
+        min: values[0],
+        p5: percentile(&values, 5.0),
+        p50: percentile(&values, 50.0),
+        p95: percentile(&values, 95.0),
+        max: values[values.len() - 1],
+        mean,
+    }
+}
+
+/// Monte Carlo analysis of manufacturing and installation variability
+///
+/// Focal length, sensor dimensions and mounting distance are perturbed independently
+/// according to their tolerances, and FOV/DORI are evaluated for every sample.
+/// Tolerances should be small relative to the nominal values; samples are not clamped.
+///
+/// # Returns
+/// 5th/50th/95th percentile bands (plus min, max and mean) for each output
+pub fn run_tolerance_analysis(params: &ToleranceAnalysisParams) -> ToleranceAnalysisResult {
+    run_tolerance_analysis_with_progress(params, &mut |_, _| true)
+        .expect("analysis without cancellation always completes")
+}
+
+/// [`run_tolerance_analysis`] reporting the samples done so far to `progress`
+///
+/// `progress` is called with the number of samples done and the total; returning
+/// `false` cancels the analysis.
+///
+/// # Returns
+/// `None` if the analysis was cancelled
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(samples = params.samples))
+)]
+pub fn run_tolerance_analysis_with_progress(
+    params: &ToleranceAnalysisParams,
+    progress: &mut dyn FnMut(usize, usize) -> bool,
+) -> Option<ToleranceAnalysisResult> {
+    let samples = params.samples.max(1);
+    let mut rng = match params.seed {
+        Some(seed) => fastrand::Rng::with_seed(seed as u64),
+        None => fastrand::Rng::new(),
+    };
+
+    let nominal = &params.camera;
+    let total = samples as usize;
+    let mut results: Vec<(FovResult, DoriDistances)> = Vec::with_capacity(total);
+    for done in 1..=total {
+        let camera = CameraSystem {
+            focal_length_mm: sample(
+                &mut rng,
+                nominal.focal_length_mm,
+                params.focal_length_mm.as_ref(),
+            ),
+            sensor_width_mm: sample(
+                &mut rng,
+                nominal.sensor_width_mm,
+                params.sensor_width_mm.as_ref(),
+            ),
+            sensor_height_mm: sample(
+                &mut rng,
+                nominal.sensor_height_mm,
+                params.sensor_height_mm.as_ref(),
+            ),
+            ..nominal.clone()
+        };
+        let distance_mm = sample(
+            &mut rng,
+            params.distance_mm,
+            params.distance_tolerance_mm.as_ref(),
+        );
+        results.push((
+            calculate_fov(&camera, distance_mm),
+            calculate_dori_distances(&camera),
+        ));
+        if (done % PROGRESS_REPORT_INTERVAL == 0 || done == total) && !progress(done, total) {
+            return None;
+        }
+    }
+
+    let fov = |value: fn(&FovResult) -> f64| band(results.iter().map(|(r, _)| value(r)).collect());
+    let dori =
+        |value: fn(&DoriDistances) -> f64| band(results.iter().map(|(_, d)| value(d)).collect());
+
+    Some(ToleranceAnalysisResult {
+        samples,
+        horizontal_fov_deg: fov(|r| r.horizontal_fov_deg),
+        vertical_fov_deg: fov(|r| r.vertical_fov_deg),
+        horizontal_fov_m: fov(|r| r.horizontal_fov_m),
+        vertical_fov_m: fov(|r| r.vertical_fov_m),
+        horizontal_ppm: fov(|r| r.horizontal_ppm),
+        detection_m: dori(|d| d.detection_m),
+        observation_m: dori(|d| d.observation_m),
+        recognition_m: dori(|d| d.recognition_m),
+        identification_m: dori(|d| d.identification_m),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(samples: u32) -> ToleranceAnalysisParams {
+        ToleranceAnalysisParams {
+            camera: CameraSystem::new(5.6, 3.15, 1920, 1080, 4.0),
+            distance_mm: 20_000.0,
+            focal_length_mm: Some(Tolerance {
+                distribution: ToleranceDistribution::Normal,
+                amount: 0.1,
+            }),
+            sensor_width_mm: None,
+            sensor_height_mm: None,
+            distance_tolerance_mm: Some(Tolerance {
+                distribution: ToleranceDistribution::Uniform,
+                amount: 500.0,
+            }),
+            samples,
+            seed: Some(42),
+        }
+    }
+
+    #[test]
+    fn test_tolerance_bands_bracket_nominal() {
+        let result = run_tolerance_analysis(&params(5000));
+        let nominal = calculate_fov(&params(1).camera, 20_000.0);
+        let band = &result.horizontal_ppm;
+
+        assert_eq!(result.samples, 5000);
+        assert!(band.min <= band.p5 && band.p5 <= band.p50);
+        assert!(band.p50 <= band.p95 && band.p95 <= band.max);
+        assert!(band.p5 < nominal.horizontal_ppm && nominal.horizontal_ppm < band.p95);
+        assert!((band.p50 / nominal.horizontal_ppm - 1.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_tolerance_seed_is_reproducible() {
+        let a = run_tolerance_analysis(&params(100));
+        let b = run_tolerance_analysis(&params(100));
+        assert_eq!(a.detection_m.p95, b.detection_m.p95);
+    }
+
+    #[test]
+    fn test_no_tolerances_gives_point_band() {
+        let mut exact = params(10);
+        exact.focal_length_mm = None;
+        exact.distance_tolerance_mm = None;
+        let result = run_tolerance_analysis(&exact);
+
+        assert_eq!(result.identification_m.min, result.identification_m.max);
+    }
+
+    #[test]
+    fn test_tolerance_progress_and_cancel() {
+        let mut reports = Vec::new();
+        let result = run_tolerance_analysis_with_progress(&params(1200), &mut |done, total| {
+            reports.push((done, total));
+            true
+        });
+        assert!(result.is_some());
+        assert_eq!(reports, [(500, 1200), (1000, 1200), (1200, 1200)]);
+
+        let cancelled = run_tolerance_analysis_with_progress(&params(1200), &mut |_, _| false);
+        assert!(cancelled.is_none());
+    }
+}
@@ -0,0 +1,3439 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents a camera sensor specification
+///
+/// Deserializes from these fields or from spec-sheet notation (see the `spec` module).
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct CameraSystem {
+    /// Sensor width in millimeters
+    pub sensor_width_mm: f64,
+    /// Sensor height in millimeters
+    pub sensor_height_mm: f64,
+    /// Horizontal pixel count
+    pub pixel_width: u32,
+    /// Vertical pixel count
+    pub pixel_height: u32,
+    /// Lens focal length in millimeters
+    pub focal_length_mm: f64,
+    /// Optional name for identification
+    pub name: Option<String>,
+    /// Lens aperture (f-number), used for depth of field and low-light comparisons
+    pub f_number: Option<f64>,
+    /// Lens transmission stop (T-stop); takes the place of the f-number in
+    /// exposure and low-light estimates when specified
+    pub t_stop: Option<f64>,
+    /// Sensor quantum efficiency (0-1), used for low-light comparisons
+    pub quantum_efficiency: Option<f64>,
+    /// Read noise in electrons RMS, used for SNR estimates
+    pub read_noise_e: Option<f64>,
+    /// Full-well capacity in electrons, used for SNR estimates
+    pub full_well_e: Option<f64>,
+    /// Dark current in electrons per pixel per second, used for SNR estimates
+    pub dark_current_e_s: Option<f64>,
+    /// Lens resolving power or MTF cutoff in line pairs per millimeter
+    pub lens_resolution_lp_mm: Option<f64>,
+    /// Specified (wide) dynamic range in dB, used for scene dynamic range checks
+    pub dynamic_range_db: Option<f64>,
+    /// Mono IR night mode of a day/night camera
+    pub night_mode: Option<NightMode>,
+}
+
+/// Mono IR night mode of a day/night camera
+///
+/// At night the IR-cut filter swings out and the image switches to monochrome,
+/// which raises the effective sensitivity; many cameras also bin pixels.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct NightMode {
+    /// Pixel binning per axis (1 = full resolution, 2 = 2×2 binning)
+    pub binning: u32,
+    /// Effective quantum efficiency in night mode, including near-IR response
+    pub quantum_efficiency: Option<f64>,
+}
+
+/// Results of field-of-view calculations
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct FovResult {
+    /// Horizontal field of view in degrees
+    pub horizontal_fov_deg: f64,
+    /// Vertical field of view in degrees
+    pub vertical_fov_deg: f64,
+    /// Horizontal field of view at specified distance in meters
+    pub horizontal_fov_m: f64,
+    /// Vertical field of view at specified distance in meters
+    pub vertical_fov_m: f64,
+    /// Horizontal pixels per meter at specified distance
+    pub horizontal_ppm: f64,
+    /// Vertical pixels per meter at specified distance
+    pub vertical_ppm: f64,
+    /// Distance at which calculation was performed in meters
+    pub distance_m: f64,
+    /// Horizontal instantaneous field of view of a single pixel in microradians
+    #[serde(default)]
+    pub horizontal_ifov_urad: f64,
+    /// Vertical instantaneous field of view of a single pixel in microradians
+    #[serde(default)]
+    pub vertical_ifov_urad: f64,
+    /// Diagonal field of view in degrees
+    #[serde(default)]
+    pub diagonal_fov_deg: f64,
+    /// Horizontal pixels per degree of field of view
+    #[serde(default)]
+    pub horizontal_px_per_deg: f64,
+    /// Vertical pixels per degree of field of view
+    #[serde(default)]
+    pub vertical_px_per_deg: f64,
+    /// Horizontal pixels per foot at specified distance
+    #[serde(default)]
+    pub horizontal_ppf: f64,
+    /// Vertical pixels per foot at specified distance
+    #[serde(default)]
+    pub vertical_ppf: f64,
+    /// Megapixels on a 1 m² target facing the camera at specified distance
+    #[serde(default)]
+    pub megapixels_per_m2: f64,
+    /// DORI distances (Detection, Observation, Recognition, Identification)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dori: Option<DoriDistances>,
+    /// Photographic class of the lens on this sensor format
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub angle_of_view: Option<AngleOfView>,
+}
+
+/// Photographic class of a lens, by its 35 mm-equivalent focal length
+///
+/// The same focal length falls into different classes on different sensor formats:
+/// 8 mm is ultra-wide on full frame but short tele on a 1/4" sensor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub enum AngleOfView {
+    /// Below 24 mm equivalent
+    UltraWide,
+    /// 24-40 mm equivalent
+    Wide,
+    /// 40-70 mm equivalent, close to the human eye's view
+    Normal,
+    /// 70-135 mm equivalent
+    ShortTele,
+    /// 135-300 mm equivalent
+    Tele,
+    /// 300 mm equivalent and beyond
+    SuperTele,
+}
+
+/// DORI (Detection, Observation, Recognition, Identification) distances
+/// Standard for surveillance camera performance evaluation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct DoriDistances {
+    /// Detection distance: identify that an object is present (25 px/m)
+    pub detection_m: f64,
+    /// Observation distance: determine general characteristics (62.5 px/m)
+    pub observation_m: f64,
+    /// Recognition distance: recognize familiar person/object (125 px/m)
+    pub recognition_m: f64,
+    /// Identification distance: identify specific person beyond reasonable doubt (250 px/m)
+    pub identification_m: f64,
+}
+
+/// Combined camera system with its calculated FOV result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct CameraWithResult {
+    pub camera: CameraSystem,
+    pub result: FovResult,
+    /// Low-light sensitivity (only available when the camera has an f-number)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub low_light: Option<LowLightScore>,
+    /// Weighted suitability score (only available when the comparison was scored)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suitability: Option<SuitabilityScore>,
+    /// Background blur for a subject isolation scenario (only available when the
+    /// comparison had one and the camera has an f-number)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub isolation: Option<SubjectIsolation>,
+    /// Results in night mode (only available when the camera has one)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub night: Option<NightModeResult>,
+}
+
+/// FOV, DORI and low-light results of a camera in night mode
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct NightModeResult {
+    /// The camera as it operates in night mode
+    pub camera: CameraSystem,
+    pub result: FovResult,
+    /// Low-light sensitivity in night mode, ranked against the other night modes
+    pub low_light: Option<LowLightScore>,
+}
+
+impl CameraWithResult {
+    /// Validate both the camera system and result, returning all warnings
+    pub fn validate(&self) -> Vec<ValidationWarning> {
+        let mut warnings = Vec::new();
+        warnings.extend(self.camera.validate());
+        warnings.extend(self.result.validate());
+        warnings
+    }
+}
+
+/// Pixel density versus distance series for charting
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct PpmCurve {
+    /// Sample distances in meters
+    pub distances_m: Vec<f64>,
+    /// Horizontal pixels per meter at each distance
+    pub horizontal_ppm: Vec<f64>,
+    /// Vertical pixels per meter at each distance
+    pub vertical_ppm: Vec<f64>,
+    /// Distances at which the curve crosses each DORI threshold
+    pub dori: DoriDistances,
+}
+
+/// FOV and DORI distance series across a zoom range, for coverage-vs-reach charts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct ZoomCurve {
+    /// Sample focal lengths in millimeters
+    pub focal_lengths_mm: Vec<f64>,
+    /// Horizontal field of view in degrees at each focal length
+    pub horizontal_fov_deg: Vec<f64>,
+    /// Vertical field of view in degrees at each focal length
+    pub vertical_fov_deg: Vec<f64>,
+    /// Detection distance in meters at each focal length
+    pub detection_m: Vec<f64>,
+    /// Observation distance in meters at each focal length
+    pub observation_m: Vec<f64>,
+    /// Recognition distance in meters at each focal length
+    pub recognition_m: Vec<f64>,
+    /// Identification distance in meters at each focal length
+    pub identification_m: Vec<f64>,
+}
+
+/// Scene size the camera must cover, for solving the working distance
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct RequiredFov {
+    /// Scene width to cover in meters (optional)
+    pub width_m: Option<f64>,
+    /// Scene height to cover in meters (optional)
+    pub height_m: Option<f64>,
+}
+
+/// Target DORI distances for inverse calculation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct DoriTargets {
+    /// Target detection distance in meters (optional)
+    pub detection_m: Option<f64>,
+    /// Target observation distance in meters (optional)
+    pub observation_m: Option<f64>,
+    /// Target recognition distance in meters (optional)
+    pub recognition_m: Option<f64>,
+    /// Target identification distance in meters (optional)
+    pub identification_m: Option<f64>,
+}
+
+/// Range of possible values for a parameter
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct ParameterRange {
+    pub min: f64,
+    pub max: f64,
+    /// Sensible default inside the range, for prefilling inputs
+    #[serde(default)]
+    pub recommended: Option<f64>,
+}
+
+/// Fixed constraint for a parameter
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct ParameterConstraint {
+    pub sensor_width_mm: Option<f64>,
+    pub sensor_height_mm: Option<f64>,
+    pub pixel_width: Option<u32>,
+    pub pixel_height: Option<u32>,
+    pub focal_length_mm: Option<f64>,
+    pub horizontal_fov_deg: Option<f64>,
+    /// Converted to a horizontal FOV at the camera's aspect ratio when none is given
+    #[serde(default)]
+    pub vertical_fov_deg: Option<f64>,
+    /// Converted to a horizontal FOV at the camera's aspect ratio when neither
+    /// a horizontal nor a vertical FOV is given
+    #[serde(default)]
+    pub diagonal_fov_deg: Option<f64>,
+}
+
+/// Plausible bounds on each parameter of the inverse DORI calculation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct SolverBounds {
+    pub min_pixel_width: u32,
+    pub max_pixel_width: u32,
+    pub min_sensor_width_mm: f64,
+    pub max_sensor_width_mm: f64,
+    pub min_focal_length_mm: f64,
+    pub max_focal_length_mm: f64,
+    /// Width-to-height ratio for deriving heights when no width and height pair is fixed
+    pub default_aspect_ratio: f64,
+}
+
+/// Ranges of camera parameters that satisfy DORI requirements
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct DoriParameterRanges {
+    /// Range for sensor width in mm (if not constrained)
+    pub sensor_width_mm: Option<ParameterRange>,
+    /// Range for sensor height in mm (if not constrained)
+    pub sensor_height_mm: Option<ParameterRange>,
+    /// Range for horizontal pixel count (if not constrained)
+    pub pixel_width: Option<ParameterRange>,
+    /// Range for vertical pixel count (if not constrained)
+    pub pixel_height: Option<ParameterRange>,
+    /// Range for focal length in mm (if not constrained)
+    pub focal_length_mm: Option<ParameterRange>,
+    /// Range for horizontal FOV in degrees (if not constrained)
+    pub horizontal_fov_deg: Option<ParameterRange>,
+    /// Set when FOV, focal length and sensor width are all fixed but disagree
+    #[serde(default)]
+    pub inconsistency: Option<ConstraintInconsistency>,
+    /// What limits each end of each returned range
+    #[serde(default)]
+    pub binding_constraints: Vec<BindingConstraint>,
+    /// Standard resolutions whose width falls inside the pixel width range
+    #[serde(default)]
+    pub standard_resolutions: Vec<StandardResolution>,
+    /// Standard fixed lenses inside, and varifocal lenses overlapping, the focal length range
+    #[serde(default)]
+    pub standard_lenses: Vec<StandardLens>,
+    /// Standard optical formats whose width falls inside the sensor width range
+    #[serde(default)]
+    pub standard_sensor_formats: Vec<SensorFormat>,
+    /// The strictest of the given DORI targets, which the ranges are solved for
+    #[serde(default)]
+    pub governing_target: Option<GoverningTarget>,
+    /// Parameters with no value inside their bounds that meets the targets
+    #[serde(default)]
+    pub infeasible_parameters: Vec<RangeParameter>,
+}
+
+/// The DORI target that sets the requirement when several are given
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct GoverningTarget {
+    pub level: DoriLevel,
+    pub distance_m: f64,
+    /// Other given targets that do not follow the standard DORI ratios and are
+    /// exceeded by meeting this one
+    pub exceeded: Vec<DoriLevel>,
+}
+
+/// A standard optical format such as 1/2.8" or APS-C
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct SensorFormat {
+    pub name: String,
+    pub sensor_width_mm: f64,
+    pub sensor_height_mm: f64,
+}
+
+/// A standard fixed or varifocal lens
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct StandardLens {
+    /// e.g. "8 mm" or "2.8-12 mm"
+    pub name: String,
+    pub min_focal_length_mm: f64,
+    /// Equal to the minimum for a fixed lens
+    pub max_focal_length_mm: f64,
+    pub varifocal: bool,
+}
+
+/// A purchasable sensor resolution such as 1080p or 4K
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct StandardResolution {
+    pub name: String,
+    pub pixel_width: u32,
+    pub pixel_height: u32,
+}
+
+/// Parameter of the inverse DORI calculation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub enum RangeParameter {
+    SensorWidth,
+    SensorHeight,
+    PixelWidth,
+    PixelHeight,
+    FocalLength,
+    HorizontalFov,
+}
+
+/// What sets one end of a returned parameter range
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub enum RangeLimit {
+    /// The DORI distance target, at the extreme of the other parameters
+    DoriTarget(DoriLevel),
+    /// The fixed horizontal FOV
+    FovConstraint,
+    /// Fixed values of the other parameters determine it exactly
+    FixedParameters,
+    /// Plausible bounds of a parameter, e.g. sensor widths of 3-50 mm
+    ParameterBounds(RangeParameter),
+}
+
+/// Limits on both ends of one returned range
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct BindingConstraint {
+    pub parameter: RangeParameter,
+    pub min_limited_by: RangeLimit,
+    pub max_limited_by: RangeLimit,
+}
+
+/// Disagreement between a fixed FOV, focal length and sensor width
+///
+/// Each corrected value restores consistency by changing that parameter alone.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct ConstraintInconsistency {
+    /// Horizontal FOV the sensor width and focal length actually give, in degrees
+    pub implied_fov_deg: f64,
+    /// Given FOV minus the implied FOV, in degrees
+    pub discrepancy_deg: f64,
+    /// Discrepancy relative to the implied FOV, in percent
+    pub discrepancy_percent: f64,
+    /// FOV to use with the given focal length and sensor width
+    pub corrected_fov_deg: f64,
+    /// Focal length to use with the given FOV and sensor width
+    pub corrected_focal_length_mm: f64,
+    /// Sensor width to use with the given FOV and focal length
+    pub corrected_sensor_width_mm: f64,
+}
+
+/// A camera body considered by the selection optimizer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct CandidateCamera {
+    /// Camera with its own lens; the focal length is the wide end of a varifocal lens
+    pub camera: CameraSystem,
+    /// Tele end of the camera's own varifocal lens
+    pub focal_length_max_mm: Option<f64>,
+    /// Unit price, used for cost optimization
+    #[serde(default)]
+    pub price: Option<f64>,
+}
+
+/// An interchangeable lens considered by the selection optimizer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct CandidateLens {
+    pub name: Option<String>,
+    /// Focal length in millimeters (wide end for a varifocal lens)
+    pub focal_length_mm: f64,
+    /// Tele end of a varifocal lens
+    pub focal_length_max_mm: Option<f64>,
+    pub f_number: Option<f64>,
+    /// Unit price, used for cost optimization
+    #[serde(default)]
+    pub price: Option<f64>,
+}
+
+/// Requirements a camera/lens combination must meet
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct SelectionRequirements {
+    /// Minimum DORI distances in meters
+    pub dori: DoriTargets,
+    /// Widest acceptable horizontal field of view in degrees
+    pub max_horizontal_fov_deg: Option<f64>,
+    /// Minimum frame width in meters at `near_distance_m`
+    pub min_frame_width_m: Option<f64>,
+    /// Nearest distance in meters that must be covered by `min_frame_width_m`
+    pub near_distance_m: Option<f64>,
+}
+
+/// A feasible camera/lens combination
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct RankedSelection {
+    /// Camera set to the chosen focal length
+    pub camera: CameraSystem,
+    /// Interchangeable lens used, if any
+    pub lens: Option<CandidateLens>,
+    /// Feasible focal length range for this combination in millimeters
+    pub focal_range: ParameterRange,
+    /// Smallest relative margin over any requirement at the chosen focal length, in percent
+    pub headroom_percent: Option<f64>,
+    pub horizontal_fov_deg: f64,
+    pub dori: DoriDistances,
+}
+
+/// Area a multi-camera design must cover
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct CoverageScene {
+    /// Total width to cover in meters
+    pub width_m: f64,
+    /// Distance at which the width must be covered in meters
+    pub distance_m: f64,
+}
+
+/// One point on the cost / camera count / coverage quality Pareto front
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct CoverageDesign {
+    pub selection: RankedSelection,
+    /// Cameras needed to span the scene width
+    pub camera_count: u32,
+    /// Price of one camera plus its lens
+    pub unit_cost: f64,
+    pub total_cost: f64,
+    /// Horizontal pixel density at the scene distance
+    pub horizontal_ppm: f64,
+}
+
+/// Feasible combinations, best first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct SelectionResult {
+    pub selections: Vec<RankedSelection>,
+    /// Number of camera/lens combinations evaluated
+    pub evaluated: usize,
+}
+
+/// Exposure settings derived from scene illuminance using the incident-light equation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct ExposureEstimate {
+    /// Scene illuminance in lux
+    pub illuminance_lux: f64,
+    /// Sensor sensitivity as ISO equivalent
+    pub iso: f64,
+    /// Geometric lens aperture (f-number)
+    pub f_number: f64,
+    /// T-stop of the lens, used for the exposure instead of the f-number when given
+    pub t_stop: Option<f64>,
+    /// Shutter time in seconds required for a correct exposure
+    pub shutter_s: f64,
+    /// Exposure value (EV) of the aperture/shutter combination, at the T-stop if given
+    pub exposure_value: f64,
+}
+
+/// Comparative low-light sensitivity derived from pixel area, aperture and quantum efficiency
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct LowLightScore {
+    /// Pixel area in square micrometers
+    pub pixel_area_um2: f64,
+    /// Lens aperture used for the score (the T-stop if the lens specifies one)
+    pub f_number: f64,
+    /// Quantum efficiency used for the score (1.0 if not specified)
+    pub quantum_efficiency: f64,
+    /// Light collected per pixel relative to a 1 µm² pixel at f/1.0 with 100% QE
+    pub score: f64,
+    /// Score as a percentage of the most sensitive camera in a comparison
+    pub relative_percent: Option<f64>,
+    /// Sensitivity rank within a comparison (1 = most sensitive)
+    pub rank: Option<u32>,
+}
+
+/// Scene and exposure for a photon-noise SNR estimate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct SnrParams {
+    /// Scene illuminance in lux
+    pub illuminance_lux: f64,
+    /// Exposure time in seconds
+    pub shutter_s: f64,
+    /// Scene reflectance (0-1), 18% grey if not given
+    pub reflectance: Option<f64>,
+}
+
+/// Per-pixel signal, noise and SNR at a given exposure
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct SnrEstimate {
+    /// Illuminance on the sensor in lux
+    pub sensor_illuminance_lux: f64,
+    /// Signal in electrons per pixel, limited by the full well
+    pub signal_e: f64,
+    /// Photon shot noise in electrons RMS
+    pub shot_noise_e: f64,
+    /// Dark current collected during the exposure in electrons
+    pub dark_e: f64,
+    /// Total noise in electrons RMS
+    pub noise_e: f64,
+    /// Signal-to-noise ratio
+    pub snr: f64,
+    /// Signal-to-noise ratio in dB (`None` without any signal, where it is -∞)
+    pub snr_db: Option<f64>,
+    /// Whether the pixel reached its full-well capacity
+    pub saturated: bool,
+}
+
+/// Relative importance of each criterion when scoring compared cameras
+///
+/// Weights are relative to each other; a weight of 0 ignores the criterion.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct ScoringWeights {
+    /// Weight of the total pixel count
+    pub resolution: f64,
+    /// Weight of the horizontal field of view (wider is better)
+    pub fov: f64,
+    /// Weight of the low-light sensitivity
+    pub low_light: f64,
+    /// Weight of the DORI distances (further is better)
+    pub dori_reach: f64,
+}
+
+/// Weighted suitability of a camera within a comparison
+///
+/// Each criterion is scored 0-100 relative to the best camera in the comparison.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct SuitabilityScore {
+    /// Weighted score from 0 to 100
+    pub score: f64,
+    /// Rank within the comparison (1 = most suitable)
+    pub rank: u32,
+    pub resolution: f64,
+    pub fov: f64,
+    /// 0 when the camera has no f-number
+    pub low_light: f64,
+    pub dori_reach: f64,
+}
+
+/// Subject and background distances for comparing background blur
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct IsolationScenario {
+    /// In-focus subject distance in millimeters
+    pub subject_distance_mm: f64,
+    /// Background distance in millimeters (default: infinity)
+    pub background_distance_mm: Option<f64>,
+}
+
+/// How strongly a camera blurs the background behind an in-focus subject
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct SubjectIsolation {
+    /// Blur disc diameter of a background point on the sensor in millimeters
+    pub blur_disc_mm: f64,
+    /// Blur disc as a percentage of the image width, comparable across formats
+    pub blur_percent_of_width: f64,
+    /// Blur as a percentage of the most blurred background in the comparison
+    pub score: f64,
+}
+
+/// Atmospheric conditions that reduce the contrast of distant targets
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct AtmosphericConditions {
+    /// Meteorological visibility in meters (fog/haze), `None` for clear air
+    pub visibility_m: Option<f64>,
+    /// Rain rate in millimeters per hour, `None` for no rain
+    pub rain_rate_mm_h: Option<f64>,
+}
+
+/// Per-tier factors (0-1) by which DORI distances are reduced
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct DoriDeratingFactors {
+    pub detection: f64,
+    pub observation: f64,
+    pub recognition: f64,
+    pub identification: f64,
+}
+
+/// DORI distances derated for atmospheric extinction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct AtmosphericDoriResult {
+    /// Extinction coefficient from fog/haze in 1/km
+    pub fog_extinction_per_km: f64,
+    /// Extinction coefficient from rain in 1/km
+    pub rain_extinction_per_km: f64,
+    /// Total extinction coefficient in 1/km
+    pub extinction_per_km: f64,
+    /// Clear-weather DORI distances
+    pub theoretical: DoriDistances,
+    /// DORI distances under the given conditions
+    pub effective: DoriDistances,
+    /// Ratio of effective to theoretical distance for each tier
+    pub derating_factors: DoriDeratingFactors,
+}
+
+/// A single stage in a DORI derating stack
+///
+/// Each stage either raises the pixel density a task needs (e.g. compression
+/// artefacts) or lowers the resolution the camera effectively delivers (e.g. a dirty dome).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct DeratingFactor {
+    /// Human-readable description of the stage
+    pub label: String,
+    /// Multiplier applied to the required px/m (≥ 1 derates)
+    pub required_ppm_multiplier: f64,
+    /// Multiplier applied to the effective resolution (≤ 1 derates)
+    pub resolution_multiplier: f64,
+}
+
+/// Result of a single derating stage, in application order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct AppliedDerating {
+    pub label: String,
+    /// Distance factor contributed by this stage alone
+    pub distance_factor: f64,
+    /// Distance factor of all stages up to and including this one
+    pub cumulative_factor: f64,
+}
+
+/// DORI distances after applying a derating stack, alongside the theoretical values
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct EffectiveDoriDistances {
+    /// Theoretical DORI distances for ideal conditions
+    pub theoretical: DoriDistances,
+    /// DORI distances after all derating stages
+    pub effective: DoriDistances,
+    /// Stages in the order they were applied
+    pub stages: Vec<AppliedDerating>,
+    /// Atmospheric derating, if conditions were given
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub atmospheric_factors: Option<DoriDeratingFactors>,
+}
+
+/// Which part of the camera system limits angular resolution
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub enum ResolutionLimit {
+    /// Pixels are coarser than the diffraction limit - adding pixels would help
+    Sensor,
+    /// Diffraction blur is coarser than a pixel - the lens cannot feed the sensor
+    Optics,
+}
+
+/// Comparison of pixel IFOV against the diffraction-limited (Rayleigh) resolution
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct DiffractionLimitResult {
+    /// Lens aperture (f-number)
+    pub f_number: f64,
+    /// Wavelength used for the diffraction limit in nanometers
+    pub wavelength_nm: f64,
+    /// Entrance pupil diameter in millimeters
+    pub aperture_diameter_mm: f64,
+    /// Larger of the horizontal and vertical pixel IFOV in microradians
+    pub pixel_ifov_urad: f64,
+    /// Rayleigh angular resolution (1.22 λ / D) in microradians
+    pub rayleigh_limit_urad: f64,
+    /// Airy disk diameter on the sensor in micrometers (2.44 λ N)
+    pub airy_disk_diameter_um: f64,
+    /// Rayleigh limit divided by pixel IFOV (> 1 means optics-limited)
+    pub rayleigh_to_ifov_ratio: f64,
+    /// Which part of the system limits resolution
+    pub limited_by: ResolutionLimit,
+}
+
+/// Comparison of the sensor Nyquist frequency against the lens resolving power
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct SamplingAnalysis {
+    /// Horizontal pixel pitch in micrometers
+    pub pixel_pitch_um: f64,
+    /// Sensor Nyquist frequency in line pairs per millimeter
+    pub nyquist_lp_mm: f64,
+    /// Lens resolving power in line pairs per millimeter
+    pub lens_resolution_lp_mm: f64,
+    /// Lens resolving power divided by the Nyquist frequency
+    pub lens_to_nyquist_ratio: f64,
+    /// The lens resolves detail beyond Nyquist, so moiré/aliasing is likely
+    pub aliasing_likely: bool,
+    /// The lens resolves less than half of Nyquist, so sensor resolution is wasted
+    pub lens_limited: bool,
+}
+
+/// Vertical angle at which a camera looks down onto a face
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct FaceIncidenceAnalysis {
+    /// Camera mounting height above the ground in metres
+    pub mounting_height_m: f64,
+    /// Face height above the ground in metres
+    pub face_height_m: f64,
+    /// Horizontal distance from the camera to the face in metres
+    pub distance_m: f64,
+    /// Angle below horizontal of the line of sight onto the face in degrees
+    pub incidence_deg: f64,
+    /// Steepest angle that still allows facial identification in degrees
+    pub max_incidence_deg: f64,
+    /// The camera looks down too steeply to identify faces
+    pub exceeds_limit: bool,
+}
+
+/// Highest camera position that still sees faces at an identification distance
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct MountingHeightLimit {
+    /// Horizontal distance at which faces must be identified in metres
+    pub distance_m: f64,
+    /// Face height above the ground in metres
+    pub face_height_m: f64,
+    /// Steepest angle onto the face allowed, in degrees
+    pub max_incidence_deg: f64,
+    /// Highest mounting height above the ground in metres
+    pub max_mounting_height_m: f64,
+    /// Tilt that centres a face at the distance from that height, negative is looking down
+    pub tilt_deg: f64,
+}
+
+/// One DORI tier measured along the floor plan
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct GroundDoriTier {
+    pub level: DoriLevel,
+    /// Straight-line distance from the lens in metres
+    pub slant_m: f64,
+    /// Horizontal distance along the ground in metres, None when the slant
+    /// distance is shorter than the height difference to the target
+    pub ground_m: Option<f64>,
+    /// The tier reaches past the blind spot below the frame
+    pub reachable: bool,
+}
+
+/// DORI distances of a mounted camera converted to ground distances
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct GroundDoriDistances {
+    /// Camera mounting height above the ground in metres
+    pub mounting_height_m: f64,
+    /// Target height above the ground in metres
+    pub target_height_m: f64,
+    /// Camera tilt in degrees, negative is looking down
+    pub tilt_deg: f64,
+    /// Vertical field of view in degrees
+    pub vertical_fov_deg: f64,
+    /// Ground distance in metres before which targets are below the frame, None
+    /// when the frame never looks down to target height
+    pub blind_spot_m: Option<f64>,
+    /// Tiers from detection to identification
+    pub tiers: Vec<GroundDoriTier>,
+}
+
+/// Lens resolving power needed to make full use of a sensor
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct LensResolutionRequirement {
+    /// Pixel pitch in micrometers
+    pub pixel_pitch_um: f64,
+    /// Sensor Nyquist frequency in line pairs per millimeter
+    pub nyquist_lp_mm: f64,
+    /// Minimum contrast (MTF, 0-1) the lens should reach at the Nyquist frequency
+    pub min_mtf_at_nyquist: f64,
+    /// Half the Nyquist frequency in line pairs per millimeter
+    pub half_nyquist_lp_mm: f64,
+    /// Minimum contrast (MTF, 0-1) the lens should reach at half the Nyquist frequency
+    pub min_mtf_at_half_nyquist: f64,
+}
+
+/// Shape of a tolerance distribution
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub enum ToleranceDistribution {
+    /// Gaussian spread; the tolerance is one standard deviation
+    Normal,
+    /// Flat spread; the tolerance is the half-width (± limit)
+    Uniform,
+}
+
+/// Tolerance on a single input parameter, in the parameter's own units
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct Tolerance {
+    pub distribution: ToleranceDistribution,
+    /// Standard deviation (normal) or half-width (uniform)
+    pub amount: f64,
+}
+
+/// Input for Monte Carlo tolerance analysis
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct ToleranceAnalysisParams {
+    /// Nominal camera system
+    pub camera: CameraSystem,
+    /// Nominal mounting distance in millimeters
+    pub distance_mm: f64,
+    /// Focal length tolerance in millimeters
+    pub focal_length_mm: Option<Tolerance>,
+    /// Sensor width tolerance in millimeters
+    pub sensor_width_mm: Option<Tolerance>,
+    /// Sensor height tolerance in millimeters
+    pub sensor_height_mm: Option<Tolerance>,
+    /// Mounting distance tolerance in millimeters
+    pub distance_tolerance_mm: Option<Tolerance>,
+    /// Number of samples to draw
+    pub samples: u32,
+    /// Random seed, for reproducible runs
+    pub seed: Option<u32>,
+}
+
+/// Percentile band of a Monte Carlo output
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct PercentileBand {
+    pub min: f64,
+    pub p5: f64,
+    pub p50: f64,
+    pub p95: f64,
+    pub max: f64,
+    pub mean: f64,
+}
+
+/// Spread of FOV and DORI outputs over all Monte Carlo samples
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct ToleranceAnalysisResult {
+    /// Number of samples evaluated
+    pub samples: u32,
+    pub horizontal_fov_deg: PercentileBand,
+    pub vertical_fov_deg: PercentileBand,
+    pub horizontal_fov_m: PercentileBand,
+    pub vertical_fov_m: PercentileBand,
+    pub horizontal_ppm: PercentileBand,
+    pub detection_m: PercentileBand,
+    pub observation_m: PercentileBand,
+    pub recognition_m: PercentileBand,
+    pub identification_m: PercentileBand,
+}
+
+/// Input for analytic uncertainty propagation
+///
+/// Uncertainties are ± values in the same units as the nominal value; results carry
+/// the same confidence (e.g. 1σ in gives 1σ out).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct UncertaintyParams {
+    /// Nominal camera system
+    pub camera: CameraSystem,
+    /// Nominal mounting distance in millimeters
+    pub distance_mm: f64,
+    /// Focal length uncertainty in millimeters
+    pub focal_length_mm: Option<f64>,
+    /// Sensor width uncertainty in millimeters
+    pub sensor_width_mm: Option<f64>,
+    /// Sensor height uncertainty in millimeters
+    pub sensor_height_mm: Option<f64>,
+    /// Mounting distance uncertainty in millimeters
+    pub distance_uncertainty_mm: Option<f64>,
+}
+
+/// A result with its propagated uncertainty
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct UncertainValue {
+    pub value: f64,
+    /// ± uncertainty in the same units as the value
+    pub uncertainty: f64,
+    /// Uncertainty as a percentage of the value
+    pub relative_percent: f64,
+}
+
+/// FOV, pixel density and DORI results with error bars
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct UncertaintyResult {
+    pub horizontal_fov_deg: UncertainValue,
+    pub vertical_fov_deg: UncertainValue,
+    pub horizontal_fov_m: UncertainValue,
+    pub vertical_fov_m: UncertainValue,
+    pub horizontal_ppm: UncertainValue,
+    pub vertical_ppm: UncertainValue,
+    pub detection_m: UncertainValue,
+    pub observation_m: UncertainValue,
+    pub recognition_m: UncertainValue,
+    pub identification_m: UncertainValue,
+}
+
+/// Point on the site plan in metres
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct PlanPoint {
+    /// East offset from the site origin in metres
+    pub x_m: f64,
+    /// North offset from the site origin in metres
+    pub y_m: f64,
+}
+
+/// Placement of a camera within the site
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct CameraPose {
+    /// East offset from the site origin in metres
+    pub x_m: f64,
+    /// North offset from the site origin in metres
+    pub y_m: f64,
+    /// Mounting height above ground in metres
+    pub height_m: f64,
+    /// Pan angle in degrees, clockwise from north
+    pub pan_deg: f64,
+    /// Tilt angle in degrees, negative is looking down
+    pub tilt_deg: f64,
+}
+
+/// A camera at a position and orientation on the site plan
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct PlacedCamera {
+    pub camera: CameraSystem,
+    pub pose: CameraPose,
+}
+
+/// DORI task level, each with its standard pixel density requirement
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub enum DoriLevel {
+    /// 25 px/m
+    Detection,
+    /// 62.5 px/m
+    Observation,
+    /// 125 px/m
+    Recognition,
+    /// 250 px/m
+    Identification,
+}
+
+/// Pixel density a zone must reach, given directly or as a DORI level
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub enum ZoneRequirement {
+    /// Horizontal pixel density in px/m
+    PixelDensity(f64),
+    Dori(DoriLevel),
+}
+
+/// Area of the site that needs a minimum pixel density
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct CoverageZone {
+    pub name: String,
+    /// Zone outline on the site plan
+    pub polygon: Vec<PlanPoint>,
+    pub requirement: ZoneRequirement,
+}
+
+/// Wall, racking, column or other structure that blocks the view
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct Obstacle {
+    pub name: Option<String>,
+    /// Outline on the site plan; a thin wall can be given as a two-point polygon
+    pub polygon: Vec<PlanPoint>,
+}
+
+/// Site plan: room outline, zones with their requirements, and obstacles
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct Scene {
+    /// Room or site outline; its walls block the view and only the parts of zones
+    /// inside it are evaluated
+    pub room: Vec<PlanPoint>,
+    pub zones: Vec<CoverageZone>,
+    /// Structures that block the view in addition to the room walls
+    #[serde(default)]
+    pub obstacles: Vec<Obstacle>,
+}
+
+/// Candidate mounting position for the placement optimizer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct MountPoint {
+    /// East offset from the site origin in metres
+    pub x_m: f64,
+    /// North offset from the site origin in metres
+    pub y_m: f64,
+    /// Mounting height above the plane of interest in metres
+    pub height_m: f64,
+}
+
+/// Input for the camera placement optimizer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct PlacementRequest {
+    pub scene: Scene,
+    pub mounts: Vec<MountPoint>,
+    /// Camera/lens options that may be installed at any mount
+    pub cameras: Vec<CandidateCamera>,
+    /// Upper limit on installed cameras
+    pub max_cameras: Option<u32>,
+    /// Spacing of the coverage sample grid in metres
+    pub grid_spacing_m: Option<f64>,
+}
+
+/// How well a zone reaches its required pixel density
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct ZoneCoverage {
+    pub name: String,
+    /// Required horizontal pixel density in px/m
+    pub required_ppm: f64,
+    /// Share of the zone's area (within the room) that meets the requirement
+    pub covered_percent: f64,
+    /// Lowest pixel density over the zone from the best camera at each point;
+    /// 0 where a point is not seen at all
+    pub worst_ppm: f64,
+    /// How far the worst point is above (positive) or below (negative) the requirement
+    pub margin_percent: f64,
+    /// Whether the whole zone meets the requirement
+    pub meets_requirement: bool,
+}
+
+/// Share of a zone's area that reaches a DORI level
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct TierCoverage {
+    pub level: DoriLevel,
+    /// Share of the zone's area (within the room) at or above the level's density
+    pub covered_percent: f64,
+}
+
+/// Distribution of pixel density over a zone
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct ZoneStatistics {
+    pub name: String,
+    /// Number of grid points the zone was sampled at
+    pub samples: u32,
+    /// Spread of the best pixel density at each point in px/m, 0 where a point is
+    /// not seen; `None` when no part of the zone lies inside the room
+    pub ppm: Option<PercentileBand>,
+    /// Area reaching each level, from detection to identification
+    pub tiers: Vec<TierCoverage>,
+}
+
+/// Cameras chosen by the placement optimizer and the coverage they achieve
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct PlacementResult {
+    pub cameras: Vec<PlacedCamera>,
+    pub zones: Vec<ZoneCoverage>,
+}
+
+/// Area outside of which a camera cannot reach a DORI level, for privacy assessments
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct PrivacyBoundary {
+    pub level: DoriLevel,
+    /// Distance from the camera beyond which the level cannot be reached, in metres
+    pub distance_m: f64,
+    /// Boundary on the site plan: the camera position followed by an arc across
+    /// the horizontal FOV at `distance_m`
+    pub polygon: Vec<PlanPoint>,
+}
+
+/// Point in site coordinates, with height above the plane of interest
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct WorldPoint {
+    /// East offset from the site origin in metres
+    pub x_m: f64,
+    /// North offset from the site origin in metres
+    pub y_m: f64,
+    /// Height above the plane of interest in metres
+    pub z_m: f64,
+}
+
+/// Area that must not be recorded, e.g. a neighbour's window or a public footpath
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct ExclusionZone {
+    pub name: String,
+    /// Outline in site coordinates; vertical for windows, at height 0 for ground areas
+    pub polygon: Vec<WorldPoint>,
+}
+
+/// Position in the camera image in pixels, from the top left corner
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct ImagePoint {
+    pub x_px: f64,
+    pub y_px: f64,
+}
+
+/// Privacy mask for one exclusion zone, clipped to the image
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct PrivacyMask {
+    pub name: String,
+    pub polygon: Vec<ImagePoint>,
+}
+
+/// Privacy masks to configure in a camera or VMS
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct PrivacyMaskSet {
+    pub image_width: u32,
+    pub image_height: u32,
+    /// Masks of the exclusion zones that are in view
+    pub masks: Vec<PrivacyMask>,
+}
+
+/// Camera mount and the point it should be aimed at
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct AimingRequest {
+    pub camera: CameraSystem,
+    pub mount: MountPoint,
+    /// Plan position of the target to centre in the image
+    pub target: PlanPoint,
+    /// Height of the target above the plane of interest in metres, e.g. 1.6 for faces
+    pub target_height_m: f64,
+    /// DORI level the target must be seen at
+    pub required_level: Option<DoriLevel>,
+}
+
+/// Bracket angles that centre a target, and what the camera then sees
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct AimingResult {
+    /// Mount position with the pan and tilt that centre the target
+    pub pose: CameraPose,
+    /// Line-of-sight distance from the camera to the target in metres
+    pub slant_distance_m: f64,
+    /// Horizontal pixel density at the target in px/m
+    pub target_ppm: f64,
+    /// Highest DORI level reached at the target
+    pub achieved_level: Option<DoriLevel>,
+    /// Whether the required level is reached, if one was given
+    pub meets_requirement: Option<bool>,
+    /// Image corners projected onto the plane of interest: bottom left, bottom right,
+    /// top right, top left. Corners at or above the horizon are cut off at the
+    /// detection distance.
+    pub footprint: Vec<PlanPoint>,
+}
+
+/// Width and height of a target, e.g. 0.5 x 1.7 m for a person
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct TargetSize {
+    pub width_m: f64,
+    pub height_m: f64,
+}
+
+/// How well a placed camera sees a target at a position on the site
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct TargetEvaluation {
+    /// Line-of-sight distance from the camera to the target in metres
+    pub slant_distance_m: f64,
+    /// Horizontal pixel density on the target in px/m, 0 behind the camera
+    pub horizontal_ppm: f64,
+    /// Vertical pixel density on the target in px/m, 0 behind the camera
+    pub vertical_ppm: f64,
+    /// Highest DORI level reached, `None` below detection or outside the frame
+    pub achieved_level: Option<DoriLevel>,
+    /// Pixels across the width of the target
+    pub pixels_horizontal: f64,
+    /// Pixels along the height of the target, foreshortened by the incidence angle
+    pub pixels_vertical: f64,
+    /// Angle below horizontal of the line of sight onto the target in degrees
+    pub incidence_deg: f64,
+    /// Target position in the image, `None` behind the camera
+    pub image_point: Option<ImagePoint>,
+    /// The target position lies inside the image
+    pub in_view: bool,
+}
+
+/// Cameras needed to cover a straight corridor or perimeter run
+///
+/// Distances are along the run, measured from its start.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct CorridorSolution {
+    pub camera_count: u32,
+    /// Distance between neighbouring cameras in metres
+    pub spacing_m: f64,
+    /// Tilt of every camera in degrees, negative is looking down
+    pub tilt_deg: f64,
+    /// Mounting positions in metres; the first is negative, set back before the run
+    pub positions_m: Vec<f64>,
+    /// Blind spot ahead of each camera before the requirement is met, in metres
+    pub blind_spot_m: f64,
+    /// Distance ahead of each camera at which the pixel density drops below the requirement
+    pub reach_m: f64,
+}
+
+/// Region with a standard licence plate format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub enum PlateRegion {
+    /// EU format, 520 × 110 mm
+    Eu,
+    /// UK format, 520 × 111 mm
+    Uk,
+    /// US format, 12 × 6 in
+    Us,
+}
+
+/// Licence plate and character dimensions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct PlateSpec {
+    pub width_mm: f64,
+    pub height_mm: f64,
+    pub character_height_mm: f64,
+}
+
+/// Image resolution an ALPR engine needs on the plate
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub enum AlprRequirement {
+    /// Vertical pixels across the character height
+    PixelsPerCharacter(f64),
+    /// Horizontal pixel density on the plate in px/m
+    PixelDensity(f64),
+}
+
+/// Input for the ALPR capture distance calculation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct AlprParams {
+    pub camera: CameraSystem,
+    pub plate: PlateSpec,
+    pub requirement: AlprRequirement,
+    /// Planned capture distance in metres; enables the required focal length
+    pub capture_distance_m: Option<f64>,
+    /// Vehicle speed in km/h; enables the motion blur check
+    pub vehicle_speed_kmh: Option<f64>,
+    /// Exposure time in seconds for the motion blur check
+    pub shutter_s: Option<f64>,
+    /// Exposure as a shutter angle at a frame rate, used instead of `shutter_s`
+    #[serde(default)]
+    pub shutter_angle: Option<ShutterAngle>,
+}
+
+/// Exposure expressed as a rotary shutter angle at a frame rate, as on cinema cameras
+///
+/// A 180° shutter at 24 fps exposes each frame for 1/48 s.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct ShutterAngle {
+    /// Shutter angle in degrees (0-360)
+    pub angle_deg: f64,
+    /// Frame rate in frames per second
+    pub frame_rate_fps: f64,
+}
+
+/// Motion blur of a moving plate during the exposure
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct MotionBlurCheck {
+    /// Blur in pixels at the given shutter, if one was given
+    pub blur_px: Option<f64>,
+    /// Longest exposure that keeps the blur within the limit, in seconds
+    pub max_shutter_s: f64,
+    /// Whether the given shutter keeps the blur within the limit
+    pub passes: Option<bool>,
+    /// Longest shutter angle that keeps the blur within the limit, if a frame rate was given
+    #[serde(default)]
+    pub max_shutter_angle_deg: Option<f64>,
+}
+
+/// Licence plate capture limits of a camera
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct AlprResult {
+    /// Required pixel density on the plate in px/m
+    pub required_ppm: f64,
+    /// Furthest distance at which plates can be read, in metres
+    pub max_distance_m: f64,
+    /// Focal length needed to read plates at the capture distance, in mm
+    pub required_focal_length_mm: Option<f64>,
+    /// Plate width in pixels at the capture distance
+    pub plate_width_px: Option<f64>,
+    /// Character height in pixels at the capture distance
+    pub character_height_px: Option<f64>,
+    /// Evaluated at the capture distance, or at the maximum distance without one
+    pub motion_blur: Option<MotionBlurCheck>,
+}
+
+/// Input for the text legibility calculation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct LegibilityParams {
+    pub camera: CameraSystem,
+    /// Height of the smallest characters to read in millimeters
+    pub character_height_mm: f64,
+    /// Vertical pixels needed across a character, e.g. 16-20 for OCR
+    pub pixels_per_character: f64,
+    /// Planned reading distance in metres; enables the minimum focal length
+    pub read_distance_m: Option<f64>,
+}
+
+/// How far a camera can read text of a given size
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct LegibilityResult {
+    /// Required vertical pixel density in px/m
+    pub required_ppm: f64,
+    /// Furthest distance at which the text is legible, in metres
+    pub max_distance_m: f64,
+    /// Shortest focal length that makes the text legible at the reading distance, in mm
+    pub min_focal_length_mm: Option<f64>,
+    /// Character height in pixels at the reading distance
+    pub character_height_px: Option<f64>,
+}
+
+/// Kind of barcode, which sets the axes a module must be resolved on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub enum CodeSymbology {
+    /// 1D barcode, e.g. Code 128 or EAN-13, read across the bars
+    Linear,
+    /// 2D code, e.g. QR or Data Matrix, read in both directions
+    Matrix,
+}
+
+/// Input for the barcode reading distance calculation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct BarcodeParams {
+    pub camera: CameraSystem,
+    pub symbology: CodeSymbology,
+    /// Width of the narrowest bar or cell in millimeters
+    pub module_size_mm: f64,
+    /// Pixels per module the decoder needs; defaults to 2 for 1D and 3 for 2D codes
+    pub pixels_per_module: Option<f64>,
+    /// Code width in millimeters, so it must fit in the frame
+    pub code_width_mm: Option<f64>,
+    /// Code height in millimeters, so it must fit in the frame
+    pub code_height_mm: Option<f64>,
+    /// Reading distance in metres to check
+    pub distance_m: Option<f64>,
+}
+
+/// Working distances at which a barcode can be decoded
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct BarcodeResult {
+    /// Pixels per module used as the decoding threshold
+    pub required_pixels_per_module: f64,
+    /// Closest distance at which the whole code fits in the frame, in metres
+    pub near_distance_m: f64,
+    /// Furthest distance at which modules reach the threshold, in metres
+    pub far_distance_m: f64,
+    /// Pixels per module at the reading distance
+    pub pixels_per_module: Option<f64>,
+    /// Whether the code can be decoded at the reading distance
+    pub decodable: Option<bool>,
+}
+
+/// Smallest feature a machine-vision inspection must detect, over the field it must cover
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct InspectionRequirements {
+    /// Smallest feature to detect in millimeters, e.g. 0.1 for a fine scratch
+    pub feature_size_mm: f64,
+    /// Pixels across the smallest feature; defaults to 3
+    pub pixels_per_feature: Option<f64>,
+    /// Width of the inspected area in millimeters
+    pub field_width_mm: f64,
+    /// Height of the inspected area in millimeters
+    pub field_height_mm: Option<f64>,
+    /// Allowed working distances in millimeters
+    pub working_distance_mm: Option<ParameterRange>,
+}
+
+/// A camera and lens at the working distance where the inspected area fills the frame
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct InspectionOption {
+    /// Camera with the lens focal length applied
+    pub camera: CameraSystem,
+    /// Lens-to-object distance in millimeters
+    pub working_distance_mm: f64,
+    /// Optical magnification (image size / object size)
+    pub magnification: f64,
+    /// Field of view at the working distance in millimeters
+    pub field_width_mm: f64,
+    pub field_height_mm: f64,
+    /// Pixels across the smallest feature
+    pub pixels_per_feature: f64,
+}
+
+/// Resolution needed for an inspection and the camera/lens combinations that achieve it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct InspectionSolution {
+    /// Pixels per feature used for the requirement
+    pub pixels_per_feature: f64,
+    /// Minimum horizontal pixel count
+    pub required_pixel_width: u32,
+    /// Minimum vertical pixel count, if a field height was given
+    pub required_pixel_height: Option<u32>,
+    /// Combinations that meet the requirement, by increasing working distance
+    pub options: Vec<InspectionOption>,
+}
+
+/// Standard resolution test chart
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub enum TestChart {
+    /// ISO 12233 chart, scaled in line widths per picture height
+    Iso12233,
+    /// USAF 1951 three-bar target, scaled in groups and elements
+    Usaf1951,
+}
+
+/// How large the test chart should appear in the frame
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub enum ChartFraming {
+    /// Fraction of the frame the chart fills along its tighter axis
+    Coverage(f64),
+    /// Optical magnification, image size over chart size
+    Magnification(f64),
+}
+
+/// Input for the test chart distance calculation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct TestChartParams {
+    pub camera: CameraSystem,
+    pub chart: TestChart,
+    /// Width of the chart's active area in millimeters
+    pub chart_width_mm: f64,
+    /// Height of the chart's active area in millimeters
+    pub chart_height_mm: f64,
+    pub framing: ChartFraming,
+}
+
+/// A USAF 1951 group and element
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct UsafElement {
+    pub group: i32,
+    pub element: u32,
+    /// Resolution of the element in line pairs per mm
+    pub line_pairs_per_mm: f64,
+}
+
+/// Chart placement for verifying a camera's resolution in the lab
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct TestChartSetup {
+    /// Distance from the lens to the chart in millimeters
+    pub working_distance_mm: f64,
+    pub magnification: f64,
+    /// Fraction of the frame width the chart fills
+    pub width_fraction: f64,
+    /// Fraction of the frame height the chart fills
+    pub height_fraction: f64,
+    /// Sensor Nyquist limit projected onto the chart, in line pairs per mm
+    pub chart_nyquist_lp_mm: f64,
+    /// Finest USAF 1951 element within the Nyquist limit
+    pub usaf_limit: Option<UsafElement>,
+    /// Factor converting ISO 12233 chart readings to LW/PH of the full frame
+    pub iso_reading_scale: Option<f64>,
+}
+
+/// Camera calibration target pattern
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub enum CalibrationPattern {
+    Checkerboard,
+    /// Checkerboard with ArUco markers in the white squares
+    Charuco,
+}
+
+/// Input for sizing a calibration target
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct CalibrationTargetParams {
+    pub camera: CameraSystem,
+    pub pattern: CalibrationPattern,
+    /// Distance from the camera to the board in millimeters
+    pub working_distance_mm: f64,
+    /// Pixels across each square (default: 25)
+    pub pixels_per_square: Option<f64>,
+    /// Fraction of the frame width and height the board should fill (default: 0.5)
+    pub fov_fraction: Option<f64>,
+}
+
+/// Recommended calibration board for a camera at a working distance
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct CalibrationTarget {
+    /// Square size rounded to a whole millimeter
+    pub square_size_mm: f64,
+    /// ArUco marker size for ChArUco boards
+    pub marker_size_mm: Option<f64>,
+    /// Squares across the board
+    pub columns: u32,
+    /// Squares down the board
+    pub rows: u32,
+    /// Inner corners as (columns, rows), the pattern size OpenCV expects for checkerboards
+    pub inner_corners: (u32, u32),
+    pub board_width_mm: f64,
+    pub board_height_mm: f64,
+    /// Pixels across each square with the rounded size
+    pub pixels_per_square: f64,
+    /// Fraction of the frame width the board fills
+    pub width_fraction: f64,
+    /// Fraction of the frame height the board fills
+    pub height_fraction: f64,
+}
+
+/// Pixel scale and sky framing of a camera/telescope combination
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct AstroSampling {
+    /// Sky angle covered by one pixel in arcseconds
+    pub pixel_scale_arcsec: f64,
+    /// Seeing (star FWHM) in arcseconds
+    pub seeing_arcsec: f64,
+    /// Pixels across the seeing FWHM
+    pub pixels_per_fwhm: f64,
+    /// Fewer than 2 pixels across the FWHM, so stars come out blocky
+    pub undersampled: bool,
+    /// More than 3 pixels across the FWHM, so signal is spread without gaining detail
+    pub oversampled: bool,
+    /// Focal lengths that give 2-3 pixels across the FWHM
+    pub optimal_focal_length_mm: ParameterRange,
+    pub horizontal_fov_deg: f64,
+    pub vertical_fov_deg: f64,
+    pub diagonal_fov_deg: f64,
+    pub horizontal_fov_arcmin: f64,
+    pub vertical_fov_arcmin: f64,
+}
+
+/// Longest exposure before stars trail on a fixed tripod
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct StarTrailLimit {
+    /// Declination of the stars in degrees (0 at the celestial equator)
+    pub declination_deg: f64,
+    /// Focal length multiplier relative to full frame
+    pub crop_factor: f64,
+    /// Pixel pitch in micrometers
+    pub pixel_pitch_um: f64,
+    /// Classic 500 rule: 500 / (focal_length × crop_factor), in seconds
+    pub rule_500_s: f64,
+    /// NPF rule: (35 × N + 30 × pixel_pitch) / focal_length, in seconds
+    pub npf_s: f64,
+}
+
+/// Telescope used as the camera's lens or with an eyepiece
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct Telescope {
+    /// Clear aperture in millimeters
+    pub aperture_mm: f64,
+    /// Focal length in millimeters, including any reducer or Barlow
+    pub focal_length_mm: f64,
+    pub name: Option<String>,
+}
+
+/// Eyepiece for visual observing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct Eyepiece {
+    /// Focal length in millimeters
+    pub focal_length_mm: f64,
+    /// Apparent field of view in degrees
+    pub apparent_fov_deg: f64,
+}
+
+/// What a telescope shows through an eyepiece
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct EyepieceView {
+    pub magnification: f64,
+    /// Sky angle seen through the eyepiece in degrees
+    pub true_fov_deg: f64,
+    /// Diameter of the light beam leaving the eyepiece in millimeters
+    pub exit_pupil_mm: f64,
+    /// Magnification is between the minimum and maximum useful magnification
+    pub within_useful_range: bool,
+}
+
+/// Optical properties of a telescope
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct TelescopeAnalysis {
+    /// Focal ratio (f-number)
+    pub focal_ratio: f64,
+    /// Lowest magnification whose exit pupil still fits a dark-adapted eye
+    pub min_useful_magnification: f64,
+    /// Highest magnification before the image only gets bigger, not sharper
+    pub max_useful_magnification: f64,
+    /// Dawes limit for resolving double stars in arcseconds
+    pub dawes_limit_arcsec: f64,
+    /// View through the eyepiece, if one was given
+    pub eyepiece: Option<EyepieceView>,
+}
+
+/// Microscope objective, with the camera coupler between it and the sensor
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct MicroscopeObjective {
+    /// Objective magnification, e.g. 40 for a 40× objective
+    pub magnification: f64,
+    pub numerical_aperture: f64,
+    /// Magnification of the camera coupler (default: 1.0)
+    pub coupler_magnification: Option<f64>,
+}
+
+/// Resolution and sampling of a camera on a microscope
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct MicroscopyAnalysis {
+    /// Objective × coupler magnification onto the sensor
+    pub total_magnification: f64,
+    /// Wavelength used for the resolution limits in nanometers
+    pub wavelength_nm: f64,
+    /// Abbe limit λ / (2 NA) in micrometers
+    pub abbe_resolution_um: f64,
+    /// Rayleigh limit 0.61 λ / NA in micrometers
+    pub rayleigh_resolution_um: f64,
+    /// Field of view on the sample in millimeters
+    pub field_width_mm: f64,
+    pub field_height_mm: f64,
+    /// Size of one pixel projected onto the sample in micrometers
+    pub sample_pixel_size_um: f64,
+    /// Largest pixel pitch on the sensor that still samples the Rayleigh limit at Nyquist
+    pub max_pixel_pitch_um: f64,
+    /// At least two pixels span the Rayleigh limit
+    pub nyquist_satisfied: bool,
+}
+
+/// Input for solving a drone's flight altitude from a ground sample distance
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct FlightAltitudeParams {
+    /// Nadir-pointing camera
+    pub camera: CameraSystem,
+    /// Required ground sample distance in centimeters per pixel
+    pub gsd_cm: f64,
+    /// Regulatory altitude ceiling above ground in metres (default: 120)
+    pub max_altitude_m: Option<f64>,
+}
+
+/// Flight altitude and ground footprint for a ground sample distance
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct FlightAltitude {
+    /// Ground sample distance in centimeters per pixel
+    pub gsd_cm: f64,
+    /// Flight altitude above ground in metres
+    pub altitude_m: f64,
+    /// Ground width across the flight direction in metres
+    pub swath_width_m: f64,
+    /// Ground length along the flight direction in metres
+    pub swath_length_m: f64,
+    /// Ground area covered by one image in square metres
+    pub area_per_image_m2: f64,
+    /// Regulatory altitude ceiling in metres
+    pub max_altitude_m: f64,
+    /// The altitude is above the ceiling
+    pub exceeds_ceiling: bool,
+}
+
+/// Rectangular area to survey, flown along its length
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct SurveyArea {
+    /// Extent across the flight lines in metres
+    pub width_m: f64,
+    /// Extent along the flight lines in metres
+    pub length_m: f64,
+}
+
+/// Input for photogrammetry mission planning
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct SurveyParams {
+    /// Nadir-pointing camera, long side across the flight direction
+    pub camera: CameraSystem,
+    /// Flight altitude above ground in metres
+    pub altitude_m: f64,
+    /// Ground speed in metres per second
+    pub ground_speed_m_s: f64,
+    /// Overlap between consecutive images along a line, in percent
+    pub forward_overlap_percent: f64,
+    /// Overlap between neighbouring flight lines, in percent
+    pub side_overlap_percent: f64,
+    /// Area to cover; enables the line and image counts
+    pub area: Option<SurveyArea>,
+}
+
+/// Exposure spacing, line spacing and image count for a survey
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct SurveyPlan {
+    /// Ground sample distance in centimeters per pixel
+    pub gsd_cm: f64,
+    /// Image footprint across the flight direction in metres
+    pub footprint_width_m: f64,
+    /// Image footprint along the flight direction in metres
+    pub footprint_length_m: f64,
+    /// Distance flown between exposures in metres
+    pub photo_base_m: f64,
+    /// Time between exposures at the ground speed in seconds
+    pub shutter_interval_s: f64,
+    /// Distance between neighbouring flight lines in metres
+    pub line_spacing_m: f64,
+    pub flight_lines: Option<u32>,
+    pub images_per_line: Option<u32>,
+    pub total_images: Option<u32>,
+}
+
+/// A camera and aperture mapped onto another sensor format
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct FormatEquivalence {
+    /// Target sensor diagonal over source sensor diagonal
+    pub scale_factor: f64,
+    /// Focal length on the target format for the same diagonal FOV
+    pub equivalent_focal_length_mm: f64,
+    /// F-number on the target format for the same DOF and total light
+    pub equivalent_f_number: f64,
+    /// ISO multiplier on the target format for the same exposure time and noise
+    pub iso_multiplier: f64,
+    /// Entrance pupil diameter, shared by both setups, in millimeters
+    pub entrance_pupil_mm: f64,
+    /// Focal length of the source setup on full frame (35 mm equivalent)
+    pub full_frame_focal_length_mm: f64,
+    /// F-number of the source setup on full frame
+    pub full_frame_f_number: f64,
+    /// The source camera rescaled to the target sensor, ready to compare
+    pub camera: CameraSystem,
+}
+
+/// Change of one derived metric between two cameras
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct MetricDelta {
+    /// Metric name, matching the field or column it comes from (e.g. `horizontal_ppm`)
+    pub metric: String,
+    /// Value for the reference camera
+    pub a: f64,
+    /// Value for the compared camera
+    pub b: f64,
+    /// Change from `a` to `b` in percent of `a` (`None` when `a` is zero or either
+    /// value is unbounded, such as a far DOF limit at infinity)
+    pub change_percent: Option<f64>,
+}
+
+/// Percentage differences of every derived metric between two cameras
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct CameraDiff {
+    /// Working distance both cameras were evaluated at in meters
+    pub distance_m: f64,
+    /// FOV, resolution and DORI metrics, then depth of field when both cameras
+    /// have an f-number
+    pub metrics: Vec<MetricDelta>,
+}
+
+/// Input for the tilted-lens (Scheimpflug) depth of field model
+///
+/// The lens is tilted about an axis parallel to the sensor; angles are measured in
+/// the plane of the tilt.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct TiltedLensParams {
+    pub focal_length_mm: f64,
+    /// Lens tilt relative to the sensor plane in degrees
+    pub tilt_deg: f64,
+    pub f_number: f64,
+    /// Circle of confusion in millimeters
+    pub coc_mm: f64,
+    /// Distance along the camera axis at which the plane of focus crosses it, in millimeters
+    pub focus_distance_mm: f64,
+}
+
+/// Plane of focus and depth of field wedge of a tilted lens
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct TiltedFocus {
+    /// Distance from the lens to the hinge line, J = f / sin(tilt), in millimeters
+    pub hinge_distance_mm: f64,
+    /// Angle between the plane of focus and the camera axis in degrees
+    pub focus_plane_angle_deg: f64,
+    /// Angle between the plane of focus and the sensor plane in degrees
+    pub focus_plane_to_sensor_deg: f64,
+    /// Angle between the near DOF limit and the camera axis in degrees
+    pub near_limit_angle_deg: f64,
+    /// Angle between the far DOF limit and the camera axis in degrees; negative when
+    /// it tilts away from the hinge
+    pub far_limit_angle_deg: f64,
+    /// Opening angle of the DOF wedge in degrees
+    pub wedge_angle_deg: f64,
+    /// Where the near DOF limit crosses the camera axis, in millimeters
+    pub near_limit_on_axis_mm: f64,
+    /// Where the far DOF limit crosses the camera axis; `None` if it never does
+    pub far_limit_on_axis_mm: Option<f64>,
+    /// Depth of field across the camera axis at the focus distance, in millimeters
+    pub depth_at_focus_mm: f64,
+}
+
+/// Lens pupil model for locating the entrance pupil
+///
+/// A thin lens has both pupils and principal planes at the lens; real lenses are
+/// described by their pupil magnification and the gap between principal planes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct LensPupils {
+    /// Exit pupil over entrance pupil diameter (default: 1.0)
+    pub pupil_magnification: Option<f64>,
+    /// Distance from the front to the rear principal plane in millimeters, negative
+    /// when they are crossed (default: 0)
+    pub principal_plane_separation_mm: Option<f64>,
+}
+
+/// Input for locating a lens's no-parallax point
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct NoParallaxParams {
+    pub camera: CameraSystem,
+    pub pupils: LensPupils,
+    /// Focus distance from the entrance pupil in millimeters (default: infinity)
+    pub focus_distance_mm: Option<f64>,
+    /// Nearest subject distance in millimeters, for the parallax error
+    pub subject_distance_mm: f64,
+    /// Rotation between panorama frames in degrees
+    pub rotation_deg: f64,
+}
+
+/// Where to rotate a camera for a parallax-free panorama
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct NoParallaxPoint {
+    /// Entrance pupil (no-parallax point) in front of the sensor at the focus distance, in millimeters
+    pub entrance_pupil_from_sensor_mm: f64,
+    /// Entrance pupil in front of the sensor when focused at infinity, in millimeters
+    pub entrance_pupil_at_infinity_mm: f64,
+    /// Parallax between the subject and infinity when rotating about the sensor plane, in degrees
+    pub parallax_deg: f64,
+    /// The same parallax in pixels on the sensor
+    pub parallax_px: f64,
+}
+
+/// Relative illumination of a lens at one point in the field
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct RelativeIlluminationPoint {
+    /// Image height as a fraction of the sensor half-diagonal (0 = centre, 1 = corner)
+    pub field_position: f64,
+    /// Illumination as a percentage of the centre
+    pub percent: f64,
+}
+
+/// Input for the illumination falloff calculation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct VignettingParams {
+    pub camera: CameraSystem,
+    /// Measured relative illumination of the lens; replaces the cos⁴ model, since
+    /// datasheet curves already include it
+    pub lens_ri_curve: Option<Vec<RelativeIlluminationPoint>>,
+    /// Lowest acceptable corner illumination in percent of the centre (default: 50)
+    pub min_corner_percent: Option<f64>,
+}
+
+/// Illumination falloff from the centre to the corner of the image
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct VignettingProfile {
+    /// Sampled image heights as fractions of the half-diagonal
+    pub field_positions: Vec<f64>,
+    /// Field angle at each sample in degrees
+    pub field_angles_deg: Vec<f64>,
+    /// Illumination at each sample in percent of the centre
+    pub relative_illumination_percent: Vec<f64>,
+    /// Illumination at the middle of the left/right edges in percent
+    pub edge_percent: f64,
+    /// Illumination in the corners in percent
+    pub corner_percent: f64,
+    /// Corner falloff in stops
+    pub corner_falloff_stops: f64,
+    pub min_corner_percent: f64,
+    /// The corners are darker than the threshold
+    pub below_threshold: bool,
+}
+
+/// Brightest and darkest luminance in a scene that must both hold detail
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct SceneLuminance {
+    /// Brightest area in cd/m²
+    pub bright_cd_m2: f64,
+    /// Darkest area in cd/m²
+    pub dark_cd_m2: f64,
+}
+
+/// Typical high-contrast surveillance scenes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub enum WdrScenePreset {
+    /// Evenly lit office or corridor
+    Indoor,
+    /// Daylight with sunlit and shaded areas
+    SunAndShade,
+    /// Entrance lobby with glass doors to a sunlit street
+    EntranceLobby,
+    /// Car park or tunnel exit looking out into daylight
+    CarParkExit,
+    /// Road at night with oncoming headlights
+    HeadlightsAtNight,
+}
+
+/// Scene whose dynamic range is assessed, given as a preset or as measured luminances
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub enum WdrScene {
+    Preset(WdrScenePreset),
+    Custom(SceneLuminance),
+}
+
+/// Whether a camera's dynamic range covers a scene
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub enum WdrVerdict {
+    /// Covers the scene with headroom to spare
+    Pass,
+    /// Covers the scene, but without headroom
+    Warn,
+    /// Highlights or shadows will clip
+    Fail,
+}
+
+/// Dynamic range a scene requires and how a camera compares
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct DynamicRangeAssessment {
+    /// Scene luminances the requirement is based on
+    pub scene: SceneLuminance,
+    /// Required dynamic range in dB
+    pub required_db: f64,
+    /// Required dynamic range in stops
+    pub required_stops: f64,
+    /// Camera's specified dynamic range in dB, if known
+    pub camera_db: Option<f64>,
+    /// Camera dynamic range minus the requirement in dB
+    pub margin_db: Option<f64>,
+    /// Pass/warn/fail, if the camera's dynamic range is known
+    pub verdict: Option<WdrVerdict>,
+}
+
+/// Language messages are rendered in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub enum Locale {
+    #[default]
+    En,
+    De,
+    Fr,
+}
+
+/// Translatable message: a catalog key plus the parameters its template refers to
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct MessageKey {
+    /// Catalog key, e.g. `camera.sensor_width_too_small`
+    pub key: String,
+    #[serde(default)]
+    pub args: Vec<MessageArg>,
+}
+
+/// Named parameter of a message
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct MessageArg {
+    pub name: String,
+    pub value: MessageValue,
+}
+
+/// Value of a message parameter
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub enum MessageValue {
+    /// Number, formatted with the locale's decimal separator
+    Number(f64),
+    /// Text inserted as is, e.g. a camera name
+    Text(String),
+    /// Catalog key of a term that is itself translated, e.g. a DORI level
+    Term(String),
+}
+
+/// Validation warning for camera system
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct ValidationWarning {
+    /// Stable identifier of the check, for highlighting inputs and in tests
+    pub code: ValidationCode,
+    pub message: String,
+    pub severity: ValidationSeverity,
+    /// Input fields the warning refers to, with their offending values
+    #[serde(default)]
+    pub fields: Vec<ValidationField>,
+    /// Concrete change that resolves the warning, e.g. a focal length to use
+    #[serde(default)]
+    pub suggestion: Option<String>,
+    /// Key and parameters `message` was rendered from, for translation
+    #[serde(default)]
+    pub message_key: Option<MessageKey>,
+    /// Key and parameters `suggestion` was rendered from, for translation
+    #[serde(default)]
+    pub suggestion_key: Option<MessageKey>,
+}
+
+/// Input field referenced by a validation warning
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub struct ValidationField {
+    /// Field name as serialized, e.g. `sensor_width_mm`
+    pub field: String,
+    pub value: f64,
+}
+
+/// Stable identifier of a validation check
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub enum ValidationCode {
+    SensorWidthTooSmall,
+    SensorWidthTooLarge,
+    SensorHeightTooSmall,
+    SensorHeightTooLarge,
+    FocalLengthTooShort,
+    FocalLengthTooLong,
+    PixelWidthTooLow,
+    PixelWidthTooHigh,
+    PixelHeightTooLow,
+    PixelHeightTooHigh,
+    PixelPitchTooSmall,
+    PixelPitchTooLarge,
+    TStopFasterThanFNumber,
+    AspectRatioMismatch,
+    NonSquarePixels,
+    QuantumEfficiencyOutOfRange,
+    NegativeSensorValue,
+    ReadNoiseAboveFullWell,
+    FovTooWide,
+    FovTooNarrow,
+    PpmTooHigh,
+    PpmTooLow,
+    DetectionDistanceUnrealistic,
+    DoriOrderInvalid,
+    LensAliasing,
+    LensLimited,
+    AstroUndersampled,
+    AstroOversampled,
+    AltitudeAboveCeiling,
+    CornerIlluminationLow,
+    FaceIncidenceTooSteep,
+    SensorSizeAssumed,
+    FocalLengthInterpolated,
+    FocalLengthAssumed,
+    LinkCapacityExceeded,
+    PeakLinkCapacityExceeded,
+    RequirementNotMet,
+    RequirementNotEvaluated,
+    DoriTargetMissed,
+    DoriTargetMet,
+    ConstraintsInconsistent,
+    DoriTargetsInconsistent,
+    DoriTargetsInfeasible,
+    DoriTargetMissing,
+    DoriTargetNotPositive,
+    ConstraintNotPositive,
+    FovOutOfRange,
+    DoriTierUnreachable,
+}
+
+/// Severity level of validation warnings
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export))]
+pub enum ValidationSeverity {
+    /// Advice only; nothing is wrong
+    Info,
+    Warning,
+    Error,
+}
+
+impl ValidationWarning {
+    /// Create a warning without field references, with its message rendered in English
+    pub fn new(code: ValidationCode, severity: ValidationSeverity, message: MessageKey) -> Self {
+        Self {
+            code,
+            message: message.render(Locale::En),
+            severity,
+            fields: Vec::new(),
+            suggestion: None,
+            message_key: Some(message),
+            suggestion_key: None,
+        }
+    }
+
+    /// Reference an input field and its offending value
+    pub fn with_field(mut self, field: impl Into<String>, value: f64) -> Self {
+        self.fields.push(ValidationField {
+            field: field.into(),
+            value,
+        });
+        self
+    }
+
+    /// Attach a suggested fix
+    pub fn with_suggestion(mut self, suggestion: MessageKey) -> Self {
+        self.suggestion = Some(suggestion.render(Locale::En));
+        self.suggestion_key = Some(suggestion);
+        self
+    }
+
+    /// Re-render the message and suggestion in another language
+    ///
+    /// Warnings without a message key (e.g. deserialized from an older project) keep
+    /// their text.
+    pub fn localize(&self, locale: Locale) -> Self {
+        let mut warning = self.clone();
+        if let Some(message) = &self.message_key {
+            warning.message = message.render(locale);
+        }
+        if let Some(suggestion) = &self.suggestion_key {
+            warning.suggestion = Some(suggestion.render(locale));
+        }
+        warning
+    }
+}
+
+impl AngleOfView {
+    /// Message catalog key of the class name
+    pub fn term_key(self) -> &'static str {
+        match self {
+            AngleOfView::UltraWide => "angle_of_view.ultra_wide",
+            AngleOfView::Wide => "angle_of_view.wide",
+            AngleOfView::Normal => "angle_of_view.normal",
+            AngleOfView::ShortTele => "angle_of_view.short_tele",
+            AngleOfView::Tele => "angle_of_view.tele",
+            AngleOfView::SuperTele => "angle_of_view.super_tele",
+        }
+    }
+
+    /// Class name in the given language
+    pub fn label(&self, locale: Locale) -> String {
+        MessageKey::new(self.term_key()).render(locale)
+    }
+}
+
+impl ValidationSeverity {
+    /// Severity label in the given language
+    pub fn label(&self, locale: Locale) -> String {
+        let key = match self {
+            ValidationSeverity::Info => "severity.info",
+            ValidationSeverity::Warning => "severity.warning",
+            ValidationSeverity::Error => "severity.error",
+        };
+        MessageKey::new(key).render(locale)
+    }
+}
+
+impl DoriTargets {
+    /// Target distance for a DORI level, if one is given
+    pub fn distance_m(&self, level: DoriLevel) -> Option<f64> {
+        match level {
+            DoriLevel::Detection => self.detection_m,
+            DoriLevel::Observation => self.observation_m,
+            DoriLevel::Recognition => self.recognition_m,
+            DoriLevel::Identification => self.identification_m,
+        }
+    }
+
+    /// Check that at least one target is given and that all are positive
+    pub fn validate(&self) -> Vec<ValidationWarning> {
+        let mut warnings = Vec::new();
+
+        if DoriLevel::ALL
+            .iter()
+            .all(|&level| self.distance_m(level).is_none())
+        {
+            warnings.push(ValidationWarning::new(
+                ValidationCode::DoriTargetMissing,
+                ValidationSeverity::Error,
+                MessageKey::new("dori_request.no_target"),
+            ));
+        }
+        for level in DoriLevel::ALL {
+            if let Some(distance_m) = self.distance_m(level).filter(|m| *m <= 0.0) {
+                let name = format!("{:?}", level).to_lowercase();
+                warnings.push(
+                    ValidationWarning::new(
+                        ValidationCode::DoriTargetNotPositive,
+                        ValidationSeverity::Error,
+                        MessageKey::new("dori_request.target_not_positive")
+                            .with_term("level", format!("dori.level.{}", name)),
+                    )
+                    .with_field(format!("{}_m", name), distance_m),
+                );
+            }
+        }
+
+        warnings
+    }
+}
+
+impl ParameterConstraint {
+    /// Check fixed values for zero, negative or physically impossible settings
+    pub fn validate(&self) -> Vec<ValidationWarning> {
+        let mut warnings = Vec::new();
+
+        for (field, parameter, value) in [
+            (
+                "sensor_width_mm",
+                RangeParameter::SensorWidth,
+                self.sensor_width_mm,
+            ),
+            (
+                "sensor_height_mm",
+                RangeParameter::SensorHeight,
+                self.sensor_height_mm,
+            ),
+            (
+                "pixel_width",
+                RangeParameter::PixelWidth,
+                self.pixel_width.map(f64::from),
+            ),
+            (
+                "pixel_height",
+                RangeParameter::PixelHeight,
+                self.pixel_height.map(f64::from),
+            ),
+            (
+                "focal_length_mm",
+                RangeParameter::FocalLength,
+                self.focal_length_mm,
+            ),
+        ] {
+            if let Some(value) = value.filter(|value| *value <= 0.0) {
+                warnings.push(
+                    ValidationWarning::new(
+                        ValidationCode::ConstraintNotPositive,
+                        ValidationSeverity::Error,
+                        MessageKey::new("dori_request.constraint_not_positive")
+                            .with_term("parameter", parameter.term_key()),
+                    )
+                    .with_field(field, value),
+                );
+            }
+        }
+
+        for (field, value) in [
+            ("horizontal_fov_deg", self.horizontal_fov_deg),
+            ("vertical_fov_deg", self.vertical_fov_deg),
+            ("diagonal_fov_deg", self.diagonal_fov_deg),
+        ] {
+            if let Some(fov_deg) = value.filter(|fov| *fov <= 0.0 || *fov >= 180.0) {
+                warnings.push(
+                    ValidationWarning::new(
+                        ValidationCode::FovOutOfRange,
+                        ValidationSeverity::Error,
+                        MessageKey::new("dori_request.fov_out_of_range")
+                            .with_number("fov_deg", fov_deg),
+                    )
+                    .with_field(field, fov_deg),
+                );
+            }
+        }
+
+        // Same lower limit as for a complete camera (typical range: 0.5-20 µm)
+        if let (Some(sensor_w), Some(pixels)) = (self.sensor_width_mm, self.pixel_width) {
+            let pitch_um = sensor_w * 1000.0 / pixels as f64;
+            if sensor_w > 0.0 && pitch_um < 0.5 {
+                warnings.push(
+                    ValidationWarning::new(
+                        ValidationCode::PixelPitchTooSmall,
+                        ValidationSeverity::Error,
+                        MessageKey::new("camera.horizontal_pitch_too_small")
+                            .with_number("pitch_um", pitch_um),
+                    )
+                    .with_field("pixel_width", pixels as f64),
+                );
+            }
+        }
+
+        warnings
+    }
+}
+
+impl RangeParameter {
+    /// Message catalog key of the parameter's name
+    pub fn term_key(self) -> &'static str {
+        match self {
+            RangeParameter::SensorWidth => "parameter.sensor_width",
+            RangeParameter::SensorHeight => "parameter.sensor_height",
+            RangeParameter::PixelWidth => "parameter.pixel_width",
+            RangeParameter::PixelHeight => "parameter.pixel_height",
+            RangeParameter::FocalLength => "parameter.focal_length",
+            RangeParameter::HorizontalFov => "parameter.horizontal_fov",
+        }
+    }
+}
+
+impl DoriParameterRanges {
+    /// Warn when the fixed FOV, focal length and sensor width contradict each other,
+    /// when the DORI targets cannot be met within the parameter bounds, and note when
+    /// some targets are exceeded by meeting a stricter one
+    pub fn validate(&self) -> Vec<ValidationWarning> {
+        let mut warnings = Vec::new();
+
+        if let Some(governing) = &self.governing_target {
+            let level = format!("dori.level.{:?}", governing.level).to_lowercase();
+            for &parameter in &self.infeasible_parameters {
+                warnings.push(ValidationWarning::new(
+                    ValidationCode::DoriTargetsInfeasible,
+                    ValidationSeverity::Error,
+                    MessageKey::new("dori_ranges.infeasible")
+                        .with_term("level", level.as_str())
+                        .with_number("distance_m", governing.distance_m)
+                        .with_term("parameter", parameter.term_key()),
+                ));
+            }
+            if !governing.exceeded.is_empty() {
+                warnings.push(ValidationWarning::new(
+                    ValidationCode::DoriTargetsInconsistent,
+                    ValidationSeverity::Info,
+                    MessageKey::new("dori_ranges.targets_inconsistent")
+                        .with_term("level", level.as_str())
+                        .with_number("distance_m", governing.distance_m),
+                ));
+            }
+        }
+
+        let Some(inconsistency) = &self.inconsistency else {
+            return warnings;
+        };
+
+        warnings.push(
+            ValidationWarning::new(
+                ValidationCode::ConstraintsInconsistent,
+                ValidationSeverity::Error,
+                MessageKey::new("dori_ranges.inconsistent")
+                    .with_number("implied_fov_deg", inconsistency.implied_fov_deg)
+                    .with_number("discrepancy_deg", inconsistency.discrepancy_deg)
+                    .with_number("discrepancy_percent", inconsistency.discrepancy_percent),
+            )
+            .with_field(
+                "horizontal_fov_deg",
+                inconsistency.implied_fov_deg + inconsistency.discrepancy_deg,
+            )
+            .with_suggestion(
+                MessageKey::new("dori_ranges.inconsistent.suggestion")
+                    .with_number("horizontal_fov_deg", inconsistency.corrected_fov_deg)
+                    .with_number("focal_length_mm", inconsistency.corrected_focal_length_mm)
+                    .with_number("sensor_width_mm", inconsistency.corrected_sensor_width_mm),
+            ),
+        );
+        warnings
+    }
+}
+
+impl DoriDistances {
+    /// Distance at which a DORI level is reached
+    pub fn distance_m(&self, level: DoriLevel) -> f64 {
+        match level {
+            DoriLevel::Detection => self.detection_m,
+            DoriLevel::Observation => self.observation_m,
+            DoriLevel::Recognition => self.recognition_m,
+            DoriLevel::Identification => self.identification_m,
+        }
+    }
+}
+
+impl DoriLevel {
+    /// All levels from the lowest to the highest pixel density
+    pub const ALL: [DoriLevel; 4] = [
+        DoriLevel::Detection,
+        DoriLevel::Observation,
+        DoriLevel::Recognition,
+        DoriLevel::Identification,
+    ];
+
+    /// Highest level a pixel density reaches, `None` if below detection
+    pub fn highest_met(px_per_m: f64) -> Option<DoriLevel> {
+        Self::ALL
+            .into_iter()
+            .rev()
+            .find(|level| px_per_m >= level.px_per_m())
+    }
+
+    /// Standard horizontal pixel density for the level in px/m
+    pub fn px_per_m(self) -> f64 {
+        use super::constants::{
+            DETECTION_PX_PER_M, IDENTIFICATION_PX_PER_M, OBSERVATION_PX_PER_M, RECOGNITION_PX_PER_M,
+        };
+        match self {
+            DoriLevel::Detection => DETECTION_PX_PER_M,
+            DoriLevel::Observation => OBSERVATION_PX_PER_M,
+            DoriLevel::Recognition => RECOGNITION_PX_PER_M,
+            DoriLevel::Identification => IDENTIFICATION_PX_PER_M,
+        }
+    }
+}
+
+impl ZoneRequirement {
+    /// Required horizontal pixel density in px/m
+    pub fn px_per_m(self) -> f64 {
+        match self {
+            ZoneRequirement::PixelDensity(ppm) => ppm,
+            ZoneRequirement::Dori(level) => level.px_per_m(),
+        }
+    }
+}
+
+impl Default for SolverBounds {
+    /// Typical surveillance hardware: 640-8192 px, 3-50 mm 4:3 sensors, 2-400 mm lenses
+    fn default() -> Self {
+        Self {
+            min_pixel_width: 640,
+            max_pixel_width: 8192,
+            min_sensor_width_mm: 3.0,
+            max_sensor_width_mm: 50.0,
+            min_focal_length_mm: 2.0,
+            max_focal_length_mm: 400.0,
+            default_aspect_ratio: 4.0 / 3.0,
+        }
+    }
+}
+
+impl Default for ScoringWeights {
+    /// Every criterion weighted equally
+    fn default() -> Self {
+        Self {
+            resolution: 1.0,
+            fov: 1.0,
+            low_light: 1.0,
+            dori_reach: 1.0,
+        }
+    }
+}
+
+impl CameraSystem {
+    /// Create a new camera system
+    pub fn new(
+        sensor_width_mm: f64,
+        sensor_height_mm: f64,
+        pixel_width: u32,
+        pixel_height: u32,
+        focal_length_mm: f64,
+    ) -> Self {
+        Self {
+            sensor_width_mm,
+            sensor_height_mm,
+            pixel_width,
+            pixel_height,
+            focal_length_mm,
+            name: None,
+            f_number: None,
+            t_stop: None,
+            quantum_efficiency: None,
+            read_noise_e: None,
+            full_well_e: None,
+            dark_current_e_s: None,
+            lens_resolution_lp_mm: None,
+            dynamic_range_db: None,
+            night_mode: None,
+        }
+    }
+
+    /// Create a camera system from its resolution and pixel pitch instead of sensor size
+    ///
+    /// Industrial sensor datasheets usually lead with the pitch; the sensor
+    /// dimensions follow as pixel count × pitch. Pixels are assumed square.
+    pub fn from_pixel_pitch(
+        pixel_width: u32,
+        pixel_height: u32,
+        pixel_pitch_um: f64,
+        focal_length_mm: f64,
+    ) -> Self {
+        // Rounded to the nanometre, so 2048 × 3.45 µm reads back as 7.0656 mm
+        let sensor_mm = |pixels: u32| (pixels as f64 * pixel_pitch_um * 1000.0).round() / 1e6;
+        Self::new(
+            sensor_mm(pixel_width),
+            sensor_mm(pixel_height),
+            pixel_width,
+            pixel_height,
+            focal_length_mm,
+        )
+    }
+
+    /// Set a name for this camera system
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Set the lens aperture (f-number) for this camera system
+    pub fn with_f_number(mut self, f_number: f64) -> Self {
+        self.f_number = Some(f_number);
+        self
+    }
+
+    /// Set the lens transmission stop (T-stop) for this camera system
+    pub fn with_t_stop(mut self, t_stop: f64) -> Self {
+        self.t_stop = Some(t_stop);
+        self
+    }
+
+    /// Stop that governs the light reaching the sensor
+    ///
+    /// The T-stop if specified, otherwise the f-number. Depth of field is always
+    /// driven by the f-number.
+    pub fn light_stop(&self) -> Option<f64> {
+        self.t_stop.or(self.f_number)
+    }
+
+    /// Set the sensor quantum efficiency (0-1) for this camera system
+    pub fn with_quantum_efficiency(mut self, quantum_efficiency: f64) -> Self {
+        self.quantum_efficiency = Some(quantum_efficiency);
+        self
+    }
+
+    /// Set the sensor read noise in electrons RMS
+    pub fn with_read_noise(mut self, read_noise_e: f64) -> Self {
+        self.read_noise_e = Some(read_noise_e);
+        self
+    }
+
+    /// Set the sensor full-well capacity in electrons
+    pub fn with_full_well(mut self, full_well_e: f64) -> Self {
+        self.full_well_e = Some(full_well_e);
+        self
+    }
+
+    /// Set the sensor dark current in electrons per pixel per second
+    pub fn with_dark_current(mut self, dark_current_e_s: f64) -> Self {
+        self.dark_current_e_s = Some(dark_current_e_s);
+        self
+    }
+
+    /// Set the lens resolving power in line pairs per millimeter
+    pub fn with_lens_resolution(mut self, lens_resolution_lp_mm: f64) -> Self {
+        self.lens_resolution_lp_mm = Some(lens_resolution_lp_mm);
+        self
+    }
+
+    /// Set the specified (wide) dynamic range in dB
+    pub fn with_dynamic_range(mut self, dynamic_range_db: f64) -> Self {
+        self.dynamic_range_db = Some(dynamic_range_db);
+        self
+    }
+
+    /// Set the mono IR night mode of a day/night camera
+    pub fn with_night_mode(mut self, night_mode: NightMode) -> Self {
+        self.night_mode = Some(night_mode);
+        self
+    }
+
+    /// The camera as it operates in night mode, if it has one
+    ///
+    /// Binning divides the pixel counts and the night mode quantum efficiency,
+    /// when given, replaces the day one.
+    pub fn night(&self) -> Option<CameraSystem> {
+        let night_mode = self.night_mode.as_ref()?;
+        let binning = night_mode.binning.max(1);
+        let mut camera = self.clone();
+        camera.pixel_width /= binning;
+        camera.pixel_height /= binning;
+        if let Some(quantum_efficiency) = night_mode.quantum_efficiency {
+            camera.quantum_efficiency = Some(quantum_efficiency);
+        }
+        camera.night_mode = None;
+        Some(camera)
+    }
+
+    /// Get pixel pitch in micrometers
+    pub fn pixel_pitch_um(&self) -> (f64, f64) {
+        let h_pitch = (self.sensor_width_mm * 1000.0) / self.pixel_width as f64;
+        let v_pitch = (self.sensor_height_mm * 1000.0) / self.pixel_height as f64;
+        (h_pitch, v_pitch)
+    }
+
+    /// Width over height of a single pixel, 1.0 for square pixels
+    pub fn pixel_aspect_ratio(&self) -> f64 {
+        let (h_pitch, v_pitch) = self.pixel_pitch_um();
+        h_pitch / v_pitch
+    }
+
+    /// Pitch of the coarser pixel axis in µm, which limits detail on non-square pixels
+    pub fn coarse_pixel_pitch_um(&self) -> f64 {
+        let (h_pitch, v_pitch) = self.pixel_pitch_um();
+        h_pitch.max(v_pitch)
+    }
+
+    /// Circle of confusion of two pixels along the coarser axis, in mm
+    pub fn default_coc_mm(&self) -> f64 {
+        2.0 * self.coarse_pixel_pitch_um() / 1000.0
+    }
+
+    /// Validate the camera system configuration and return any warnings
+    pub fn validate(&self) -> Vec<ValidationWarning> {
+        let mut warnings = Vec::new();
+
+        // Check sensor dimensions (typical range: 1-100mm)
+        if self.sensor_width_mm < 1.0 {
+            warnings.push(
+                ValidationWarning::new(
+                    ValidationCode::SensorWidthTooSmall,
+                    ValidationSeverity::Error,
+                    MessageKey::new("camera.sensor_width_too_small")
+                        .with_number("sensor_width_mm", self.sensor_width_mm),
+                )
+                .with_field("sensor_width_mm", self.sensor_width_mm),
+            );
+        }
+        if self.sensor_width_mm > 100.0 {
+            warnings.push(
+                ValidationWarning::new(
+                    ValidationCode::SensorWidthTooLarge,
+                    ValidationSeverity::Warning,
+                    MessageKey::new("camera.sensor_width_too_large")
+                        .with_number("sensor_width_mm", self.sensor_width_mm),
+                )
+                .with_field("sensor_width_mm", self.sensor_width_mm),
+            );
+        }
+
+        if self.sensor_height_mm < 1.0 {
+            warnings.push(
+                ValidationWarning::new(
+                    ValidationCode::SensorHeightTooSmall,
+                    ValidationSeverity::Error,
+                    MessageKey::new("camera.sensor_height_too_small")
+                        .with_number("sensor_height_mm", self.sensor_height_mm),
+                )
+                .with_field("sensor_height_mm", self.sensor_height_mm),
+            );
+        }
+        if self.sensor_height_mm > 100.0 {
+            warnings.push(
+                ValidationWarning::new(
+                    ValidationCode::SensorHeightTooLarge,
+                    ValidationSeverity::Warning,
+                    MessageKey::new("camera.sensor_height_too_large")
+                        .with_number("sensor_height_mm", self.sensor_height_mm),
+                )
+                .with_field("sensor_height_mm", self.sensor_height_mm),
+            );
+        }
+
+        // Check focal length (typical range: 1-2000mm)
+        if self.focal_length_mm < 1.0 {
+            warnings.push(
+                ValidationWarning::new(
+                    ValidationCode::FocalLengthTooShort,
+                    ValidationSeverity::Error,
+                    MessageKey::new("camera.focal_length_too_short")
+                        .with_number("focal_length_mm", self.focal_length_mm),
+                )
+                .with_field("focal_length_mm", self.focal_length_mm),
+            );
+        }
+        if self.focal_length_mm > 2000.0 {
+            warnings.push(
+                ValidationWarning::new(
+                    ValidationCode::FocalLengthTooLong,
+                    ValidationSeverity::Warning,
+                    MessageKey::new("camera.focal_length_too_long")
+                        .with_number("focal_length_mm", self.focal_length_mm),
+                )
+                .with_field("focal_length_mm", self.focal_length_mm),
+            );
+        }
+
+        // Check resolution (typical range: 100-50000 pixels)
+        if self.pixel_width < 100 {
+            warnings.push(
+                ValidationWarning::new(
+                    ValidationCode::PixelWidthTooLow,
+                    ValidationSeverity::Error,
+                    MessageKey::new("camera.pixel_width_too_low")
+                        .with_number("pixel_width", self.pixel_width as f64),
+                )
+                .with_field("pixel_width", self.pixel_width as f64),
+            );
+        }
+        if self.pixel_width > 50000 {
+            warnings.push(
+                ValidationWarning::new(
+                    ValidationCode::PixelWidthTooHigh,
+                    ValidationSeverity::Warning,
+                    MessageKey::new("camera.pixel_width_too_high")
+                        .with_number("pixel_width", self.pixel_width as f64),
+                )
+                .with_field("pixel_width", self.pixel_width as f64),
+            );
+        }
+
+        if self.pixel_height < 100 {
+            warnings.push(
+                ValidationWarning::new(
+                    ValidationCode::PixelHeightTooLow,
+                    ValidationSeverity::Error,
+                    MessageKey::new("camera.pixel_height_too_low")
+                        .with_number("pixel_height", self.pixel_height as f64),
+                )
+                .with_field("pixel_height", self.pixel_height as f64),
+            );
+        }
+        if self.pixel_height > 50000 {
+            warnings.push(
+                ValidationWarning::new(
+                    ValidationCode::PixelHeightTooHigh,
+                    ValidationSeverity::Warning,
+                    MessageKey::new("camera.pixel_height_too_high")
+                        .with_number("pixel_height", self.pixel_height as f64),
+                )
+                .with_field("pixel_height", self.pixel_height as f64),
+            );
+        }
+
+        // Check pixel pitch (typical range: 0.5-20 µm)
+        let (h_pitch, v_pitch) = self.pixel_pitch_um();
+        if h_pitch < 0.5 {
+            warnings.push(
+                ValidationWarning::new(
+                    ValidationCode::PixelPitchTooSmall,
+                    ValidationSeverity::Error,
+                    MessageKey::new("camera.horizontal_pitch_too_small")
+                        .with_number("pitch_um", h_pitch),
+                )
+                .with_field("sensor_width_mm", self.sensor_width_mm)
+                .with_field("pixel_width", self.pixel_width as f64),
+            );
+        }
+        if h_pitch > 20.0 {
+            warnings.push(
+                ValidationWarning::new(
+                    ValidationCode::PixelPitchTooLarge,
+                    ValidationSeverity::Warning,
+                    MessageKey::new("camera.horizontal_pitch_too_large")
+                        .with_number("pitch_um", h_pitch),
+                )
+                .with_field("sensor_width_mm", self.sensor_width_mm)
+                .with_field("pixel_width", self.pixel_width as f64),
+            );
+        }
+
+        if v_pitch < 0.5 {
+            warnings.push(
+                ValidationWarning::new(
+                    ValidationCode::PixelPitchTooSmall,
+                    ValidationSeverity::Error,
+                    MessageKey::new("camera.vertical_pitch_too_small")
+                        .with_number("pitch_um", v_pitch),
+                )
+                .with_field("sensor_height_mm", self.sensor_height_mm)
+                .with_field("pixel_height", self.pixel_height as f64),
+            );
+        }
+        if v_pitch > 20.0 {
+            warnings.push(
+                ValidationWarning::new(
+                    ValidationCode::PixelPitchTooLarge,
+                    ValidationSeverity::Warning,
+                    MessageKey::new("camera.vertical_pitch_too_large")
+                        .with_number("pitch_um", v_pitch),
+                )
+                .with_field("sensor_height_mm", self.sensor_height_mm)
+                .with_field("pixel_height", self.pixel_height as f64),
+            );
+        }
+
+        // A lens cannot transmit more light than its geometric aperture allows
+        if let (Some(t_stop), Some(f_number)) = (self.t_stop, self.f_number) {
+            if t_stop < f_number {
+                warnings.push(
+                    ValidationWarning::new(
+                        ValidationCode::TStopFasterThanFNumber,
+                        ValidationSeverity::Error,
+                        MessageKey::new("camera.t_stop_faster_than_f_number")
+                            .with_number("t_stop", t_stop)
+                            .with_number("f_number", f_number),
+                    )
+                    .with_field("t_stop", t_stop)
+                    .with_field("f_number", f_number),
+                );
+            }
+        }
+
+        // Check aspect ratio consistency (sensor vs pixel)
+        let sensor_aspect = self.sensor_width_mm / self.sensor_height_mm;
+        let pixel_aspect = self.pixel_width as f64 / self.pixel_height as f64;
+
+        // Allow 5% tolerance for aspect ratio mismatch
+        let aspect_tolerance = 0.05;
+        let aspect_diff_percent = ((sensor_aspect - pixel_aspect).abs() / sensor_aspect) * 100.0;
+
+        if (sensor_aspect - pixel_aspect).abs() / sensor_aspect > aspect_tolerance {
+            warnings.push(
+                ValidationWarning::new(
+                    ValidationCode::AspectRatioMismatch,
+                    ValidationSeverity::Error,
+                    MessageKey::new("camera.aspect_ratio_mismatch")
+                        .with_number("sensor_aspect", sensor_aspect)
+                        .with_number("pixel_aspect", pixel_aspect)
+                        .with_number("difference_percent", aspect_diff_percent),
+                )
+                .with_field("sensor_width_mm", self.sensor_width_mm)
+                .with_field("sensor_height_mm", self.sensor_height_mm)
+                .with_field("pixel_width", self.pixel_width as f64)
+                .with_field("pixel_height", self.pixel_height as f64),
+            );
+        }
+
+        // Check that pixel pitch is consistent in both dimensions (square pixels)
+        let pitch_diff_percent = ((h_pitch - v_pitch).abs() / h_pitch) * 100.0;
+        if pitch_diff_percent > 5.0 {
+            warnings.push(
+                ValidationWarning::new(
+                    ValidationCode::NonSquarePixels,
+                    ValidationSeverity::Warning,
+                    MessageKey::new("camera.non_square_pixels")
+                        .with_number("horizontal_pitch_um", h_pitch)
+                        .with_number("vertical_pitch_um", v_pitch)
+                        .with_number("difference_percent", pitch_diff_percent),
+                )
+                .with_field("sensor_width_mm", self.sensor_width_mm)
+                .with_field("sensor_height_mm", self.sensor_height_mm)
+                .with_field("pixel_width", self.pixel_width as f64)
+                .with_field("pixel_height", self.pixel_height as f64),
+            );
+        }
+
+        // Check sensor noise characteristics if specified
+        if let Some(qe) = self.quantum_efficiency {
+            if qe <= 0.0 || qe > 1.0 {
+                warnings.push(
+                    ValidationWarning::new(
+                        ValidationCode::QuantumEfficiencyOutOfRange,
+                        ValidationSeverity::Error,
+                        MessageKey::new("camera.quantum_efficiency_out_of_range")
+                            .with_number("quantum_efficiency", qe),
+                    )
+                    .with_field("quantum_efficiency", qe),
+                );
+            }
+        }
+        for (field, value) in [
+            ("read_noise_e", self.read_noise_e),
+            ("full_well_e", self.full_well_e),
+            ("dark_current_e_s", self.dark_current_e_s),
+        ] {
+            if let Some(value) = value.filter(|value| *value < 0.0) {
+                warnings.push(
+                    ValidationWarning::new(
+                        ValidationCode::NegativeSensorValue,
+                        ValidationSeverity::Error,
+                        MessageKey::new(format!("camera.{}_negative", field)),
+                    )
+                    .with_field(field, value),
+                );
+            }
+        }
+        if let (Some(read_noise), Some(full_well)) = (self.read_noise_e, self.full_well_e) {
+            if read_noise >= full_well {
+                warnings.push(
+                    ValidationWarning::new(
+                        ValidationCode::ReadNoiseAboveFullWell,
+                        ValidationSeverity::Error,
+                        MessageKey::new("camera.read_noise_above_full_well")
+                            .with_number("read_noise_e", read_noise)
+                            .with_number("full_well_e", full_well),
+                    )
+                    .with_field("read_noise_e", read_noise)
+                    .with_field("full_well_e", full_well),
+                );
+            }
+        }
+
+        // Check lens/sensor sampling match if the lens resolving power is known
+        if let Some(lens_resolution) = self.lens_resolution_lp_mm {
+            warnings
+                .extend(super::resolution::calculate_sampling(self, lens_resolution).validate());
+        }
+
+        warnings
+    }
+
+    /// Get aspect ratio (width / height)
+    pub fn aspect_ratio(&self) -> (f64, f64) {
+        let sensor_aspect = self.sensor_width_mm / self.sensor_height_mm;
+        let pixel_aspect = self.pixel_width as f64 / self.pixel_height as f64;
+        (sensor_aspect, pixel_aspect)
+    }
+}
+
+impl CameraSystem {
+    /// One-line summary of the camera, as a translatable message
+    pub fn summary(&self) -> MessageKey {
+        let (h_pitch, v_pitch) = self.pixel_pitch_um();
+        let summary = match &self.name {
+            Some(name) => MessageKey::new("camera.summary").with_text("name", name.as_str()),
+            None => MessageKey::new("camera.summary").with_term("name", "camera.unnamed"),
+        };
+        summary
+            .with_number("sensor_width_mm", self.sensor_width_mm)
+            .with_number("sensor_height_mm", self.sensor_height_mm)
+            .with_number("pixel_width", self.pixel_width as f64)
+            .with_number("pixel_height", self.pixel_height as f64)
+            .with_number("horizontal_pitch_um", h_pitch)
+            .with_number("vertical_pitch_um", v_pitch)
+            .with_number("pixel_aspect_ratio", h_pitch / v_pitch)
+            .with_number("focal_length_mm", self.focal_length_mm)
+    }
+}
+
+impl std::fmt::Display for CameraSystem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.summary().render(Locale::En))
+    }
+}
+
+impl AstroSampling {
+    /// Validate the sampling of the seeing disk and return any warnings
+    pub fn validate(&self) -> Vec<ValidationWarning> {
+        let mut warnings = Vec::new();
+
+        if self.undersampled {
+            warnings.push(
+                ValidationWarning::new(
+                    ValidationCode::AstroUndersampled,
+                    ValidationSeverity::Warning,
+                    MessageKey::new("astro.undersampled")
+                        .with_number("pixel_scale_arcsec", self.pixel_scale_arcsec)
+                        .with_number("pixels_per_fwhm", self.pixels_per_fwhm)
+                        .with_number("seeing_arcsec", self.seeing_arcsec),
+                )
+                .with_field("pixel_scale_arcsec", self.pixel_scale_arcsec)
+                .with_field("seeing_arcsec", self.seeing_arcsec)
+                .with_suggestion(
+                    MessageKey::new("astro.undersampled.suggestion")
+                        .with_number("focal_length_mm", self.optimal_focal_length_mm.min),
+                ),
+            );
+        }
+        if self.oversampled {
+            warnings.push(
+                ValidationWarning::new(
+                    ValidationCode::AstroOversampled,
+                    ValidationSeverity::Warning,
+                    MessageKey::new("astro.oversampled")
+                        .with_number("pixel_scale_arcsec", self.pixel_scale_arcsec)
+                        .with_number("pixels_per_fwhm", self.pixels_per_fwhm)
+                        .with_number("seeing_arcsec", self.seeing_arcsec),
+                )
+                .with_field("pixel_scale_arcsec", self.pixel_scale_arcsec)
+                .with_field("seeing_arcsec", self.seeing_arcsec)
+                .with_suggestion(
+                    MessageKey::new("astro.oversampled.suggestion")
+                        .with_number("focal_length_mm", self.optimal_focal_length_mm.max),
+                ),
+            );
+        }
+
+        warnings
+    }
+}
+
+impl FovResult {
+    /// Validate the FOV result and return any warnings
+    pub fn validate(&self) -> Vec<ValidationWarning> {
+        let mut warnings = Vec::new();
+
+        // Check FOV angles (should be between 0 and 180 degrees)
+        if self.horizontal_fov_deg > 180.0 {
+            warnings.push(
+                ValidationWarning::new(
+                    ValidationCode::FovTooWide,
+                    ValidationSeverity::Error,
+                    MessageKey::new("fov.horizontal_too_wide")
+                        .with_number("horizontal_fov_deg", self.horizontal_fov_deg),
+                )
+                .with_field("horizontal_fov_deg", self.horizontal_fov_deg),
+            );
+        }
+        if self.horizontal_fov_deg < 0.1 {
+            warnings.push(
+                ValidationWarning::new(
+                    ValidationCode::FovTooNarrow,
+                    ValidationSeverity::Warning,
+                    MessageKey::new("fov.horizontal_too_narrow")
+                        .with_number("horizontal_fov_deg", self.horizontal_fov_deg),
+                )
+                .with_field("horizontal_fov_deg", self.horizontal_fov_deg),
+            );
+        }
+
+        if self.vertical_fov_deg > 180.0 {
+            warnings.push(
+                ValidationWarning::new(
+                    ValidationCode::FovTooWide,
+                    ValidationSeverity::Error,
+                    MessageKey::new("fov.vertical_too_wide")
+                        .with_number("vertical_fov_deg", self.vertical_fov_deg),
+                )
+                .with_field("vertical_fov_deg", self.vertical_fov_deg),
+            );
+        }
+        if self.vertical_fov_deg < 0.1 {
+            warnings.push(
+                ValidationWarning::new(
+                    ValidationCode::FovTooNarrow,
+                    ValidationSeverity::Warning,
+                    MessageKey::new("fov.vertical_too_narrow")
+                        .with_number("vertical_fov_deg", self.vertical_fov_deg),
+                )
+                .with_field("vertical_fov_deg", self.vertical_fov_deg),
+            );
+        }
+
+        // Check for unrealistic PPM values
+        if self.horizontal_ppm > 100000.0 || self.vertical_ppm > 100000.0 {
+            warnings.push(
+                ValidationWarning::new(
+                    ValidationCode::PpmTooHigh,
+                    ValidationSeverity::Warning,
+                    MessageKey::new("fov.ppm_too_high")
+                        .with_number("horizontal_ppm", self.horizontal_ppm)
+                        .with_number("vertical_ppm", self.vertical_ppm),
+                )
+                .with_field("horizontal_ppm", self.horizontal_ppm)
+                .with_field("vertical_ppm", self.vertical_ppm),
+            );
+        }
+        if self.horizontal_ppm < 0.001 || self.vertical_ppm < 0.001 {
+            warnings.push(
+                ValidationWarning::new(
+                    ValidationCode::PpmTooLow,
+                    ValidationSeverity::Warning,
+                    MessageKey::new("fov.ppm_too_low")
+                        .with_number("horizontal_ppm", self.horizontal_ppm)
+                        .with_number("vertical_ppm", self.vertical_ppm),
+                )
+                .with_field("horizontal_ppm", self.horizontal_ppm)
+                .with_field("vertical_ppm", self.vertical_ppm),
+            );
+        }
+
+        // Check DORI distances if available
+        if let Some(dori) = &self.dori {
+            // Detection distance should be reasonable (0.1m - 10,000m)
+            if dori.detection_m < 0.1 || dori.detection_m > 10000.0 {
+                warnings.push(
+                    ValidationWarning::new(
+                        ValidationCode::DetectionDistanceUnrealistic,
+                        ValidationSeverity::Warning,
+                        MessageKey::new("dori.detection_unrealistic")
+                            .with_number("detection_m", dori.detection_m),
+                    )
+                    .with_field("detection_m", dori.detection_m),
+                );
+            }
+
+            // DORI distances should be in descending order (D > O > R > I)
+            if dori.detection_m < dori.observation_m {
+                warnings.push(
+                    ValidationWarning::new(
+                        ValidationCode::DoriOrderInvalid,
+                        ValidationSeverity::Error,
+                        MessageKey::new("dori.detection_within_observation"),
+                    )
+                    .with_field("detection_m", dori.detection_m)
+                    .with_field("observation_m", dori.observation_m),
+                );
+            }
+            if dori.observation_m < dori.recognition_m {
+                warnings.push(
+                    ValidationWarning::new(
+                        ValidationCode::DoriOrderInvalid,
+                        ValidationSeverity::Error,
+                        MessageKey::new("dori.observation_within_recognition"),
+                    )
+                    .with_field("observation_m", dori.observation_m)
+                    .with_field("recognition_m", dori.recognition_m),
+                );
+            }
+            if dori.recognition_m < dori.identification_m {
+                warnings.push(
+                    ValidationWarning::new(
+                        ValidationCode::DoriOrderInvalid,
+                        ValidationSeverity::Error,
+                        MessageKey::new("dori.recognition_within_identification"),
+                    )
+                    .with_field("recognition_m", dori.recognition_m)
+                    .with_field("identification_m", dori.identification_m),
+                );
+            }
+        }
+
+        warnings
+    }
+}
+
+impl SamplingAnalysis {
+    /// Validate the lens/sensor sampling match and return any warnings
+    pub fn validate(&self) -> Vec<ValidationWarning> {
+        let mut warnings = Vec::new();
+
+        if self.aliasing_likely {
+            warnings.push(
+                ValidationWarning::new(
+                    ValidationCode::LensAliasing,
+                    ValidationSeverity::Warning,
+                    MessageKey::new("sampling.aliasing")
+                        .with_number("lens_resolution_lp_mm", self.lens_resolution_lp_mm)
+                        .with_number("nyquist_lp_mm", self.nyquist_lp_mm),
+                )
+                .with_field("lens_resolution_lp_mm", self.lens_resolution_lp_mm),
+            );
+        }
+        if self.lens_limited {
+            warnings.push(
+                ValidationWarning::new(
+                    ValidationCode::LensLimited,
+                    ValidationSeverity::Warning,
+                    MessageKey::new("sampling.lens_limited")
+                        .with_number("lens_resolution_lp_mm", self.lens_resolution_lp_mm)
+                        .with_number("nyquist_lp_mm", self.nyquist_lp_mm),
+                )
+                .with_field("lens_resolution_lp_mm", self.lens_resolution_lp_mm)
+                .with_suggestion(
+                    MessageKey::new("sampling.lens_limited.suggestion")
+                        .with_number("lens_resolution_lp_mm", self.nyquist_lp_mm / 2.0),
+                ),
+            );
+        }
+
+        warnings
+    }
+}
+
+impl WdrScenePreset {
+    /// Representative luminances of the scene
+    pub fn luminance(self) -> SceneLuminance {
+        let (bright_cd_m2, dark_cd_m2) = match self {
+            WdrScenePreset::Indoor => (500.0, 10.0),
+            WdrScenePreset::SunAndShade => (8_000.0, 50.0),
+            WdrScenePreset::EntranceLobby => (10_000.0, 2.0),
+            WdrScenePreset::CarParkExit => (10_000.0, 0.5),
+            WdrScenePreset::HeadlightsAtNight => (50_000.0, 0.05),
+        };
+        SceneLuminance {
+            bright_cd_m2,
+            dark_cd_m2,
+        }
+    }
+}
+
+impl WdrScene {
+    /// Luminances of the scene, resolving presets
+    pub fn luminance(self) -> SceneLuminance {
+        match self {
+            WdrScene::Preset(preset) => preset.luminance(),
+            WdrScene::Custom(luminance) => luminance,
+        }
+    }
+}
+
+impl PlateRegion {
+    /// Standard plate dimensions of the region
+    pub fn plate(self) -> PlateSpec {
+        match self {
+            PlateRegion::Eu => PlateSpec {
+                width_mm: 520.0,
+                height_mm: 110.0,
+                character_height_mm: 75.0,
+            },
+            PlateRegion::Uk => PlateSpec {
+                width_mm: 520.0,
+                height_mm: 111.0,
+                character_height_mm: 79.0,
+            },
+            PlateRegion::Us => PlateSpec {
+                width_mm: 305.0,
+                height_mm: 152.0,
+                character_height_mm: 70.0,
+            },
+        }
+    }
+}
+
+impl FlightAltitude {
+    /// Warn when the altitude needed for the GSD is above the regulatory ceiling
+    pub fn validate(&self) -> Vec<ValidationWarning> {
+        let mut warnings = Vec::new();
+
+        if self.exceeds_ceiling {
+            warnings.push(
+                ValidationWarning::new(
+                    ValidationCode::AltitudeAboveCeiling,
+                    ValidationSeverity::Error,
+                    MessageKey::new("aerial.altitude_above_ceiling")
+                        .with_number("gsd_cm", self.gsd_cm)
+                        .with_number("altitude_m", self.altitude_m)
+                        .with_number("max_altitude_m", self.max_altitude_m),
+                )
+                .with_field("gsd_cm", self.gsd_cm)
+                .with_field("max_altitude_m", self.max_altitude_m),
+            );
+        }
+
+        warnings
+    }
+}
+
+impl VignettingProfile {
+    /// Warn when the corners fall below the illumination threshold
+    pub fn validate(&self) -> Vec<ValidationWarning> {
+        let mut warnings = Vec::new();
+
+        if self.below_threshold {
+            warnings.push(
+                ValidationWarning::new(
+                    ValidationCode::CornerIlluminationLow,
+                    ValidationSeverity::Warning,
+                    MessageKey::new("vignetting.corner_illumination_low")
+                        .with_number("corner_percent", self.corner_percent)
+                        .with_number("corner_falloff_stops", self.corner_falloff_stops)
+                        .with_number("min_corner_percent", self.min_corner_percent),
+                )
+                .with_field("min_corner_percent", self.min_corner_percent),
+            );
+        }
+
+        warnings
+    }
+}
+
+impl FaceIncidenceAnalysis {
+    /// Warn when the camera looks down too steeply for facial identification
+    pub fn validate(&self) -> Vec<ValidationWarning> {
+        let mut warnings = Vec::new();
+        let max_slope = self.max_incidence_deg.to_radians().tan();
+
+        if self.exceeds_limit {
+            warnings.push(
+                ValidationWarning::new(
+                    ValidationCode::FaceIncidenceTooSteep,
+                    ValidationSeverity::Warning,
+                    MessageKey::new("incidence.too_steep")
+                        .with_number("incidence_deg", self.incidence_deg)
+                        .with_number("max_incidence_deg", self.max_incidence_deg),
+                )
+                .with_field("max_incidence_deg", self.max_incidence_deg)
+                .with_suggestion(
+                    MessageKey::new("incidence.too_steep.suggestion")
+                        .with_number(
+                            "mounting_height_m",
+                            self.face_height_m + self.distance_m * max_slope,
+                        )
+                        .with_number(
+                            "distance_m",
+                            (self.mounting_height_m - self.face_height_m) / max_slope,
+                        ),
+                ),
+            );
+        }
+
+        warnings
+    }
+}
+
+impl GroundDoriDistances {
+    /// Warn for each DORI tier the mounting geometry keeps out of reach
+    pub fn validate(&self) -> Vec<ValidationWarning> {
+        let mut warnings = Vec::new();
+        let drop_m = self.mounting_height_m - self.target_height_m;
+
+        for tier in self.tiers.iter().filter(|tier| !tier.reachable) {
+            let level_term = format!("dori.level.{:?}", tier.level).to_lowercase();
+            let (message, suggestion) = match tier.ground_m {
+                None => (
+                    MessageKey::new("dori_ground.below_mount")
+                        .with_term("level", level_term.as_str())
+                        .with_number("slant_m", tier.slant_m)
+                        .with_number("mounting_height_m", self.mounting_height_m)
+                        .with_number("target_height_m", self.target_height_m),
+                    MessageKey::new("dori_ground.below_mount.suggestion")
+                        .with_number("mounting_height_m", self.target_height_m + tier.slant_m)
+                        .with_term("level", level_term.as_str())
+                        .with_number("target_height_m", self.target_height_m),
+                ),
+                Some(ground_m) => (
+                    MessageKey::new("dori_ground.in_blind_spot")
+                        .with_term("level", level_term.as_str())
+                        .with_number("ground_m", ground_m)
+                        .with_number("blind_spot_m", self.blind_spot_m.unwrap_or(f64::INFINITY)),
+                    // Tilt that brings the bottom edge of the frame down to the tier's end
+                    MessageKey::new("dori_ground.in_blind_spot.suggestion").with_number(
+                        "tilt_deg",
+                        self.vertical_fov_deg / 2.0 - drop_m.atan2(ground_m).to_degrees(),
+                    ),
+                ),
+            };
+            warnings.push(
+                ValidationWarning::new(
+                    ValidationCode::DoriTierUnreachable,
+                    ValidationSeverity::Warning,
+                    message,
+                )
+                .with_field("mounting_height_m", self.mounting_height_m)
+                .with_field("tilt_deg", self.tilt_deg)
+                .with_suggestion(suggestion),
+            );
+        }
+
+        warnings
+    }
+}
+
+impl FovResult {
+    /// Angular/linear FOV and resolution summary, as a translatable message
+    pub fn summary(&self) -> MessageKey {
+        MessageKey::new("fov.summary")
+            .with_number("horizontal_fov_deg", self.horizontal_fov_deg)
+            .with_number("vertical_fov_deg", self.vertical_fov_deg)
+            .with_number("horizontal_fov_m", self.horizontal_fov_m)
+            .with_number("vertical_fov_m", self.vertical_fov_m)
+            .with_number("distance_m", self.distance_m)
+            .with_number("horizontal_ppm", self.horizontal_ppm)
+            .with_number("vertical_ppm", self.vertical_ppm)
+            .with_number("horizontal_ifov_urad", self.horizontal_ifov_urad)
+            .with_number("vertical_ifov_urad", self.vertical_ifov_urad)
+            .with_number("diagonal_fov_deg", self.diagonal_fov_deg)
+            .with_number("horizontal_px_per_deg", self.horizontal_px_per_deg)
+            .with_number("horizontal_ppf", self.horizontal_ppf)
+            .with_number("vertical_ppf", self.vertical_ppf)
+            .with_number("megapixels_per_m2", self.megapixels_per_m2)
+            .with_term(
+                "angle_of_view",
+                self.angle_of_view
+                    .map_or("angle_of_view.unknown", AngleOfView::term_key),
+            )
+    }
+}
+
+impl std::fmt::Display for FovResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.summary().render(Locale::En))
+    }
+}
@@ -0,0 +1,134 @@
+use super::calculations::{calculate_dori_distances, calculate_fov};
+use super::types::{UncertainValue, UncertaintyParams, UncertaintyResult};
+
+impl UncertainValue {
+    fn new(value: f64, uncertainty: f64) -> Self {
+        Self {
+            value,
+            uncertainty,
+            relative_percent: if value != 0.0 {
+                uncertainty / value.abs() * 100.0
+            } else {
+                0.0
+            },
+        }
+    }
+
+    /// Value with an uncertainty given relative to it
+    fn relative(value: f64, relative: f64) -> Self {
+        Self::new(value, value.abs() * relative)
+    }
+}
+
+/// Angular FOV uncertainty in degrees for θ = 2·atan(s / 2f)
+///
+/// ∂θ/∂s = 1 / (f·(1 + (s/2f)²)) and ∂θ/∂f = -s / (f²·(1 + (s/2f)²))
+fn angle_uncertainty_deg(sensor_mm: f64, focal_mm: f64, d_sensor: f64, d_focal: f64) -> f64 {
+    let k = 1.0 + (sensor_mm / (2.0 * focal_mm)).powi(2);
+    let by_sensor = d_sensor / (focal_mm * k);
+    let by_focal = d_focal * sensor_mm / (focal_mm * focal_mm * k);
+    by_sensor.hypot(by_focal).to_degrees()
+}
+
+/// Propagate input uncertainties analytically through FOV, pixel density and DORI
+///
+/// Uses first-order (linearised) propagation with the inputs treated as
+/// independent, so uncertainties add in quadrature. Linear FOV and pixel density
+/// are products and quotients of the inputs and combine relative uncertainties;
+/// DORI distances do not depend on the mounting distance. Accurate while the
+/// uncertainties are small relative to the nominal values; see the Monte Carlo
+/// tolerance analysis for large or non-normal spreads.
+pub fn propagate_uncertainty(params: &UncertaintyParams) -> UncertaintyResult {
+    let camera = &params.camera;
+    let fov = calculate_fov(camera, params.distance_mm);
+    let dori = calculate_dori_distances(camera);
+
+    let d_focal = params.focal_length_mm.unwrap_or(0.0);
+    let d_width = params.sensor_width_mm.unwrap_or(0.0);
+    let d_height = params.sensor_height_mm.unwrap_or(0.0);
+    let d_distance = params.distance_uncertainty_mm.unwrap_or(0.0);
+
+    let rel_focal = d_focal / camera.focal_length_mm;
+    let rel_width = d_width / camera.sensor_width_mm;
+    let rel_height = d_height / camera.sensor_height_mm;
+    let rel_distance = d_distance / params.distance_mm;
+
+    // L = s·d / f and ppm = px·f / (s·d)
+    let rel_horizontal = rel_width.hypot(rel_focal).hypot(rel_distance);
+    let rel_vertical = rel_height.hypot(rel_focal).hypot(rel_distance);
+    // D = f·px / (s·ppm_required)
+    let rel_dori = rel_width.hypot(rel_focal);
+
+    UncertaintyResult {
+        horizontal_fov_deg: UncertainValue::new(
+            fov.horizontal_fov_deg,
+            angle_uncertainty_deg(
+                camera.sensor_width_mm,
+                camera.focal_length_mm,
+                d_width,
+                d_focal,
+            ),
+        ),
+        vertical_fov_deg: UncertainValue::new(
+            fov.vertical_fov_deg,
+            angle_uncertainty_deg(
+                camera.sensor_height_mm,
+                camera.focal_length_mm,
+                d_height,
+                d_focal,
+            ),
+        ),
+        horizontal_fov_m: UncertainValue::relative(fov.horizontal_fov_m, rel_horizontal),
+        vertical_fov_m: UncertainValue::relative(fov.vertical_fov_m, rel_vertical),
+        horizontal_ppm: UncertainValue::relative(fov.horizontal_ppm, rel_horizontal),
+        vertical_ppm: UncertainValue::relative(fov.vertical_ppm, rel_vertical),
+        detection_m: UncertainValue::relative(dori.detection_m, rel_dori),
+        observation_m: UncertainValue::relative(dori.observation_m, rel_dori),
+        recognition_m: UncertainValue::relative(dori.recognition_m, rel_dori),
+        identification_m: UncertainValue::relative(dori.identification_m, rel_dori),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::CameraSystem;
+
+    fn params() -> UncertaintyParams {
+        UncertaintyParams {
+            camera: CameraSystem::new(5.6, 3.15, 1920, 1080, 4.0),
+            distance_mm: 10000.0,
+            focal_length_mm: Some(0.12),
+            sensor_width_mm: None,
+            sensor_height_mm: None,
+            distance_uncertainty_mm: Some(400.0),
+        }
+    }
+
+    #[test]
+    fn test_relative_uncertainties_add_in_quadrature() {
+        let result = propagate_uncertainty(&params());
+
+        // 3% focal length and 4% distance
+        assert!((result.horizontal_ppm.relative_percent - 5.0).abs() < 1e-9);
+        assert!((result.vertical_fov_m.relative_percent - 5.0).abs() < 1e-9);
+        // DORI distances don't depend on where the camera is mounted
+        assert!((result.identification_m.relative_percent - 3.0).abs() < 1e-9);
+        assert!((result.identification_m.value - 5.486).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_angle_uncertainty_matches_finite_difference() {
+        let mut params = params();
+        params.sensor_width_mm = Some(0.05);
+        let result = propagate_uncertainty(&params);
+
+        let angle = |sensor: f64, focal: f64| 2.0 * (sensor / (2.0 * focal)).atan().to_degrees();
+        let h = 1e-6;
+        let by_sensor = (angle(5.6 + h, 4.0) - angle(5.6 - h, 4.0)) / (2.0 * h) * 0.05;
+        let by_focal = (angle(5.6, 4.0 + h) - angle(5.6, 4.0 - h)) / (2.0 * h) * 0.12;
+
+        assert!((result.horizontal_fov_deg.uncertainty - by_sensor.hypot(by_focal)).abs() < 1e-6);
+        assert!((result.horizontal_fov_deg.value - angle(5.6, 4.0)).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,99 @@
+use super::constants::{DEFAULT_MIN_CORNER_ILLUMINATION_PERCENT, VIGNETTING_SAMPLES};
+use super::types::{RelativeIlluminationPoint, VignettingParams, VignettingProfile};
+
+/// Linearly interpolate a relative illumination curve, holding the end values
+fn interpolate_curve(curve: &[RelativeIlluminationPoint], position: f64) -> f64 {
+    let mut points: Vec<&RelativeIlluminationPoint> = curve.iter().collect();
+    points.sort_by(|a, b| a.field_position.total_cmp(&b.field_position));
+
+    match points.iter().position(|p| p.field_position >= position) {
+        None => points.last().map_or(100.0, |p| p.percent),
+        Some(0) => points[0].percent,
+        Some(i) => {
+            let (a, b) = (points[i - 1], points[i]);
+            let t = (position - a.field_position) / (b.field_position - a.field_position);
+            a.percent + t * (b.percent - a.percent)
+        }
+    }
+}
+
+/// Calculate the illumination falloff across the image
+///
+/// Without a lens curve, natural vignetting follows cos⁴ of the field angle, with
+/// tan(angle) = image height / focal length for a rectilinear lens. Real lenses
+/// add mechanical vignetting at wide apertures and some retrofocus designs do
+/// better than cos⁴, so a measured relative illumination curve is used instead
+/// when given. Measurement applications usually need flat-field correction once
+/// the corners fall much below the centre.
+pub fn calculate_vignetting(params: &VignettingParams) -> VignettingProfile {
+    let camera = &params.camera;
+    let half_diagonal_mm = camera.sensor_width_mm.hypot(camera.sensor_height_mm) / 2.0;
+    let field_angle = |position: f64| (position * half_diagonal_mm / camera.focal_length_mm).atan();
+    let illumination = |position: f64| match &params.lens_ri_curve {
+        Some(curve) if !curve.is_empty() => interpolate_curve(curve, position),
+        _ => field_angle(position).cos().powi(4) * 100.0,
+    };
+
+    let field_positions: Vec<f64> = (0..VIGNETTING_SAMPLES)
+        .map(|i| i as f64 / (VIGNETTING_SAMPLES - 1) as f64)
+        .collect();
+    let corner_percent = illumination(1.0);
+    let min_corner_percent = params
+        .min_corner_percent
+        .unwrap_or(DEFAULT_MIN_CORNER_ILLUMINATION_PERCENT);
+
+    VignettingProfile {
+        field_angles_deg: field_positions
+            .iter()
+            .map(|&p| field_angle(p).to_degrees())
+            .collect(),
+        relative_illumination_percent: field_positions.iter().map(|&p| illumination(p)).collect(),
+        field_positions,
+        edge_percent: illumination(camera.sensor_width_mm / 2.0 / half_diagonal_mm),
+        corner_percent,
+        corner_falloff_stops: (100.0 / corner_percent).log2(),
+        min_corner_percent,
+        below_threshold: corner_percent < min_corner_percent,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::CameraSystem;
+
+    #[test]
+    fn test_cos4_falloff() {
+        // 24 mm on full frame: 42° corner field angle
+        let profile = calculate_vignetting(&VignettingParams {
+            camera: CameraSystem::new(36.0, 24.0, 6000, 4000, 24.0),
+            lens_ri_curve: None,
+            min_corner_percent: None,
+        });
+
+        let corner_angle = (21.633f64 / 24.0).atan();
+        assert!((profile.corner_percent - corner_angle.cos().powi(4) * 100.0).abs() < 0.01);
+        assert_eq!(profile.relative_illumination_percent[0], 100.0);
+        assert!(profile.edge_percent > profile.corner_percent);
+        assert!(profile.below_threshold);
+        assert_eq!(profile.validate().len(), 1);
+    }
+
+    #[test]
+    fn test_lens_curve_replaces_cos4() {
+        let point = |field_position, percent| RelativeIlluminationPoint {
+            field_position,
+            percent,
+        };
+        let profile = calculate_vignetting(&VignettingParams {
+            camera: CameraSystem::new(36.0, 24.0, 6000, 4000, 24.0),
+            lens_ri_curve: Some(vec![point(1.0, 60.0), point(0.0, 100.0), point(0.5, 90.0)]),
+            min_corner_percent: Some(80.0),
+        });
+
+        assert!((profile.corner_percent - 60.0).abs() < 1e-9);
+        assert!((profile.relative_illumination_percent[8] - 72.0).abs() < 1e-9);
+        assert!((profile.corner_falloff_stops - (100.0f64 / 60.0).log2()).abs() < 1e-9);
+        assert!(profile.below_threshold);
+    }
+}
@@ -0,0 +1,71 @@
+use super::constants::WDR_HEADROOM_DB;
+use super::types::{CameraSystem, DynamicRangeAssessment, WdrScene, WdrVerdict};
+
+/// Compare the dynamic range a scene requires with the camera's specified range
+///
+/// The requirement is the contrast between the brightest and darkest areas that
+/// must both hold detail: DR = 20 × log10(bright / dark) dB, or log2 in stops.
+/// A camera passes with at least 6 dB (1 stop) of headroom, warns when it only
+/// just covers the scene and fails when highlights or shadows will clip.
+pub fn assess_dynamic_range(camera: &CameraSystem, scene: WdrScene) -> DynamicRangeAssessment {
+    let luminance = scene.luminance();
+    let contrast = luminance.bright_cd_m2 / luminance.dark_cd_m2;
+    let required_db = 20.0 * contrast.log10();
+    let margin_db = camera
+        .dynamic_range_db
+        .map(|camera_db| camera_db - required_db);
+
+    DynamicRangeAssessment {
+        scene: luminance,
+        required_db,
+        required_stops: contrast.log2(),
+        camera_db: camera.dynamic_range_db,
+        margin_db,
+        verdict: margin_db.map(|margin_db| {
+            if margin_db >= WDR_HEADROOM_DB {
+                WdrVerdict::Pass
+            } else if margin_db >= 0.0 {
+                WdrVerdict::Warn
+            } else {
+                WdrVerdict::Fail
+            }
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{SceneLuminance, WdrScenePreset};
+
+    #[test]
+    fn test_required_range_db_and_stops() {
+        let camera = CameraSystem::new(5.6, 3.15, 1920, 1080, 4.0);
+        let result = assess_dynamic_range(
+            &camera,
+            WdrScene::Custom(SceneLuminance {
+                bright_cd_m2: 1000.0,
+                dark_cd_m2: 1.0,
+            }),
+        );
+
+        // 1000:1 is 60 dB, just under 10 stops
+        assert!((result.required_db - 60.0).abs() < 1e-9);
+        assert!((result.required_stops - 9.97).abs() < 0.01);
+        assert!(result.verdict.is_none());
+    }
+
+    #[test]
+    fn test_lobby_verdicts() {
+        // Entrance lobby: 5000:1 needs ~74 dB
+        let scene = WdrScene::Preset(WdrScenePreset::EntranceLobby);
+        let verdict = |db| {
+            let camera = CameraSystem::new(5.6, 3.15, 1920, 1080, 4.0).with_dynamic_range(db);
+            assess_dynamic_range(&camera, scene).verdict
+        };
+
+        assert_eq!(verdict(120.0), Some(WdrVerdict::Pass));
+        assert_eq!(verdict(76.0), Some(WdrVerdict::Warn));
+        assert_eq!(verdict(66.0), Some(WdrVerdict::Fail));
+    }
+}
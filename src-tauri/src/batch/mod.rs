@@ -0,0 +1,5 @@
+pub mod run;
+pub mod types;
+
+pub use run::*;
+pub use types::*;
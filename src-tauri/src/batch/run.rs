@@ -0,0 +1,163 @@
+use std::fs;
+use std::path::Path;
+
+use super::types::{
+    BatchConfig, BatchEntry, BatchError, BatchResults, BatchScenario, DepthOfField,
+};
+use crate::optics::calculations::{calculate_dof, calculate_fov, calculate_hyperfocal};
+use crate::optics::types::CameraSystem;
+
+/// Sensor diagonal divided by this gives the default circle of confusion
+const COC_DIAGONAL_DIVISOR: f64 = 1500.0;
+
+/// Read a batch description from a `.toml` or `.json` file
+pub fn read_batch_config(path: &Path) -> Result<BatchConfig, BatchError> {
+    let text = fs::read_to_string(path).map_err(BatchError::Io)?;
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_ascii_lowercase();
+    match extension.as_str() {
+        "toml" => toml::from_str(&text).map_err(|e| BatchError::Parse(e.to_string())),
+        "json" => serde_json::from_str(&text).map_err(|e| BatchError::Parse(e.to_string())),
+        _ => Err(BatchError::UnsupportedFormat(extension)),
+    }
+}
+
+/// Calculate depth of field for a camera in a scenario
+///
+/// # Returns
+/// `None` if neither the scenario nor the camera specifies an f-number
+fn scenario_dof(camera: &CameraSystem, scenario: &BatchScenario) -> Option<DepthOfField> {
+    let f_number = scenario.f_number.or(camera.f_number)?;
+    let coc_mm = scenario.coc_mm.unwrap_or_else(|| {
+        camera.sensor_width_mm.hypot(camera.sensor_height_mm) / COC_DIAGONAL_DIVISOR
+    });
+
+    let distance_mm = scenario.distance_m * 1000.0;
+    let (near_mm, far_mm, total_mm) =
+        calculate_dof(distance_mm, camera.focal_length_mm, f_number, coc_mm, None);
+    let finite = |v: f64| if v.is_finite() { Some(v) } else { None };
+
+    Some(DepthOfField {
+        f_number,
+        coc_mm,
+        hyperfocal_mm: calculate_hyperfocal(camera.focal_length_mm, f_number, coc_mm),
+        near_mm,
+        far_mm: finite(far_mm),
+        total_mm: finite(total_mm),
+    })
+}
+
+/// Evaluate FOV, DORI and depth of field for every camera in every scenario
+///
+/// Entries are ordered by camera, then by scenario.
+pub fn run_batch(config: &BatchConfig) -> BatchResults {
+    let entries = config
+        .cameras
+        .iter()
+        .flat_map(|camera| {
+            config.scenarios.iter().map(move |scenario| BatchEntry {
+                camera: camera.clone(),
+                scenario: scenario.clone(),
+                fov: calculate_fov(camera, scenario.distance_m * 1000.0),
+                dof: scenario_dof(camera, scenario),
+            })
+        })
+        .collect();
+
+    BatchResults { entries }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"
+[[cameras]]
+name = "Wide"
+sensor_width_mm = 36.0
+sensor_height_mm = 24.0
+pixel_width = 6000
+pixel_height = 4000
+focal_length_mm = 50.0
+f_number = 2.8
+
+[[cameras]]
+name = "Box"
+sensor_width_mm = 6.4
+sensor_height_mm = 4.8
+pixel_width = 1920
+pixel_height = 1080
+focal_length_mm = 4.0
+
+[[scenarios]]
+name = "Door"
+distance_m = 5.0
+coc_mm = 0.03
+
+[[scenarios]]
+name = "Car park"
+distance_m = 30.0
+"#;
+
+    #[test]
+    fn test_run_batch_from_toml() {
+        let config: BatchConfig = toml::from_str(CONFIG).unwrap();
+        let results = run_batch(&config);
+
+        assert_eq!(results.entries.len(), 4);
+        assert_eq!(results.entries[1].camera.name.as_deref(), Some("Wide"));
+        assert_eq!(
+            results.entries[1].scenario.name.as_deref(),
+            Some("Car park")
+        );
+        assert!((results.entries[0].fov.distance_m - 5.0).abs() < 1e-9);
+
+        // DOF uses the given CoC and matches a direct calculation
+        let dof = results.entries[0].dof.as_ref().unwrap();
+        let (near, _, _) = calculate_dof(5000.0, 50.0, 2.8, 0.03, None);
+        assert!((dof.near_mm - near).abs() < 1e-9);
+
+        // Default CoC is the sensor diagonal / 1500 (0.0288 mm for full frame)
+        let dof = results.entries[1].dof.as_ref().unwrap();
+        assert!((dof.coc_mm - 36f64.hypot(24.0) / 1500.0).abs() < 1e-12);
+
+        // No f-number on the camera or scenario means no DOF
+        assert!(results.entries[2].dof.is_none());
+    }
+
+    #[test]
+    fn test_infinite_far_limit() {
+        // 4 mm at f/2.0 is focused beyond the hyperfocal distance at 30 m
+        let camera = CameraSystem::new(6.4, 4.8, 1920, 1080, 4.0).with_f_number(2.0);
+        let scenario = BatchScenario {
+            name: None,
+            distance_m: 30.0,
+            f_number: None,
+            coc_mm: None,
+        };
+        let dof = scenario_dof(&camera, &scenario).unwrap();
+
+        assert!(dof.far_mm.is_none());
+        assert!(dof.total_mm.is_none());
+    }
+
+    #[test]
+    fn test_read_json_file() {
+        let path = std::env::temp_dir().join("camera_optics_batch_test.json");
+        fs::write(
+            &path,
+            r#"{"cameras": [{"sensor_width_mm": 6.4, "sensor_height_mm": 4.8,
+                "pixel_width": 1920, "pixel_height": 1080, "focal_length_mm": 4.0}],
+                "scenarios": [{"distance_m": 10.0}]}"#,
+        )
+        .unwrap();
+        let config = read_batch_config(&path).unwrap();
+        fs::remove_file(&path).ok();
+
+        assert_eq!(config.cameras.len(), 1);
+        assert_eq!(config.scenarios[0].distance_m, 10.0);
+    }
+}
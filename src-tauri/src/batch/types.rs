@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::optics::types::{CameraSystem, FovResult};
+
+/// A viewing scenario evaluated for every camera in a batch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct BatchScenario {
+    /// Optional name for identification
+    pub name: Option<String>,
+    /// Subject distance in meters
+    pub distance_m: f64,
+    /// Aperture for depth of field; falls back to the camera's f-number
+    pub f_number: Option<f64>,
+    /// Circle of confusion in millimeters; defaults to sensor diagonal / 1500
+    pub coc_mm: Option<f64>,
+}
+
+/// Cameras and scenarios to evaluate, read from a TOML or JSON file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub struct BatchConfig {
+    pub cameras: Vec<CameraSystem>,
+    pub scenarios: Vec<BatchScenario>,
+}
+
+/// Depth of field for a camera in a scenario
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct DepthOfField {
+    pub f_number: f64,
+    pub coc_mm: f64,
+    pub hyperfocal_mm: f64,
+    pub near_mm: f64,
+    /// Far limit in millimeters, `None` if it extends to infinity
+    pub far_mm: Option<f64>,
+    /// Total depth of field in millimeters, `None` if infinite
+    pub total_mm: Option<f64>,
+}
+
+/// Result for one camera in one scenario
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct BatchEntry {
+    pub camera: CameraSystem,
+    pub scenario: BatchScenario,
+    /// Field of view, pixel density and DORI distances
+    pub fov: FovResult,
+    /// Depth of field, if an f-number is known
+    pub dof: Option<DepthOfField>,
+}
+
+/// Results for every camera/scenario combination
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub struct BatchResults {
+    pub entries: Vec<BatchEntry>,
+}
+
+/// Errors that can occur while reading a batch file
+#[derive(Debug)]
+pub enum BatchError {
+    /// The file could not be read
+    Io(std::io::Error),
+    /// The file is not a valid batch description
+    Parse(String),
+    /// The file extension is not `.toml` or `.json`
+    UnsupportedFormat(String),
+}
+
+impl fmt::Display for BatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BatchError::Io(e) => write!(f, "Could not read batch file: {}", e),
+            BatchError::Parse(e) => write!(f, "Invalid batch file: {}", e),
+            BatchError::UnsupportedFormat(ext) => {
+                write!(f, "Unsupported batch format '{}'; use TOML or JSON", ext)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BatchError {}
@@ -0,0 +1,185 @@
+use std::any::Any;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Instant;
+
+use serde::Serialize;
+
+use super::types::CacheStats;
+
+/// Results kept before the oldest are evicted
+pub const DEFAULT_CACHE_CAPACITY: usize = 1024;
+
+#[derive(Default)]
+struct CacheState {
+    entries: HashMap<String, Arc<dyn Any + Send + Sync>>,
+    /// Keys from oldest to newest, for eviction
+    order: VecDeque<String>,
+    hits: u64,
+    misses: u64,
+}
+
+/// Results of calculation commands keyed by command name and inputs
+///
+/// The frontend calls the same commands with identical inputs on every slider
+/// tick; cached results are returned without recomputing. Inputs are keyed by
+/// their JSON form. JSON writes NaN and infinity as `null`, so inputs that differ
+/// only in those values would share a key; commands compute such inputs without
+/// the cache.
+pub struct CalculationCache {
+    capacity: usize,
+    state: Mutex<CacheState>,
+}
+
+impl Default for CalculationCache {
+    fn default() -> Self {
+        Self::with_capacity(DEFAULT_CACHE_CAPACITY)
+    }
+}
+
+impl CalculationCache {
+    /// Cache that keeps at most `capacity` results, evicting the oldest first
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            state: Mutex::new(CacheState::default()),
+        }
+    }
+
+    /// Cached result of `command` for `input`, computing and storing it on a miss
+    ///
+    /// Inputs that cannot be serialized are computed without caching.
+    pub fn get_or_compute<I, T>(&self, command: &str, input: &I, compute: impl FnOnce() -> T) -> T
+    where
+        I: Serialize + ?Sized,
+        T: Clone + Send + Sync + 'static,
+    {
+        let Ok(json) = serde_json::to_string(input) else {
+            return compute();
+        };
+        let key = format!("{command}:{json}");
+
+        {
+            let mut state = self.lock();
+            let cached = state
+                .entries
+                .get(&key)
+                .and_then(|value| value.downcast_ref::<T>())
+                .cloned();
+            if let Some(result) = cached {
+                state.hits += 1;
+                tracing::debug!(command, "cache hit");
+                return result;
+            }
+            state.misses += 1;
+        }
+
+        // Computed without holding the lock, so other commands are not blocked
+        let started = Instant::now();
+        let result = compute();
+        tracing::debug!(
+            command,
+            input = %json,
+            elapsed_ms = started.elapsed().as_secs_f64() * 1000.0,
+            "computed"
+        );
+        let mut state = self.lock();
+        if state
+            .entries
+            .insert(key.clone(), Arc::new(result.clone()))
+            .is_none()
+        {
+            state.order.push_back(key);
+        }
+        while state.entries.len() > self.capacity {
+            match state.order.pop_front() {
+                Some(oldest) => state.entries.remove(&oldest),
+                None => break,
+            };
+        }
+        result
+    }
+
+    /// Drop the cached results of one command, or of all commands when `None`
+    ///
+    /// # Returns
+    /// The cache statistics after invalidating
+    pub fn invalidate(&self, command: Option<&str>) -> CacheStats {
+        let mut state = self.lock();
+        match command {
+            Some(command) => {
+                let prefix = format!("{command}:");
+                state.entries.retain(|key, _| !key.starts_with(&prefix));
+                state.order.retain(|key| !key.starts_with(&prefix));
+            }
+            None => *state = CacheState::default(),
+        }
+        Self::stats_of(&state)
+    }
+
+    /// Current size and hit rate
+    pub fn stats(&self) -> CacheStats {
+        Self::stats_of(&self.lock())
+    }
+
+    fn stats_of(state: &CacheState) -> CacheStats {
+        CacheStats {
+            entries: state.entries.len(),
+            hits: state.hits,
+            misses: state.misses,
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, CacheState> {
+        // Entries are only inserted whole, so a poisoned lock still holds a valid cache
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repeated_inputs_are_computed_once() {
+        let cache = CalculationCache::default();
+        let mut calls = 0;
+        for _ in 0..3 {
+            let result = cache.get_or_compute("square", &(4.0, "mm"), || {
+                calls += 1;
+                16.0
+            });
+            assert_eq!(result, 16.0);
+        }
+        assert_eq!(calls, 1);
+        assert_eq!(
+            cache.stats(),
+            CacheStats {
+                entries: 1,
+                hits: 2,
+                misses: 1
+            }
+        );
+
+        // Different inputs or a different command are separate entries
+        cache.get_or_compute("square", &(5.0, "mm"), || 25.0);
+        cache.get_or_compute("cube", &(4.0, "mm"), || 64.0);
+        assert_eq!(cache.stats().entries, 3);
+    }
+
+    #[test]
+    fn test_invalidate_and_evict() {
+        let cache = CalculationCache::with_capacity(2);
+        cache.get_or_compute("a", &1, || 1);
+        cache.get_or_compute("a", &2, || 2);
+        cache.get_or_compute("b", &1, || 3);
+        // The oldest entry was evicted
+        assert_eq!(cache.stats().entries, 2);
+        assert_eq!(cache.get_or_compute("a", &1, || 10), 10);
+
+        assert_eq!(cache.invalidate(Some("a")).entries, 1);
+        assert_eq!(cache.invalidate(None), CacheStats::default());
+    }
+}
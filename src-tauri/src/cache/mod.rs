@@ -0,0 +1,5 @@
+pub mod memo;
+pub mod types;
+
+pub use memo::*;
+pub use types::*;
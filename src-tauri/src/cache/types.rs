@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// Size and hit rate of the calculation cache
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub struct CacheStats {
+    /// Results currently held
+    pub entries: usize,
+    /// Lookups answered from the cache since it was last cleared
+    pub hits: u64,
+    /// Lookups that had to compute the result since it was last cleared
+    pub misses: u64,
+}
@@ -0,0 +1,369 @@
+use std::path::Path;
+
+use calamine::{open_workbook_auto, Reader};
+
+use super::types::{CatalogCamera, CatalogError, CatalogImport, CatalogRowError};
+use crate::optics::spec::{
+    parse_focal_range, parse_resolution, parse_spec_number, sensor_from_optical_format,
+};
+use crate::optics::types::{CameraSystem, ValidationSeverity};
+
+/// Column indices resolved from the header row
+#[derive(Debug, Default)]
+struct Columns {
+    name: Option<usize>,
+    sensor_width: Option<usize>,
+    sensor_height: Option<usize>,
+    sensor_format: Option<usize>,
+    resolution: Option<usize>,
+    pixel_width: Option<usize>,
+    pixel_height: Option<usize>,
+    focal_length: Option<usize>,
+    focal_length_min: Option<usize>,
+    focal_length_max: Option<usize>,
+    f_number: Option<usize>,
+    quantum_efficiency: Option<usize>,
+    read_noise: Option<usize>,
+    full_well: Option<usize>,
+    dark_current: Option<usize>,
+}
+
+/// Lowercase a header and drop everything but letters and digits
+fn normalize_header(header: &str) -> String {
+    header
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect::<String>()
+        .to_ascii_lowercase()
+}
+
+impl Columns {
+    fn from_header(header: &[String]) -> Result<Self, CatalogError> {
+        let mut columns = Columns::default();
+        for (i, raw) in header.iter().enumerate() {
+            let slot = match normalize_header(raw).as_str() {
+                "name" | "model" | "modelname" | "camera" => &mut columns.name,
+                "sensorwidth" | "sensorwidthmm" => &mut columns.sensor_width,
+                "sensorheight" | "sensorheightmm" => &mut columns.sensor_height,
+                "sensor" | "sensorsize" | "sensorformat" | "opticalformat" => {
+                    &mut columns.sensor_format
+                }
+                "resolution" | "maxresolution" => &mut columns.resolution,
+                "pixelwidth" | "width" | "widthpx" | "horizontalpixels" => &mut columns.pixel_width,
+                "pixelheight" | "height" | "heightpx" | "verticalpixels" => {
+                    &mut columns.pixel_height
+                }
+                "focallength" | "focallengthmm" | "lens" | "lensmm" | "lensrange" => {
+                    &mut columns.focal_length
+                }
+                "focallengthmin" | "focallengthminmm" | "minfocallength" => {
+                    &mut columns.focal_length_min
+                }
+                "focallengthmax" | "focallengthmaxmm" | "maxfocallength" => {
+                    &mut columns.focal_length_max
+                }
+                "fnumber" | "aperture" | "maxaperture" => &mut columns.f_number,
+                "qe" | "quantumefficiency" => &mut columns.quantum_efficiency,
+                "readnoise" | "readnoisee" => &mut columns.read_noise,
+                "fullwell" | "fullwellcapacity" | "fullwelle" => &mut columns.full_well,
+                "darkcurrent" | "darkcurrentes" => &mut columns.dark_current,
+                _ => continue,
+            };
+            slot.get_or_insert(i);
+        }
+
+        if columns.sensor_format.is_none()
+            && (columns.sensor_width.is_none() || columns.sensor_height.is_none())
+        {
+            return Err(CatalogError::MissingColumn("sensor size"));
+        }
+        if columns.resolution.is_none()
+            && (columns.pixel_width.is_none() || columns.pixel_height.is_none())
+        {
+            return Err(CatalogError::MissingColumn("resolution"));
+        }
+        if columns.focal_length.is_none() && columns.focal_length_min.is_none() {
+            return Err(CatalogError::MissingColumn("focal length"));
+        }
+        Ok(columns)
+    }
+}
+
+fn parse_row(columns: &Columns, row: &[String]) -> Result<CatalogCamera, String> {
+    let cell = |index: Option<usize>| {
+        index
+            .and_then(|i| row.get(i))
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+    };
+    let number = |index: Option<usize>, label: &str| -> Result<Option<f64>, String> {
+        cell(index)
+            .map(|s| parse_spec_number(s).ok_or_else(|| format!("Invalid {}: '{}'", label, s)))
+            .transpose()
+    };
+
+    let (pixel_width, pixel_height) = match cell(columns.resolution) {
+        Some(res) => {
+            parse_resolution(res).ok_or_else(|| format!("Invalid resolution: '{}'", res))?
+        }
+        None => (
+            number(columns.pixel_width, "pixel width")?.ok_or("Missing pixel width")? as u32,
+            number(columns.pixel_height, "pixel height")?.ok_or("Missing pixel height")? as u32,
+        ),
+    };
+    if pixel_width == 0 || pixel_height == 0 {
+        return Err("Resolution must be non-zero".to_string());
+    }
+
+    let (sensor_width_mm, sensor_height_mm) = match (
+        number(columns.sensor_width, "sensor width")?,
+        number(columns.sensor_height, "sensor height")?,
+    ) {
+        (Some(w), Some(h)) => (w, h),
+        _ => {
+            let format = cell(columns.sensor_format).ok_or("Missing sensor size")?;
+            sensor_from_optical_format(format, pixel_height as f64 / pixel_width as f64)
+                .ok_or_else(|| format!("Invalid sensor format: '{}'", format))?
+        }
+    };
+
+    let (focal_length_mm, focal_length_max_mm) = match cell(columns.focal_length) {
+        Some(lens) => {
+            parse_focal_range(lens).ok_or_else(|| format!("Invalid focal length: '{}'", lens))?
+        }
+        None => (
+            number(columns.focal_length_min, "focal length")?.ok_or("Missing focal length")?,
+            number(columns.focal_length_max, "focal length")?,
+        ),
+    };
+
+    let mut camera = CameraSystem::new(
+        sensor_width_mm,
+        sensor_height_mm,
+        pixel_width,
+        pixel_height,
+        focal_length_mm,
+    );
+    if let Some(name) = cell(columns.name) {
+        camera = camera.with_name(name);
+    }
+    if let Some(f_number) = number(columns.f_number, "f-number")? {
+        camera = camera.with_f_number(f_number);
+    }
+    if let Some(qe) = number(columns.quantum_efficiency, "quantum efficiency")? {
+        // Datasheets quote QE in percent as often as as a fraction
+        camera = camera.with_quantum_efficiency(if qe > 1.0 { qe / 100.0 } else { qe });
+    }
+    if let Some(read_noise) = number(columns.read_noise, "read noise")? {
+        camera = camera.with_read_noise(read_noise);
+    }
+    if let Some(full_well) = number(columns.full_well, "full-well capacity")? {
+        camera = camera.with_full_well(full_well);
+    }
+    if let Some(dark_current) = number(columns.dark_current, "dark current")? {
+        camera = camera.with_dark_current(dark_current);
+    }
+
+    if let Some(error) = camera
+        .validate()
+        .into_iter()
+        .find(|w| w.severity == ValidationSeverity::Error)
+    {
+        return Err(error.message);
+    }
+
+    Ok(CatalogCamera {
+        row: 0,
+        camera,
+        focal_length_max_mm,
+    })
+}
+
+/// Import cameras from spreadsheet rows, the first of which is the header
+///
+/// Recognized columns (case and punctuation insensitive): name/model, sensor
+/// width and height in mm or an optical format like `1/2.8"`, resolution as
+/// `1920x1080` or separate pixel width/height, focal length or lens range like
+/// `2.8-12mm`, and optionally f-number, quantum efficiency, read noise, full-well
+/// capacity and dark current. Unknown columns are ignored.
+///
+/// # Returns
+/// The imported cameras and an error for every row that could not be imported
+pub fn import_catalog_rows(rows: &[Vec<String>]) -> Result<CatalogImport, CatalogError> {
+    let (header, data) = rows
+        .split_first()
+        .ok_or(CatalogError::MissingColumn("header"))?;
+    let columns = Columns::from_header(header)?;
+
+    let mut cameras = Vec::new();
+    let mut errors = Vec::new();
+    for (i, row) in data.iter().enumerate() {
+        let row_number = i + 2;
+        if row.iter().all(|c| c.trim().is_empty()) {
+            continue;
+        }
+        match parse_row(&columns, row) {
+            Ok(mut camera) => {
+                camera.row = row_number;
+                cameras.push(camera);
+            }
+            Err(message) => errors.push(CatalogRowError {
+                row: row_number,
+                message,
+            }),
+        }
+    }
+
+    Ok(CatalogImport { cameras, errors })
+}
+
+/// Read all rows of a CSV file
+fn read_csv(path: &Path) -> Result<Vec<Vec<String>>, CatalogError> {
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .from_path(path)
+        .map_err(|e| CatalogError::Read(e.to_string()))?;
+    reader
+        .records()
+        .map(|record| {
+            record
+                .map(|r| r.iter().map(str::to_string).collect())
+                .map_err(|e| CatalogError::Read(e.to_string()))
+        })
+        .collect()
+}
+
+/// Read all rows of the first worksheet of an Excel or OpenDocument file
+fn read_workbook(path: &Path) -> Result<Vec<Vec<String>>, CatalogError> {
+    let mut workbook = open_workbook_auto(path).map_err(|e| CatalogError::Read(e.to_string()))?;
+    let range = workbook
+        .worksheet_range_at(0)
+        .ok_or_else(|| CatalogError::Read("workbook has no worksheets".to_string()))?
+        .map_err(|e| CatalogError::Read(e.to_string()))?;
+    Ok(range
+        .rows()
+        .map(|row| row.iter().map(|c| c.to_string()).collect())
+        .collect())
+}
+
+/// Import cameras from a CSV or XLSX datasheet export
+///
+/// # Arguments
+/// * `path` - Path to a `.csv`, `.xlsx`, `.xls` or `.ods` file
+pub fn import_catalog_file(path: &Path) -> Result<CatalogImport, CatalogError> {
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_ascii_lowercase();
+    let rows = match extension.as_str() {
+        "csv" => read_csv(path)?,
+        "xlsx" | "xlsm" | "xls" | "ods" => read_workbook(path)?,
+        _ => return Err(CatalogError::UnsupportedFormat(extension)),
+    };
+    import_catalog_rows(&rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(text: &str) -> Vec<Vec<String>> {
+        text.lines()
+            .map(|line| line.split(';').map(str::to_string).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_import_with_explicit_columns() {
+        let rows = rows(
+            "Model;Sensor Width (mm);Sensor Height (mm);Resolution;Lens (mm);Aperture\n\
+             Bullet 4MP;5.12;2.88;2560x1440;2.8-12mm;F1.6\n\
+             Dome 2MP;5.6;3.15;1920 × 1080;4;",
+        );
+        let import = import_catalog_rows(&rows).unwrap();
+
+        assert!(import.errors.is_empty());
+        assert_eq!(import.cameras.len(), 2);
+
+        let bullet = &import.cameras[0];
+        assert_eq!(bullet.row, 2);
+        assert_eq!(bullet.camera.name.as_deref(), Some("Bullet 4MP"));
+        assert_eq!(bullet.camera.pixel_width, 2560);
+        assert!((bullet.camera.focal_length_mm - 2.8).abs() < 1e-9);
+        assert_eq!(bullet.focal_length_max_mm, Some(12.0));
+        assert_eq!(bullet.camera.f_number, Some(1.6));
+
+        assert_eq!(import.cameras[1].camera.pixel_height, 1080);
+        assert!(import.cameras[1].focal_length_max_mm.is_none());
+    }
+
+    #[test]
+    fn test_optical_format() {
+        let rows = rows("name;sensor;width;height;focal length\nCam;1/2.8\";1920;1080;4");
+        let import = import_catalog_rows(&rows).unwrap();
+        let camera = &import.cameras[0].camera;
+
+        // 1/2.8" ≈ 6.43 mm diagonal at 16:9
+        let diagonal = camera.sensor_width_mm.hypot(camera.sensor_height_mm);
+        assert!((diagonal - 18.0 / 2.8).abs() < 1e-9);
+        assert!((camera.sensor_height_mm / camera.sensor_width_mm - 0.5625).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_row_errors_reported() {
+        let rows = rows(
+            "model;sensor width;sensor height;resolution;focal length\n\
+             Good;5.6;3.15;1920x1080;4\n\
+             Bad resolution;5.6;3.15;full hd;4\n\
+             ;;;;\n\
+             Zero focal;5.6;3.15;1920x1080;0",
+        );
+        let import = import_catalog_rows(&rows).unwrap();
+
+        assert_eq!(import.cameras.len(), 1);
+        assert_eq!(import.errors.len(), 2);
+        assert_eq!(import.errors[0].row, 3);
+        assert!(import.errors[0].message.contains("resolution"));
+        assert_eq!(import.errors[1].row, 5);
+    }
+
+    #[test]
+    fn test_sensor_noise_columns() {
+        let rows = rows(
+            "model;sensor width;sensor height;resolution;focal length;QE;Read noise (e-);Full well;Dark current\n\
+             Cam;5.6;3.15;1920x1080;4;80%;2.1;11000;0.4",
+        );
+        let camera = &import_catalog_rows(&rows).unwrap().cameras[0].camera;
+
+        assert_eq!(camera.quantum_efficiency, Some(0.8));
+        assert_eq!(camera.read_noise_e, Some(2.1));
+        assert_eq!(camera.full_well_e, Some(11_000.0));
+        assert_eq!(camera.dark_current_e_s, Some(0.4));
+    }
+
+    #[test]
+    fn test_missing_column() {
+        let rows = rows("model;resolution;focal length\nCam;1920x1080;4");
+        assert!(matches!(
+            import_catalog_rows(&rows),
+            Err(CatalogError::MissingColumn("sensor size"))
+        ));
+    }
+
+    #[test]
+    fn test_csv_file() {
+        let path = std::env::temp_dir().join("camera_optics_catalog_test.csv");
+        std::fs::write(
+            &path,
+            "model,sensor width,sensor height,resolution,focal length\nCam,6.4,4.8,2048x1536,8\n",
+        )
+        .unwrap();
+        let import = import_catalog_file(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(import.cameras.len(), 1);
+        assert_eq!(import.cameras[0].camera.pixel_height, 1536);
+    }
+}
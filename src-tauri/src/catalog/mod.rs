@@ -0,0 +1,5 @@
+pub mod import;
+pub mod types;
+
+pub use import::*;
+pub use types::*;
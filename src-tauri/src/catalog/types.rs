@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::optics::types::CameraSystem;
+
+/// A camera model imported from a catalog row
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct CatalogCamera {
+    /// 1-based spreadsheet row number (the header is row 1)
+    pub row: usize,
+    /// Camera at the wide end of its lens range
+    pub camera: CameraSystem,
+    /// Tele end of a varifocal lens, if the row gives a range
+    pub focal_length_max_mm: Option<f64>,
+}
+
+/// A catalog row that could not be imported
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub struct CatalogRowError {
+    /// 1-based spreadsheet row number (the header is row 1)
+    pub row: usize,
+    pub message: String,
+}
+
+/// Result of importing a camera catalog
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub struct CatalogImport {
+    pub cameras: Vec<CatalogCamera>,
+    pub errors: Vec<CatalogRowError>,
+}
+
+/// Errors that prevent a catalog from being read at all
+#[derive(Debug)]
+pub enum CatalogError {
+    /// The file could not be opened or parsed
+    Read(String),
+    /// The file extension is not a supported spreadsheet format
+    UnsupportedFormat(String),
+    /// A required column is missing from the header
+    MissingColumn(&'static str),
+}
+
+impl fmt::Display for CatalogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CatalogError::Read(e) => write!(f, "Could not read catalog: {}", e),
+            CatalogError::UnsupportedFormat(ext) => {
+                write!(f, "Unsupported catalog format '{}'; use CSV or XLSX", ext)
+            }
+            CatalogError::MissingColumn(col) => write!(f, "Catalog has no {} column", col),
+        }
+    }
+}
+
+impl std::error::Error for CatalogError {}
@@ -0,0 +1,165 @@
+use serde::Deserialize;
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+use tauri_app_lib::optics::CameraSystem;
+
+/// Path argument that stands for standard input
+pub const STDIN_PATH: &str = "-";
+
+/// Parse a "WxH" pair of numbers
+fn parse_pair<T: std::str::FromStr>(pair: &str, what: &str) -> Result<(T, T), String> {
+    let (a, b) = pair
+        .split_once(['x', 'X'])
+        .ok_or_else(|| format!("{} must be given as WIDTHxHEIGHT, got '{}'", what, pair))?;
+    let parse = |v: &str| {
+        v.trim()
+            .parse::<T>()
+            .map_err(|_| format!("Invalid {} '{}'", what, pair))
+    };
+    Ok((parse(a)?, parse(b)?))
+}
+
+/// Parse a camera given as `[name:]SENSOR_WxSENSOR_H:PIXELS_WxPIXELS_H:FOCAL`
+///
+/// Sensor size and focal length are in millimeters, e.g. `Full Frame:36x24:6000x4000:50`.
+/// The sensor size may instead be a pixel pitch in micrometers, e.g. `IMX264:3.45um:2448x2048:16`.
+/// The name is optional and may itself contain colons.
+pub fn parse_camera_spec(spec: &str) -> Result<CameraSystem, String> {
+    let mut parts = spec.rsplitn(4, ':');
+    let (Some(focal), Some(pixels), Some(sensor)) = (parts.next(), parts.next(), parts.next())
+    else {
+        return Err(format!(
+            "Camera must be given as [name:]36x24:6000x4000:50, got '{}'",
+            spec
+        ));
+    };
+    let name = parts.next().map(str::trim).filter(|n| !n.is_empty());
+
+    let (pixel_width, pixel_height) = parse_pair::<u32>(pixels, "resolution")?;
+    let focal_length = focal
+        .trim()
+        .trim_end_matches("mm")
+        .parse::<f64>()
+        .map_err(|_| format!("Invalid focal length '{}'", focal))?;
+
+    let sensor = sensor.trim();
+    let camera = match sensor
+        .strip_suffix("um")
+        .or_else(|| sensor.strip_suffix("µm"))
+    {
+        Some(pitch) => {
+            let pitch = pitch
+                .trim()
+                .parse::<f64>()
+                .map_err(|_| format!("Invalid pixel pitch '{}'", sensor))?;
+            CameraSystem::from_pixel_pitch(pixel_width, pixel_height, pitch, focal_length)
+        }
+        None => {
+            let (sensor_width, sensor_height) = parse_pair::<f64>(sensor, "sensor size")?;
+            CameraSystem::new(
+                sensor_width,
+                sensor_height,
+                pixel_width,
+                pixel_height,
+                focal_length,
+            )
+        }
+    };
+    Ok(match name {
+        Some(name) => camera.with_name(name),
+        None => camera,
+    })
+}
+
+/// A single camera or a list of cameras
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OneOrMany {
+    Many(Vec<CameraSystem>),
+    One(CameraSystem),
+}
+
+/// Parse a JSON camera system or array of camera systems
+pub fn parse_cameras_json(json: &str) -> Result<Vec<CameraSystem>, String> {
+    match serde_json::from_str(json) {
+        Ok(OneOrMany::Many(cameras)) => Ok(cameras),
+        Ok(OneOrMany::One(camera)) => Ok(vec![camera]),
+        Err(e) => Err(format!("Invalid camera JSON: {}", e)),
+    }
+}
+
+/// Read the whole of a file, or standard input if the path is "-"
+pub fn read_input(path: &Path) -> Result<String, String> {
+    if path == Path::new(STDIN_PATH) {
+        let mut text = String::new();
+        std::io::stdin()
+            .read_to_string(&mut text)
+            .map_err(|e| format!("Could not read standard input: {}", e))?;
+        Ok(text)
+    } else {
+        fs::read_to_string(path).map_err(|e| format!("Could not read {}: {}", path.display(), e))
+    }
+}
+
+/// Read camera systems as JSON from a file, or standard input if the path is "-"
+pub fn read_cameras_file(path: &Path) -> Result<Vec<CameraSystem>, String> {
+    parse_cameras_json(&read_input(path)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_named_spec() {
+        let camera = parse_camera_spec("Full Frame:36x24:6000x4000:50").unwrap();
+
+        assert_eq!(camera.name.as_deref(), Some("Full Frame"));
+        assert_eq!(camera.sensor_width_mm, 36.0);
+        assert_eq!(camera.pixel_height, 4000);
+        assert_eq!(camera.focal_length_mm, 50.0);
+    }
+
+    #[test]
+    fn test_parse_unnamed_spec_and_name_with_colon() {
+        let camera = parse_camera_spec("5.6x3.15:1920x1080:4mm").unwrap();
+        assert!(camera.name.is_none());
+        assert_eq!(camera.focal_length_mm, 4.0);
+
+        let camera = parse_camera_spec("Gate: PTZ:5.6x3.15:1920x1080:4").unwrap();
+        assert_eq!(camera.name.as_deref(), Some("Gate: PTZ"));
+    }
+
+    #[test]
+    fn test_parse_spec_with_pixel_pitch() {
+        let camera = parse_camera_spec("IMX264:3.45um:2448x2048:16").unwrap();
+        assert_eq!(camera.name.as_deref(), Some("IMX264"));
+        assert!((camera.sensor_width_mm - 8.4456).abs() < 1e-9);
+        assert!((camera.sensor_height_mm - 7.0656).abs() < 1e-9);
+
+        let camera = parse_camera_spec("3.45µm:2448x2048:16").unwrap();
+        assert!((camera.sensor_width_mm - 8.4456).abs() < 1e-9);
+
+        assert!(parse_camera_spec("3.4.5um:2448x2048:16").is_err());
+    }
+
+    #[test]
+    fn test_parse_cameras_json_one_or_many() {
+        let one = r#"{"sensor_width_mm": 6.4, "sensor_height_mm": 4.8,
+            "pixel_width": 1920, "pixel_height": 1080, "focal_length_mm": 4.0}"#;
+        assert_eq!(parse_cameras_json(one).unwrap().len(), 1);
+
+        let many = format!("[{}, {}]", one, one);
+        assert_eq!(parse_cameras_json(&many).unwrap().len(), 2);
+
+        assert!(parse_cameras_json("{}").is_err());
+    }
+
+    #[test]
+    fn test_parse_invalid_spec() {
+        assert!(parse_camera_spec("36x24:6000x4000").is_err());
+        assert!(parse_camera_spec("36:6000x4000:50").is_err());
+        assert!(parse_camera_spec("36x24:6000x4000.5:50").is_err());
+    }
+}
@@ -0,0 +1,197 @@
+use clap::ValueEnum;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Output format for command results
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable text
+    #[default]
+    Text,
+    /// JSON document
+    Json,
+    /// Comma-separated values with a header row
+    Csv,
+    /// Markdown table
+    Markdown,
+    /// Aligned plain-text table
+    Table,
+}
+
+/// Tabular view of a command result, one row per item
+#[derive(Debug, Clone, Default)]
+pub struct Table {
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(headers: &[&str]) -> Self {
+        Self {
+            headers: headers.iter().map(|h| h.to_string()).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+
+    fn to_csv(&self) -> String {
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        writer.write_record(&self.headers).unwrap();
+        for row in &self.rows {
+            writer.write_record(row).unwrap();
+        }
+        String::from_utf8(writer.into_inner().unwrap()).unwrap()
+    }
+
+    fn to_markdown(&self) -> String {
+        let escape = |cell: &String| cell.replace('|', "\\|");
+        let mut out = format!(
+            "| {} |\n|{}|\n",
+            self.headers
+                .iter()
+                .map(escape)
+                .collect::<Vec<_>>()
+                .join(" | "),
+            self.headers
+                .iter()
+                .map(|_| "---")
+                .collect::<Vec<_>>()
+                .join("|")
+        );
+        for row in &self.rows {
+            out.push_str(&format!(
+                "| {} |\n",
+                row.iter().map(escape).collect::<Vec<_>>().join(" | ")
+            ));
+        }
+        out
+    }
+
+    /// Render as an aligned plain-text table
+    pub fn to_aligned(&self) -> String {
+        let widths: Vec<usize> = (0..self.headers.len())
+            .map(|i| {
+                self.rows
+                    .iter()
+                    .filter_map(|r| r.get(i))
+                    .chain(std::iter::once(&self.headers[i]))
+                    .map(|c| c.chars().count())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+        let line = |cells: &[String]| {
+            cells
+                .iter()
+                .zip(&widths)
+                .map(|(c, w)| format!("{:<w$}", c, w = *w))
+                .collect::<Vec<_>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        };
+
+        let mut out = line(&self.headers) + "\n";
+        out.push_str(
+            &widths
+                .iter()
+                .map(|w| "-".repeat(*w))
+                .collect::<Vec<_>>()
+                .join("  "),
+        );
+        out.push('\n');
+        for row in &self.rows {
+            out.push_str(&line(row));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// A command result in every supported output format
+pub struct Report {
+    /// Human-readable text output
+    pub text: String,
+    /// Structured result for JSON output
+    pub json: Value,
+    /// Tabular result for CSV, Markdown and table output
+    pub table: Table,
+}
+
+impl Report {
+    pub fn new(text: String, json: &impl Serialize, table: Table) -> Self {
+        Self {
+            text,
+            json: serde_json::to_value(json).expect("result types serialize to JSON"),
+            table,
+        }
+    }
+
+    /// Render the report in the requested format
+    pub fn render(&self, format: OutputFormat) -> String {
+        match format {
+            OutputFormat::Text => self.text.clone(),
+            OutputFormat::Json => serde_json::to_string_pretty(&self.json).unwrap() + "\n",
+            OutputFormat::Csv => self.table.to_csv(),
+            OutputFormat::Markdown => self.table.to_markdown(),
+            OutputFormat::Table => self.table.to_aligned(),
+        }
+    }
+
+    pub fn print(&self, format: OutputFormat) {
+        print!("{}", self.render(format));
+    }
+}
+
+/// Format a number for tabular output, writing infinity as "inf"
+pub fn num(value: f64) -> String {
+    if value.is_infinite() {
+        "inf".to_string()
+    } else {
+        format!("{:.4}", value)
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table() -> Table {
+        let mut table = Table::new(&["name", "focal_length_mm"]);
+        table.push(vec!["Wide, 4MP".to_string(), num(2.8)]);
+        table.push(vec!["Tele".to_string(), num(12.0)]);
+        table
+    }
+
+    #[test]
+    fn test_csv_quotes_commas() {
+        assert_eq!(
+            table().to_csv(),
+            "name,focal_length_mm\n\"Wide, 4MP\",2.8\nTele,12\n"
+        );
+    }
+
+    #[test]
+    fn test_markdown_and_aligned() {
+        let markdown = table().to_markdown();
+        assert!(markdown.starts_with("| name | focal_length_mm |\n|---|---|\n"));
+
+        let aligned = table().to_aligned();
+        let lines: Vec<&str> = aligned.lines().collect();
+        assert_eq!(lines[0], "name       focal_length_mm");
+        assert_eq!(lines[2], "Wide, 4MP  2.8");
+    }
+
+    #[test]
+    fn test_num() {
+        assert_eq!(num(f64::INFINITY), "inf");
+        assert_eq!(num(1.23456), "1.2346");
+        assert_eq!(num(100.0), "100");
+    }
+}
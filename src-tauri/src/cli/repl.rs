@@ -0,0 +1,309 @@
+use std::fmt::Write as _;
+use std::io::{self, BufRead, IsTerminal, Write};
+use tauri_app_lib::optics::*;
+
+use crate::camera_spec::parse_camera_spec;
+
+const HELP: &str = "\
+Commands:
+  show                         Show the current camera and settings
+  set sensor <W>x<H>           Sensor size in mm
+  set pixels <W>x<H>           Resolution in pixels
+  set focal <mm>               Focal length in mm
+  set aperture <N>             F-number (for DOF and hyperfocal)
+  set coc <mm>                 Circle of confusion in mm
+  set distance <d>             Working distance (e.g. 20m, 500mm; bare numbers are meters)
+  set name <text>              Camera name
+  load <spec>                  Replace the camera, e.g. FF:36x24:6000x4000:50
+  fov [at <d>]                 Field of view and pixel density
+  dori                         DORI distances
+  dof [at <d>]                 Depth of field
+  hyperfocal                   Hyperfocal distance
+  help                         Show this help
+  quit                         Leave interactive mode";
+
+/// Parse a distance with an optional unit suffix, returning millimeters
+///
+/// Bare numbers are meters, since that is how distances are usually discussed on site.
+fn parse_distance_mm(text: &str) -> Result<f64, String> {
+    let text = text.trim();
+    let (number, scale) = if let Some(v) = text.strip_suffix("mm") {
+        (v, 1.0)
+    } else if let Some(v) = text.strip_suffix("cm") {
+        (v, 10.0)
+    } else if let Some(v) = text.strip_suffix("km") {
+        (v, 1_000_000.0)
+    } else if let Some(v) = text.strip_suffix('m') {
+        (v, 1000.0)
+    } else {
+        (text, 1000.0)
+    };
+    let value: f64 = number
+        .trim()
+        .parse()
+        .map_err(|_| format!("Invalid distance '{}'", text))?;
+    if value <= 0.0 {
+        return Err("Distance must be greater than zero".to_string());
+    }
+    Ok(value * scale)
+}
+
+fn parse_positive(text: &str, what: &str) -> Result<f64, String> {
+    match text.trim().parse::<f64>() {
+        Ok(v) if v > 0.0 => Ok(v),
+        _ => Err(format!(
+            "{} must be a positive number, got '{}'",
+            what, text
+        )),
+    }
+}
+
+fn parse_pair<T: std::str::FromStr>(text: &str, what: &str) -> Result<(T, T), String> {
+    text.split_once(['x', 'X'])
+        .and_then(|(a, b)| Some((a.trim().parse().ok()?, b.trim().parse().ok()?)))
+        .ok_or_else(|| format!("{} must be given as WIDTHxHEIGHT, got '{}'", what, text))
+}
+
+/// Result of executing one REPL line
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    /// Text to print (may be empty)
+    Output(String),
+    /// The user asked to leave
+    Quit,
+}
+
+/// Camera parameters that persist between interactive queries
+pub struct Session {
+    pub camera: CameraSystem,
+    pub distance_mm: f64,
+    pub coc_mm: f64,
+}
+
+impl Default for Session {
+    fn default() -> Self {
+        let camera = CameraSystem::new(5.6, 3.15, 1920, 1080, 4.0).with_f_number(1.6);
+        Self {
+            coc_mm: camera.default_coc_mm(),
+            camera,
+            distance_mm: 10_000.0,
+        }
+    }
+}
+
+impl Session {
+    /// Execute one line of input against the session
+    pub fn execute(&mut self, line: &str) -> Result<Outcome, String> {
+        let line = line.trim();
+        let (command, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        let rest = rest.trim();
+
+        let output = match command.to_ascii_lowercase().as_str() {
+            "" => String::new(),
+            "help" | "?" => HELP.to_string(),
+            "quit" | "exit" | "q" => return Ok(Outcome::Quit),
+            "show" | "camera" => self.show(),
+            "set" => {
+                self.set(rest)?;
+                self.show()
+            }
+            "load" => {
+                self.camera = parse_camera_spec(rest)?;
+                self.show()
+            }
+            "fov" => {
+                let distance_mm = self.distance_arg(rest)?;
+                calculate_fov(&self.camera, distance_mm).to_string()
+            }
+            "dori" => {
+                let dori = calculate_dori_distances(&self.camera);
+                format!(
+                    "Detection: {:.1} m\nObservation: {:.1} m\nRecognition: {:.1} m\nIdentification: {:.1} m",
+                    dori.detection_m, dori.observation_m, dori.recognition_m, dori.identification_m
+                )
+            }
+            "dof" => {
+                let distance_mm = self.distance_arg(rest)?;
+                let f_number = self.f_number()?;
+                let (near, far, total) = calculate_dof(
+                    distance_mm,
+                    self.camera.focal_length_mm,
+                    f_number,
+                    self.coc_mm,
+                    None,
+                );
+                let mut text = format!(
+                    "DOF at {:.2} m, f/{}: near {:.2} m, ",
+                    distance_mm / 1000.0,
+                    f_number,
+                    near / 1000.0
+                );
+                if far.is_infinite() {
+                    text.push_str("far ∞");
+                } else {
+                    write!(
+                        text,
+                        "far {:.2} m, total {:.2} m",
+                        far / 1000.0,
+                        total / 1000.0
+                    )
+                    .unwrap();
+                }
+                text
+            }
+            "hyperfocal" => {
+                let f_number = self.f_number()?;
+                let hyperfocal =
+                    calculate_hyperfocal(self.camera.focal_length_mm, f_number, self.coc_mm);
+                format!("Hyperfocal Distance: {:.2} m", hyperfocal / 1000.0)
+            }
+            other => return Err(format!("Unknown command '{}'; type 'help'", other)),
+        };
+        Ok(Outcome::Output(output))
+    }
+
+    fn show(&self) -> String {
+        let mut text = self.camera.to_string();
+        if let Some(f_number) = self.camera.f_number {
+            write!(text, ", f/{}", f_number).unwrap();
+        }
+        write!(
+            text,
+            "\nDistance: {:.2} m, CoC: {} mm",
+            self.distance_mm / 1000.0,
+            self.coc_mm
+        )
+        .unwrap();
+        text
+    }
+
+    fn set(&mut self, args: &str) -> Result<(), String> {
+        let (param, value) = args
+            .split_once(char::is_whitespace)
+            .ok_or("Usage: set <parameter> <value>; type 'help'")?;
+        let value = value.trim();
+
+        match param.to_ascii_lowercase().as_str() {
+            "sensor" => {
+                let (w, h) = parse_pair::<f64>(value, "Sensor size")?;
+                self.camera.sensor_width_mm = w;
+                self.camera.sensor_height_mm = h;
+            }
+            "pixels" | "resolution" => {
+                let (w, h) = parse_pair::<u32>(value, "Resolution")?;
+                if w == 0 || h == 0 {
+                    return Err("Resolution must be non-zero".to_string());
+                }
+                self.camera.pixel_width = w;
+                self.camera.pixel_height = h;
+            }
+            "focal" | "focal-length" => {
+                self.camera.focal_length_mm =
+                    parse_positive(value.trim_end_matches("mm"), "Focal length")?
+            }
+            "aperture" | "f-number" | "fnumber" | "f" => {
+                let value = value.trim_start_matches(['f', 'F', '/']);
+                self.camera.f_number = Some(parse_positive(value, "F-number")?);
+            }
+            "coc" => self.coc_mm = parse_positive(value, "Circle of confusion")?,
+            "distance" => self.distance_mm = parse_distance_mm(value)?,
+            "name" => self.camera.name = Some(value.to_string()).filter(|n| !n.is_empty()),
+            other => return Err(format!("Unknown parameter '{}'; type 'help'", other)),
+        }
+        Ok(())
+    }
+
+    /// Distance from an optional "at <d>" argument, defaulting to the session distance
+    fn distance_arg(&self, args: &str) -> Result<f64, String> {
+        let args = args.strip_prefix("at").unwrap_or(args).trim();
+        if args.is_empty() {
+            Ok(self.distance_mm)
+        } else {
+            parse_distance_mm(args)
+        }
+    }
+
+    fn f_number(&self) -> Result<f64, String> {
+        self.camera
+            .f_number
+            .ok_or_else(|| "Set an aperture first, e.g. 'set aperture 2.8'".to_string())
+    }
+}
+
+/// Run the interactive loop on standard input until EOF or "quit"
+pub fn run_repl(mut session: Session) {
+    let stdin = io::stdin();
+    let interactive = stdin.is_terminal();
+    if interactive {
+        println!("Interactive mode. Type 'help' for commands, 'quit' to leave.");
+        println!("{}", session.show());
+    }
+
+    let mut lines = stdin.lock().lines();
+    loop {
+        if interactive {
+            print!("camera-optics> ");
+            io::stdout().flush().ok();
+        }
+        let Some(Ok(line)) = lines.next() else {
+            break;
+        };
+        match session.execute(&line) {
+            Ok(Outcome::Quit) => break,
+            Ok(Outcome::Output(text)) if text.is_empty() => {}
+            Ok(Outcome::Output(text)) => println!("{}", text),
+            Err(e) => eprintln!("Error: {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(session: &mut Session, line: &str) -> String {
+        match session.execute(line).unwrap() {
+            Outcome::Output(text) => text,
+            Outcome::Quit => panic!("unexpected quit"),
+        }
+    }
+
+    #[test]
+    fn test_settings_persist_between_queries() {
+        let mut session = Session::default();
+        run(&mut session, "set sensor 6.4x4.8");
+        run(&mut session, "set pixels 1920x1080");
+        run(&mut session, "set focal 8");
+
+        let fov = run(&mut session, "fov at 20m");
+        let expected = calculate_fov(&CameraSystem::new(6.4, 4.8, 1920, 1080, 8.0), 20_000.0);
+        assert_eq!(fov, expected.to_string());
+
+        // Without "at", the session distance is used
+        run(&mut session, "set distance 500mm");
+        assert!(run(&mut session, "fov").contains("@ 0.50 m"));
+    }
+
+    #[test]
+    fn test_parse_distance_units() {
+        assert_eq!(parse_distance_mm("20").unwrap(), 20_000.0);
+        assert_eq!(parse_distance_mm("20m").unwrap(), 20_000.0);
+        assert_eq!(parse_distance_mm("35 cm").unwrap(), 350.0);
+        assert_eq!(parse_distance_mm("1.5km").unwrap(), 1_500_000.0);
+        assert!(parse_distance_mm("-3m").is_err());
+    }
+
+    #[test]
+    fn test_errors_and_quit() {
+        let mut session = Session::default();
+        session.camera.f_number = None;
+
+        assert!(session.execute("dof").is_err());
+        assert!(session.execute("set focal zero").is_err());
+        assert!(session.execute("frobnicate").is_err());
+        assert_eq!(session.execute("quit").unwrap(), Outcome::Quit);
+
+        run(&mut session, "set aperture f/2.8");
+        assert!(run(&mut session, "hyperfocal").starts_with("Hyperfocal Distance"));
+    }
+}
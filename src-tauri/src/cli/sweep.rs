@@ -0,0 +1,129 @@
+use clap::ValueEnum;
+use tauri_app_lib::batch::{BatchConfig, BatchScenario};
+use tauri_app_lib::optics::CameraSystem;
+
+/// Upper limit on sweep steps, to catch a mistyped step size
+const MAX_STEPS: usize = 10_000;
+
+/// Parameter varied by a sweep
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SweepParam {
+    /// Working distance in millimeters
+    Distance,
+    /// Focal length in millimeters
+    Focal,
+    /// F-number
+    Aperture,
+}
+
+/// Values from `from` to `to` (inclusive) in increments of `step`
+pub fn sweep_values(from: f64, to: f64, step: f64) -> Result<Vec<f64>, String> {
+    if step <= 0.0 {
+        return Err("Step must be greater than zero".to_string());
+    }
+    if from <= 0.0 || to < from {
+        return Err("Range must be positive with --from <= --to".to_string());
+    }
+    // Small tolerance so that e.g. 0.1 steps reach the end value despite rounding
+    let steps = ((to - from) / step + 1e-9).floor() as usize;
+    if steps >= MAX_STEPS {
+        return Err(format!("Sweep would take more than {} steps", MAX_STEPS));
+    }
+    Ok((0..=steps).map(|i| from + step * i as f64).collect())
+}
+
+/// Build the batch of camera/scenario pairs evaluated by a sweep
+///
+/// Focal length sweeps vary the camera; distance and aperture sweeps vary the scenario.
+pub fn build_sweep(
+    camera: &CameraSystem,
+    param: SweepParam,
+    values: &[f64],
+    distance_mm: f64,
+    f_number: Option<f64>,
+    coc_mm: Option<f64>,
+) -> BatchConfig {
+    let scenario = |distance_mm: f64, f_number: Option<f64>| BatchScenario {
+        name: None,
+        distance_m: distance_mm / 1000.0,
+        f_number,
+        coc_mm,
+    };
+
+    match param {
+        SweepParam::Focal => BatchConfig {
+            cameras: values
+                .iter()
+                .map(|&focal_length_mm| CameraSystem {
+                    focal_length_mm,
+                    ..camera.clone()
+                })
+                .collect(),
+            scenarios: vec![scenario(distance_mm, f_number)],
+        },
+        SweepParam::Distance => BatchConfig {
+            cameras: vec![camera.clone()],
+            scenarios: values.iter().map(|&d| scenario(d, f_number)).collect(),
+        },
+        SweepParam::Aperture => BatchConfig {
+            cameras: vec![camera.clone()],
+            scenarios: values
+                .iter()
+                .map(|&n| scenario(distance_mm, Some(n)))
+                .collect(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tauri_app_lib::batch::run_batch;
+
+    #[test]
+    fn test_sweep_values_inclusive() {
+        assert_eq!(sweep_values(2.8, 12.0, 0.1).unwrap().len(), 93);
+        assert_eq!(
+            sweep_values(1000.0, 5000.0, 2000.0).unwrap(),
+            vec![1000.0, 3000.0, 5000.0]
+        );
+        assert!(sweep_values(1.0, 2.0, 0.0).is_err());
+        assert!(sweep_values(5.0, 2.0, 1.0).is_err());
+        assert!(sweep_values(1.0, 1e9, 1.0).is_err());
+    }
+
+    #[test]
+    fn test_focal_sweep_varies_camera() {
+        let camera = CameraSystem::new(5.6, 3.15, 1920, 1080, 4.0);
+        let config = build_sweep(
+            &camera,
+            SweepParam::Focal,
+            &[4.0, 8.0],
+            20_000.0,
+            None,
+            None,
+        );
+        let results = run_batch(&config);
+
+        assert_eq!(results.entries.len(), 2);
+        let ppm = |i: usize| results.entries[i].fov.horizontal_ppm;
+        assert!((ppm(1) / ppm(0) - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_aperture_sweep_varies_dof() {
+        let camera = CameraSystem::new(36.0, 24.0, 6000, 4000, 50.0);
+        let config = build_sweep(
+            &camera,
+            SweepParam::Aperture,
+            &[2.8, 8.0],
+            5000.0,
+            None,
+            Some(0.03),
+        );
+        let results = run_batch(&config);
+
+        let near = |i: usize| results.entries[i].dof.as_ref().unwrap().near_mm;
+        assert!(near(1) < near(0));
+    }
+}
@@ -1,10 +1,33 @@
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, Parser, Subcommand};
+use clap_complete::Shell;
+use serde_json::json;
+use std::fmt::Write;
+use std::path::PathBuf;
+use tauri_app_lib::batch::*;
 use tauri_app_lib::optics::*;
+use tauri_app_lib::video::*;
+
+#[path = "cli/camera_spec.rs"]
+mod camera_spec;
+#[path = "cli/output.rs"]
+mod output;
+#[path = "cli/repl.rs"]
+mod repl;
+#[path = "cli/sweep.rs"]
+mod sweep;
+
+use camera_spec::{parse_camera_spec, read_cameras_file, read_input, STDIN_PATH};
+use output::{num, OutputFormat, Report, Table};
+use sweep::{build_sweep, sweep_values, SweepParam};
 
 #[derive(Parser)]
 #[command(name = "camera-optics-cli")]
 #[command(about = "Camera optics calculator - FOV, resolution, and depth of field", long_about = None)]
 struct Cli {
+    /// Output format for results [default: text, or json when reading from stdin]
+    #[arg(short = 'o', long, global = true, value_enum)]
+    output: Option<OutputFormat>,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -14,12 +37,16 @@ enum Commands {
     /// Calculate field of view and spatial resolution
     Fov {
         /// Sensor width in millimeters
-        #[arg(short = 'W', long)]
-        sensor_width: f64,
+        #[arg(short = 'W', long, required_unless_present = "pixel_pitch")]
+        sensor_width: Option<f64>,
 
         /// Sensor height in millimeters
-        #[arg(short = 'H', long)]
-        sensor_height: f64,
+        #[arg(short = 'H', long, required_unless_present = "pixel_pitch")]
+        sensor_height: Option<f64>,
+
+        /// Pixel pitch in micrometers, instead of the sensor size
+        #[arg(short = 'p', long, conflicts_with_all = ["sensor_width", "sensor_height"])]
+        pixel_pitch: Option<f64>,
 
         /// Horizontal pixel count
         #[arg(short = 'x', long)]
@@ -74,17 +101,33 @@ enum Commands {
         /// Circle of confusion in millimeters (default: 0.03 for full frame)
         #[arg(short = 'c', long, default_value = "0.03")]
         coc: f64,
+
+        /// Pupil magnification (exit / entrance pupil) for retrofocus or telephoto lenses
+        #[arg(short = 'p', long)]
+        pupil_magnification: Option<f64>,
     },
 
-    /// Compare multiple camera presets
+    /// Compare multiple camera systems
     Compare {
         /// Working distance in millimeters
         #[arg(short = 'd', long)]
         distance: f64,
 
-        /// Use common sensor presets (full-frame, aps-c, micro-43)
+        /// Camera as [name:]SENSOR_WxSENSOR_H:PIXELS_WxPIXELS_H:FOCAL, e.g. "FF:36x24:6000x4000:50" (repeatable)
+        #[arg(short = 'c', long = "camera", value_parser = parse_camera_spec)]
+        cameras: Vec<CameraSystem>,
+
+        /// JSON file containing a camera system or an array of them ("-" for stdin)
+        #[arg(long)]
+        file: Option<PathBuf>,
+
+        /// Include common sensor presets (full-frame, aps-c, micro-43)
         #[arg(long)]
         presets: bool,
+
+        /// Include smartphone camera modules (main, ultrawide, 3x and 5x tele)
+        #[arg(long)]
+        phones: bool,
     },
 
     /// Calculate focal length from field of view
@@ -101,38 +144,330 @@ enum Commands {
         #[arg(short = 'v', long)]
         vertical: bool,
     },
+
+    /// Estimate recording storage for a group of identical cameras
+    Storage {
+        /// Number of cameras
+        #[arg(short = 'n', long, default_value = "1")]
+        cameras: u32,
+
+        /// Horizontal pixel count
+        #[arg(short = 'x', long)]
+        pixel_width: u32,
+
+        /// Vertical pixel count
+        #[arg(short = 'y', long)]
+        pixel_height: u32,
+
+        /// Frames per second
+        #[arg(short = 'r', long, default_value = "25")]
+        fps: f64,
+
+        /// Video codec (h264, h265, mjpeg)
+        #[arg(short = 'c', long, default_value = "h264")]
+        codec: VideoCodec,
+
+        /// Scene activity (low, medium, high)
+        #[arg(short = 'a', long, default_value = "medium")]
+        activity: SceneActivity,
+
+        /// Retention period in days
+        #[arg(short = 'd', long)]
+        days: f64,
+
+        /// Record on motion only, for this percentage of the time (default: continuous)
+        #[arg(short = 'm', long)]
+        motion_percent: Option<f64>,
+    },
+
+    /// Longest exposure before stars trail on a fixed tripod (500 and NPF rules)
+    StarTrails {
+        /// Camera as [name:]SENSOR_WxSENSOR_H:PIXELS_WxPIXELS_H:FOCAL
+        #[arg(short = 'c', long = "camera", value_parser = parse_camera_spec)]
+        camera: CameraSystem,
+
+        /// F-number (aperture)
+        #[arg(short = 'a', long)]
+        f_number: f64,
+
+        /// Declination of the stars in degrees (default: 0, the celestial equator)
+        #[arg(short = 'D', long, default_value = "0", allow_hyphen_values = true)]
+        declination: f64,
+    },
+
+    /// Evaluate FOV, DORI and DOF for every camera/scenario pair in a TOML or JSON file
+    Batch {
+        /// Batch file describing cameras and scenarios ("-" for JSON on stdin)
+        file: PathBuf,
+
+        /// Write the consolidated results to this file instead of stdout
+        #[arg(short = 'O', long)]
+        out: Option<PathBuf>,
+    },
+
+    /// Vary distance, focal length or aperture over a range and tabulate the results
+    Sweep {
+        /// Parameter to vary
+        #[arg(value_enum)]
+        param: SweepParam,
+
+        /// Camera as [name:]SENSOR_WxSENSOR_H:PIXELS_WxPIXELS_H:FOCAL
+        #[arg(short = 'c', long = "camera", value_parser = parse_camera_spec)]
+        camera: CameraSystem,
+
+        /// Start of the range (mm for distance and focal length)
+        #[arg(long)]
+        from: f64,
+
+        /// End of the range, inclusive
+        #[arg(long)]
+        to: f64,
+
+        /// Step between values
+        #[arg(long)]
+        step: f64,
+
+        /// Working distance in millimeters when not sweeping distance
+        #[arg(short = 'd', long, default_value = "10000")]
+        distance: f64,
+
+        /// F-number for depth of field when not sweeping aperture
+        #[arg(short = 'a', long)]
+        f_number: Option<f64>,
+
+        /// Circle of confusion in millimeters (default: sensor diagonal / 1500)
+        #[arg(long)]
+        coc: Option<f64>,
+    },
+
+    /// Interactive mode where camera parameters persist between queries
+    Interactive {
+        /// Starting camera as [name:]SENSOR_WxSENSOR_H:PIXELS_WxPIXELS_H:FOCAL
+        #[arg(short = 'c', long = "camera", value_parser = parse_camera_spec)]
+        camera: Option<CameraSystem>,
+    },
+
+    /// Print a shell completion script
+    Completions {
+        /// Shell to generate completions for
+        #[arg(value_enum)]
+        shell: Shell,
+    },
+}
+
+/// Tabular view of FOV results, one row per camera
+fn fov_table(results: &[CameraWithResult]) -> Table {
+    let mut table = Table::new(&[
+        "name",
+        "sensor_width_mm",
+        "sensor_height_mm",
+        "pixel_width",
+        "pixel_height",
+        "focal_length_mm",
+        "distance_m",
+        "horizontal_fov_deg",
+        "vertical_fov_deg",
+        "horizontal_fov_m",
+        "vertical_fov_m",
+        "horizontal_ppm",
+        "vertical_ppm",
+        "diagonal_fov_deg",
+        "horizontal_px_per_deg",
+        "horizontal_ppf",
+        "vertical_ppf",
+        "megapixels_per_m2",
+        "horizontal_ifov_urad",
+        "vertical_ifov_urad",
+        "detection_m",
+        "observation_m",
+        "recognition_m",
+        "identification_m",
+    ]);
+    for CameraWithResult { camera, result, .. } in results {
+        let dori = |f: fn(&DoriDistances) -> f64| result.dori.as_ref().map(f).map(num);
+        table.push(vec![
+            camera.name.clone().unwrap_or_default(),
+            num(camera.sensor_width_mm),
+            num(camera.sensor_height_mm),
+            camera.pixel_width.to_string(),
+            camera.pixel_height.to_string(),
+            num(camera.focal_length_mm),
+            num(result.distance_m),
+            num(result.horizontal_fov_deg),
+            num(result.vertical_fov_deg),
+            num(result.horizontal_fov_m),
+            num(result.vertical_fov_m),
+            num(result.horizontal_ppm),
+            num(result.vertical_ppm),
+            num(result.diagonal_fov_deg),
+            num(result.horizontal_px_per_deg),
+            num(result.horizontal_ppf),
+            num(result.vertical_ppf),
+            num(result.megapixels_per_m2),
+            num(result.horizontal_ifov_urad),
+            num(result.vertical_ifov_urad),
+            dori(|d| d.detection_m).unwrap_or_default(),
+            dori(|d| d.observation_m).unwrap_or_default(),
+            dori(|d| d.recognition_m).unwrap_or_default(),
+            dori(|d| d.identification_m).unwrap_or_default(),
+        ]);
+    }
+    table
+}
+
+/// Result columns shared by batch and sweep output
+const ENTRY_COLUMNS: [&str; 15] = [
+    "distance_m",
+    "horizontal_fov_deg",
+    "vertical_fov_deg",
+    "horizontal_fov_m",
+    "vertical_fov_m",
+    "horizontal_ppm",
+    "vertical_ppm",
+    "detection_m",
+    "observation_m",
+    "recognition_m",
+    "identification_m",
+    "f_number",
+    "near_m",
+    "far_m",
+    "total_dof_m",
+];
+
+/// Table cells for a batch entry, matching `ENTRY_COLUMNS`
+fn entry_cells(entry: &BatchEntry) -> Vec<String> {
+    let fov = &entry.fov;
+    let dori = fov.dori.as_ref();
+    let dof = entry.dof.as_ref();
+    let metres = |mm: Option<f64>| num(mm.map_or(f64::INFINITY, |mm| mm / 1000.0));
+
+    vec![
+        num(fov.distance_m),
+        num(fov.horizontal_fov_deg),
+        num(fov.vertical_fov_deg),
+        num(fov.horizontal_fov_m),
+        num(fov.vertical_fov_m),
+        num(fov.horizontal_ppm),
+        num(fov.vertical_ppm),
+        dori.map(|d| num(d.detection_m)).unwrap_or_default(),
+        dori.map(|d| num(d.observation_m)).unwrap_or_default(),
+        dori.map(|d| num(d.recognition_m)).unwrap_or_default(),
+        dori.map(|d| num(d.identification_m)).unwrap_or_default(),
+        dof.map(|d| num(d.f_number)).unwrap_or_default(),
+        dof.map(|d| num(d.near_mm / 1000.0)).unwrap_or_default(),
+        dof.map(|d| metres(d.far_mm)).unwrap_or_default(),
+        dof.map(|d| metres(d.total_mm)).unwrap_or_default(),
+    ]
+}
+
+/// Consolidated report for a batch run, one row per camera/scenario pair
+fn batch_report(results: &BatchResults) -> Report {
+    let mut headers = vec!["camera", "scenario"];
+    headers.extend(ENTRY_COLUMNS);
+    let mut table = Table::new(&headers);
+    let mut text = String::new();
+
+    for (i, entry) in results.entries.iter().enumerate() {
+        let camera = entry.camera.name.clone().unwrap_or_default();
+        let scenario = entry.scenario.name.clone().unwrap_or_default();
+        let fov = &entry.fov;
+
+        let mut row = vec![camera.clone(), scenario.clone()];
+        row.extend(entry_cells(entry));
+        table.push(row);
+
+        if i > 0 {
+            writeln!(text, "{}", "=".repeat(80)).unwrap();
+        }
+        let title = if camera.is_empty() {
+            "Unnamed"
+        } else {
+            &camera
+        };
+        if scenario.is_empty() {
+            writeln!(text, "{} ({:.2} m)", title, fov.distance_m).unwrap();
+        } else {
+            writeln!(text, "{} @ {} ({:.2} m)", title, scenario, fov.distance_m).unwrap();
+        }
+        writeln!(text, "{}", fov).unwrap();
+        if let Some(dof) = &entry.dof {
+            let far = dof
+                .far_mm
+                .map_or("∞".to_string(), |far| format!("{:.2} m", far / 1000.0));
+            writeln!(
+                text,
+                "DOF at f/{}: {:.2} m – {}",
+                dof.f_number,
+                dof.near_mm / 1000.0,
+                far
+            )
+            .unwrap();
+        }
+    }
+
+    Report::new(text, results, table)
+}
+
+/// Report for a sweep, one row per step; text output is an aligned table
+///
+/// Distance and f-number are already among the entry columns, so only the focal length is added.
+fn sweep_report(results: &BatchResults) -> Report {
+    let mut headers = vec!["focal_length_mm"];
+    headers.extend(ENTRY_COLUMNS);
+    let mut table = Table::new(&headers);
+
+    for entry in &results.entries {
+        let mut row = vec![num(entry.camera.focal_length_mm)];
+        row.extend(entry_cells(entry));
+        table.push(row);
+    }
+
+    Report::new(table.to_aligned(), results, table)
 }
 
 fn main() {
     let cli = Cli::parse();
+    let mut out_file = None;
+    let mut from_stdin = false;
 
-    match cli.command {
+    let report = match cli.command {
         Commands::Fov {
             sensor_width,
             sensor_height,
+            pixel_pitch,
             pixel_width,
             pixel_height,
             focal_length,
             distance,
             name,
         } => {
-            let mut camera = CameraSystem::new(
-                sensor_width,
-                sensor_height,
-                pixel_width,
-                pixel_height,
-                focal_length,
-            );
+            let mut camera = match (pixel_pitch, sensor_width, sensor_height) {
+                (Some(pitch), _, _) => {
+                    CameraSystem::from_pixel_pitch(pixel_width, pixel_height, pitch, focal_length)
+                }
+                (None, Some(width), Some(height)) => {
+                    CameraSystem::new(width, height, pixel_width, pixel_height, focal_length)
+                }
+                // clap requires the sensor size whenever no pitch is given
+                _ => unreachable!("sensor size or pixel pitch is required"),
+            };
 
             if let Some(name) = name {
                 camera = camera.with_name(name);
             }
 
-            println!("{}", camera);
-            println!();
-
             let result = calculate_fov(&camera, distance);
-            println!("{}", result);
+            let text = format!("{}\n\n{}\n", camera, result);
+
+            let results = vec![CameraWithResult {
+                low_light: calculate_low_light_score(&camera),
+                suitability: None,
+                isolation: None,
+                night: None,
+                camera,
+                result,
+            }];
+            Report::new(text, &results[0], fov_table(&results))
         }
 
         Commands::Hyperfocal {
@@ -141,14 +476,34 @@ fn main() {
             coc,
         } => {
             let hyperfocal = calculate_hyperfocal(focal_length, f_number, coc);
-            println!(
+
+            let mut text = String::new();
+            writeln!(
+                text,
                 "Hyperfocal Distance: {:.2} mm ({:.2} m)",
                 hyperfocal,
                 hyperfocal / 1000.0
-            );
-            println!("Focal Length: {} mm", focal_length);
-            println!("F-number: f/{}", f_number);
-            println!("Circle of Confusion: {} mm", coc);
+            )
+            .unwrap();
+            writeln!(text, "Focal Length: {} mm", focal_length).unwrap();
+            writeln!(text, "F-number: f/{}", f_number).unwrap();
+            writeln!(text, "Circle of Confusion: {} mm", coc).unwrap();
+
+            let mut table = Table::new(&["focal_length_mm", "f_number", "coc_mm", "hyperfocal_mm"]);
+            table.push(vec![
+                num(focal_length),
+                num(f_number),
+                num(coc),
+                num(hyperfocal),
+            ]);
+
+            let json = json!({
+                "focal_length_mm": focal_length,
+                "f_number": f_number,
+                "coc_mm": coc,
+                "hyperfocal_mm": hyperfocal,
+            });
+            Report::new(text, &json, table)
         }
 
         Commands::Dof {
@@ -156,60 +511,135 @@ fn main() {
             focal_length,
             f_number,
             coc,
+            pupil_magnification,
         } => {
-            let (near, far, total) = calculate_dof(distance, focal_length, f_number, coc);
-
-            println!("Depth of Field Calculation");
-            println!("==========================");
-            println!(
+            let (near, far, total) =
+                calculate_dof(distance, focal_length, f_number, coc, pupil_magnification);
+
+            let mut text = String::new();
+            writeln!(text, "Depth of Field Calculation").unwrap();
+            writeln!(text, "==========================").unwrap();
+            writeln!(
+                text,
                 "Object Distance: {:.2} mm ({:.2} m)",
                 distance,
                 distance / 1000.0
-            );
-            println!("Focal Length: {} mm", focal_length);
-            println!("F-number: f/{}", f_number);
-            println!("Circle of Confusion: {} mm", coc);
-            println!();
-            println!("Near Limit: {:.2} mm ({:.2} m)", near, near / 1000.0);
+            )
+            .unwrap();
+            writeln!(text, "Focal Length: {} mm", focal_length).unwrap();
+            writeln!(text, "F-number: f/{}", f_number).unwrap();
+            writeln!(text, "Circle of Confusion: {} mm", coc).unwrap();
+            if let Some(pupil_magnification) = pupil_magnification {
+                writeln!(text, "Pupil Magnification: {}", pupil_magnification).unwrap();
+            }
+            writeln!(text).unwrap();
+            writeln!(text, "Near Limit: {:.2} mm ({:.2} m)", near, near / 1000.0).unwrap();
 
             if far.is_infinite() {
-                println!("Far Limit: ∞ (infinity)");
+                writeln!(text, "Far Limit: ∞ (infinity)").unwrap();
             } else {
-                println!("Far Limit: {:.2} mm ({:.2} m)", far, far / 1000.0);
+                writeln!(text, "Far Limit: {:.2} mm ({:.2} m)", far, far / 1000.0).unwrap();
             }
 
             if total.is_infinite() {
-                println!("Total DOF: ∞ (infinity)");
+                writeln!(text, "Total DOF: ∞ (infinity)").unwrap();
             } else {
-                println!("Total DOF: {:.2} mm ({:.2} m)", total, total / 1000.0);
+                writeln!(text, "Total DOF: {:.2} mm ({:.2} m)", total, total / 1000.0).unwrap();
             }
+
+            let mut table = Table::new(&[
+                "distance_mm",
+                "focal_length_mm",
+                "f_number",
+                "coc_mm",
+                "near_mm",
+                "far_mm",
+                "total_mm",
+            ]);
+            table.push(vec![
+                num(distance),
+                num(focal_length),
+                num(f_number),
+                num(coc),
+                num(near),
+                num(far),
+                num(total),
+            ]);
+
+            // JSON has no infinity; an unbounded far limit is written as null
+            let finite = |v: f64| if v.is_finite() { Some(v) } else { None };
+            let json = json!({
+                "distance_mm": distance,
+                "focal_length_mm": focal_length,
+                "f_number": f_number,
+                "coc_mm": coc,
+                "pupil_magnification": pupil_magnification,
+                "near_mm": near,
+                "far_mm": finite(far),
+                "total_mm": finite(total),
+            });
+            Report::new(text, &json, table)
         }
 
-        Commands::Compare { distance, presets } => {
-            let cameras = if presets {
-                vec![
+        Commands::Compare {
+            distance,
+            mut cameras,
+            file,
+            presets,
+            phones,
+        } => {
+            if let Some(file) = file {
+                from_stdin = file.as_os_str() == STDIN_PATH;
+                let from_file = read_cameras_file(&file).unwrap_or_else(|e| {
+                    eprintln!("{}", e);
+                    std::process::exit(1);
+                });
+                cameras.splice(0..0, from_file);
+            }
+            if presets {
+                cameras.extend([
                     CameraSystem::new(36.0, 24.0, 6000, 4000, 50.0).with_name("Full Frame - 50mm"),
                     CameraSystem::new(23.5, 15.6, 6000, 4000, 35.0).with_name("APS-C - 35mm"),
                     CameraSystem::new(17.3, 13.0, 5184, 3888, 25.0).with_name("Micro 4/3 - 25mm"),
-                ]
-            } else {
-                println!("Use --presets flag to compare common sensor formats");
+                ]);
+            }
+            if phones {
+                cameras.extend(smartphone_camera_presets());
+            }
+            if cameras.is_empty() {
+                println!("Specify cameras with --camera or --file, or use --presets or --phones to compare common sensor formats");
                 return;
-            };
+            }
 
-            println!(
+            let results = compare_cameras(cameras, distance);
+
+            let mut text = String::new();
+            writeln!(
+                text,
                 "Comparing camera systems at {} mm ({} m) distance:\n",
                 distance,
                 distance / 1000.0
-            );
-
-            for camera in &cameras {
-                println!("{}", camera);
-                let result = calculate_fov(camera, distance);
-                println!("{}", result);
-                println!("{}", "=".repeat(80));
-                println!();
+            )
+            .unwrap();
+
+            for CameraWithResult {
+                camera,
+                result,
+                night,
+                ..
+            } in &results
+            {
+                writeln!(text, "{}", camera).unwrap();
+                writeln!(text, "{}", result).unwrap();
+                if let Some(night) = night {
+                    writeln!(text, "\nNight mode: {}", night.camera).unwrap();
+                    writeln!(text, "{}", night.result).unwrap();
+                }
+                writeln!(text, "{}", "=".repeat(80)).unwrap();
+                writeln!(text).unwrap();
             }
+
+            Report::new(text, &results, fov_table(&results))
         }
 
         Commands::FocalLength {
@@ -221,12 +651,234 @@ fn main() {
 
             let fov_type = if vertical { "Vertical" } else { "Horizontal" };
 
-            println!("Focal Length Calculation");
-            println!("========================");
-            println!("Sensor Size: {} mm", sensor_size);
-            println!("{} FOV: {}°", fov_type, fov);
-            println!();
-            println!("Calculated Focal Length: {:.2} mm", focal_length);
+            let mut text = String::new();
+            writeln!(text, "Focal Length Calculation").unwrap();
+            writeln!(text, "========================").unwrap();
+            writeln!(text, "Sensor Size: {} mm", sensor_size).unwrap();
+            writeln!(text, "{} FOV: {}°", fov_type, fov).unwrap();
+            writeln!(text).unwrap();
+            writeln!(text, "Calculated Focal Length: {:.2} mm", focal_length).unwrap();
+
+            let mut table =
+                Table::new(&["sensor_size_mm", "fov_deg", "fov_type", "focal_length_mm"]);
+            table.push(vec![
+                num(sensor_size),
+                num(fov),
+                fov_type.to_lowercase(),
+                num(focal_length),
+            ]);
+
+            let json = json!({
+                "sensor_size_mm": sensor_size,
+                "fov_deg": fov,
+                "fov_type": fov_type.to_lowercase(),
+                "focal_length_mm": focal_length,
+            });
+            Report::new(text, &json, table)
+        }
+
+        Commands::Storage {
+            cameras,
+            pixel_width,
+            pixel_height,
+            fps,
+            codec,
+            activity,
+            days,
+            motion_percent,
+        } => {
+            let recording_mode = match motion_percent {
+                Some(activity_percent) => RecordingMode::Motion { activity_percent },
+                None => RecordingMode::Continuous,
+            };
+            let group = StorageStream {
+                name: None,
+                count: cameras,
+                stream: BitrateParams {
+                    pixel_width,
+                    pixel_height,
+                    frame_rate: fps,
+                    codec,
+                    activity,
+                },
+                recording_mode,
+            };
+            let estimate = estimate_storage(&[group], days);
+            let stream = &estimate.streams[0];
+
+            let mut text = String::new();
+            writeln!(text, "Storage Estimate").unwrap();
+            writeln!(text, "================").unwrap();
+            writeln!(
+                text,
+                "Cameras: {} × {}x{} @ {} fps ({:?}, {:?} activity)",
+                cameras, pixel_width, pixel_height, fps, codec, activity
+            )
+            .unwrap();
+            match recording_mode {
+                RecordingMode::Continuous => writeln!(text, "Recording: continuous").unwrap(),
+                RecordingMode::Motion { activity_percent } => writeln!(
+                    text,
+                    "Recording: motion ({}% of the time)",
+                    activity_percent
+                )
+                .unwrap(),
+            }
+            writeln!(text, "Retention: {} days", days).unwrap();
+            writeln!(text).unwrap();
+            writeln!(
+                text,
+                "Average Bitrate: {:.2} Mbps per camera",
+                stream.average_mbps
+            )
+            .unwrap();
+            writeln!(text, "Total Storage: {:.2} TB", estimate.total_tb).unwrap();
+
+            let mut table = Table::new(&[
+                "cameras",
+                "pixel_width",
+                "pixel_height",
+                "fps",
+                "codec",
+                "activity",
+                "recording_fraction",
+                "retention_days",
+                "average_mbps",
+                "total_tb",
+            ]);
+            table.push(vec![
+                cameras.to_string(),
+                pixel_width.to_string(),
+                pixel_height.to_string(),
+                num(fps),
+                format!("{:?}", codec).to_lowercase(),
+                format!("{:?}", activity).to_lowercase(),
+                num(recording_mode.recording_fraction()),
+                num(days),
+                num(stream.average_mbps),
+                num(estimate.total_tb),
+            ]);
+            Report::new(text, &estimate, table)
+        }
+
+        Commands::StarTrails {
+            camera,
+            f_number,
+            declination,
+        } => {
+            let limit = calculate_star_trail_limit(&camera, f_number, declination);
+
+            let mut text = String::new();
+            writeln!(text, "Star Trail Exposure Limit").unwrap();
+            writeln!(text, "=========================").unwrap();
+            writeln!(
+                text,
+                "Focal Length: {} mm (crop factor {:.2})",
+                camera.focal_length_mm, limit.crop_factor
+            )
+            .unwrap();
+            writeln!(text, "F-number: f/{}", f_number).unwrap();
+            writeln!(text, "Pixel Pitch: {:.2} µm", limit.pixel_pitch_um).unwrap();
+            writeln!(text, "Declination: {}°", declination).unwrap();
+            writeln!(text).unwrap();
+            writeln!(text, "500 Rule: {:.1} s", limit.rule_500_s).unwrap();
+            writeln!(text, "NPF Rule: {:.1} s", limit.npf_s).unwrap();
+
+            let mut table = Table::new(&[
+                "focal_length_mm",
+                "f_number",
+                "declination_deg",
+                "crop_factor",
+                "pixel_pitch_um",
+                "rule_500_s",
+                "npf_s",
+            ]);
+            table.push(vec![
+                num(camera.focal_length_mm),
+                num(f_number),
+                num(declination),
+                num(limit.crop_factor),
+                num(limit.pixel_pitch_um),
+                num(limit.rule_500_s),
+                num(limit.npf_s),
+            ]);
+            Report::new(text, &limit, table)
+        }
+
+        Commands::Batch { file, out } => {
+            let config = if file.as_os_str() == STDIN_PATH {
+                from_stdin = true;
+                read_input(&file)
+                    .and_then(|json| {
+                        serde_json::from_str(&json)
+                            .map_err(|e| format!("Invalid batch JSON: {}", e))
+                    })
+                    .unwrap_or_else(|e| {
+                        eprintln!("{}", e);
+                        std::process::exit(1);
+                    })
+            } else {
+                read_batch_config(&file).unwrap_or_else(|e| {
+                    eprintln!("{}", e);
+                    std::process::exit(1);
+                })
+            };
+            out_file = out;
+            batch_report(&run_batch(&config))
+        }
+
+        Commands::Sweep {
+            param,
+            camera,
+            from,
+            to,
+            step,
+            distance,
+            f_number,
+            coc,
+        } => {
+            let values = sweep_values(from, to, step).unwrap_or_else(|e| {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            });
+            let config = build_sweep(&camera, param, &values, distance, f_number, coc);
+            sweep_report(&run_batch(&config))
+        }
+
+        Commands::Interactive { camera } => {
+            let mut session = repl::Session::default();
+            if let Some(camera) = camera {
+                session.camera = camera;
+            }
+            repl::run_repl(session);
+            return;
+        }
+
+        Commands::Completions { shell } => {
+            clap_complete::generate(
+                shell,
+                &mut Cli::command(),
+                "camera-optics-cli",
+                &mut std::io::stdout(),
+            );
+            return;
+        }
+    };
+
+    // Input piped in as JSON is answered with JSON unless another format is requested
+    let format = cli.output.unwrap_or(if from_stdin {
+        OutputFormat::Json
+    } else {
+        OutputFormat::Text
+    });
+
+    match out_file {
+        Some(path) => {
+            if let Err(e) = std::fs::write(&path, report.render(format)) {
+                eprintln!("Could not write {}: {}", path.display(), e);
+                std::process::exit(1);
+            }
         }
+        None => report.print(format),
     }
 }
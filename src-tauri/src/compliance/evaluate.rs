@@ -0,0 +1,238 @@
+use super::types::{
+    ComplianceReport, ComplianceStatus, DoriRequirement, RequirementCheck, RequirementSpec,
+    WidthRequirement,
+};
+use crate::optics::calculations::{calculate_dori_distances, calculate_fov};
+use crate::optics::types::{CameraSystem, DoriDistances};
+use crate::video::{estimate_bitrate, BitrateParams};
+
+/// Requirement text prefixed with its optional location, e.g. "Entrance: Identification within 8 m"
+fn describe(what: String, label: &Option<String>) -> String {
+    match label {
+        Some(label) => format!("{label}: {what}"),
+        None => what,
+    }
+}
+
+/// Check a value that must reach at least `required`
+fn at_least(requirement: String, required: f64, achieved: f64, unit: &str) -> RequirementCheck {
+    RequirementCheck {
+        requirement,
+        status: if achieved >= required {
+            ComplianceStatus::Pass
+        } else {
+            ComplianceStatus::Fail
+        },
+        required,
+        achieved: Some(achieved),
+        unit: unit.to_string(),
+        margin_percent: Some((achieved / required - 1.0) * 100.0),
+    }
+}
+
+/// Check a value that must stay at or below `required`
+fn at_most(requirement: String, required: f64, achieved: f64, unit: &str) -> RequirementCheck {
+    RequirementCheck {
+        requirement,
+        status: if achieved <= required {
+            ComplianceStatus::Pass
+        } else {
+            ComplianceStatus::Fail
+        },
+        required,
+        achieved: Some(achieved),
+        unit: unit.to_string(),
+        margin_percent: Some((1.0 - achieved / required) * 100.0),
+    }
+}
+
+/// Placeholder for a requirement that needs stream settings
+fn not_evaluated(requirement: String, required: f64, unit: &str) -> RequirementCheck {
+    RequirementCheck {
+        requirement,
+        status: ComplianceStatus::NotEvaluated,
+        required,
+        achieved: None,
+        unit: unit.to_string(),
+        margin_percent: None,
+    }
+}
+
+fn dori_check(dori: &DoriDistances, req: &DoriRequirement) -> RequirementCheck {
+    at_least(
+        describe(
+            format!("{:?} within {} m", req.level, req.distance_m),
+            &req.label,
+        ),
+        req.distance_m,
+        dori.distance_m(req.level),
+        "m",
+    )
+}
+
+fn width_checks(camera: &CameraSystem, req: &WidthRequirement) -> Vec<RequirementCheck> {
+    let fov = calculate_fov(camera, req.distance_m * 1000.0);
+    let mut checks = vec![at_least(
+        describe(
+            format!("{} m wide at {} m", req.width_m, req.distance_m),
+            &req.label,
+        ),
+        req.width_m,
+        fov.horizontal_fov_m,
+        "m",
+    )];
+    if let Some(level) = req.level {
+        // The full width is spread over the sensor, so density is checked at the required width
+        let ppm = camera.pixel_width as f64 / fov.horizontal_fov_m.max(req.width_m);
+        checks.push(at_least(
+            describe(format!("{level:?} at {} m", req.distance_m), &req.label),
+            level.px_per_m(),
+            ppm,
+            "px/m",
+        ));
+    }
+    checks
+}
+
+/// Check a camera against a requirement specification
+///
+/// DORI requirements pass when the camera reaches the level at or beyond the required
+/// distance. Coverage requirements pass when the frame is at least the required width
+/// at the distance and, if a level is given, the density across it meets that level.
+/// Frame rate and bandwidth limits are checked against `stream`; without stream
+/// settings they are reported as not evaluated.
+pub fn evaluate_camera_against_spec(
+    camera: &CameraSystem,
+    stream: Option<&BitrateParams>,
+    spec: &RequirementSpec,
+) -> ComplianceReport {
+    let dori = calculate_dori_distances(camera);
+    let mut checks: Vec<RequirementCheck> =
+        spec.dori.iter().map(|req| dori_check(&dori, req)).collect();
+    checks.extend(
+        spec.coverage
+            .iter()
+            .flat_map(|req| width_checks(camera, req)),
+    );
+
+    if let Some(min_fps) = spec.min_fps {
+        let requirement = format!("At least {min_fps} fps");
+        checks.push(match stream {
+            Some(stream) => at_least(requirement, min_fps, stream.frame_rate, "fps"),
+            None => not_evaluated(requirement, min_fps, "fps"),
+        });
+    }
+    if let Some(max_mbps) = spec.max_bandwidth_mbps {
+        let requirement = format!("At most {max_mbps} Mbps");
+        checks.push(match stream {
+            Some(stream) => at_most(
+                requirement,
+                max_mbps,
+                estimate_bitrate(stream).average_mbps,
+                "Mbps",
+            ),
+            None => not_evaluated(requirement, max_mbps, "Mbps"),
+        });
+    }
+
+    ComplianceReport {
+        spec_name: spec.name.clone(),
+        camera_name: camera.name.clone(),
+        passed: checks
+            .iter()
+            .all(|check| check.status != ComplianceStatus::Fail),
+        checks,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optics::types::{DoriLevel, ValidationCode, ValidationSeverity};
+    use crate::video::{SceneActivity, VideoCodec};
+
+    fn camera() -> CameraSystem {
+        // 1920 px over 5.6 mm at 8 mm: identification to ~11 m, ~13.4 m wide at 20 m
+        CameraSystem::new(5.6, 3.15, 1920, 1080, 8.0)
+    }
+
+    fn spec() -> RequirementSpec {
+        RequirementSpec {
+            name: Some("Site A".to_string()),
+            dori: vec![DoriRequirement {
+                label: Some("Entrance".to_string()),
+                level: DoriLevel::Identification,
+                distance_m: 8.0,
+            }],
+            coverage: vec![WidthRequirement {
+                label: Some("Car park".to_string()),
+                width_m: 20.0,
+                distance_m: 20.0,
+                level: Some(DoriLevel::Observation),
+            }],
+            min_fps: Some(12.0),
+            max_bandwidth_mbps: Some(8.0),
+        }
+    }
+
+    fn stream(frame_rate: f64) -> BitrateParams {
+        BitrateParams {
+            pixel_width: 1920,
+            pixel_height: 1080,
+            frame_rate,
+            codec: VideoCodec::H265,
+            activity: SceneActivity::Medium,
+        }
+    }
+
+    #[test]
+    fn test_pass_and_fail_checks() {
+        let report = evaluate_camera_against_spec(&camera(), Some(&stream(25.0)), &spec());
+        let statuses: Vec<_> = report.checks.iter().map(|check| check.status).collect();
+
+        // Identification reaches ~11 m; the frame is only ~14 m wide at 20 m, at ~96 px/m
+        assert_eq!(
+            statuses[..3],
+            [
+                ComplianceStatus::Pass,
+                ComplianceStatus::Fail,
+                ComplianceStatus::Pass
+            ]
+        );
+        assert_eq!(
+            report.checks[0].requirement,
+            "Entrance: Identification within 8 m"
+        );
+        assert!(report.checks[0].margin_percent.unwrap() > 30.0);
+        assert!(report.checks[1].margin_percent.unwrap() < 0.0);
+        assert_eq!(statuses[3], ComplianceStatus::Pass);
+        assert!(!report.passed);
+    }
+
+    #[test]
+    fn test_stream_limits_need_stream_settings() {
+        let report = evaluate_camera_against_spec(&camera(), None, &spec());
+        let stream_checks = &report.checks[3..];
+        assert!(stream_checks.iter().all(
+            |check| check.status == ComplianceStatus::NotEvaluated && check.achieved.is_none()
+        ));
+
+        let slow = evaluate_camera_against_spec(&camera(), Some(&stream(6.0)), &spec());
+        assert_eq!(slow.checks[3].status, ComplianceStatus::Fail);
+    }
+
+    #[test]
+    fn test_warnings_for_validation_panel() {
+        let report = evaluate_camera_against_spec(&camera(), None, &spec());
+        let warnings = report.warnings();
+        // The frame width fails, frame rate and bandwidth are unchecked
+        assert_eq!(warnings.len(), 3);
+        assert!(matches!(warnings[0].severity, ValidationSeverity::Error));
+        assert_eq!(warnings[0].code, ValidationCode::RequirementNotMet);
+        assert_eq!(warnings[1].code, ValidationCode::RequirementNotEvaluated);
+        assert!(warnings[0]
+            .message
+            .starts_with("Car park: 20 m wide at 20 m"));
+        assert!(matches!(warnings[1].severity, ValidationSeverity::Warning));
+    }
+}
@@ -0,0 +1,5 @@
+pub mod evaluate;
+pub mod types;
+
+pub use evaluate::*;
+pub use types::*;
@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+
+use crate::optics::types::{
+    DoriLevel, MessageKey, ValidationCode, ValidationSeverity, ValidationWarning,
+};
+
+/// DORI level required out to a distance, e.g. identification at the entrance within 8 m
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct DoriRequirement {
+    /// Where the requirement applies, e.g. "Entrance"
+    pub label: Option<String>,
+    pub level: DoriLevel,
+    /// Distance in metres out to which the level must be reached
+    pub distance_m: f64,
+}
+
+/// Width the camera must cover at a distance, e.g. observation across a 30 m lot
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct WidthRequirement {
+    /// Where the requirement applies, e.g. "Car park"
+    pub label: Option<String>,
+    /// Minimum frame width in metres
+    pub width_m: f64,
+    /// Distance in metres at which the width is measured
+    pub distance_m: f64,
+    /// DORI level that must also be reached across the width
+    pub level: Option<DoriLevel>,
+}
+
+/// Set of requirements a camera is checked against
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct RequirementSpec {
+    pub name: Option<String>,
+    #[serde(default)]
+    pub dori: Vec<DoriRequirement>,
+    #[serde(default)]
+    pub coverage: Vec<WidthRequirement>,
+    /// Minimum stream frame rate in frames per second
+    pub min_fps: Option<f64>,
+    /// Maximum average stream bitrate in Mbps
+    pub max_bandwidth_mbps: Option<f64>,
+}
+
+/// Outcome of a single requirement check
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub enum ComplianceStatus {
+    Pass,
+    Fail,
+    /// The requirement needs stream settings that were not given
+    NotEvaluated,
+}
+
+/// Result of checking one requirement
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct RequirementCheck {
+    /// Human-readable requirement, e.g. "Identification at Entrance within 8 m"
+    pub requirement: String,
+    pub status: ComplianceStatus,
+    /// Required value, in `unit`
+    pub required: f64,
+    /// Value the camera achieves, in `unit`
+    pub achieved: Option<f64>,
+    pub unit: String,
+    /// How far the achieved value is better (positive) or worse (negative) than required
+    pub margin_percent: Option<f64>,
+}
+
+/// Pass/fail results of a camera against a requirement spec
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct ComplianceReport {
+    pub spec_name: Option<String>,
+    pub camera_name: Option<String>,
+    /// Whether every evaluated requirement passes
+    pub passed: bool,
+    pub checks: Vec<RequirementCheck>,
+}
+
+impl ComplianceReport {
+    /// Failed and unevaluated checks as validation warnings, for the validation panel
+    pub fn warnings(&self) -> Vec<ValidationWarning> {
+        self.checks
+            .iter()
+            .filter_map(|check| {
+                let (code, severity) = match check.status {
+                    ComplianceStatus::Pass => return None,
+                    ComplianceStatus::Fail => {
+                        (ValidationCode::RequirementNotMet, ValidationSeverity::Error)
+                    }
+                    ComplianceStatus::NotEvaluated => (
+                        ValidationCode::RequirementNotEvaluated,
+                        ValidationSeverity::Warning,
+                    ),
+                };
+                let message = match (check.status, check.achieved) {
+                    (ComplianceStatus::Fail, Some(achieved)) => {
+                        MessageKey::new("compliance.requirement_not_met")
+                            .with_text("requirement", check.requirement.as_str())
+                            .with_number("achieved", achieved)
+                            .with_number("required", check.required)
+                            .with_text("unit", check.unit.as_str())
+                    }
+                    _ => MessageKey::new("compliance.requirement_not_evaluated")
+                        .with_text("requirement", check.requirement.as_str()),
+                };
+                let warning = ValidationWarning::new(code, severity, message)
+                    .with_field("required", check.required);
+                Some(match check.achieved {
+                    Some(achieved) => warning.with_field("achieved", achieved),
+                    None => warning,
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,208 @@
+use std::collections::VecDeque;
+use std::fmt::{self, Write};
+use std::sync::{Arc, Mutex};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{fmt as tracing_fmt, Layer};
+
+use super::types::LogRecord;
+
+/// Log events kept for diagnostics before the oldest are dropped
+pub const DEFAULT_LOG_CAPACITY: usize = 2000;
+
+/// Milliseconds since the Unix epoch
+pub(crate) fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// Recent log events held in memory for `export_diagnostics`
+///
+/// Installed as a tracing layer; clones share the same buffer, so one copy can
+/// be handed to the subscriber and another kept in managed state.
+#[derive(Clone)]
+pub struct LogBuffer {
+    capacity: usize,
+    records: Arc<Mutex<VecDeque<LogRecord>>>,
+}
+
+impl Default for LogBuffer {
+    fn default() -> Self {
+        Self::with_capacity(DEFAULT_LOG_CAPACITY)
+    }
+}
+
+impl LogBuffer {
+    /// Buffer that keeps at most `capacity` events, dropping the oldest first
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            records: Arc::new(Mutex::new(VecDeque::new())),
+        }
+    }
+
+    /// Append an event, dropping the oldest once full
+    pub fn push(&self, record: LogRecord) {
+        let mut records = self.records.lock().unwrap_or_else(|e| e.into_inner());
+        if records.len() == self.capacity {
+            records.pop_front();
+        }
+        records.push_back(record);
+    }
+
+    /// Recorded events, oldest first
+    pub fn records(&self) -> Vec<LogRecord> {
+        let records = self.records.lock().unwrap_or_else(|e| e.into_inner());
+        records.iter().cloned().collect()
+    }
+}
+
+/// Collects an event's message and fields into one line
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: String,
+}
+
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message.push_str(value);
+        } else {
+            let _ = write!(self.fields, " {}={}", field.name(), value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            let _ = write!(self.message, "{value:?}");
+        } else {
+            let _ = write!(self.fields, " {}={:?}", field.name(), value);
+        }
+    }
+}
+
+/// Start time and input fields of an open span, kept in its extensions
+struct SpanTiming {
+    started: Instant,
+    fields: String,
+}
+
+impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for LogBuffer {
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut visitor = MessageVisitor::default();
+        attrs.record(&mut visitor);
+        span.extensions_mut().insert(SpanTiming {
+            started: Instant::now(),
+            fields: visitor.fields,
+        });
+    }
+
+    /// Record a finished span as one event with its inputs and elapsed time
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(&id) else {
+            return;
+        };
+        let Some(timing) = span.extensions_mut().remove::<SpanTiming>() else {
+            return;
+        };
+        let metadata = span.metadata();
+        self.push(LogRecord {
+            timestamp_ms: now_ms(),
+            level: metadata.level().to_string(),
+            target: metadata.target().to_string(),
+            message: format!(
+                "{} finished elapsed_ms={:.3}{}",
+                span.name(),
+                timing.started.elapsed().as_secs_f64() * 1000.0,
+                timing.fields
+            ),
+        });
+    }
+
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        let metadata = event.metadata();
+        self.push(LogRecord {
+            timestamp_ms: now_ms(),
+            level: metadata.level().to_string(),
+            target: metadata.target().to_string(),
+            message: format!("{}{}", visitor.message, visitor.fields)
+                .trim_start()
+                .to_string(),
+        });
+    }
+}
+
+/// Install the global logger: info and above to stderr, debug and above into `buffer`
+///
+/// Debug spans, such as the ones around each command, land in `buffer` with their
+/// inputs and elapsed time when they close.
+///
+/// Does nothing if a logger is already installed.
+pub fn init_logging(buffer: LogBuffer) {
+    let _ = tracing_subscriber::registry()
+        .with(
+            tracing_fmt::layer()
+                .with_writer(std::io::stderr)
+                .with_filter(LevelFilter::INFO),
+        )
+        .with(buffer.with_filter(LevelFilter::DEBUG))
+        .try_init();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_buffer_records_events_and_drops_oldest() {
+        let buffer = LogBuffer::with_capacity(2);
+        let subscriber = tracing_subscriber::registry().with(buffer.clone());
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!("first");
+            tracing::warn!(command = "calculate_camera_fov", elapsed_ms = 3, "computed");
+            tracing::debug!("third");
+        });
+
+        let records = buffer.records();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].level, "WARN");
+        assert_eq!(
+            records[0].message,
+            "computed command=calculate_camera_fov elapsed_ms=3"
+        );
+        assert_eq!(records[1].message, "third");
+    }
+
+    #[test]
+    fn test_buffer_records_span_inputs_and_elapsed_time() {
+        let buffer = LogBuffer::default();
+        let subscriber = tracing_subscriber::registry().with(buffer.clone());
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::debug_span!("calculate_camera_fov", distance_mm = 5000.0).in_scope(|| {
+                tracing::debug!("computing");
+            });
+        });
+
+        let records = buffer.records();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].message, "computing");
+        let message = &records[1].message;
+        assert!(message.starts_with("calculate_camera_fov finished elapsed_ms="));
+        assert!(message.ends_with(" distance_mm=5000.0"));
+    }
+}
@@ -0,0 +1,7 @@
+pub mod log;
+pub mod report;
+pub mod types;
+
+pub use log::*;
+pub use report::*;
+pub use types::*;
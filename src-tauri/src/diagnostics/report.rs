@@ -0,0 +1,26 @@
+use std::fs;
+use std::path::Path;
+
+use super::log::{now_ms, LogBuffer};
+use super::types::{DiagnosticsReport, DIAGNOSTICS_SCHEMA_VERSION};
+use crate::project::{Project, ProjectError};
+
+/// Bundle the open project and recent log events into a report
+pub fn build_diagnostics_report(project: Project, logs: &LogBuffer) -> DiagnosticsReport {
+    DiagnosticsReport {
+        schema_version: DIAGNOSTICS_SCHEMA_VERSION,
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        created_at_ms: now_ms(),
+        project,
+        logs: logs.records(),
+    }
+}
+
+/// Save a diagnostics report as pretty-printed JSON
+pub fn write_diagnostics_report(
+    report: &DiagnosticsReport,
+    path: &Path,
+) -> Result<(), ProjectError> {
+    fs::write(path, serde_json::to_string_pretty(report)?)?;
+    Ok(())
+}
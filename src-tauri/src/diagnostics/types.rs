@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+use crate::project::Project;
+
+/// Current version of the diagnostics report schema
+pub const DIAGNOSTICS_SCHEMA_VERSION: u32 = 1;
+
+/// One log event recorded by the backend
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub struct LogRecord {
+    /// Milliseconds since the Unix epoch
+    pub timestamp_ms: u64,
+    /// Severity: "ERROR", "WARN", "INFO", "DEBUG" or "TRACE"
+    pub level: String,
+    /// Module that emitted the event
+    pub target: String,
+    /// Message followed by the event's fields as `key=value`
+    pub message: String,
+}
+
+/// Everything needed to reproduce a problem, bundled for a bug report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub struct DiagnosticsReport {
+    /// Schema version the report was written with
+    pub schema_version: u32,
+    /// Version of the application that wrote the report
+    pub app_version: String,
+    /// Milliseconds since the Unix epoch
+    pub created_at_ms: u64,
+    /// The open project at the time of the export
+    pub project: Project,
+    /// Recent log events, oldest first
+    pub logs: Vec<LogRecord>,
+}
@@ -0,0 +1,210 @@
+use super::types::{Drawing, DrawingLayer, DrawingRequest, Shape};
+use crate::optics::coverage::{blind_spots, camera_footprint};
+use crate::optics::types::{DoriLevel, PlanPoint};
+
+/// Blind spot cell size when the request gives no grid spacing, matching the coverage default
+const DEFAULT_GRID_SPACING_M: f64 = 0.5;
+
+fn layer(name: &'static str, color: &'static str, aci: u8, filled: bool) -> DrawingLayer {
+    DrawingLayer {
+        name,
+        color,
+        aci,
+        filled,
+        shapes: Vec::new(),
+    }
+}
+
+/// Layer name, SVG colour and AutoCAD colour index of a DORI range outline
+fn dori_style(level: DoriLevel) -> (&'static str, &'static str, u8) {
+    match level {
+        DoriLevel::Detection => ("DORI_DETECTION", "#17becf", 4),
+        DoriLevel::Observation => ("DORI_OBSERVATION", "#bcbd22", 2),
+        DoriLevel::Recognition => ("DORI_RECOGNITION", "#ff7f0e", 30),
+        DoriLevel::Identification => ("DORI_IDENTIFICATION", "#d62728", 1),
+    }
+}
+
+/// Mean of the polygon vertices, used to place labels
+fn label_point(polygon: &[PlanPoint]) -> PlanPoint {
+    let n = polygon.len().max(1) as f64;
+    PlanPoint {
+        x_m: polygon.iter().map(|p| p.x_m).sum::<f64>() / n,
+        y_m: polygon.iter().map(|p| p.y_m).sum::<f64>() / n,
+    }
+}
+
+/// Square grid cell centred on a sample point
+fn cell(center: PlanPoint, size_m: f64) -> Vec<PlanPoint> {
+    let h = size_m / 2.0;
+    [(-h, -h), (h, -h), (h, h), (-h, h)]
+        .iter()
+        .map(|&(dx, dy)| PlanPoint {
+            x_m: center.x_m + dx,
+            y_m: center.y_m + dy,
+        })
+        .collect()
+}
+
+/// Collect the coverage geometry of a scene into drawing layers
+///
+/// Camera footprints are drawn at the lowest zone requirement in the scene
+/// (detection when there are no zones) and, like the DORI range outlines, are
+/// clipped by walls and obstacles. Empty layers are left out.
+pub fn build_drawing(request: &DrawingRequest) -> Drawing {
+    let scene = &request.scene;
+
+    let mut room = layer("ROOM", "#000000", 7, false);
+    room.shapes.push(Shape::Polygon(scene.room.clone()));
+
+    let mut obstacles = layer("OBSTACLES", "#808080", 8, true);
+    for obstacle in &scene.obstacles {
+        obstacles
+            .shapes
+            .push(Shape::Polygon(obstacle.polygon.clone()));
+    }
+
+    let mut zones = layer("ZONES", "#1f77b4", 5, false);
+    for zone in &scene.zones {
+        zones.shapes.push(Shape::Polygon(zone.polygon.clone()));
+        zones
+            .shapes
+            .push(Shape::Label(label_point(&zone.polygon), zone.name.clone()));
+    }
+
+    let footprint_ppm = scene
+        .zones
+        .iter()
+        .map(|zone| zone.requirement.px_per_m())
+        .reduce(f64::min)
+        .unwrap_or(DoriLevel::Detection.px_per_m());
+    let mut footprints = layer("FOOTPRINTS", "#2ca02c", 3, true);
+    let mut cameras = layer("CAMERAS", "#9467bd", 6, false);
+    for (i, placed) in request.cameras.iter().enumerate() {
+        footprints.shapes.push(Shape::Polygon(camera_footprint(
+            placed,
+            footprint_ppm,
+            scene,
+        )));
+        let position = PlanPoint {
+            x_m: placed.pose.x_m,
+            y_m: placed.pose.y_m,
+        };
+        let name = placed
+            .camera
+            .name
+            .clone()
+            .unwrap_or_else(|| format!("Camera {}", i + 1));
+        cameras.shapes.push(Shape::Marker(position));
+        cameras.shapes.push(Shape::Label(position, name));
+    }
+
+    let mut layers = vec![room, obstacles, zones, footprints];
+    for &level in &request.dori_levels {
+        let (name, color, aci) = dori_style(level);
+        let mut outlines = layer(name, color, aci, false);
+        for placed in &request.cameras {
+            outlines.shapes.push(Shape::Polygon(camera_footprint(
+                placed,
+                level.px_per_m(),
+                scene,
+            )));
+        }
+        layers.push(outlines);
+    }
+
+    if request.blind_spots {
+        let spacing_m = request.grid_spacing_m.unwrap_or(DEFAULT_GRID_SPACING_M);
+        let mut uncovered = layer("BLIND_SPOTS", "#d62728", 1, true);
+        for point in blind_spots(scene, &request.cameras, Some(spacing_m)) {
+            uncovered
+                .shapes
+                .push(Shape::Polygon(cell(point, spacing_m)));
+        }
+        layers.push(uncovered);
+    }
+    layers.push(cameras);
+
+    layers.retain(|layer| !layer.shapes.is_empty());
+    Drawing { layers }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optics::types::{
+        CameraPose, CameraSystem, CoverageZone, PlacedCamera, Scene, ZoneRequirement,
+    };
+
+    fn point(x_m: f64, y_m: f64) -> PlanPoint {
+        PlanPoint { x_m, y_m }
+    }
+
+    fn request() -> DrawingRequest {
+        DrawingRequest {
+            scene: Scene {
+                room: vec![
+                    point(0.0, 0.0),
+                    point(20.0, 0.0),
+                    point(20.0, 10.0),
+                    point(0.0, 10.0),
+                ],
+                zones: vec![CoverageZone {
+                    name: "Hall".to_string(),
+                    polygon: vec![
+                        point(0.0, 0.0),
+                        point(20.0, 0.0),
+                        point(20.0, 10.0),
+                        point(0.0, 10.0),
+                    ],
+                    requirement: ZoneRequirement::Dori(DoriLevel::Identification),
+                }],
+                obstacles: Vec::new(),
+            },
+            cameras: vec![PlacedCamera {
+                camera: CameraSystem::new(5.6, 3.15, 1920, 1080, 4.0).with_name("Entrance"),
+                pose: CameraPose {
+                    x_m: 0.5,
+                    y_m: 0.5,
+                    height_m: 3.0,
+                    pan_deg: 45.0,
+                    tilt_deg: -10.0,
+                },
+            }],
+            dori_levels: vec![DoriLevel::Detection, DoriLevel::Identification],
+            blind_spots: true,
+            grid_spacing_m: Some(1.0),
+        }
+    }
+
+    #[test]
+    fn test_layers() {
+        let drawing = build_drawing(&request());
+        let names: Vec<_> = drawing.layers.iter().map(|layer| layer.name).collect();
+        // No obstacles, so that layer is left out
+        assert_eq!(
+            names,
+            [
+                "ROOM",
+                "ZONES",
+                "FOOTPRINTS",
+                "DORI_DETECTION",
+                "DORI_IDENTIFICATION",
+                "BLIND_SPOTS",
+                "CAMERAS"
+            ]
+        );
+
+        // One camera can't reach identification across the whole hall
+        let uncovered = &drawing.layers[5].shapes;
+        assert!(!uncovered.is_empty() && uncovered.len() < 200);
+        assert_eq!(
+            drawing.layers[6].shapes[1],
+            Shape::Label(point(0.5, 0.5), "Entrance".to_string())
+        );
+
+        let (min, max) = drawing.bounds().unwrap();
+        assert_eq!((min.x_m, min.y_m), (0.0, 0.0));
+        assert!(max.x_m >= 20.0 && max.y_m >= 10.0);
+    }
+}
@@ -0,0 +1,145 @@
+use std::fmt::{Display, Write};
+
+use super::types::{Drawing, Shape};
+
+/// Camera marker radius and label height in metres
+const DXF_MARKER_M: f64 = 0.25;
+
+/// Append a DXF group code and value
+fn pair(dxf: &mut String, code: u16, value: impl Display) {
+    // Writing to a String cannot fail
+    let _ = write!(dxf, "{code}\n{value}\n");
+}
+
+/// Write a drawing as an AutoCAD R12 ASCII DXF in metres
+///
+/// Each drawing layer becomes a DXF layer. Polygons are written as closed
+/// polylines, camera markers as circles and labels as single-line text.
+pub fn render_dxf(drawing: &Drawing) -> String {
+    let mut dxf = String::new();
+
+    pair(&mut dxf, 0, "SECTION");
+    pair(&mut dxf, 2, "HEADER");
+    pair(&mut dxf, 9, "$ACADVER");
+    pair(&mut dxf, 1, "AC1009");
+    // Drawing units: metres
+    pair(&mut dxf, 9, "$INSUNITS");
+    pair(&mut dxf, 70, 6);
+    pair(&mut dxf, 0, "ENDSEC");
+
+    pair(&mut dxf, 0, "SECTION");
+    pair(&mut dxf, 2, "TABLES");
+    pair(&mut dxf, 0, "TABLE");
+    pair(&mut dxf, 2, "LAYER");
+    pair(&mut dxf, 70, drawing.layers.len());
+    for layer in &drawing.layers {
+        pair(&mut dxf, 0, "LAYER");
+        pair(&mut dxf, 2, layer.name);
+        pair(&mut dxf, 70, 0);
+        pair(&mut dxf, 62, layer.aci);
+        pair(&mut dxf, 6, "CONTINUOUS");
+    }
+    pair(&mut dxf, 0, "ENDTAB");
+    pair(&mut dxf, 0, "ENDSEC");
+
+    pair(&mut dxf, 0, "SECTION");
+    pair(&mut dxf, 2, "ENTITIES");
+    for layer in &drawing.layers {
+        for shape in &layer.shapes {
+            match shape {
+                Shape::Polygon(points) => {
+                    pair(&mut dxf, 0, "POLYLINE");
+                    pair(&mut dxf, 8, layer.name);
+                    pair(&mut dxf, 66, 1);
+                    pair(&mut dxf, 10, 0.0);
+                    pair(&mut dxf, 20, 0.0);
+                    pair(&mut dxf, 30, 0.0);
+                    // Closed polyline
+                    pair(&mut dxf, 70, 1);
+                    for point in points {
+                        pair(&mut dxf, 0, "VERTEX");
+                        pair(&mut dxf, 8, layer.name);
+                        pair(&mut dxf, 10, format!("{:.4}", point.x_m));
+                        pair(&mut dxf, 20, format!("{:.4}", point.y_m));
+                        pair(&mut dxf, 30, 0.0);
+                    }
+                    pair(&mut dxf, 0, "SEQEND");
+                    pair(&mut dxf, 8, layer.name);
+                }
+                Shape::Marker(point) => {
+                    pair(&mut dxf, 0, "CIRCLE");
+                    pair(&mut dxf, 8, layer.name);
+                    pair(&mut dxf, 10, format!("{:.4}", point.x_m));
+                    pair(&mut dxf, 20, format!("{:.4}", point.y_m));
+                    pair(&mut dxf, 30, 0.0);
+                    pair(&mut dxf, 40, DXF_MARKER_M);
+                }
+                Shape::Label(point, text) => {
+                    pair(&mut dxf, 0, "TEXT");
+                    pair(&mut dxf, 8, layer.name);
+                    pair(
+                        &mut dxf,
+                        10,
+                        format!("{:.4}", point.x_m + DXF_MARKER_M * 1.5),
+                    );
+                    pair(&mut dxf, 20, format!("{:.4}", point.y_m));
+                    pair(&mut dxf, 30, 0.0);
+                    pair(&mut dxf, 40, DXF_MARKER_M * 2.0);
+                    // A value ends at the line break, so keep labels on one line
+                    pair(&mut dxf, 1, text.replace(['\r', '\n'], " "));
+                }
+            }
+        }
+    }
+    pair(&mut dxf, 0, "ENDSEC");
+    pair(&mut dxf, 0, "EOF");
+    dxf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawing::types::DrawingLayer;
+    use crate::optics::types::PlanPoint;
+
+    #[test]
+    fn test_dxf_layers_and_entities() {
+        let camera = PlanPoint { x_m: 1.5, y_m: 2.0 };
+        let drawing = Drawing {
+            layers: vec![
+                DrawingLayer {
+                    name: "ROOM",
+                    color: "#000000",
+                    aci: 7,
+                    filled: false,
+                    shapes: vec![Shape::Polygon(vec![
+                        PlanPoint { x_m: 0.0, y_m: 0.0 },
+                        PlanPoint { x_m: 4.0, y_m: 0.0 },
+                        PlanPoint { x_m: 4.0, y_m: 3.0 },
+                    ])],
+                },
+                DrawingLayer {
+                    name: "CAMERAS",
+                    color: "#9467bd",
+                    aci: 6,
+                    filled: false,
+                    shapes: vec![
+                        Shape::Marker(camera),
+                        Shape::Label(camera, "Gate\nNorth".to_string()),
+                    ],
+                },
+            ],
+        };
+        let dxf = render_dxf(&drawing);
+        let lines: Vec<&str> = dxf.lines().collect();
+
+        // Group codes and values alternate
+        assert_eq!(lines.len() % 2, 0);
+        assert_eq!(lines[lines.len() - 2..], ["0", "EOF"]);
+        assert!(dxf.contains("0\nLAYER\n2\nCAMERAS\n70\n0\n62\n6\n"));
+        assert_eq!(dxf.matches("\nVERTEX\n").count(), 3);
+        assert_eq!(dxf.matches("\nSEQEND\n").count(), 1);
+        assert!(dxf.contains("0\nCIRCLE\n8\nCAMERAS\n10\n1.5000\n20\n2.0000\n"));
+        assert!(dxf.contains("1\nGate North\n"));
+    }
+}
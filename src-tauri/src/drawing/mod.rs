@@ -0,0 +1,9 @@
+pub mod build;
+pub mod dxf;
+pub mod svg;
+pub mod types;
+
+pub use build::*;
+pub use dxf::*;
+pub use svg::*;
+pub use types::*;
@@ -0,0 +1,138 @@
+use std::fmt::Write;
+
+use super::types::{Drawing, Shape};
+use crate::optics::types::PlanPoint;
+
+/// Pixels per metre of the SVG canvas size; the drawing itself is in metres
+const SVG_PX_PER_M: f64 = 20.0;
+/// Space around the drawing in metres
+const SVG_MARGIN_M: f64 = 1.0;
+/// Camera marker radius and label height in metres
+const SVG_MARKER_M: f64 = 0.25;
+
+/// Escape text for use in SVG content and attributes
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// SVG coordinates of a plan point; SVG's y axis points down, the plan's points north
+fn xy(point: PlanPoint) -> (f64, f64) {
+    // Subtracting from zero avoids writing "-0.000"
+    (point.x_m, 0.0 - point.y_m)
+}
+
+/// Write a drawing as an SVG document in metres, north up
+///
+/// Each layer becomes a group with its name as `id`, so layers can be toggled
+/// in vector editors.
+pub fn render_svg(drawing: &Drawing) -> String {
+    let origin = PlanPoint { x_m: 0.0, y_m: 0.0 };
+    let (min, max) = drawing.bounds().unwrap_or((origin, origin));
+    let width_m = max.x_m - min.x_m + 2.0 * SVG_MARGIN_M;
+    let height_m = max.y_m - min.y_m + 2.0 * SVG_MARGIN_M;
+
+    let mut svg = String::new();
+    // Writing to a String cannot fail
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{:.0}" height="{:.0}" viewBox="{:.3} {:.3} {:.3} {:.3}">"#,
+        width_m * SVG_PX_PER_M,
+        height_m * SVG_PX_PER_M,
+        min.x_m - SVG_MARGIN_M,
+        -max.y_m - SVG_MARGIN_M,
+        width_m,
+        height_m,
+    );
+
+    for layer in &drawing.layers {
+        let fill = if layer.filled { layer.color } else { "none" };
+        let _ = writeln!(
+            svg,
+            r#"  <g id="{}" stroke="{}" fill="{fill}" fill-opacity="0.25" stroke-width="1">"#,
+            layer.name, layer.color,
+        );
+        for shape in &layer.shapes {
+            match shape {
+                Shape::Polygon(points) => {
+                    let points: Vec<String> = points
+                        .iter()
+                        .map(|&p| {
+                            let (x, y) = xy(p);
+                            format!("{x:.3},{y:.3}")
+                        })
+                        .collect();
+                    let _ = writeln!(
+                        svg,
+                        r#"    <polygon points="{}" vector-effect="non-scaling-stroke"/>"#,
+                        points.join(" ")
+                    );
+                }
+                Shape::Marker(point) => {
+                    let (x, y) = xy(*point);
+                    let _ = writeln!(
+                        svg,
+                        r#"    <circle cx="{x:.3}" cy="{y:.3}" r="{SVG_MARKER_M}" fill="{}" fill-opacity="1"/>"#,
+                        layer.color
+                    );
+                }
+                Shape::Label(point, text) => {
+                    let (x, y) = xy(*point);
+                    let _ = writeln!(
+                        svg,
+                        r#"    <text x="{:.3}" y="{y:.3}" font-size="{}" fill="{}" fill-opacity="1" stroke="none">{}</text>"#,
+                        x + SVG_MARKER_M * 1.5,
+                        SVG_MARKER_M * 2.0,
+                        layer.color,
+                        escape(text)
+                    );
+                }
+            }
+        }
+        svg.push_str("  </g>\n");
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawing::types::DrawingLayer;
+
+    fn drawing() -> Drawing {
+        Drawing {
+            layers: vec![DrawingLayer {
+                name: "ZONES",
+                color: "#1f77b4",
+                aci: 5,
+                filled: false,
+                shapes: vec![
+                    Shape::Polygon(vec![
+                        PlanPoint { x_m: 0.0, y_m: 0.0 },
+                        PlanPoint { x_m: 4.0, y_m: 0.0 },
+                        PlanPoint { x_m: 4.0, y_m: 2.0 },
+                    ]),
+                    Shape::Label(
+                        PlanPoint { x_m: 2.0, y_m: 1.0 },
+                        "Gate & <Yard>".to_string(),
+                    ),
+                ],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_svg_flips_north_up() {
+        let svg = render_svg(&drawing());
+        // 4 × 2 m plus a 1 m margin, with y negated
+        assert!(svg.contains(r#"width="120" height="80" viewBox="-1.000 -3.000 6.000 4.000""#));
+        assert!(svg.contains(r#"points="0.000,0.000 4.000,0.000 4.000,-2.000""#));
+        assert!(svg.contains(r#"<g id="ZONES""#));
+        assert!(svg.contains(">Gate &amp; &lt;Yard&gt;</text>"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+    }
+}
@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+
+use crate::optics::types::{DoriLevel, PlacedCamera, PlanPoint, Scene};
+
+/// File format of an exported coverage drawing
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub enum DrawingFormat {
+    Svg,
+    /// AutoCAD R12 ASCII DXF, in metres
+    Dxf,
+}
+
+/// What to include in a coverage drawing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct DrawingRequest {
+    pub scene: Scene,
+    pub cameras: Vec<PlacedCamera>,
+    /// DORI levels to draw range outlines for
+    #[serde(default)]
+    pub dori_levels: Vec<DoriLevel>,
+    /// Whether to draw the zone cells no camera covers at the zone's requirement
+    #[serde(default)]
+    pub blind_spots: bool,
+    /// Blind spot grid spacing in metres; defaults to 0.5 m
+    pub grid_spacing_m: Option<f64>,
+}
+
+/// A single element of a drawing layer, in site plan coordinates
+#[derive(Debug, Clone, PartialEq)]
+pub enum Shape {
+    /// Closed outline
+    Polygon(Vec<PlanPoint>),
+    /// Camera position
+    Marker(PlanPoint),
+    Label(PlanPoint, String),
+}
+
+/// Named group of shapes, exported as a DXF layer or SVG group
+#[derive(Debug, Clone)]
+pub struct DrawingLayer {
+    pub name: &'static str,
+    /// SVG colour
+    pub color: &'static str,
+    /// AutoCAD colour index
+    pub aci: u8,
+    /// Whether polygons are filled (SVG only)
+    pub filled: bool,
+    pub shapes: Vec<Shape>,
+}
+
+/// 2D coverage geometry ready to be written as SVG or DXF
+#[derive(Debug, Clone, Default)]
+pub struct Drawing {
+    pub layers: Vec<DrawingLayer>,
+}
+
+impl Drawing {
+    /// Bounding box of every point in the drawing as (min, max)
+    pub fn bounds(&self) -> Option<(PlanPoint, PlanPoint)> {
+        let points =
+            self.layers
+                .iter()
+                .flat_map(|layer| &layer.shapes)
+                .flat_map(|shape| match shape {
+                    Shape::Polygon(points) => points.as_slice(),
+                    Shape::Marker(point) | Shape::Label(point, _) => std::slice::from_ref(point),
+                });
+        points.fold(None, |bounds, &p| {
+            let (min, max) = bounds.unwrap_or((p, p));
+            Some((
+                PlanPoint {
+                    x_m: min.x_m.min(p.x_m),
+                    y_m: min.y_m.min(p.y_m),
+                },
+                PlanPoint {
+                    x_m: max.x_m.max(p.x_m),
+                    y_m: max.y_m.max(p.y_m),
+                },
+            ))
+        })
+    }
+}
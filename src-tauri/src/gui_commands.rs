@@ -1,57 +1,161 @@
+use crate::cache::*;
+use crate::catalog::*;
+use crate::compliance::*;
+use crate::diagnostics::*;
+use crate::drawing::*;
 use crate::images::downsample::*;
 use crate::images::types::*;
+use crate::onvif::*;
+use crate::optics::aerial::*;
+use crate::optics::aiming::*;
+use crate::optics::alpr::*;
+use crate::optics::astro::*;
+use crate::optics::atmosphere::*;
+use crate::optics::barcode::*;
 use crate::optics::calculations::*;
+use crate::optics::calibration::*;
+use crate::optics::charts::*;
+use crate::optics::corridor::*;
+use crate::optics::coverage::*;
+use crate::optics::derating::*;
+use crate::optics::diff::*;
+use crate::optics::equivalence::*;
+use crate::optics::exposure::*;
+use crate::optics::i18n::*;
+use crate::optics::incidence::*;
+use crate::optics::inspection::*;
+use crate::optics::isolation::*;
+use crate::optics::legibility::*;
+use crate::optics::masking::*;
+use crate::optics::microscopy::*;
+use crate::optics::panorama::*;
+use crate::optics::placement::*;
+use crate::optics::privacy::*;
+use crate::optics::resolution::*;
+use crate::optics::scoring::*;
+use crate::optics::selection::*;
+use crate::optics::sensitivity::*;
+use crate::optics::telescope::*;
+use crate::optics::tilt::*;
+use crate::optics::tolerance::*;
 use crate::optics::types::*;
+use crate::optics::uncertainty::*;
+use crate::optics::vignetting::*;
+use crate::optics::wdr::*;
+use crate::project::*;
+use crate::tasks::*;
+use crate::thermal::*;
+use crate::video::*;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+use tauri::{Emitter, Manager};
 
 /// Tauri command to calculate image downsampling parameters for preview
 #[tauri::command]
+#[tracing::instrument(level = "debug")]
 pub fn calculate_image_downsample_command(params: ImageDownsampleParams) -> ImageDownsampleResult {
     calculate_image_downsample(&params)
 }
 
 /// Tauri command to calculate FOV for a single camera system
 #[tauri::command]
-pub fn calculate_camera_fov(camera: CameraSystem, distance_mm: f64) -> FovResult {
-    calculate_fov(&camera, distance_mm)
+#[tracing::instrument(level = "debug", skip(cache))]
+pub fn calculate_camera_fov(
+    cache: tauri::State<'_, CalculationCache>,
+    camera: CameraSystem,
+    distance_mm: f64,
+) -> FovResult {
+    // NaN and infinity serialize as `null` and would share a cache key
+    if !distance_mm.is_finite() {
+        return calculate_fov(&camera, distance_mm);
+    }
+    cache.get_or_compute("calculate_camera_fov", &(&camera, distance_mm), || {
+        calculate_fov(&camera, distance_mm)
+    })
+}
+
+/// Record validation warnings in the diagnostics log before returning them
+fn logged_warnings(command: &str, warnings: Vec<ValidationWarning>) -> Vec<ValidationWarning> {
+    for warning in &warnings {
+        tracing::warn!(
+            command,
+            code = ?warning.code,
+            severity = ?warning.severity,
+            "{}",
+            warning.message
+        );
+    }
+    warnings
 }
 
 /// Tauri command to validate a camera system and its result
 #[tauri::command]
+#[tracing::instrument(level = "debug")]
 pub fn validate_camera_system(camera: CameraSystem, result: FovResult) -> Vec<ValidationWarning> {
-    let camera_with_result = CameraWithResult { camera, result };
-    camera_with_result.validate()
+    let camera_with_result = CameraWithResult {
+        camera,
+        result,
+        low_light: None,
+        suitability: None,
+        isolation: None,
+        night: None,
+    };
+    logged_warnings("validate_camera_system", camera_with_result.validate())
 }
 
-/// Tauri command to calculate FOV for multiple camera systems
+/// Tauri command to calculate FOV for multiple camera systems, ranked by low-light sensitivity
+/// and, when a weighting profile is given, by overall suitability; with a subject/background
+/// scenario each camera also gets a background blur score
 #[tauri::command]
+#[tracing::instrument(level = "debug", skip(cache))]
 pub fn compare_camera_systems(
+    cache: tauri::State<'_, CalculationCache>,
     cameras: Vec<CameraSystem>,
     distance_mm: f64,
+    weights: Option<ScoringWeights>,
+    isolation: Option<IsolationScenario>,
 ) -> Vec<CameraWithResult> {
-    cameras
-        .into_iter()
-        .map(|camera| {
-            let result = calculate_fov(&camera, distance_mm);
-            CameraWithResult { camera, result }
-        })
-        .collect()
+    let compare = || {
+        let mut results = compare_cameras(cameras.clone(), distance_mm);
+        if let Some(weights) = &weights {
+            score_cameras(&mut results, weights);
+        }
+        if let Some(scenario) = &isolation {
+            score_subject_isolation(&mut results, scenario);
+        }
+        results
+    };
+    if !distance_mm.is_finite() {
+        return compare();
+    }
+    let input = (&cameras, distance_mm, &weights, &isolation);
+    cache.get_or_compute("compare_camera_systems", &input, compare)
 }
 
 /// Tauri command to calculate hyperfocal distance
 #[tauri::command]
+#[tracing::instrument(level = "debug")]
 pub fn calculate_hyperfocal_distance(focal_length_mm: f64, f_number: f64, coc_mm: f64) -> f64 {
     calculate_hyperfocal(focal_length_mm, f_number, coc_mm)
 }
 
-/// Tauri command to calculate depth of field
+/// Tauri command to calculate depth of field, optionally for an asymmetric lens
 #[tauri::command]
+#[tracing::instrument(level = "debug")]
 pub fn calculate_depth_of_field(
     object_distance_mm: f64,
     focal_length_mm: f64,
     f_number: f64,
     coc_mm: f64,
+    pupil_magnification: Option<f64>,
 ) -> serde_json::Value {
-    let (near, far, total) = calculate_dof(object_distance_mm, focal_length_mm, f_number, coc_mm);
+    let (near, far, total) = calculate_dof(
+        object_distance_mm,
+        focal_length_mm,
+        f_number,
+        coc_mm,
+        pupil_magnification,
+    );
 
     serde_json::json!({
         "near_mm": near,
@@ -62,21 +166,964 @@ pub fn calculate_depth_of_field(
 
 /// Tauri command to calculate focal length from FOV
 #[tauri::command]
+#[tracing::instrument(level = "debug")]
 pub fn calculate_focal_length_from_fov_command(sensor_size_mm: f64, fov_deg: f64) -> f64 {
     calculate_focal_length_from_fov(sensor_size_mm, fov_deg)
 }
 
 /// Tauri command to calculate parameter ranges for given DORI requirements
+///
+/// The request is validated first, so a missing target or impossible constraint is
+/// returned as its error-level warnings instead of reaching the solver.
 #[tauri::command]
+#[tracing::instrument(level = "debug", skip(cache))]
 pub fn calculate_dori_ranges(
+    cache: tauri::State<'_, CalculationCache>,
     targets: DoriTargets,
     constraints: ParameterConstraint,
-) -> DoriParameterRanges {
-    calculate_dori_parameter_ranges(&targets, &constraints)
+    bounds: Option<SolverBounds>,
+) -> Result<DoriParameterRanges, Vec<ValidationWarning>> {
+    let bounds = bounds.unwrap_or_default();
+    let solve = || calculate_dori_parameter_ranges_with_bounds(&targets, &constraints, &bounds);
+    let mut floats = DoriLevel::ALL
+        .into_iter()
+        .filter_map(|level| targets.distance_m(level))
+        .chain(
+            [
+                constraints.sensor_width_mm,
+                constraints.sensor_height_mm,
+                constraints.focal_length_mm,
+                constraints.horizontal_fov_deg,
+                constraints.vertical_fov_deg,
+                constraints.diagonal_fov_deg,
+            ]
+            .into_iter()
+            .flatten(),
+        )
+        .chain([
+            bounds.min_sensor_width_mm,
+            bounds.max_sensor_width_mm,
+            bounds.min_focal_length_mm,
+            bounds.max_focal_length_mm,
+            bounds.default_aspect_ratio,
+        ]);
+    let ranges = if floats.all(f64::is_finite) {
+        cache.get_or_compute(
+            "calculate_dori_ranges",
+            &(&targets, &constraints, &bounds),
+            solve,
+        )
+    } else {
+        solve()
+    };
+    ranges.map_err(|errors| logged_warnings("calculate_dori_ranges", errors))
 }
 
 /// Tauri command to calculate all DORI distances from a single input
 #[tauri::command]
-pub fn calculate_dori_from_single_distance(distance_m: f64, dori_type: String) -> DoriDistances {
-    calculate_dori_from_single(distance_m, &dori_type)
+#[tracing::instrument(level = "debug", skip(cache))]
+pub fn calculate_dori_from_single_distance(
+    cache: tauri::State<'_, CalculationCache>,
+    distance_m: f64,
+    dori_type: String,
+) -> DoriDistances {
+    if !distance_m.is_finite() {
+        return calculate_dori_from_single(distance_m, &dori_type);
+    }
+    cache.get_or_compute(
+        "calculate_dori_from_single_distance",
+        &(distance_m, &dori_type),
+        || calculate_dori_from_single(distance_m, &dori_type),
+    )
+}
+
+/// Tauri command to estimate the shutter time for a scene illuminance
+///
+/// The T-stop, when given, is used for the exposure instead of the f-number; the
+/// estimate reports both. With a gain in dB
+/// (as security cameras specify it), `iso` is the sensor's base ISO.
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn estimate_exposure_settings(
+    illuminance_lux: f64,
+    iso: f64,
+    f_number: f64,
+    t_stop: Option<f64>,
+    gain_db: Option<f64>,
+) -> ExposureEstimate {
+    let iso = gain_db.map_or(iso, |gain_db| iso_from_gain_db(iso, gain_db));
+    estimate_exposure(illuminance_lux, iso, f_number, t_stop)
+}
+
+/// Tauri command to calculate the minimum illuminance for a given shutter time
+///
+/// The T-stop, when given, is used instead of the f-number. With a gain in dB
+/// (as security cameras specify it), `iso` is the sensor's base ISO.
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn calculate_required_illuminance(
+    shutter_s: f64,
+    iso: f64,
+    f_number: f64,
+    t_stop: Option<f64>,
+    gain_db: Option<f64>,
+) -> f64 {
+    let iso = gain_db.map_or(iso, |gain_db| iso_from_gain_db(iso, gain_db));
+    calculate_illuminance_for_shutter(shutter_s, iso, t_stop.unwrap_or(f_number))
+}
+
+/// Tauri command to calculate DORI distances derated for visibility and rain
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn calculate_dori_with_atmosphere(
+    camera: CameraSystem,
+    conditions: AtmosphericConditions,
+) -> AtmosphericDoriResult {
+    calculate_atmospheric_dori(&camera, &conditions)
+}
+
+/// Tauri command to calculate DORI distances after a derating stack
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn calculate_effective_dori_distances(
+    camera: CameraSystem,
+    stages: Vec<DeratingFactor>,
+    conditions: Option<AtmosphericConditions>,
+) -> EffectiveDoriDistances {
+    calculate_effective_dori(&camera, &stages, conditions.as_ref())
+}
+
+/// Tauri command to compare pixel IFOV against the diffraction limit of the lens
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn calculate_diffraction_limit_command(
+    camera: CameraSystem,
+    f_number: f64,
+    wavelength_nm: Option<f64>,
+) -> DiffractionLimitResult {
+    calculate_diffraction_limit(&camera, f_number, wavelength_nm)
+}
+
+/// Tauri command to compare the sensor Nyquist frequency against the lens resolving power
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn calculate_sampling_analysis(
+    camera: CameraSystem,
+    lens_resolution_lp_mm: f64,
+) -> SamplingAnalysis {
+    calculate_sampling(&camera, lens_resolution_lp_mm)
+}
+
+/// Tauri command to calculate the lens resolving power a sensor needs
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn calculate_required_lens_resolution_command(
+    pixel_pitch_um: f64,
+) -> LensResolutionRequirement {
+    calculate_required_lens_resolution(pixel_pitch_um)
+}
+
+/// Tauri command to calculate thermal DRI ranges for all NATO targets
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn calculate_thermal_dri_ranges(camera: ThermalCameraSystem) -> Vec<ThermalDriResult> {
+    calculate_thermal_dri_all(&camera)
+}
+
+/// Tauri command to estimate the bitrate of a camera stream
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn estimate_stream_bitrate(params: BitrateParams) -> BitrateEstimate {
+    estimate_bitrate(&params)
+}
+
+/// Tauri command to estimate recording storage for a set of cameras
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn estimate_storage_requirement(
+    streams: Vec<StorageStream>,
+    retention_days: f64,
+) -> StorageEstimate {
+    estimate_storage(&streams, retention_days)
+}
+
+/// Tauri command to summarize streaming bandwidth per switch and recorder
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn summarize_stream_bandwidth(
+    streams: Vec<BandwidthStream>,
+    capacities: Vec<LinkCapacity>,
+) -> BandwidthSummary {
+    summarize_bandwidth(&streams, &capacities)
+}
+
+/// Emit the recalculated results of the open project to every frontend view
+///
+/// Best effort: a view that misses an update can still call `recalculate_all`.
+fn publish_project(app: &tauri::AppHandle, state: &ProjectState) {
+    let _ = app.emit(PROJECT_UPDATED_EVENT, state.results());
+}
+
+/// Tauri command to save a project to a JSON file
+///
+/// Saves the open project when none is given; a given project becomes the open one.
+#[tauri::command]
+#[tracing::instrument(level = "debug", skip(app, state))]
+pub fn save_project(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, ProjectState>,
+    project: Option<Project>,
+    path: String,
+) -> Result<(), String> {
+    if let Some(project) = project {
+        state.replace(project);
+        publish_project(&app, &state);
+    }
+    write_project_file(&state.project(), Path::new(&path)).map_err(|e| e.to_string())?;
+    tracing::info!(path, "project saved");
+    Ok(())
+}
+
+/// Tauri command to load a project from a JSON file, migrating older versions,
+/// and make it the open project
+#[tauri::command]
+#[tracing::instrument(level = "debug", skip(app, state))]
+pub fn load_project(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, ProjectState>,
+    path: String,
+) -> Result<Project, String> {
+    let project = read_project_file(Path::new(&path)).map_err(|e| {
+        tracing::error!(path, error = %e, "project could not be loaded");
+        e.to_string()
+    })?;
+    tracing::info!(path, cameras = project.cameras.len(), "project loaded");
+    state.replace(project.clone());
+    publish_project(&app, &state);
+    Ok(project)
+}
+
+/// Location of the camera preset store in the app config directory
+fn presets_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_config_dir()
+        .map(|dir| dir.join(PRESETS_FILE_NAME))
+        .map_err(|e| e.to_string())
+}
+
+/// Tauri command to list the user's saved camera presets
+#[tauri::command]
+#[tracing::instrument(level = "debug", skip(app))]
+pub fn list_camera_presets(app: tauri::AppHandle) -> Result<Vec<CameraPreset>, String> {
+    read_presets(&presets_path(&app)?).map_err(|e| e.to_string())
+}
+
+/// Tauri command to save a camera preset, replacing one with the same name
+#[tauri::command]
+#[tracing::instrument(level = "debug", skip(app))]
+pub fn save_camera_preset(
+    app: tauri::AppHandle,
+    preset: CameraPreset,
+) -> Result<Vec<CameraPreset>, String> {
+    store_preset(&presets_path(&app)?, preset).map_err(|e| e.to_string())
+}
+
+/// Tauri command to delete a saved camera preset by name
+#[tauri::command]
+#[tracing::instrument(level = "debug", skip(app))]
+pub fn delete_camera_preset(
+    app: tauri::AppHandle,
+    name: String,
+) -> Result<Vec<CameraPreset>, String> {
+    remove_preset(&presets_path(&app)?, &name).map_err(|e| e.to_string())
+}
+
+/// Tauri command to import a camera from an ONVIF device on the network
+#[tauri::command(async)]
+#[tracing::instrument(level = "debug")]
+pub fn import_onvif_device(request: OnvifImportRequest) -> Result<OnvifImportResult, String> {
+    import_onvif_camera(&request).map_err(|e| e.to_string())
+}
+
+/// Tauri command to import cameras from a CSV/XLSX datasheet export
+#[tauri::command(async)]
+#[tracing::instrument(level = "debug")]
+pub fn import_camera_catalog(path: String) -> Result<CatalogImport, String> {
+    import_catalog_file(Path::new(&path)).map_err(|e| e.to_string())
+}
+
+/// Tauri command to generate a px/m-vs-distance series for resolution charts
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn generate_ppm_curve(
+    camera: CameraSystem,
+    distance_min: f64,
+    distance_max: f64,
+    steps: usize,
+) -> PpmCurve {
+    crate::optics::curves::generate_ppm_curve(&camera, distance_min, distance_max, steps)
+}
+
+/// Tauri command to sweep focal length over a zoom range for FOV/DORI tradeoff charts
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn generate_zoom_dori_curve(
+    camera: CameraSystem,
+    focal_min_mm: f64,
+    focal_max_mm: f64,
+    steps: usize,
+) -> ZoomCurve {
+    crate::optics::curves::generate_zoom_curve(&camera, focal_min_mm, focal_max_mm, steps)
+}
+
+/// Run a long-running computation, emitting progress events for `task_id` and
+/// stopping once the frontend cancels it
+///
+/// Without a task id the computation runs to completion silently. Progress events
+/// are best effort: a failure to emit one does not stop the computation.
+fn run_tracked<T>(
+    app: &tauri::AppHandle,
+    registry: &CancellationRegistry,
+    task_id: Option<String>,
+    compute: impl FnOnce(&mut dyn FnMut(usize, usize) -> bool) -> Option<T>,
+) -> Result<T, String> {
+    let started = Instant::now();
+    let result = match &task_id {
+        Some(task_id) => {
+            let result = compute(&mut |done, total| {
+                let progress = ComputationProgress {
+                    task_id: task_id.clone(),
+                    done,
+                    total,
+                };
+                let _ = app.emit(PROGRESS_EVENT, progress);
+                !registry.is_cancelled(task_id)
+            });
+            registry.finish(task_id);
+            result
+        }
+        None => compute(&mut |_, _| true),
+    };
+    tracing::info!(
+        task_id = ?task_id,
+        cancelled = result.is_none(),
+        elapsed_ms = started.elapsed().as_secs_f64() * 1000.0,
+        "long-running computation finished"
+    );
+    result.ok_or_else(|| CANCELLED_ERROR.to_string())
+}
+
+/// Tauri command to ask a running computation started with `task_id` to stop
+#[tauri::command]
+#[tracing::instrument(level = "debug", skip(registry))]
+pub fn cancel_computation(registry: tauri::State<'_, CancellationRegistry>, task_id: String) {
+    registry.cancel(&task_id);
+}
+
+/// Tauri command to run a Monte Carlo tolerance analysis on FOV/DORI outputs
+#[tauri::command(async)]
+#[tracing::instrument(level = "debug", skip(app, registry))]
+pub fn analyze_tolerances(
+    app: tauri::AppHandle,
+    registry: tauri::State<'_, CancellationRegistry>,
+    params: ToleranceAnalysisParams,
+    task_id: Option<String>,
+) -> Result<ToleranceAnalysisResult, String> {
+    run_tracked(&app, &registry, task_id, |progress| {
+        run_tolerance_analysis_with_progress(&params, progress)
+    })
+}
+
+/// Tauri command to find and rank camera/lens combinations meeting a requirement set
+#[tauri::command(async)]
+#[tracing::instrument(level = "debug")]
+pub fn select_camera_systems(
+    requirements: SelectionRequirements,
+    cameras: Vec<CandidateCamera>,
+    lenses: Vec<CandidateLens>,
+) -> SelectionResult {
+    select_cameras(&requirements, &cameras, &lenses)
+}
+
+/// Tauri command to find cost / camera count / quality tradeoffs for covering a scene
+#[tauri::command(async)]
+#[tracing::instrument(level = "debug")]
+pub fn optimize_camera_designs(
+    requirements: SelectionRequirements,
+    scene: CoverageScene,
+    cameras: Vec<CandidateCamera>,
+    lenses: Vec<CandidateLens>,
+    max_designs: Option<usize>,
+) -> Vec<CoverageDesign> {
+    optimize_designs(
+        &requirements,
+        &scene,
+        &cameras,
+        &lenses,
+        max_designs.unwrap_or(3),
+    )
+}
+
+/// Tauri command to choose camera positions, orientations and lenses for a site
+#[tauri::command(async)]
+#[tracing::instrument(level = "debug", skip(app, registry))]
+pub fn optimize_camera_placement(
+    app: tauri::AppHandle,
+    registry: tauri::State<'_, CancellationRegistry>,
+    request: PlacementRequest,
+    task_id: Option<String>,
+) -> Result<PlacementResult, String> {
+    run_tracked(&app, &registry, task_id, |progress| {
+        optimize_placement_with_progress(&request, progress)
+    })
+}
+
+/// Tauri command to trace the area a camera sees at a pixel density, clipped by walls
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn calculate_camera_footprint(
+    camera: PlacedCamera,
+    required_ppm: f64,
+    scene: Scene,
+) -> Vec<PlanPoint> {
+    camera_footprint(&camera, required_ppm, &scene)
+}
+
+/// Tauri command to report which scene zones posed cameras cover, and by how much
+#[tauri::command(async)]
+#[tracing::instrument(level = "debug", skip(app, registry))]
+pub fn evaluate_scene_coverage(
+    app: tauri::AppHandle,
+    registry: tauri::State<'_, CancellationRegistry>,
+    scene: Scene,
+    cameras: Vec<PlacedCamera>,
+    grid_spacing_m: Option<f64>,
+    task_id: Option<String>,
+) -> Result<Vec<ZoneCoverage>, String> {
+    run_tracked(&app, &registry, task_id, |progress| {
+        evaluate_zone_coverage_with_progress(&scene, &cameras, grid_spacing_m, progress)
+    })
+}
+
+/// Tauri command to check a camera against a requirement spec, with pass/fail per requirement
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn evaluate_camera_against_spec(
+    camera: CameraSystem,
+    stream: Option<BitrateParams>,
+    spec: RequirementSpec,
+) -> ComplianceReport {
+    crate::compliance::evaluate_camera_against_spec(&camera, stream.as_ref(), &spec)
+}
+
+/// Tauri command to find the fewest cameras that cover a corridor or perimeter run
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn solve_corridor_camera_count(
+    camera: CameraSystem,
+    length_m: f64,
+    mounting_height_m: f64,
+    required_ppm: f64,
+) -> Result<CorridorSolution, String> {
+    solve_corridor_coverage(&camera, length_m, mounting_height_m, required_ppm).ok_or_else(|| {
+        format!("Camera does not reach {required_ppm} px/m from {mounting_height_m} m up")
+    })
+}
+
+/// Tauri command to export the coverage geometry of a scene as an SVG or DXF drawing
+#[tauri::command(async)]
+#[tracing::instrument(level = "debug")]
+pub fn export_coverage_drawing(
+    request: DrawingRequest,
+    format: DrawingFormat,
+    path: String,
+) -> Result<(), String> {
+    let drawing = build_drawing(&request);
+    let contents = match format {
+        DrawingFormat::Svg => render_svg(&drawing),
+        DrawingFormat::Dxf => render_dxf(&drawing),
+    };
+    std::fs::write(path, contents).map_err(|e| e.to_string())
+}
+
+/// Tauri command to compute the bracket pan and tilt that centre a target point
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn aim_camera_at_target(request: AimingRequest) -> AimingResult {
+    aim_camera(&request)
+}
+
+/// Tauri command to check the vertical angle onto faces against the identification limit
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn check_face_incidence(
+    mounting_height_m: f64,
+    distance_m: f64,
+    face_height_m: Option<f64>,
+) -> FaceIncidenceAnalysis {
+    calculate_face_incidence(mounting_height_m, distance_m, face_height_m)
+}
+
+/// Tauri command to find the highest mount that still allows facial identification at a distance
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn recommend_max_mounting_height(
+    distance_m: f64,
+    face_height_m: Option<f64>,
+) -> MountingHeightLimit {
+    calculate_max_mounting_height(distance_m, face_height_m)
+}
+
+/// Tauri command to find the area outside of which a camera cannot identify or recognize people
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn calculate_privacy_boundary(camera: PlacedCamera, level: DoriLevel) -> PrivacyBoundary {
+    privacy_boundary(&camera, level)
+}
+
+/// Tauri command to turn exclusion zones on the site into privacy mask polygons in image pixels
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn generate_privacy_mask_polygons(
+    camera: PlacedCamera,
+    zones: Vec<ExclusionZone>,
+) -> PrivacyMaskSet {
+    generate_privacy_masks(&camera, &zones)
+}
+
+/// Tauri command to get the standard licence plate dimensions of a region
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn get_plate_spec(region: PlateRegion) -> PlateSpec {
+    region.plate()
+}
+
+/// Tauri command to calculate licence plate capture distance, focal length and motion blur
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn calculate_alpr_capture(params: AlprParams) -> AlprResult {
+    calculate_alpr(&params)
+}
+
+/// Tauri command to calculate how far a camera can read labels, signage or gauges
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn calculate_text_legibility(params: LegibilityParams) -> LegibilityResult {
+    calculate_legibility(&params)
+}
+
+/// Tauri command to find the working distances at which a barcode or QR code can be decoded
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn calculate_barcode_reading_window(params: BarcodeParams) -> BarcodeResult {
+    calculate_barcode_reading(&params)
+}
+
+/// Tauri command to find camera, lens and working distance combinations for a defect size
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn solve_inspection_setup(
+    requirements: InspectionRequirements,
+    cameras: Vec<CameraSystem>,
+    focal_lengths_mm: Vec<f64>,
+) -> InspectionSolution {
+    solve_inspection(&requirements, &cameras, &focal_lengths_mm)
+}
+
+/// Tauri command to calculate FOV, pixel density and DORI with propagated error bars
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn calculate_with_uncertainty(params: UncertaintyParams) -> UncertaintyResult {
+    propagate_uncertainty(&params)
+}
+
+/// Tauri command to solve the working distance for a required scene width/height
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn solve_working_distance(
+    camera: CameraSystem,
+    required_fov_m: RequiredFov,
+) -> Result<FovResult, String> {
+    crate::optics::calculations::solve_working_distance(&camera, &required_fov_m)
+        .ok_or_else(|| "Give a positive scene width or height to cover".to_string())
+}
+
+/// Tauri command to calculate the chart distance for lab resolution verification
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn calculate_test_chart_distance(params: TestChartParams) -> Result<TestChartSetup, String> {
+    calculate_test_chart_setup(&params)
+        .ok_or_else(|| "Chart size and framing must be positive".to_string())
+}
+
+/// Tauri command to recommend a calibration board for a camera at a working distance
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn recommend_calibration_target(
+    params: CalibrationTargetParams,
+) -> Result<CalibrationTarget, String> {
+    size_calibration_target(&params).ok_or_else(|| {
+        "Fewer than 3 squares fit; lower the pixels per square or raise the FOV fraction"
+            .to_string()
+    })
+}
+
+/// Tauri command to calculate astrophotography pixel scale, seeing sampling and sky FOV
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn calculate_astro_pixel_scale(camera: CameraSystem, seeing_arcsec: f64) -> AstroSampling {
+    calculate_astro_sampling(&camera, seeing_arcsec)
+}
+
+/// Tauri command to calculate the longest untrailed exposure (500 and NPF rules)
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn calculate_star_trail_exposure(
+    camera: CameraSystem,
+    f_number: f64,
+    declination_deg: f64,
+) -> StarTrailLimit {
+    calculate_star_trail_limit(&camera, f_number, declination_deg)
+}
+
+/// Tauri command to calculate telescope focal ratio, useful magnification and eyepiece view
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn calculate_telescope_view(
+    telescope: Telescope,
+    eyepiece: Option<Eyepiece>,
+) -> TelescopeAnalysis {
+    analyze_telescope(&telescope, eyepiece.as_ref())
+}
+
+/// Tauri command to put a camera at the prime focus of a telescope
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn attach_camera_to_telescope(telescope: Telescope, camera: CameraSystem) -> CameraSystem {
+    telescope.with_camera(&camera)
+}
+
+/// Tauri command to check a camera against a microscope objective's resolution
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn calculate_microscope_sampling(
+    camera: CameraSystem,
+    objective: MicroscopeObjective,
+    wavelength_nm: Option<f64>,
+) -> MicroscopyAnalysis {
+    analyze_microscope(&camera, &objective, wavelength_nm)
+}
+
+/// Tauri command to solve drone flight altitude and swath for a required GSD
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn solve_drone_flight_altitude(params: FlightAltitudeParams) -> FlightAltitude {
+    solve_flight_altitude(&params)
+}
+
+/// Tauri command to check a flight altitude against the regulatory ceiling
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn validate_flight_altitude(flight: FlightAltitude) -> Vec<ValidationWarning> {
+    logged_warnings("validate_flight_altitude", flight.validate())
+}
+
+/// Tauri command to plan photogrammetry exposure and flight-line spacing
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn plan_photogrammetry_survey(params: SurveyParams) -> Result<SurveyPlan, String> {
+    plan_survey(&params)
+        .ok_or_else(|| "Forward and side overlap must be at least 0 % and below 100 %".to_string())
+}
+
+/// Tauri command to find the equivalent focal length and aperture on another sensor format
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn calculate_equivalent_setup(
+    camera: CameraSystem,
+    f_number: f64,
+    sensor_width_mm: f64,
+    sensor_height_mm: f64,
+) -> FormatEquivalence {
+    calculate_format_equivalence(&camera, f_number, sensor_width_mm, sensor_height_mm)
+}
+
+/// Tauri command to calculate the plane of focus and DOF wedge of a tilted lens
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn calculate_tilt_shift_dof(params: TiltedLensParams) -> Result<TiltedFocus, String> {
+    calculate_tilted_focus(&params)
+        .ok_or_else(|| "Tilt must be positive and the focus distance beyond the hinge".to_string())
+}
+
+/// Tauri command to find the lens tilt that puts the plane of focus through a hinge line
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn calculate_scheimpflug_tilt(
+    focal_length_mm: f64,
+    hinge_distance_mm: f64,
+) -> Result<f64, String> {
+    scheimpflug_tilt_deg(focal_length_mm, hinge_distance_mm)
+        .ok_or_else(|| "The hinge must be further from the lens than the focal length".to_string())
+}
+
+/// Tauri command to find a lens's no-parallax point for panorama rigs
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn calculate_no_parallax_offset(params: NoParallaxParams) -> NoParallaxPoint {
+    calculate_no_parallax_point(&params)
+}
+
+/// Tauri command to calculate illumination falloff from the image centre to the corners
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn calculate_relative_illumination(params: VignettingParams) -> VignettingProfile {
+    calculate_vignetting(&params)
+}
+
+/// Tauri command to convert an f-number and lens transmission to a T-stop
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn calculate_t_stop(f_number: f64, transmission: f64) -> f64 {
+    t_stop_from_f_number(f_number, transmission)
+}
+
+/// Tauri command to calculate the light transmission of a lens from its f-number and T-stop
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn calculate_lens_transmission(f_number: f64, t_stop: f64) -> f64 {
+    lens_transmission(f_number, t_stop)
+}
+
+/// Tauri command to convert a shutter angle at a frame rate to an exposure time
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn calculate_shutter_from_angle(angle_deg: f64, frame_rate_fps: f64) -> f64 {
+    shutter_from_angle(angle_deg, frame_rate_fps)
+}
+
+/// Tauri command to convert an exposure time at a frame rate to a shutter angle
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn calculate_shutter_angle(shutter_s: f64, frame_rate_fps: f64) -> f64 {
+    angle_from_shutter(shutter_s, frame_rate_fps)
+}
+
+/// Tauri command to compare a scene's dynamic range requirement with the camera's WDR
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn assess_scene_dynamic_range(camera: CameraSystem, scene: WdrScene) -> DynamicRangeAssessment {
+    assess_dynamic_range(&camera, scene)
+}
+
+/// Tauri command to estimate the per-pixel photon-noise SNR at a given exposure
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn estimate_sensor_snr(camera: CameraSystem, params: SnrParams) -> Result<SnrEstimate, String> {
+    estimate_snr(&camera, &params)
+        .ok_or_else(|| "Camera needs an f-number or T-stop for an SNR estimate".to_string())
+}
+
+/// Tauri command to evaluate a paired visible/thermal installation as one system
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn evaluate_dual_sensor_system(
+    system: DualSensorSystem,
+    target: NatoTarget,
+    scene: Option<Scene>,
+) -> DualSensorEvaluation {
+    evaluate_dual_sensor(&system, target, scene.as_ref())
+}
+
+/// Tauri command to check a camera against a DORI target and suggest a focal length
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn advise_dori_focal_length(
+    camera: CameraSystem,
+    level: DoriLevel,
+    distance_m: f64,
+) -> ValidationWarning {
+    advise_dori_target(&camera, level, distance_m)
+}
+
+/// Tauri command to re-render validation warnings in another language
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn localize_validation_warnings(
+    warnings: Vec<ValidationWarning>,
+    locale: Locale,
+) -> Vec<ValidationWarning> {
+    localize_warnings(&warnings, locale)
+}
+
+/// Tauri command to summarize a camera and its FOV result in a language
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn describe_camera_result(camera: CameraSystem, result: FovResult, locale: Locale) -> String {
+    format!(
+        "{}\n{}",
+        camera.summary().render(locale),
+        result.summary().render(locale)
+    )
+}
+
+/// Tauri command to check DORI targets and constraints before solving
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn validate_dori_request(
+    targets: DoriTargets,
+    constraints: ParameterConstraint,
+) -> Vec<ValidationWarning> {
+    logged_warnings(
+        "validate_dori_request",
+        crate::optics::calculations::validate_dori_request(&targets, &constraints),
+    )
+}
+
+/// Tauri command to convert DORI distances into ground distances for a mounted, tilted camera
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn calculate_ground_dori(
+    camera: CameraSystem,
+    mounting_height_m: f64,
+    tilt_deg: f64,
+    target_height_m: Option<f64>,
+) -> GroundDoriDistances {
+    calculate_ground_dori_distances(&camera, mounting_height_m, tilt_deg, target_height_m)
+}
+
+/// Tauri command to evaluate pixel density, DORI level and pixels on a target at a site position
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn evaluate_target_position(
+    camera: CameraSystem,
+    pose: CameraPose,
+    target_position: WorldPoint,
+    target_size: TargetSize,
+) -> TargetEvaluation {
+    evaluate_target(&camera, &pose, target_position, target_size)
+}
+
+/// Tauri command to report pixel density percentiles and DORI level coverage over scene zones
+#[tauri::command(async)]
+#[tracing::instrument(level = "debug", skip(app, registry))]
+pub fn calculate_zone_statistics(
+    app: tauri::AppHandle,
+    registry: tauri::State<'_, CancellationRegistry>,
+    scene: Scene,
+    cameras: Vec<PlacedCamera>,
+    grid_spacing_m: Option<f64>,
+    task_id: Option<String>,
+) -> Result<Vec<ZoneStatistics>, String> {
+    run_tracked(&app, &registry, task_id, |progress| {
+        zone_coverage_statistics_with_progress(&scene, &cameras, grid_spacing_m, progress)
+    })
+}
+
+/// Tauri command to drop cached results of one command, or of all commands
+#[tauri::command]
+#[tracing::instrument(level = "debug", skip(cache))]
+pub fn clear_calculation_cache(
+    cache: tauri::State<'_, CalculationCache>,
+    command: Option<String>,
+) -> CacheStats {
+    cache.invalidate(command.as_deref())
+}
+
+/// Tauri command to report the size and hit rate of the calculation cache
+#[tauri::command]
+#[tracing::instrument(level = "debug", skip(cache))]
+pub fn calculation_cache_stats(cache: tauri::State<'_, CalculationCache>) -> CacheStats {
+    cache.stats()
+}
+
+/// Tauri command to list the cameras of the open project
+#[tauri::command]
+#[tracing::instrument(level = "debug", skip(state))]
+pub fn list_cameras(state: tauri::State<'_, ProjectState>) -> Vec<ProjectCamera> {
+    state.list_cameras()
+}
+
+/// Tauri command to add a camera to the open project
+#[tauri::command]
+#[tracing::instrument(level = "debug", skip(app, state))]
+pub fn add_camera(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, ProjectState>,
+    camera: ProjectCamera,
+) -> Vec<ProjectCamera> {
+    let cameras = state.add_camera(camera);
+    publish_project(&app, &state);
+    cameras
+}
+
+/// Tauri command to replace a camera of the open project by index
+#[tauri::command]
+#[tracing::instrument(level = "debug", skip(app, state))]
+pub fn update_camera(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, ProjectState>,
+    index: usize,
+    camera: ProjectCamera,
+) -> Result<Vec<ProjectCamera>, String> {
+    let cameras = state
+        .update_camera(index, camera)
+        .map_err(|e| e.to_string())?;
+    publish_project(&app, &state);
+    Ok(cameras)
+}
+
+/// Tauri command to remove a camera from the open project by index
+#[tauri::command]
+#[tracing::instrument(level = "debug", skip(app, state))]
+pub fn remove_camera(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, ProjectState>,
+    index: usize,
+) -> Result<Vec<ProjectCamera>, String> {
+    let cameras = state.remove_camera(index).map_err(|e| e.to_string())?;
+    publish_project(&app, &state);
+    Ok(cameras)
+}
+
+/// Tauri command to calculate every camera of the open project at its target distance
+#[tauri::command]
+#[tracing::instrument(level = "debug", skip(state))]
+pub fn recalculate_all(state: tauri::State<'_, ProjectState>) -> Vec<CameraWithResult> {
+    state.recalculate_all()
+}
+
+/// Tauri command to replace the site plan of the open project, or remove it with `None`
+#[tauri::command]
+#[tracing::instrument(level = "debug", skip(app, state))]
+pub fn set_project_scene(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, ProjectState>,
+    scene: Option<Scene>,
+) {
+    state.set_scene(scene);
+    publish_project(&app, &state);
+}
+
+/// Tauri command to save recent backend logs and the open project to a JSON file
+/// for attaching to a bug report
+#[tauri::command]
+#[tracing::instrument(level = "debug", skip(state, logs))]
+pub fn export_diagnostics(
+    state: tauri::State<'_, ProjectState>,
+    logs: tauri::State<'_, LogBuffer>,
+    path: String,
+) -> Result<(), String> {
+    let report = build_diagnostics_report(state.project(), &logs);
+    write_diagnostics_report(&report, Path::new(&path)).map_err(|e| e.to_string())?;
+    tracing::info!(path, "diagnostics exported");
+    Ok(())
+}
+
+/// Tauri command to compare every derived metric of two cameras at one distance,
+/// as percentage changes from camera `a` to camera `b`
+#[tauri::command]
+#[tracing::instrument(level = "debug")]
+pub fn diff_cameras(a: CameraSystem, b: CameraSystem, distance_mm: f64) -> CameraDiff {
+    calculate_camera_diff(&a, &b, distance_mm)
 }
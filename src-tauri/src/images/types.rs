@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 
 /// Input parameters for image preview downsampling calculation
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
 pub struct ImageDownsampleParams {
     /// Horizontal pixels per meter at the working distance
     pub horizontal_ppm: f64,
@@ -19,6 +20,7 @@ pub struct ImageDownsampleParams {
 
 /// Result of image preview downsampling calculation
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
 pub struct ImageDownsampleResult {
     /// Number of horizontal pixels the camera would capture
     pub camera_pixels_h: u32,
@@ -1,14 +1,34 @@
 // Optical calculation modules
+pub mod batch;
+pub mod cache;
+pub mod catalog;
+pub mod compliance;
+pub mod diagnostics;
+pub mod drawing;
 mod gui_commands;
 pub mod images;
-pub mod optics;
+pub mod onvif;
+pub mod project;
+pub mod tasks;
+pub mod thermal;
+pub mod video;
+
+// Pure calculation engine, usable without Tauri as the `camera-optics` crate
+pub use camera_optics as optics;
 
 use crate::gui_commands::*;
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    let logs = diagnostics::LogBuffer::default();
+    diagnostics::init_logging(logs.clone());
+
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
+        .manage(tasks::CancellationRegistry::default())
+        .manage(cache::CalculationCache::default())
+        .manage(project::ProjectState::default())
+        .manage(logs)
         .invoke_handler(tauri::generate_handler![
             calculate_image_downsample_command,
             calculate_camera_fov,
@@ -18,7 +38,88 @@ pub fn run() {
             calculate_focal_length_from_fov_command,
             calculate_dori_ranges,
             calculate_dori_from_single_distance,
-            validate_camera_system
+            validate_camera_system,
+            estimate_exposure_settings,
+            calculate_required_illuminance,
+            calculate_thermal_dri_ranges,
+            calculate_dori_with_atmosphere,
+            calculate_effective_dori_distances,
+            calculate_diffraction_limit_command,
+            calculate_sampling_analysis,
+            calculate_required_lens_resolution_command,
+            estimate_stream_bitrate,
+            estimate_storage_requirement,
+            summarize_stream_bandwidth,
+            save_project,
+            load_project,
+            list_camera_presets,
+            save_camera_preset,
+            delete_camera_preset,
+            import_onvif_device,
+            import_camera_catalog,
+            generate_ppm_curve,
+            generate_zoom_dori_curve,
+            analyze_tolerances,
+            select_camera_systems,
+            optimize_camera_designs,
+            optimize_camera_placement,
+            calculate_camera_footprint,
+            evaluate_scene_coverage,
+            evaluate_camera_against_spec,
+            solve_corridor_camera_count,
+            export_coverage_drawing,
+            aim_camera_at_target,
+            check_face_incidence,
+            recommend_max_mounting_height,
+            calculate_privacy_boundary,
+            generate_privacy_mask_polygons,
+            get_plate_spec,
+            calculate_alpr_capture,
+            calculate_text_legibility,
+            calculate_barcode_reading_window,
+            solve_inspection_setup,
+            calculate_with_uncertainty,
+            solve_working_distance,
+            calculate_test_chart_distance,
+            recommend_calibration_target,
+            calculate_astro_pixel_scale,
+            calculate_star_trail_exposure,
+            calculate_telescope_view,
+            attach_camera_to_telescope,
+            calculate_microscope_sampling,
+            solve_drone_flight_altitude,
+            validate_flight_altitude,
+            plan_photogrammetry_survey,
+            calculate_equivalent_setup,
+            calculate_tilt_shift_dof,
+            calculate_scheimpflug_tilt,
+            calculate_no_parallax_offset,
+            calculate_relative_illumination,
+            calculate_t_stop,
+            calculate_lens_transmission,
+            calculate_shutter_from_angle,
+            calculate_shutter_angle,
+            assess_scene_dynamic_range,
+            estimate_sensor_snr,
+            evaluate_dual_sensor_system,
+            advise_dori_focal_length,
+            localize_validation_warnings,
+            describe_camera_result,
+            validate_dori_request,
+            calculate_ground_dori,
+            evaluate_target_position,
+            calculate_zone_statistics,
+            cancel_computation,
+            clear_calculation_cache,
+            calculation_cache_stats,
+            list_cameras,
+            add_camera,
+            update_camera,
+            remove_camera,
+            recalculate_all,
+            set_project_scene,
+            export_diagnostics,
+            diff_cameras
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
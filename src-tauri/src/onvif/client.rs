@@ -0,0 +1,352 @@
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use sha1::{Digest, Sha1};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use super::parse::{
+    parse_capabilities, parse_device_information, parse_profiles, parse_zoom_position,
+};
+use super::types::{
+    OnvifDeviceInfo, OnvifError, OnvifImportRequest, OnvifImportResult, OnvifLensHints,
+    OnvifProfile,
+};
+use crate::optics::types::{
+    CameraSystem, MessageKey, ValidationCode, ValidationSeverity, ValidationWarning,
+};
+
+/// Sensor width assumed when none is given (1/2.8", the most common surveillance format)
+const DEFAULT_SENSOR_WIDTH_MM: f64 = 5.6;
+
+/// Focal length assumed when none is given
+const DEFAULT_FOCAL_LENGTH_MM: f64 = 4.0;
+
+/// Timeout for each request to the device
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Build the device service URL from a host, host:port or full URL
+pub fn device_service_url(address: &str) -> String {
+    let address = address.trim().trim_end_matches('/');
+    if address.contains("://") {
+        address.to_string()
+    } else {
+        format!("http://{}/onvif/device_service", address)
+    }
+}
+
+/// WS-Security UsernameToken header with a password digest
+///
+/// PasswordDigest = Base64(SHA1(nonce + created + password))
+fn security_header(username: &str, password: &str) -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    let nonce = Sha1::digest(format!("{}{}", now.as_nanos(), username));
+    let created = format_utc(now.as_secs());
+
+    let mut hasher = Sha1::new();
+    hasher.update(nonce);
+    hasher.update(created.as_bytes());
+    hasher.update(password.as_bytes());
+    let digest = BASE64.encode(hasher.finalize());
+
+    format!(
+        r#"<s:Header><Security xmlns="http://docs.oasis-open.org/wss/2004/01/oasis-200401-wss-wssecurity-secext-1.0.xsd" s:mustUnderstand="1"><UsernameToken><Username>{}</Username><Password Type="http://docs.oasis-open.org/wss/2004/01/oasis-200401-wss-username-token-profile-1.0#PasswordDigest">{}</Password><Nonce EncodingType="http://docs.oasis-open.org/wss/2004/01/oasis-200401-wss-soap-message-security-1.0#Base64Binary">{}</Nonce><Created xmlns="http://docs.oasis-open.org/wss/2004/01/oasis-200401-wss-wssecurity-utility-1.0.xsd">{}</Created></UsernameToken></Security></s:Header>"#,
+        username,
+        digest,
+        BASE64.encode(nonce),
+        created
+    )
+}
+
+/// Format seconds since the Unix epoch as an ISO 8601 UTC timestamp
+fn format_utc(secs: u64) -> String {
+    let days = (secs / 86400) as i64;
+    let rem = secs % 86400;
+
+    // Civil-from-days (Howard Hinnant)
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        rem / 3600,
+        rem % 3600 / 60,
+        rem % 60
+    )
+}
+
+/// Minimal ONVIF SOAP client for a single device
+struct OnvifClient {
+    agent: ureq::Agent,
+    username: Option<String>,
+    password: Option<String>,
+}
+
+impl OnvifClient {
+    fn new(username: Option<String>, password: Option<String>) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .timeout_global(Some(REQUEST_TIMEOUT))
+            .http_status_as_error(false)
+            .build()
+            .into();
+        Self {
+            agent,
+            username,
+            password,
+        }
+    }
+
+    /// Send a SOAP request body and return the response XML
+    fn call(&self, url: &str, body: &str) -> Result<String, OnvifError> {
+        let header = match (&self.username, &self.password) {
+            (Some(user), Some(pass)) => security_header(user, pass),
+            _ => String::new(),
+        };
+        let envelope = format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?><s:Envelope xmlns:s="http://www.w3.org/2003/05/soap-envelope">{}<s:Body>{}</s:Body></s:Envelope>"#,
+            header, body
+        );
+
+        let mut response = self
+            .agent
+            .post(url)
+            .header("Content-Type", "application/soap+xml; charset=utf-8")
+            .send(envelope)
+            .map_err(|e| OnvifError::Http(e.to_string()))?;
+        let status = response.status();
+        let text = response
+            .body_mut()
+            .read_to_string()
+            .map_err(|e| OnvifError::Http(e.to_string()))?;
+
+        // SOAP faults come back with 4xx/5xx and are reported by the parsers
+        if !status.is_success() && !text.contains("Fault") {
+            return Err(OnvifError::Http(format!("HTTP {}", status)));
+        }
+        Ok(text)
+    }
+}
+
+/// Build a camera system from ONVIF profile data and user lens hints
+///
+/// The resolution comes from the device. Sensor size and focal length are taken
+/// from the hints; when the device reports a zoom position and a focal length range
+/// is given, the focal length is interpolated linearly between the two ends.
+/// Anything that has to be assumed is reported as a warning.
+pub fn build_onvif_camera(
+    device: &OnvifDeviceInfo,
+    profile: &OnvifProfile,
+    zoom_position: Option<f64>,
+    hints: &OnvifLensHints,
+) -> (CameraSystem, Vec<ValidationWarning>) {
+    let mut warnings = Vec::new();
+    let aspect = profile.height as f64 / profile.width as f64;
+
+    let (sensor_width_mm, sensor_height_mm) = match (hints.sensor_width_mm, hints.sensor_height_mm)
+    {
+        (Some(w), Some(h)) => (w, h),
+        (Some(w), None) => (w, w * aspect),
+        _ => {
+            warnings.push(
+                ValidationWarning::new(
+                    ValidationCode::SensorSizeAssumed,
+                    ValidationSeverity::Warning,
+                    MessageKey::new("onvif.sensor_size_assumed")
+                        .with_number("sensor_width_mm", DEFAULT_SENSOR_WIDTH_MM),
+                )
+                .with_field("sensor_width_mm", DEFAULT_SENSOR_WIDTH_MM),
+            );
+            (DEFAULT_SENSOR_WIDTH_MM, DEFAULT_SENSOR_WIDTH_MM * aspect)
+        }
+    };
+
+    let focal_length_mm = match (
+        hints.focal_length_min_mm,
+        hints.focal_length_max_mm,
+        zoom_position,
+    ) {
+        (Some(min), Some(max), Some(zoom)) => {
+            let focal_length_mm = min + (max - min) * zoom.clamp(0.0, 1.0);
+            warnings.push(
+                ValidationWarning::new(
+                    ValidationCode::FocalLengthInterpolated,
+                    ValidationSeverity::Warning,
+                    MessageKey::new("onvif.focal_length_interpolated"),
+                )
+                .with_field("focal_length_mm", focal_length_mm),
+            );
+            focal_length_mm
+        }
+        (Some(min), _, _) => min,
+        _ => {
+            warnings.push(
+                ValidationWarning::new(
+                    ValidationCode::FocalLengthAssumed,
+                    ValidationSeverity::Warning,
+                    MessageKey::new("onvif.focal_length_assumed")
+                        .with_number("focal_length_mm", DEFAULT_FOCAL_LENGTH_MM),
+                )
+                .with_field("focal_length_mm", DEFAULT_FOCAL_LENGTH_MM),
+            );
+            DEFAULT_FOCAL_LENGTH_MM
+        }
+    };
+
+    let mut camera = CameraSystem::new(
+        sensor_width_mm,
+        sensor_height_mm,
+        profile.width,
+        profile.height,
+        focal_length_mm,
+    );
+    let name = match (&device.manufacturer, &device.model) {
+        (Some(make), Some(model)) => Some(format!("{} {}", make, model)),
+        (None, Some(model)) => Some(model.clone()),
+        (Some(make), None) => Some(make.clone()),
+        (None, None) => None,
+    };
+    if let Some(name) = name {
+        camera = camera.with_name(name);
+    }
+
+    (camera, warnings)
+}
+
+/// Query an ONVIF device and construct a camera system from its video configuration
+///
+/// # Arguments
+/// * `request` - Device address, credentials, profile and lens hints
+///
+/// # Returns
+/// The imported camera with the raw device data and assumption warnings
+pub fn import_onvif_camera(request: &OnvifImportRequest) -> Result<OnvifImportResult, OnvifError> {
+    let client = OnvifClient::new(request.username.clone(), request.password.clone());
+    let device_url = device_service_url(&request.address);
+
+    let (media_url, ptz_url) = parse_capabilities(&client.call(
+        &device_url,
+        r#"<GetCapabilities xmlns="http://www.onvif.org/ver10/device/wsdl"><Category>All</Category></GetCapabilities>"#,
+    )?)?;
+
+    // Device information is optional for the import
+    let device = client
+        .call(
+            &device_url,
+            r#"<GetDeviceInformation xmlns="http://www.onvif.org/ver10/device/wsdl"/>"#,
+        )
+        .and_then(|xml| parse_device_information(&xml))
+        .unwrap_or_default();
+
+    let profiles = parse_profiles(&client.call(
+        &media_url,
+        r#"<GetProfiles xmlns="http://www.onvif.org/ver10/media/wsdl"/>"#,
+    )?)?;
+    let profile = match &request.profile_token {
+        Some(token) => profiles.into_iter().find(|p| &p.token == token),
+        None => profiles.into_iter().next(),
+    }
+    .ok_or(OnvifError::NoProfile)?;
+
+    let zoom_position = ptz_url.and_then(|url| {
+        let body = format!(
+            r#"<GetStatus xmlns="http://www.onvif.org/ver20/ptz/wsdl"><ProfileToken>{}</ProfileToken></GetStatus>"#,
+            profile.token
+        );
+        client
+            .call(&url, &body)
+            .and_then(|xml| parse_zoom_position(&xml))
+            .ok()
+            .flatten()
+    });
+
+    let (camera, warnings) = build_onvif_camera(&device, &profile, zoom_position, &request.hints);
+
+    Ok(OnvifImportResult {
+        camera,
+        device,
+        profile,
+        zoom_position,
+        warnings,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile() -> OnvifProfile {
+        OnvifProfile {
+            token: "main".to_string(),
+            name: None,
+            width: 1920,
+            height: 1080,
+            encoding: None,
+            frame_rate: None,
+        }
+    }
+
+    #[test]
+    fn test_device_service_url() {
+        assert_eq!(
+            device_service_url("192.168.1.10"),
+            "http://192.168.1.10/onvif/device_service"
+        );
+        assert_eq!(
+            device_service_url("http://cam:8080/onvif/device_service/"),
+            "http://cam:8080/onvif/device_service"
+        );
+    }
+
+    #[test]
+    fn test_format_utc() {
+        assert_eq!(format_utc(0), "1970-01-01T00:00:00Z");
+        assert_eq!(format_utc(1_709_210_096), "2024-02-29T12:34:56Z");
+    }
+
+    #[test]
+    fn test_build_camera_with_zoom_hints() {
+        let device = OnvifDeviceInfo {
+            manufacturer: Some("Acme".to_string()),
+            model: Some("PTZ-30X".to_string()),
+            firmware_version: None,
+        };
+        let hints = OnvifLensHints {
+            sensor_width_mm: Some(5.6),
+            sensor_height_mm: None,
+            focal_length_min_mm: Some(4.0),
+            focal_length_max_mm: Some(12.0),
+        };
+        let (camera, warnings) = build_onvif_camera(&device, &profile(), Some(0.5), &hints);
+
+        assert_eq!(camera.name.as_deref(), Some("Acme PTZ-30X"));
+        assert!((camera.focal_length_mm - 8.0).abs() < 1e-9);
+        assert!((camera.sensor_height_mm - 3.15).abs() < 1e-9);
+        assert_eq!(warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_build_camera_without_hints_warns() {
+        let (camera, warnings) = build_onvif_camera(
+            &OnvifDeviceInfo::default(),
+            &profile(),
+            None,
+            &OnvifLensHints::default(),
+        );
+
+        assert_eq!(camera.pixel_width, 1920);
+        assert!((camera.sensor_width_mm - DEFAULT_SENSOR_WIDTH_MM).abs() < 1e-9);
+        assert!((camera.focal_length_mm - DEFAULT_FOCAL_LENGTH_MM).abs() < 1e-9);
+        assert_eq!(warnings.len(), 2);
+        assert!(camera.name.is_none());
+    }
+}
@@ -0,0 +1,7 @@
+pub mod client;
+pub mod parse;
+pub mod types;
+
+pub use client::*;
+pub use parse::*;
+pub use types::*;
@@ -0,0 +1,173 @@
+use roxmltree::{Document, Node};
+
+use super::types::{OnvifDeviceInfo, OnvifError, OnvifProfile};
+
+/// Find the first descendant element with the given local name, ignoring namespaces
+fn find<'a, 'i>(node: Node<'a, 'i>, name: &str) -> Option<Node<'a, 'i>> {
+    node.descendants()
+        .find(|n| n.is_element() && n.tag_name().name() == name)
+}
+
+/// Text of the first descendant element with the given local name
+fn find_text(node: Node, name: &str) -> Option<String> {
+    find(node, name)
+        .and_then(|n| n.text())
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())
+}
+
+fn parse_document(xml: &str) -> Result<Document<'_>, OnvifError> {
+    let doc = Document::parse(xml).map_err(|e| OnvifError::Parse(e.to_string()))?;
+    if let Some(fault) = find(doc.root(), "Fault") {
+        let reason = find_text(fault, "Text").unwrap_or_else(|| "unknown fault".to_string());
+        return Err(OnvifError::Fault(reason));
+    }
+    Ok(doc)
+}
+
+/// Extract the media and PTZ service addresses from a GetCapabilities response
+///
+/// # Returns
+/// (media XAddr, PTZ XAddr if the device supports PTZ)
+pub fn parse_capabilities(xml: &str) -> Result<(String, Option<String>), OnvifError> {
+    let doc = parse_document(xml)?;
+    let media = find(doc.root(), "Media")
+        .and_then(|n| find_text(n, "XAddr"))
+        .ok_or_else(|| OnvifError::Parse("device reports no media service".to_string()))?;
+    let ptz = find(doc.root(), "PTZ").and_then(|n| find_text(n, "XAddr"));
+    Ok((media, ptz))
+}
+
+/// Extract manufacturer, model and firmware from a GetDeviceInformation response
+pub fn parse_device_information(xml: &str) -> Result<OnvifDeviceInfo, OnvifError> {
+    let doc = parse_document(xml)?;
+    let root = doc.root();
+    Ok(OnvifDeviceInfo {
+        manufacturer: find_text(root, "Manufacturer"),
+        model: find_text(root, "Model"),
+        firmware_version: find_text(root, "FirmwareVersion"),
+    })
+}
+
+/// Extract the video settings of every media profile from a GetProfiles response
+///
+/// Profiles without any resolution information are skipped.
+pub fn parse_profiles(xml: &str) -> Result<Vec<OnvifProfile>, OnvifError> {
+    let doc = parse_document(xml)?;
+    let profiles = doc
+        .descendants()
+        .filter(|n| n.is_element() && n.tag_name().name() == "Profiles")
+        .filter_map(|profile| {
+            let token = profile.attribute("token")?.to_string();
+            let encoder = find(profile, "VideoEncoderConfiguration");
+
+            let (width, height) = encoder
+                .and_then(|e| find(e, "Resolution"))
+                .and_then(|r| {
+                    Some((
+                        find_text(r, "Width")?.parse().ok()?,
+                        find_text(r, "Height")?.parse().ok()?,
+                    ))
+                })
+                .or_else(|| {
+                    let bounds = find(find(profile, "VideoSourceConfiguration")?, "Bounds")?;
+                    Some((
+                        bounds.attribute("width")?.parse().ok()?,
+                        bounds.attribute("height")?.parse().ok()?,
+                    ))
+                })?;
+
+            Some(OnvifProfile {
+                token,
+                name: find_text(profile, "Name"),
+                width,
+                height,
+                encoding: encoder.and_then(|e| find_text(e, "Encoding")),
+                frame_rate: encoder
+                    .and_then(|e| find_text(e, "FrameRateLimit"))
+                    .and_then(|f| f.parse().ok()),
+            })
+        })
+        .collect();
+    Ok(profiles)
+}
+
+/// Extract the normalized zoom position from a PTZ GetStatus response
+pub fn parse_zoom_position(xml: &str) -> Result<Option<f64>, OnvifError> {
+    let doc = parse_document(xml)?;
+    Ok(find(doc.root(), "Position")
+        .and_then(|p| find(p, "Zoom"))
+        .and_then(|z| z.attribute("x"))
+        .and_then(|x| x.parse().ok()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROFILES: &str = r#"<?xml version="1.0"?>
+<s:Envelope xmlns:s="http://www.w3.org/2003/05/soap-envelope"
+    xmlns:trt="http://www.onvif.org/ver10/media/wsdl"
+    xmlns:tt="http://www.onvif.org/ver10/schema">
+  <s:Body>
+    <trt:GetProfilesResponse>
+      <trt:Profiles token="main" fixed="true">
+        <tt:Name>MainStream</tt:Name>
+        <tt:VideoSourceConfiguration token="vsc">
+          <tt:Bounds x="0" y="0" width="3840" height="2160"/>
+        </tt:VideoSourceConfiguration>
+        <tt:VideoEncoderConfiguration token="vec">
+          <tt:Encoding>H264</tt:Encoding>
+          <tt:Resolution><tt:Width>2688</tt:Width><tt:Height>1520</tt:Height></tt:Resolution>
+          <tt:RateControl><tt:FrameRateLimit>25</tt:FrameRateLimit></tt:RateControl>
+        </tt:VideoEncoderConfiguration>
+      </trt:Profiles>
+      <trt:Profiles token="sub">
+        <tt:VideoSourceConfiguration token="vsc">
+          <tt:Bounds x="0" y="0" width="3840" height="2160"/>
+        </tt:VideoSourceConfiguration>
+      </trt:Profiles>
+    </trt:GetProfilesResponse>
+  </s:Body>
+</s:Envelope>"#;
+
+    #[test]
+    fn test_parse_profiles() {
+        let profiles = parse_profiles(PROFILES).unwrap();
+
+        assert_eq!(profiles.len(), 2);
+        assert_eq!(profiles[0].token, "main");
+        assert_eq!(profiles[0].name.as_deref(), Some("MainStream"));
+        assert_eq!((profiles[0].width, profiles[0].height), (2688, 1520));
+        assert_eq!(profiles[0].frame_rate, Some(25.0));
+        // No encoder configuration: fall back to the video source bounds
+        assert_eq!((profiles[1].width, profiles[1].height), (3840, 2160));
+    }
+
+    #[test]
+    fn test_parse_zoom_and_capabilities() {
+        let status = r#"<Envelope><Body><GetStatusResponse><PTZStatus>
+            <Position><PanTilt x="0.1" y="0.2"/><Zoom x="0.25"/></Position>
+        </PTZStatus></GetStatusResponse></Body></Envelope>"#;
+        assert_eq!(parse_zoom_position(status).unwrap(), Some(0.25));
+
+        let caps = r#"<Envelope><Body><GetCapabilitiesResponse><Capabilities>
+            <Device><XAddr>http://10.0.0.5/onvif/device_service</XAddr></Device>
+            <Media><XAddr>http://10.0.0.5/onvif/media_service</XAddr></Media>
+        </Capabilities></GetCapabilitiesResponse></Body></Envelope>"#;
+        let (media, ptz) = parse_capabilities(caps).unwrap();
+        assert_eq!(media, "http://10.0.0.5/onvif/media_service");
+        assert!(ptz.is_none());
+    }
+
+    #[test]
+    fn test_soap_fault() {
+        let fault = r#"<s:Envelope xmlns:s="http://www.w3.org/2003/05/soap-envelope"><s:Body>
+            <s:Fault><s:Reason><s:Text xml:lang="en">Sender not authorized</s:Text></s:Reason></s:Fault>
+        </s:Body></s:Envelope>"#;
+        match parse_device_information(fault) {
+            Err(OnvifError::Fault(reason)) => assert_eq!(reason, "Sender not authorized"),
+            other => panic!("expected fault, got {:?}", other),
+        }
+    }
+}
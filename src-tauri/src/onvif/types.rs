@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::optics::types::{CameraSystem, ValidationWarning};
+
+/// Connection details and lens hints for importing a camera over ONVIF
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct OnvifImportRequest {
+    /// Device address, either `host[:port]` or a full device service URL
+    pub address: String,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    /// Media profile to import; the first profile is used if not given
+    pub profile_token: Option<String>,
+    /// Sensor and lens data the device does not report
+    #[serde(default)]
+    pub hints: OnvifLensHints,
+}
+
+/// Sensor and lens data to fill in what ONVIF does not expose
+///
+/// ONVIF reports the stream resolution and a normalized zoom position, but not
+/// the physical sensor size or focal length.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct OnvifLensHints {
+    pub sensor_width_mm: Option<f64>,
+    pub sensor_height_mm: Option<f64>,
+    /// Focal length at the wide end (zoom position 0)
+    pub focal_length_min_mm: Option<f64>,
+    /// Focal length at the tele end (zoom position 1)
+    pub focal_length_max_mm: Option<f64>,
+}
+
+/// Device identification from GetDeviceInformation
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct OnvifDeviceInfo {
+    pub manufacturer: Option<String>,
+    pub model: Option<String>,
+    pub firmware_version: Option<String>,
+}
+
+/// Video settings of a media profile from GetProfiles
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct OnvifProfile {
+    pub token: String,
+    pub name: Option<String>,
+    /// Encoder resolution, falling back to the video source bounds
+    pub width: u32,
+    pub height: u32,
+    pub encoding: Option<String>,
+    pub frame_rate: Option<f64>,
+}
+
+/// Result of importing a camera from an ONVIF device
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct OnvifImportResult {
+    pub camera: CameraSystem,
+    pub device: OnvifDeviceInfo,
+    pub profile: OnvifProfile,
+    /// Normalized zoom position (0 = wide, 1 = tele), if the device has PTZ
+    pub zoom_position: Option<f64>,
+    /// Notes on values that had to be assumed
+    pub warnings: Vec<ValidationWarning>,
+}
+
+/// Errors that can occur while talking to an ONVIF device
+#[derive(Debug)]
+pub enum OnvifError {
+    /// The device could not be reached
+    Http(String),
+    /// The device returned a SOAP fault
+    Fault(String),
+    /// The response could not be understood
+    Parse(String),
+    /// The device has no media profile (with the requested token)
+    NoProfile,
+}
+
+impl fmt::Display for OnvifError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OnvifError::Http(e) => write!(f, "Could not reach ONVIF device: {}", e),
+            OnvifError::Fault(e) => write!(f, "ONVIF device returned an error: {}", e),
+            OnvifError::Parse(e) => write!(f, "Invalid ONVIF response: {}", e),
+            OnvifError::NoProfile => write!(f, "No matching media profile found on device"),
+        }
+    }
+}
+
+impl std::error::Error for OnvifError {}
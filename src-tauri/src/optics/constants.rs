@@ -1,4 +0,0 @@
-pub(super) const DETECTION_PX_PER_M: f64 = 25.0;
-pub(super) const OBSERVATION_PX_PER_M: f64 = 62.5;
-pub(super) const RECOGNITION_PX_PER_M: f64 = 125.0;
-pub(super) const IDENTIFICATION_PX_PER_M: f64 = 250.0;
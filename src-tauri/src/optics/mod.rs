@@ -1,6 +0,0 @@
-pub mod calculations;
-mod constants;
-pub mod types;
-
-pub use calculations::*;
-pub use types::*;
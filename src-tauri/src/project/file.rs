@@ -0,0 +1,109 @@
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+
+use super::migrate::migrate_project;
+use super::types::{Project, ProjectError, PROJECT_SCHEMA_VERSION};
+
+/// Parse a project from JSON, migrating older schema versions
+pub fn project_from_json(json: &str) -> Result<Project, ProjectError> {
+    let doc: Value = serde_json::from_str(json)?;
+    let doc = migrate_project(doc)?;
+    Ok(serde_json::from_value(doc)?)
+}
+
+/// Serialize a project to pretty-printed JSON at the current schema version
+pub fn project_to_json(project: &Project) -> Result<String, ProjectError> {
+    let mut project = project.clone();
+    project.schema_version = PROJECT_SCHEMA_VERSION;
+    Ok(serde_json::to_string_pretty(&project)?)
+}
+
+/// Save a project to a JSON file
+pub fn write_project_file(project: &Project, path: &Path) -> Result<(), ProjectError> {
+    fs::write(path, project_to_json(project)?)?;
+    Ok(())
+}
+
+/// Load a project from a JSON file
+pub fn read_project_file(path: &Path) -> Result<Project, ProjectError> {
+    project_from_json(&fs::read_to_string(path)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optics::types::CameraSystem;
+    use crate::project::types::{CameraPose, ProjectCamera};
+
+    #[test]
+    fn test_round_trip() {
+        let mut project = Project::new("Car park");
+        project.cameras.push(ProjectCamera {
+            camera: CameraSystem::new(6.4, 4.8, 1920, 1080, 4.0)
+                .with_name("Entrance")
+                .with_read_noise(2.5)
+                .with_full_well(12_000.0)
+                .with_dark_current(0.5),
+            pose: Some(CameraPose {
+                x_m: 1.0,
+                y_m: 2.0,
+                height_m: 4.5,
+                pan_deg: 90.0,
+                tilt_deg: -15.0,
+            }),
+            target_distance_m: Some(25.0),
+        });
+
+        let json = project_to_json(&project).unwrap();
+        let loaded = project_from_json(&json).unwrap();
+
+        assert_eq!(loaded.name, "Car park");
+        assert_eq!(loaded.schema_version, PROJECT_SCHEMA_VERSION);
+        assert_eq!(loaded.cameras.len(), 1);
+        assert_eq!(loaded.cameras[0].camera.name.as_deref(), Some("Entrance"));
+        assert_eq!(loaded.cameras[0].pose.as_ref().unwrap().height_m, 4.5);
+        assert_eq!(loaded.cameras[0].camera.full_well_e, Some(12_000.0));
+        assert_eq!(loaded.cameras[0].camera.dark_current_e_s, Some(0.5));
+    }
+
+    #[test]
+    fn test_unversioned_file_migrates() {
+        let json = r#"{
+            "cameras": [{
+                "sensor_width_mm": 6.4,
+                "sensor_height_mm": 4.8,
+                "pixel_width": 1920,
+                "pixel_height": 1080,
+                "focal_length_mm": 4.0
+            }]
+        }"#;
+        let project = project_from_json(json).unwrap();
+
+        assert_eq!(project.schema_version, PROJECT_SCHEMA_VERSION);
+        assert_eq!(project.name, "Untitled");
+        assert_eq!(project.cameras[0].camera.pixel_width, 1920);
+        assert!(project.cameras[0].pose.is_none());
+        assert!(project.cameras[0].camera.read_noise_e.is_none());
+        assert_eq!(project.settings.default_distance_m, 10.0);
+    }
+
+    #[test]
+    fn test_newer_version_rejected() {
+        let json = r#"{ "schema_version": 99, "name": "Future" }"#;
+        assert!(matches!(
+            project_from_json(json),
+            Err(ProjectError::UnsupportedVersion { found: 99, .. })
+        ));
+    }
+
+    #[test]
+    fn test_save_and_load_file() {
+        let path = std::env::temp_dir().join("camera_optics_project_test.json");
+        write_project_file(&Project::new("Site"), &path).unwrap();
+        let loaded = read_project_file(&path).unwrap();
+        fs::remove_file(&path).ok();
+
+        assert_eq!(loaded.name, "Site");
+    }
+}
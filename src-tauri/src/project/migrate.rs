@@ -0,0 +1,115 @@
+use serde_json::Value;
+
+use super::types::{ProjectError, PRESETS_SCHEMA_VERSION, PROJECT_SCHEMA_VERSION};
+
+/// Upgrade of a raw document from one schema version to the next
+type MigrationStep = fn(&mut Value);
+
+/// Project upgrades; the step at index `n` upgrades version `n` to `n + 1`
+const PROJECT_MIGRATIONS: &[MigrationStep] = &[project_v0_to_v1];
+
+/// Preset file upgrades; the step at index `n` upgrades version `n` to `n + 1`
+const PRESETS_MIGRATIONS: &[MigrationStep] = &[presets_v0_to_v1];
+
+/// Schema version of a raw document
+///
+/// Files written before versioning was introduced have no `schema_version`
+/// and are treated as version 0.
+pub fn schema_version(doc: &Value) -> u32 {
+    doc.get("schema_version")
+        .and_then(Value::as_u64)
+        .unwrap_or(0) as u32
+}
+
+/// Run the steps from the document's version up to `current`
+fn migrate(mut doc: Value, current: u32, steps: &[MigrationStep]) -> Result<Value, ProjectError> {
+    let version = schema_version(&doc);
+    if version > current {
+        return Err(ProjectError::UnsupportedVersion {
+            found: version,
+            supported: current,
+        });
+    }
+
+    for step in &steps[version as usize..current as usize] {
+        step(&mut doc);
+    }
+
+    // Anything other than an object is left for deserialization to reject
+    if let Some(fields) = doc.as_object_mut() {
+        fields.insert("schema_version".to_string(), Value::from(current));
+    }
+    Ok(doc)
+}
+
+/// Bring a raw project document up to the current schema version
+///
+/// # Returns
+/// The migrated document, or an error if it comes from a newer schema
+pub fn migrate_project(doc: Value) -> Result<Value, ProjectError> {
+    migrate(doc, PROJECT_SCHEMA_VERSION, PROJECT_MIGRATIONS)
+}
+
+/// Bring a raw preset file up to the current schema version
+///
+/// # Returns
+/// The migrated document, or an error if it comes from a newer schema
+pub fn migrate_presets(doc: Value) -> Result<Value, ProjectError> {
+    migrate(doc, PRESETS_SCHEMA_VERSION, PRESETS_MIGRATIONS)
+}
+
+/// Unversioned projects may list bare camera systems and have no name
+fn project_v0_to_v1(doc: &mut Value) {
+    let Some(fields) = doc.as_object_mut() else {
+        return;
+    };
+    if let Some(cameras) = fields.get_mut("cameras").and_then(Value::as_array_mut) {
+        for camera in cameras.iter_mut() {
+            if camera.get("camera").is_none() {
+                *camera = serde_json::json!({ "camera": camera.take() });
+            }
+        }
+    }
+    fields
+        .entry("name")
+        .or_insert_with(|| Value::from("Untitled"));
+}
+
+/// Unversioned preset files are a bare array of presets
+fn presets_v0_to_v1(doc: &mut Value) {
+    if doc.is_array() {
+        *doc = serde_json::json!({ "presets": doc.take() });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_one_step_per_version() {
+        assert_eq!(PROJECT_MIGRATIONS.len(), PROJECT_SCHEMA_VERSION as usize);
+        assert_eq!(PRESETS_MIGRATIONS.len(), PRESETS_SCHEMA_VERSION as usize);
+    }
+
+    #[test]
+    fn test_bare_preset_array_migrates() {
+        let doc = json!([{ "name": "Wide", "camera": {} }]);
+        let doc = migrate_presets(doc).unwrap();
+
+        assert_eq!(schema_version(&doc), PRESETS_SCHEMA_VERSION);
+        assert_eq!(doc["presets"][0]["name"], "Wide");
+    }
+
+    #[test]
+    fn test_current_version_is_unchanged() {
+        let doc = json!({ "schema_version": PRESETS_SCHEMA_VERSION, "presets": [] });
+        assert_eq!(migrate_presets(doc.clone()).unwrap(), doc);
+    }
+
+    #[test]
+    fn test_non_object_is_left_for_parsing() {
+        assert_eq!(migrate_project(json!(42)).unwrap(), json!(42));
+    }
+}
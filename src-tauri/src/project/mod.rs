@@ -0,0 +1,11 @@
+pub mod file;
+pub mod migrate;
+pub mod presets;
+pub mod state;
+pub mod types;
+
+pub use file::*;
+pub use migrate::*;
+pub use presets::*;
+pub use state::*;
+pub use types::*;
@@ -0,0 +1,116 @@
+use std::fs;
+use std::path::Path;
+
+use serde_json::Value;
+
+use super::migrate::migrate_presets;
+use super::types::{CameraPreset, PresetFile, ProjectError, PRESETS_SCHEMA_VERSION};
+
+/// File name of the preset store inside the app config directory
+pub const PRESETS_FILE_NAME: &str = "camera_presets.json";
+
+/// Read all presets from a preset file, migrating older schema versions
+///
+/// # Returns
+/// The stored presets, or an empty list if the file does not exist yet
+pub fn read_presets(path: &Path) -> Result<Vec<CameraPreset>, ProjectError> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let doc: Value = serde_json::from_str(&fs::read_to_string(path)?)?;
+    let file: PresetFile = serde_json::from_value(migrate_presets(doc)?)?;
+    Ok(file.presets)
+}
+
+fn write_presets(path: &Path, presets: &[CameraPreset]) -> Result<(), ProjectError> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let file = PresetFile {
+        schema_version: PRESETS_SCHEMA_VERSION,
+        presets: presets.to_vec(),
+    };
+    fs::write(path, serde_json::to_string_pretty(&file)?)?;
+    Ok(())
+}
+
+/// Add a preset, replacing any existing preset with the same name
+///
+/// # Returns
+/// The updated list of presets, sorted by name
+pub fn store_preset(path: &Path, preset: CameraPreset) -> Result<Vec<CameraPreset>, ProjectError> {
+    let mut presets = read_presets(path)?;
+    presets.retain(|p| p.name != preset.name);
+    presets.push(preset);
+    presets.sort_by(|a, b| a.name.cmp(&b.name));
+    write_presets(path, &presets)?;
+    Ok(presets)
+}
+
+/// Remove the preset with the given name
+///
+/// # Returns
+/// The updated list of presets
+pub fn remove_preset(path: &Path, name: &str) -> Result<Vec<CameraPreset>, ProjectError> {
+    let mut presets = read_presets(path)?;
+    presets.retain(|p| p.name != name);
+    write_presets(path, &presets)?;
+    Ok(presets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optics::types::CameraSystem;
+
+    fn preset(name: &str, focal_length_mm: f64) -> CameraPreset {
+        CameraPreset {
+            name: name.to_string(),
+            camera: CameraSystem::new(6.4, 4.8, 1920, 1080, focal_length_mm),
+        }
+    }
+
+    #[test]
+    fn test_store_replace_and_remove() {
+        let path = std::env::temp_dir()
+            .join("camera_optics_presets_test")
+            .join(PRESETS_FILE_NAME);
+        fs::remove_file(&path).ok();
+
+        assert!(read_presets(&path).unwrap().is_empty());
+
+        store_preset(&path, preset("Wide", 2.8)).unwrap();
+        store_preset(&path, preset("Tele", 12.0)).unwrap();
+        let presets = store_preset(&path, preset("Wide", 4.0)).unwrap();
+
+        assert_eq!(presets.len(), 2);
+        assert_eq!(presets[0].name, "Tele");
+        assert_eq!(presets[1].camera.focal_length_mm, 4.0);
+
+        let presets = remove_preset(&path, "Tele").unwrap();
+        assert_eq!(presets.len(), 1);
+        assert_eq!(read_presets(&path).unwrap().len(), 1);
+
+        fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_legacy_preset_file_is_upgraded() {
+        let path = std::env::temp_dir()
+            .join("camera_optics_legacy_presets_test")
+            .join(PRESETS_FILE_NAME);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let legacy = serde_json::to_string(&[preset("Wide", 2.8)]).unwrap();
+        fs::write(&path, legacy).unwrap();
+
+        assert_eq!(read_presets(&path).unwrap()[0].name, "Wide");
+
+        // The next write stores the versioned form
+        store_preset(&path, preset("Tele", 12.0)).unwrap();
+        let doc: Value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        fs::remove_file(&path).ok();
+
+        assert_eq!(doc["schema_version"], PRESETS_SCHEMA_VERSION);
+        assert_eq!(doc["presets"].as_array().unwrap().len(), 2);
+    }
+}
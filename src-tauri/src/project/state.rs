@@ -0,0 +1,251 @@
+use std::sync::{Mutex, MutexGuard};
+
+use crate::optics::calculations::{calculate_fov, compare_cameras};
+use crate::optics::coverage::evaluate_zone_coverage;
+use crate::optics::types::{CameraWithResult, PlacedCamera, Scene};
+
+use super::types::{Project, ProjectCamera, ProjectError, ProjectResults};
+
+/// Name of the event that carries [`ProjectResults`] after the project changes
+pub const PROJECT_UPDATED_EVENT: &str = "project-updated";
+
+/// The open project, kept in Tauri managed state
+///
+/// The backend holds the one copy of the camera list; the frontend edits it through
+/// commands and renders what they return, so saving, undo and reports all see the
+/// same cameras. Cameras are addressed by their index in the list.
+#[derive(Debug)]
+pub struct ProjectState {
+    project: Mutex<Project>,
+}
+
+impl Default for ProjectState {
+    fn default() -> Self {
+        Self {
+            project: Mutex::new(Project::new("Untitled")),
+        }
+    }
+}
+
+impl ProjectState {
+    /// Copy of the whole project, e.g. for saving
+    pub fn project(&self) -> Project {
+        self.lock().clone()
+    }
+
+    /// Replace the open project, e.g. after loading a file
+    pub fn replace(&self, project: Project) {
+        *self.lock() = project;
+    }
+
+    /// All cameras in the project
+    pub fn list_cameras(&self) -> Vec<ProjectCamera> {
+        self.lock().cameras.clone()
+    }
+
+    /// Append a camera
+    ///
+    /// # Returns
+    /// The updated camera list
+    pub fn add_camera(&self, camera: ProjectCamera) -> Vec<ProjectCamera> {
+        let mut project = self.lock();
+        project.cameras.push(camera);
+        project.cameras.clone()
+    }
+
+    /// Replace the camera at `index`
+    ///
+    /// # Returns
+    /// The updated camera list, or an error if there is no camera at `index`
+    pub fn update_camera(
+        &self,
+        index: usize,
+        camera: ProjectCamera,
+    ) -> Result<Vec<ProjectCamera>, ProjectError> {
+        let mut project = self.lock();
+        let slot = project
+            .cameras
+            .get_mut(index)
+            .ok_or(ProjectError::NoSuchCamera(index))?;
+        *slot = camera;
+        Ok(project.cameras.clone())
+    }
+
+    /// Remove the camera at `index`
+    ///
+    /// # Returns
+    /// The updated camera list, or an error if there is no camera at `index`
+    pub fn remove_camera(&self, index: usize) -> Result<Vec<ProjectCamera>, ProjectError> {
+        let mut project = self.lock();
+        if index >= project.cameras.len() {
+            return Err(ProjectError::NoSuchCamera(index));
+        }
+        project.cameras.remove(index);
+        Ok(project.cameras.clone())
+    }
+
+    /// Replace the site plan, or remove it with `None`
+    pub fn set_scene(&self, scene: Option<Scene>) {
+        self.lock().scene = scene;
+    }
+
+    /// Camera results plus the zone coverage of the cameras placed on the scene
+    pub fn results(&self) -> ProjectResults {
+        let project = self.project();
+        let coverage = match &project.scene {
+            Some(scene) => {
+                let placed: Vec<PlacedCamera> = project
+                    .cameras
+                    .iter()
+                    .filter_map(|entry| {
+                        Some(PlacedCamera {
+                            camera: entry.camera.clone(),
+                            pose: entry.pose.clone()?,
+                        })
+                    })
+                    .collect();
+                evaluate_zone_coverage(scene, &placed, None)
+            }
+            None => Vec::new(),
+        };
+        ProjectResults {
+            cameras: self.recalculate_all(),
+            coverage,
+        }
+    }
+
+    /// FOV, DORI and rankings for every camera in the project
+    ///
+    /// Each camera is calculated at its own target distance, or at the project's
+    /// default distance when it has none. Low-light ranks compare all cameras.
+    pub fn recalculate_all(&self) -> Vec<CameraWithResult> {
+        let project = self.project();
+        let default_distance_mm = project.settings.default_distance_m * 1000.0;
+        let cameras = project.cameras.iter().map(|c| c.camera.clone()).collect();
+
+        let mut results = compare_cameras(cameras, default_distance_mm);
+        for (result, entry) in results.iter_mut().zip(&project.cameras) {
+            if let Some(distance_m) = entry.target_distance_m {
+                result.result = calculate_fov(&result.camera, distance_m * 1000.0);
+                if let Some(night) = &mut result.night {
+                    night.result = calculate_fov(&night.camera, distance_m * 1000.0);
+                }
+            }
+        }
+        results
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Project> {
+        // Every edit replaces whole values, so a poisoned lock still holds a valid project
+        self.project
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optics::types::CameraSystem;
+
+    fn entry(focal_length_mm: f64, target_distance_m: Option<f64>) -> ProjectCamera {
+        ProjectCamera {
+            camera: CameraSystem::new(6.4, 4.8, 1920, 1080, focal_length_mm),
+            pose: None,
+            target_distance_m,
+        }
+    }
+
+    #[test]
+    fn test_camera_crud() {
+        let state = ProjectState::default();
+        assert!(state.list_cameras().is_empty());
+
+        state.add_camera(entry(4.0, None));
+        let cameras = state.add_camera(entry(8.0, None));
+        assert_eq!(cameras.len(), 2);
+
+        let cameras = state.update_camera(1, entry(12.0, None)).unwrap();
+        assert_eq!(cameras[1].camera.focal_length_mm, 12.0);
+        assert!(matches!(
+            state.update_camera(2, entry(6.0, None)),
+            Err(ProjectError::NoSuchCamera(2))
+        ));
+
+        let cameras = state.remove_camera(0).unwrap();
+        assert_eq!(cameras.len(), 1);
+        assert_eq!(state.project().cameras[0].camera.focal_length_mm, 12.0);
+        assert!(state.remove_camera(1).is_err());
+    }
+
+    #[test]
+    fn test_recalculate_all_uses_camera_distances() {
+        let state = ProjectState::default();
+        state.add_camera(entry(4.0, None));
+        state.add_camera(entry(4.0, Some(20.0)));
+
+        let results = state.recalculate_all();
+        assert_eq!(results.len(), 2);
+        // The project default is 10 m
+        assert!((results[0].result.distance_m - 10.0).abs() < 1e-9);
+        assert!((results[1].result.distance_m - 20.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_results_cover_scene_with_placed_cameras() {
+        use crate::optics::types::{
+            CameraPose, CoverageZone, DoriLevel, PlanPoint, ZoneRequirement,
+        };
+
+        let state = ProjectState::default();
+        state.add_camera(entry(4.0, None));
+        assert!(state.results().coverage.is_empty());
+
+        let square = |size: f64| {
+            vec![
+                PlanPoint {
+                    x_m: -size,
+                    y_m: 0.0,
+                },
+                PlanPoint {
+                    x_m: size,
+                    y_m: 0.0,
+                },
+                PlanPoint {
+                    x_m: size,
+                    y_m: 2.0 * size,
+                },
+                PlanPoint {
+                    x_m: -size,
+                    y_m: 2.0 * size,
+                },
+            ]
+        };
+        let mut room = square(20.0);
+        room.iter_mut().for_each(|p| p.y_m -= 20.0);
+        state.set_scene(Some(Scene {
+            room,
+            zones: vec![CoverageZone {
+                name: "Entrance".to_string(),
+                polygon: square(1.0),
+                requirement: ZoneRequirement::Dori(DoriLevel::Detection),
+            }],
+            obstacles: Vec::new(),
+        }));
+        // Not placed yet: the zone is not covered
+        assert_eq!(state.results().coverage[0].covered_percent, 0.0);
+
+        let mut placed = entry(4.0, None);
+        placed.pose = Some(CameraPose {
+            x_m: 0.0,
+            y_m: -1.0,
+            height_m: 3.0,
+            pan_deg: 0.0,
+            tilt_deg: -10.0,
+        });
+        state.update_camera(0, placed).unwrap();
+        let results = state.results();
+        assert_eq!(results.cameras.len(), 1);
+        assert_eq!(results.coverage[0].covered_percent, 100.0);
+    }
+}
@@ -0,0 +1,149 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+pub use crate::optics::types::CameraPose;
+use crate::optics::types::{
+    AtmosphericConditions, CameraSystem, CameraWithResult, Scene, ZoneCoverage,
+};
+
+/// Current version of the project file schema
+///
+/// Bump this when a change to the project types cannot be expressed with serde
+/// defaults alone, and add a matching step to `PROJECT_MIGRATIONS`.
+pub const PROJECT_SCHEMA_VERSION: u32 = 1;
+
+/// Current version of the preset file schema
+///
+/// Version 0 files are a bare array of presets; see `PRESETS_MIGRATIONS`.
+pub const PRESETS_SCHEMA_VERSION: u32 = 1;
+
+/// A camera as stored in a project
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct ProjectCamera {
+    pub camera: CameraSystem,
+    /// Position and orientation on site, if placed
+    #[serde(default)]
+    pub pose: Option<CameraPose>,
+    /// Distance to the area of interest in metres
+    #[serde(default)]
+    pub target_distance_m: Option<f64>,
+}
+
+/// Project-wide calculation settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct ProjectSettings {
+    /// Default calculation distance in metres for cameras without their own
+    pub default_distance_m: f64,
+    /// Atmospheric conditions used for derated DORI distances
+    #[serde(default)]
+    pub conditions: Option<AtmosphericConditions>,
+}
+
+impl Default for ProjectSettings {
+    fn default() -> Self {
+        Self {
+            default_distance_m: 10.0,
+            conditions: None,
+        }
+    }
+}
+
+/// A saved camera design project
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct Project {
+    /// Schema version the file was written with
+    pub schema_version: u32,
+    pub name: String,
+    #[serde(default)]
+    pub cameras: Vec<ProjectCamera>,
+    #[serde(default)]
+    pub settings: ProjectSettings,
+    /// Site plan with coverage zones, if one has been drawn
+    #[serde(default)]
+    pub scene: Option<Scene>,
+}
+
+impl Project {
+    /// Create an empty project at the current schema version
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            schema_version: PROJECT_SCHEMA_VERSION,
+            name: name.into(),
+            cameras: Vec::new(),
+            settings: ProjectSettings::default(),
+            scene: None,
+        }
+    }
+}
+
+/// Calculated results for the whole open project, pushed to the frontend on change
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub struct ProjectResults {
+    /// FOV, DORI and rankings, in the order of the project's cameras
+    pub cameras: Vec<CameraWithResult>,
+    /// Zone coverage of the placed cameras; empty without a scene
+    pub coverage: Vec<ZoneCoverage>,
+}
+
+/// A user-defined camera model saved for reuse across sessions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub struct CameraPreset {
+    /// Unique preset name
+    pub name: String,
+    pub camera: CameraSystem,
+}
+
+/// The preset store as saved in the app config directory
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PresetFile {
+    /// Schema version the file was written with
+    pub schema_version: u32,
+    pub presets: Vec<CameraPreset>,
+}
+
+/// Errors that can occur while saving or loading project and preset files
+#[derive(Debug)]
+pub enum ProjectError {
+    /// The file could not be read or written
+    Io(std::io::Error),
+    /// The file is not a valid project
+    Parse(serde_json::Error),
+    /// The file was written by a newer version of the application
+    UnsupportedVersion { found: u32, supported: u32 },
+    /// There is no camera at this index in the project
+    NoSuchCamera(usize),
+}
+
+impl fmt::Display for ProjectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProjectError::Io(e) => write!(f, "File error: {}", e),
+            ProjectError::Parse(e) => write!(f, "Invalid file contents: {}", e),
+            ProjectError::UnsupportedVersion { found, supported } => write!(
+                f,
+                "File schema version {} is newer than supported version {}",
+                found, supported
+            ),
+            ProjectError::NoSuchCamera(i) => write!(f, "No camera at index {} in the project", i),
+        }
+    }
+}
+
+impl std::error::Error for ProjectError {}
+
+impl From<std::io::Error> for ProjectError {
+    fn from(e: std::io::Error) -> Self {
+        ProjectError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for ProjectError {
+    fn from(e: serde_json::Error) -> Self {
+        ProjectError::Parse(e)
+    }
+}
@@ -0,0 +1,60 @@
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+/// Name of the event that carries [`super::ComputationProgress`] updates
+pub const PROGRESS_EVENT: &str = "computation-progress";
+
+/// Error returned by a computation that was cancelled
+pub const CANCELLED_ERROR: &str = "Computation cancelled";
+
+/// Ids of running computations the frontend asked to cancel
+///
+/// Long-running commands check their id between progress steps and stop once it
+/// is cancelled. Ids are cleared when the computation finishes, so a cancel that
+/// arrives too late does not affect a later run with the same id.
+#[derive(Debug, Default)]
+pub struct CancellationRegistry {
+    cancelled: Mutex<HashSet<String>>,
+}
+
+impl CancellationRegistry {
+    /// Ask the computation with this id to stop
+    pub fn cancel(&self, task_id: &str) {
+        self.lock().insert(task_id.to_string());
+    }
+
+    /// Whether the computation with this id was asked to stop
+    pub fn is_cancelled(&self, task_id: &str) -> bool {
+        self.lock().contains(task_id)
+    }
+
+    /// Forget a finished or cancelled computation
+    pub fn finish(&self, task_id: &str) {
+        self.lock().remove(task_id);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashSet<String>> {
+        // A panic while holding the lock cannot leave the set inconsistent
+        self.cancelled
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cancel_until_finished() {
+        let registry = CancellationRegistry::default();
+        assert!(!registry.is_cancelled("placement"));
+
+        registry.cancel("placement");
+        assert!(registry.is_cancelled("placement"));
+        assert!(!registry.is_cancelled("tolerances"));
+
+        registry.finish("placement");
+        assert!(!registry.is_cancelled("placement"));
+    }
+}
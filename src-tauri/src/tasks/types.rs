@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// Progress of a long-running computation, sent to the frontend as an event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub struct ComputationProgress {
+    /// Id the frontend gave the computation when starting it
+    pub task_id: String,
+    /// Steps done so far
+    pub done: usize,
+    /// Total number of steps
+    pub total: usize,
+}
@@ -0,0 +1,113 @@
+use super::types::{JohnsonDriDistances, NatoTarget, ThermalCameraSystem, ThermalDriResult};
+
+/// Johnson criteria expressed as pixels across the target's critical dimension
+/// (one cycle = two pixels)
+pub(super) const DETECTION_PX: f64 = 1.5;
+const RECOGNITION_PX: f64 = 6.0;
+const IDENTIFICATION_PX: f64 = 12.0;
+
+/// Calculate thermal DRI ranges for a NATO target using the Johnson criteria
+///
+/// # Formula
+/// Range = (critical_dimension × focal_length) / (required_pixels × detector_pitch)
+///
+/// The ranges are geometric: they assume sufficient thermal contrast between target
+/// and background. NETD is carried on the camera for comparison but does not shorten
+/// the ranges.
+///
+/// # Arguments
+/// * `camera` - The thermal camera specification
+/// * `target` - NATO target to evaluate
+///
+/// # Returns
+/// FOV, IFOV and DRI ranges for the target
+pub fn calculate_thermal_dri(camera: &ThermalCameraSystem, target: NatoTarget) -> ThermalDriResult {
+    let (detector_width_mm, detector_height_mm) = camera.detector_size_mm();
+
+    let horizontal_fov_deg = 2.0
+        * (detector_width_mm / (2.0 * camera.focal_length_mm))
+            .atan()
+            .to_degrees();
+    let vertical_fov_deg = 2.0
+        * (detector_height_mm / (2.0 * camera.focal_length_mm))
+            .atan()
+            .to_degrees();
+
+    // IFOV in mrad: pitch (µm) / focal (mm)
+    let ifov_mrad = camera.detector_pitch_um / camera.focal_length_mm;
+
+    let critical_dimension_m = target.critical_dimension_m();
+    let range_for = |required_px: f64| {
+        (critical_dimension_m * camera.focal_length_mm * 1000.0)
+            / (required_px * camera.detector_pitch_um)
+    };
+
+    ThermalDriResult {
+        target,
+        critical_dimension_m,
+        horizontal_fov_deg,
+        vertical_fov_deg,
+        ifov_mrad,
+        dri: JohnsonDriDistances {
+            detection_m: range_for(DETECTION_PX),
+            recognition_m: range_for(RECOGNITION_PX),
+            identification_m: range_for(IDENTIFICATION_PX),
+        },
+    }
+}
+
+/// Calculate thermal DRI ranges for all standard NATO targets
+pub fn calculate_thermal_dri_all(camera: &ThermalCameraSystem) -> Vec<ThermalDriResult> {
+    [NatoTarget::Human, NatoTarget::Vehicle]
+        .into_iter()
+        .map(|target| calculate_thermal_dri(camera, target))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_thermal_dri_human() {
+        // 384x288, 17 µm, 25 mm lens
+        let camera = ThermalCameraSystem::new(17.0, 384, 288, 25.0, 50.0);
+        let result = calculate_thermal_dri(&camera, NatoTarget::Human);
+
+        // Detection: 0.75 m × 25 mm / (1.5 × 17 µm) ≈ 735 m
+        assert!((result.dri.detection_m - 735.3).abs() < 0.5);
+        // Identification needs 8x the pixels of detection
+        assert!((result.dri.detection_m / result.dri.identification_m - 8.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_thermal_dri_vehicle_further_than_human() {
+        let camera = ThermalCameraSystem::new(12.0, 640, 512, 19.0, 40.0);
+        let human = calculate_thermal_dri(&camera, NatoTarget::Human);
+        let vehicle = calculate_thermal_dri(&camera, NatoTarget::Vehicle);
+
+        assert!(vehicle.dri.detection_m > human.dri.detection_m);
+        assert!((vehicle.dri.detection_m / human.dri.detection_m - 2.3 / 0.75).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_thermal_fov_and_ifov() {
+        // 640 × 17 µm = 10.88 mm detector behind a 35 mm lens
+        let camera = ThermalCameraSystem::new(17.0, 640, 480, 35.0, 50.0);
+        let result = calculate_thermal_dri(&camera, NatoTarget::Human);
+
+        let expected_hfov = 2.0 * (10.88_f64 / 70.0).atan().to_degrees();
+        assert!((result.horizontal_fov_deg - expected_hfov).abs() < 1e-9);
+        assert!((result.ifov_mrad - 17.0 / 35.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_thermal_dri_all_targets() {
+        let camera = ThermalCameraSystem::new(17.0, 384, 288, 25.0, 50.0);
+        let results = calculate_thermal_dri_all(&camera);
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].target, NatoTarget::Human);
+        assert_eq!(results[1].target, NatoTarget::Vehicle);
+    }
+}
@@ -0,0 +1,7 @@
+pub mod combined;
+pub mod dri;
+pub mod types;
+
+pub use combined::*;
+pub use dri::*;
+pub use types::*;
@@ -0,0 +1,204 @@
+use serde::{Deserialize, Serialize};
+
+use crate::optics::types::{CameraPose, CameraSystem, DoriDistances, PlanPoint};
+
+/// Represents a thermal (LWIR/MWIR) camera with a microbolometer or cooled detector
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct ThermalCameraSystem {
+    /// Detector pixel pitch in micrometers (typically 12, 17 or 25 µm)
+    pub detector_pitch_um: f64,
+    /// Horizontal detector pixel count
+    pub pixel_width: u32,
+    /// Vertical detector pixel count
+    pub pixel_height: u32,
+    /// Lens focal length in millimeters
+    pub focal_length_mm: f64,
+    /// Noise equivalent temperature difference in millikelvin
+    pub netd_mk: f64,
+    /// Optional name for identification
+    pub name: Option<String>,
+}
+
+/// Standard NATO target sizes used for thermal DRI ranges
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub enum NatoTarget {
+    /// Standing person, 1.8 × 0.5 m
+    Human,
+    /// Vehicle, 2.3 × 2.3 m
+    Vehicle,
+}
+
+/// DRI (Detection, Recognition, Identification) ranges according to the Johnson criteria
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub struct JohnsonDriDistances {
+    /// Detection range: an object is present (1.5 px across the critical dimension)
+    pub detection_m: f64,
+    /// Recognition range: the class of object can be determined (6 px)
+    pub recognition_m: f64,
+    /// Identification range: the object can be described in detail (12 px)
+    pub identification_m: f64,
+}
+
+/// Results of thermal DRI calculations for a single NATO target
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub struct ThermalDriResult {
+    /// Target the ranges were calculated for
+    pub target: NatoTarget,
+    /// Critical dimension of the target in meters
+    pub critical_dimension_m: f64,
+    /// Horizontal field of view in degrees
+    pub horizontal_fov_deg: f64,
+    /// Vertical field of view in degrees
+    pub vertical_fov_deg: f64,
+    /// Instantaneous field of view of a single detector pixel in milliradians
+    pub ifov_mrad: f64,
+    /// DRI ranges for this target
+    pub dri: JohnsonDriDistances,
+}
+
+/// Visible and thermal cameras installed together and evaluated as one system
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct DualSensorSystem {
+    pub visible: CameraSystem,
+    pub thermal: ThermalCameraSystem,
+    /// Shared mounting pose; enables the coverage footprints
+    pub pose: Option<CameraPose>,
+}
+
+/// Imaging channel of a dual-sensor system
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub enum SensorChannel {
+    Visible,
+    Thermal,
+}
+
+/// Detection, recognition and identification task levels shared by DORI and DRI
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub enum DriLevel {
+    Detection,
+    Recognition,
+    Identification,
+}
+
+/// Reach of both channels of a dual-sensor system for one task level
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub struct CombinedRange {
+    pub level: DriLevel,
+    /// Visible channel range (DORI pixel density) in meters
+    pub visible_m: f64,
+    /// Thermal channel range (Johnson criteria) in meters
+    pub thermal_m: f64,
+    /// Range up to which both channels perform the task, in meters
+    pub both_m: f64,
+    /// Range up to which at least one channel performs the task, in meters
+    pub combined_m: f64,
+    /// Channel with the shorter reach; beyond `both_m` only the other one performs the task
+    pub limited_by: SensorChannel,
+}
+
+/// Combined DRI/DORI summary and coverage footprints of a dual-sensor system
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct DualSensorEvaluation {
+    /// Target the thermal ranges were calculated for
+    pub target: NatoTarget,
+    pub visible_dori: DoriDistances,
+    pub thermal_dri: JohnsonDriDistances,
+    /// Detection, recognition and identification reach of the combined system
+    pub ranges: Vec<CombinedRange>,
+    /// Plan outline of the visible detection coverage (only available with a pose)
+    pub visible_footprint: Option<Vec<PlanPoint>>,
+    /// Plan outline of the thermal detection coverage (only available with a pose)
+    pub thermal_footprint: Option<Vec<PlanPoint>>,
+}
+
+impl ThermalCameraSystem {
+    /// Create a new thermal camera system
+    pub fn new(
+        detector_pitch_um: f64,
+        pixel_width: u32,
+        pixel_height: u32,
+        focal_length_mm: f64,
+        netd_mk: f64,
+    ) -> Self {
+        Self {
+            detector_pitch_um,
+            pixel_width,
+            pixel_height,
+            focal_length_mm,
+            netd_mk,
+            name: None,
+        }
+    }
+
+    /// Set a name for this thermal camera system
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// The thermal camera as a generic camera system, for the visible-light geometry functions
+    pub fn as_camera_system(&self) -> CameraSystem {
+        let (width_mm, height_mm) = self.detector_size_mm();
+        let camera = CameraSystem::new(
+            width_mm,
+            height_mm,
+            self.pixel_width,
+            self.pixel_height,
+            self.focal_length_mm,
+        );
+        match &self.name {
+            Some(name) => camera.with_name(name.clone()),
+            None => camera,
+        }
+    }
+
+    /// Get detector dimensions in millimeters (width, height)
+    pub fn detector_size_mm(&self) -> (f64, f64) {
+        let width = self.pixel_width as f64 * self.detector_pitch_um / 1000.0;
+        let height = self.pixel_height as f64 * self.detector_pitch_um / 1000.0;
+        (width, height)
+    }
+}
+
+impl NatoTarget {
+    /// Target size in meters (width, height)
+    pub fn size_m(&self) -> (f64, f64) {
+        match self {
+            NatoTarget::Human => (0.5, 1.8),
+            NatoTarget::Vehicle => (2.3, 2.3),
+        }
+    }
+
+    /// Critical dimension in meters used for the Johnson criteria
+    pub fn critical_dimension_m(&self) -> f64 {
+        match self {
+            NatoTarget::Human => 0.75,
+            NatoTarget::Vehicle => 2.3,
+        }
+    }
+}
+
+impl std::fmt::Display for ThermalCameraSystem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = self.name.as_deref().unwrap_or("Unnamed");
+        write!(
+            f,
+            "{}: {}x{} px thermal ({} µm pitch, {} mK NETD), {} mm lens",
+            name,
+            self.pixel_width,
+            self.pixel_height,
+            self.detector_pitch_um,
+            self.netd_mk,
+            self.focal_length_mm
+        )
+    }
+}
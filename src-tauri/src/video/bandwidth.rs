@@ -0,0 +1,193 @@
+use std::collections::BTreeMap;
+
+use super::bitrate::estimate_bitrate;
+use super::types::{BandwidthGroup, BandwidthStream, BandwidthSummary, LinkCapacity};
+use crate::optics::types::{MessageKey, ValidationCode, ValidationSeverity, ValidationWarning};
+
+/// Name used for the system-wide total, which can also be given a capacity
+const TOTAL_NAME: &str = "Total";
+
+/// Summarize streaming bandwidth for a set of cameras
+///
+/// Bandwidth is summed system-wide and per switch and recorder. Any group whose
+/// name matches a link capacity is checked against it; a capacity named "Total"
+/// applies to the whole system.
+///
+/// # Arguments
+/// * `streams` - Groups of identical cameras with their stream settings
+/// * `capacities` - Link capacities keyed by switch/recorder name
+///
+/// # Returns
+/// Totals per group and warnings for overloaded links
+pub fn summarize_bandwidth(
+    streams: &[BandwidthStream],
+    capacities: &[LinkCapacity],
+) -> BandwidthSummary {
+    let mut total = (0u32, 0.0, 0.0);
+    let mut switches: BTreeMap<&str, (u32, f64, f64)> = BTreeMap::new();
+    let mut recorders: BTreeMap<&str, (u32, f64, f64)> = BTreeMap::new();
+
+    for group in streams {
+        let estimate = estimate_bitrate(&group.stream);
+        let count = group.count;
+        let average = estimate.average_mbps * count as f64;
+        let peak = estimate.peak_mbps * count as f64;
+
+        let add = |entry: &mut (u32, f64, f64)| {
+            entry.0 += count;
+            entry.1 += average;
+            entry.2 += peak;
+        };
+
+        add(&mut total);
+        if let Some(switch) = &group.switch {
+            add(switches.entry(switch).or_default());
+        }
+        if let Some(recorder) = &group.recorder {
+            add(recorders.entry(recorder).or_default());
+        }
+    }
+
+    let mut warnings = Vec::new();
+    let mut build = |name: &str, (camera_count, average_mbps, peak_mbps): (u32, f64, f64)| {
+        let capacity_mbps = capacities
+            .iter()
+            .find(|c| c.name == name)
+            .map(|c| c.capacity_mbps);
+
+        if let Some(capacity) = capacity_mbps {
+            if average_mbps > capacity {
+                warnings.push(
+                    ValidationWarning::new(
+                        ValidationCode::LinkCapacityExceeded,
+                        ValidationSeverity::Error,
+                        MessageKey::new("bandwidth.link_capacity_exceeded")
+                            .with_text("name", name)
+                            .with_number("average_mbps", average_mbps)
+                            .with_number("capacity_mbps", capacity),
+                    )
+                    .with_field("average_mbps", average_mbps)
+                    .with_field("capacity_mbps", capacity),
+                );
+            } else if peak_mbps > capacity {
+                warnings.push(
+                    ValidationWarning::new(
+                        ValidationCode::PeakLinkCapacityExceeded,
+                        ValidationSeverity::Warning,
+                        MessageKey::new("bandwidth.peak_link_capacity_exceeded")
+                            .with_text("name", name)
+                            .with_number("peak_mbps", peak_mbps)
+                            .with_number("capacity_mbps", capacity),
+                    )
+                    .with_field("peak_mbps", peak_mbps)
+                    .with_field("capacity_mbps", capacity),
+                );
+            }
+        }
+
+        BandwidthGroup {
+            name: name.to_string(),
+            camera_count,
+            average_mbps,
+            peak_mbps,
+            capacity_mbps,
+            utilization_percent: capacity_mbps.map(|c| average_mbps / c * 100.0),
+        }
+    };
+
+    let total = build(TOTAL_NAME, total);
+    let switches = switches
+        .into_iter()
+        .map(|(name, sums)| build(name, sums))
+        .collect();
+    let recorders = recorders
+        .into_iter()
+        .map(|(name, sums)| build(name, sums))
+        .collect();
+
+    BandwidthSummary {
+        total,
+        switches,
+        recorders,
+        warnings,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::video::types::{BitrateParams, SceneActivity, VideoCodec};
+
+    fn group(count: u32, switch: &str, recorder: &str) -> BandwidthStream {
+        BandwidthStream {
+            name: None,
+            count,
+            stream: BitrateParams {
+                pixel_width: 1920,
+                pixel_height: 1080,
+                frame_rate: 30.0,
+                codec: VideoCodec::H264,
+                activity: SceneActivity::Medium,
+            },
+            switch: Some(switch.to_string()),
+            recorder: Some(recorder.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_totals_and_grouping() {
+        let streams = vec![
+            group(4, "SW1", "NVR1"),
+            group(6, "SW2", "NVR1"),
+            group(2, "SW2", "NVR2"),
+        ];
+        let summary = summarize_bandwidth(&streams, &[]);
+
+        assert_eq!(summary.total.camera_count, 12);
+        assert_eq!(summary.switches.len(), 2);
+        assert_eq!(summary.switches[1].name, "SW2");
+        assert_eq!(summary.switches[1].camera_count, 8);
+        assert_eq!(summary.recorders[0].camera_count, 10);
+
+        let per_camera = summary.total.average_mbps / 12.0;
+        assert!((summary.switches[0].average_mbps - 4.0 * per_camera).abs() < 1e-9);
+        assert!(summary.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_capacity_exceeded() {
+        // 30 × ~5 Mbps = ~150 Mbps average on a 100 Mbps uplink
+        let streams = vec![group(30, "SW1", "NVR1")];
+        let capacities = vec![
+            LinkCapacity {
+                name: "SW1".to_string(),
+                capacity_mbps: 100.0,
+            },
+            LinkCapacity {
+                name: "NVR1".to_string(),
+                capacity_mbps: 1000.0,
+            },
+        ];
+        let summary = summarize_bandwidth(&streams, &capacities);
+
+        assert_eq!(summary.warnings.len(), 1);
+        assert_eq!(summary.warnings[0].severity, ValidationSeverity::Error);
+        assert!(summary.switches[0].utilization_percent.unwrap() > 100.0);
+        assert!(summary.recorders[0].utilization_percent.unwrap() < 100.0);
+    }
+
+    #[test]
+    fn test_peak_exceeds_total_capacity() {
+        // ~50 Mbps average, ~100 Mbps peak against an 80 Mbps total budget
+        let streams = vec![group(10, "SW1", "NVR1")];
+        let capacities = vec![LinkCapacity {
+            name: "Total".to_string(),
+            capacity_mbps: 80.0,
+        }];
+        let summary = summarize_bandwidth(&streams, &capacities);
+
+        assert_eq!(summary.warnings.len(), 1);
+        assert_eq!(summary.warnings[0].severity, ValidationSeverity::Warning);
+        assert_eq!(summary.total.capacity_mbps, Some(80.0));
+    }
+}
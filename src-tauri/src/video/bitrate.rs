@@ -0,0 +1,157 @@
+use super::types::{BitrateEstimate, BitrateParams, SceneActivity, VideoCodec};
+
+impl VideoCodec {
+    /// Typical compressed bits per pixel per frame at medium scene activity
+    pub fn bits_per_pixel(&self) -> f64 {
+        match self {
+            VideoCodec::H264 => 0.08,
+            VideoCodec::H265 => 0.045,
+            VideoCodec::Mjpeg => 0.6,
+        }
+    }
+
+    /// Ratio of peak to average bitrate (keyframes and scene changes)
+    pub fn peak_factor(&self) -> f64 {
+        match self {
+            VideoCodec::H264 | VideoCodec::H265 => 2.0,
+            // Every frame is a keyframe, so the bitrate barely fluctuates
+            VideoCodec::Mjpeg => 1.2,
+        }
+    }
+}
+
+impl std::str::FromStr for VideoCodec {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "h264" | "h.264" => Ok(VideoCodec::H264),
+            "h265" | "h.265" | "hevc" => Ok(VideoCodec::H265),
+            "mjpeg" => Ok(VideoCodec::Mjpeg),
+            _ => Err(format!(
+                "Unknown codec '{}' (expected h264, h265 or mjpeg)",
+                s
+            )),
+        }
+    }
+}
+
+impl SceneActivity {
+    /// Bitrate multiplier relative to medium activity
+    pub fn multiplier(&self) -> f64 {
+        match self {
+            SceneActivity::Low => 0.5,
+            SceneActivity::Medium => 1.0,
+            SceneActivity::High => 1.8,
+        }
+    }
+}
+
+impl std::str::FromStr for SceneActivity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "low" => Ok(SceneActivity::Low),
+            "medium" => Ok(SceneActivity::Medium),
+            "high" => Ok(SceneActivity::High),
+            _ => Err(format!(
+                "Unknown activity '{}' (expected low, medium or high)",
+                s
+            )),
+        }
+    }
+}
+
+/// Estimate the average and peak bitrate of a camera stream
+///
+/// # Formula
+/// bitrate = pixels × frame_rate × bits_per_pixel(codec) × activity_multiplier
+///
+/// The bits-per-pixel values are empirical averages for surveillance footage; e.g.
+/// 1080p at 30 fps in H.264 with medium activity comes out at about 5 Mbps.
+/// Inter-frame codecs (H.264/H.265) benefit more from static scenes than MJPEG,
+/// so the activity multiplier is only partially applied to MJPEG.
+///
+/// # Arguments
+/// * `params` - Resolution, frame rate, codec and scene activity
+///
+/// # Returns
+/// Bits per pixel, average and peak bitrate in Mbps
+pub fn estimate_bitrate(params: &BitrateParams) -> BitrateEstimate {
+    let activity = match params.codec {
+        VideoCodec::Mjpeg => 1.0 + (params.activity.multiplier() - 1.0) * 0.25,
+        VideoCodec::H264 | VideoCodec::H265 => params.activity.multiplier(),
+    };
+    let bits_per_pixel = params.codec.bits_per_pixel() * activity;
+
+    let pixels = params.pixel_width as f64 * params.pixel_height as f64;
+    let average_mbps = pixels * params.frame_rate * bits_per_pixel / 1_000_000.0;
+    let peak_mbps = average_mbps * params.codec.peak_factor();
+
+    BitrateEstimate {
+        bits_per_pixel,
+        average_mbps,
+        peak_mbps,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(codec: VideoCodec, activity: SceneActivity) -> BitrateParams {
+        BitrateParams {
+            pixel_width: 1920,
+            pixel_height: 1080,
+            frame_rate: 30.0,
+            codec,
+            activity,
+        }
+    }
+
+    #[test]
+    fn test_bitrate_1080p_h264() {
+        let estimate = estimate_bitrate(&params(VideoCodec::H264, SceneActivity::Medium));
+
+        // 1920 × 1080 × 30 × 0.08 ≈ 4.98 Mbps
+        assert!((estimate.average_mbps - 4.977).abs() < 0.01);
+        assert!((estimate.peak_mbps - 2.0 * estimate.average_mbps).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_h265_lower_than_h264() {
+        let h264 = estimate_bitrate(&params(VideoCodec::H264, SceneActivity::Medium));
+        let h265 = estimate_bitrate(&params(VideoCodec::H265, SceneActivity::Medium));
+        let mjpeg = estimate_bitrate(&params(VideoCodec::Mjpeg, SceneActivity::Medium));
+
+        assert!(h265.average_mbps < h264.average_mbps);
+        assert!(mjpeg.average_mbps > h264.average_mbps);
+    }
+
+    #[test]
+    fn test_activity_scales_bitrate() {
+        let low = estimate_bitrate(&params(VideoCodec::H264, SceneActivity::Low));
+        let high = estimate_bitrate(&params(VideoCodec::H264, SceneActivity::High));
+
+        assert!((high.average_mbps / low.average_mbps - 3.6).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_mjpeg_less_sensitive_to_activity() {
+        let low = estimate_bitrate(&params(VideoCodec::Mjpeg, SceneActivity::Low));
+        let high = estimate_bitrate(&params(VideoCodec::Mjpeg, SceneActivity::High));
+
+        assert!(high.average_mbps / low.average_mbps < 1.5);
+    }
+
+    #[test]
+    fn test_bitrate_scales_with_frame_rate() {
+        let mut p = params(VideoCodec::H265, SceneActivity::Medium);
+        let at_30 = estimate_bitrate(&p);
+        p.frame_rate = 15.0;
+        let at_15 = estimate_bitrate(&p);
+
+        assert!((at_30.average_mbps / at_15.average_mbps - 2.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,9 @@
+pub mod bandwidth;
+pub mod bitrate;
+pub mod storage;
+pub mod types;
+
+pub use bandwidth::*;
+pub use bitrate::*;
+pub use storage::*;
+pub use types::*;
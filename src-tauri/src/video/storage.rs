@@ -0,0 +1,129 @@
+use super::bitrate::estimate_bitrate;
+use super::types::{RecordingMode, StorageEstimate, StorageStream, StreamStorage};
+
+const SECONDS_PER_DAY: f64 = 86_400.0;
+const BYTES_PER_TB: f64 = 1e12;
+
+impl RecordingMode {
+    /// Fraction of the time spent recording (0-1)
+    pub fn recording_fraction(&self) -> f64 {
+        match self {
+            RecordingMode::Continuous => 1.0,
+            RecordingMode::Motion { activity_percent } => {
+                (activity_percent / 100.0).clamp(0.0, 1.0)
+            }
+        }
+    }
+}
+
+/// Estimate the storage needed to retain recordings from a set of cameras
+///
+/// # Formula
+/// storage = cameras × average_bitrate × recording_fraction × retention_days × 86400 s / 8
+///
+/// # Arguments
+/// * `streams` - Groups of identical cameras with their stream settings
+/// * `retention_days` - How long recordings are kept
+///
+/// # Returns
+/// Per-group and total storage in terabytes
+pub fn estimate_storage(streams: &[StorageStream], retention_days: f64) -> StorageEstimate {
+    let streams: Vec<StreamStorage> = streams
+        .iter()
+        .map(|group| {
+            let average_mbps = estimate_bitrate(&group.stream).average_mbps;
+            let recording_fraction = group.recording_mode.recording_fraction();
+
+            let bytes = group.count as f64 * average_mbps * 1_000_000.0 / 8.0
+                * recording_fraction
+                * retention_days
+                * SECONDS_PER_DAY;
+
+            StreamStorage {
+                name: group.name.clone(),
+                count: group.count,
+                average_mbps,
+                recording_fraction,
+                storage_tb: bytes / BYTES_PER_TB,
+            }
+        })
+        .collect();
+
+    let total_tb = streams.iter().map(|s| s.storage_tb).sum();
+
+    StorageEstimate {
+        retention_days,
+        streams,
+        total_tb,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::video::types::{BitrateParams, SceneActivity, VideoCodec};
+
+    fn group(count: u32, recording_mode: RecordingMode) -> StorageStream {
+        StorageStream {
+            name: None,
+            count,
+            stream: BitrateParams {
+                pixel_width: 1920,
+                pixel_height: 1080,
+                frame_rate: 30.0,
+                codec: VideoCodec::H264,
+                activity: SceneActivity::Medium,
+            },
+            recording_mode,
+        }
+    }
+
+    #[test]
+    fn test_continuous_storage() {
+        let estimate = estimate_storage(&[group(1, RecordingMode::Continuous)], 30.0);
+
+        // ≈ 4.977 Mbps × 30 days ≈ 1.61 TB
+        let expected = 4.977_28 * 1e6 / 8.0 * 30.0 * 86_400.0 / 1e12;
+        assert!((estimate.total_tb - expected).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_motion_recording_reduces_storage() {
+        let continuous = estimate_storage(&[group(10, RecordingMode::Continuous)], 14.0);
+        let motion = estimate_storage(
+            &[group(
+                10,
+                RecordingMode::Motion {
+                    activity_percent: 25.0,
+                },
+            )],
+            14.0,
+        );
+
+        assert!((motion.total_tb / continuous.total_tb - 0.25).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_total_sums_groups() {
+        let estimate = estimate_storage(
+            &[
+                group(4, RecordingMode::Continuous),
+                group(
+                    6,
+                    RecordingMode::Motion {
+                        activity_percent: 50.0,
+                    },
+                ),
+            ],
+            7.0,
+        );
+
+        assert_eq!(estimate.streams.len(), 2);
+        let sum: f64 = estimate.streams.iter().map(|s| s.storage_tb).sum();
+        assert!((estimate.total_tb - sum).abs() < 1e-12);
+        // 6 cameras at half time equal 3 continuous cameras
+        assert!(
+            (estimate.streams[1].storage_tb / estimate.streams[0].storage_tb - 0.75).abs() < 1e-9
+        );
+    }
+}
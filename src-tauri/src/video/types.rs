@@ -0,0 +1,158 @@
+use serde::{Deserialize, Serialize};
+
+use crate::optics::types::ValidationWarning;
+
+/// Video compression codec
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub enum VideoCodec {
+    H264,
+    H265,
+    Mjpeg,
+}
+
+/// Amount of motion in the scene, which drives inter-frame compression efficiency
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub enum SceneActivity {
+    /// Mostly static scene, e.g. an empty corridor
+    Low,
+    /// Occasional movement, e.g. an office entrance
+    Medium,
+    /// Constant movement, e.g. a busy street or foliage in wind
+    High,
+}
+
+/// Input parameters for bitrate estimation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub struct BitrateParams {
+    /// Horizontal pixel count of the stream
+    pub pixel_width: u32,
+    /// Vertical pixel count of the stream
+    pub pixel_height: u32,
+    /// Frames per second
+    pub frame_rate: f64,
+    /// Compression codec
+    pub codec: VideoCodec,
+    /// Scene activity level
+    pub activity: SceneActivity,
+}
+
+/// Result of bitrate estimation for a single camera stream
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub struct BitrateEstimate {
+    /// Compressed bits per pixel per frame
+    pub bits_per_pixel: f64,
+    /// Expected average bitrate in megabits per second
+    pub average_mbps: f64,
+    /// Expected peak bitrate in megabits per second
+    pub peak_mbps: f64,
+}
+
+/// How much of the time a camera records
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub enum RecordingMode {
+    /// Records 24/7
+    Continuous,
+    /// Records only on motion, for the given percentage of the time
+    Motion { activity_percent: f64 },
+}
+
+/// A group of identical camera streams for storage estimation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct StorageStream {
+    /// Optional name for identification
+    pub name: Option<String>,
+    /// Number of cameras with this stream configuration
+    pub count: u32,
+    /// Stream parameters used for the bitrate estimate
+    pub stream: BitrateParams,
+    /// Recording mode
+    pub recording_mode: RecordingMode,
+}
+
+/// Storage requirement for a single stream group
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct StreamStorage {
+    pub name: Option<String>,
+    pub count: u32,
+    /// Average bitrate of a single camera in Mbps
+    pub average_mbps: f64,
+    /// Fraction of the time the cameras record (0-1)
+    pub recording_fraction: f64,
+    /// Storage for all cameras in the group in terabytes
+    pub storage_tb: f64,
+}
+
+/// Storage requirement for a whole system
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub struct StorageEstimate {
+    /// Retention period in days
+    pub retention_days: f64,
+    /// Per-group breakdown
+    pub streams: Vec<StreamStorage>,
+    /// Total storage in terabytes (10^12 bytes)
+    pub total_tb: f64,
+}
+
+/// A group of identical camera streams for bandwidth aggregation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct BandwidthStream {
+    /// Optional name for identification
+    pub name: Option<String>,
+    /// Number of cameras with this stream configuration
+    pub count: u32,
+    /// Stream parameters used for the bitrate estimate
+    pub stream: BitrateParams,
+    /// Network switch the cameras are connected to
+    pub switch: Option<String>,
+    /// Recorder (NVR/VMS server) the cameras stream to
+    pub recorder: Option<String>,
+}
+
+/// Capacity of a named network link, switch uplink or recorder input
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub struct LinkCapacity {
+    /// Name of the switch or recorder
+    pub name: String,
+    /// Capacity in Mbps
+    pub capacity_mbps: f64,
+}
+
+/// Aggregated bandwidth for a switch, recorder or the whole system
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export, optional_fields = nullable))]
+pub struct BandwidthGroup {
+    pub name: String,
+    pub camera_count: u32,
+    /// Sum of average bitrates in Mbps
+    pub average_mbps: f64,
+    /// Sum of peak bitrates in Mbps
+    pub peak_mbps: f64,
+    /// Link capacity in Mbps, if specified
+    pub capacity_mbps: Option<f64>,
+    /// Average bandwidth as a percentage of the capacity
+    pub utilization_percent: Option<f64>,
+}
+
+/// Streaming bandwidth summary for a multi-camera system
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(test, derive(ts_rs::TS), ts(export))]
+pub struct BandwidthSummary {
+    /// System-wide totals
+    pub total: BandwidthGroup,
+    /// Totals per switch (cameras without a switch are not listed)
+    pub switches: Vec<BandwidthGroup>,
+    /// Totals per recorder (cameras without a recorder are not listed)
+    pub recorders: Vec<BandwidthGroup>,
+    /// Warnings for links whose capacity is exceeded
+    pub warnings: Vec<ValidationWarning>,
+}
@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CameraSystem } from "./CameraSystem";
+import type { DoriLevel } from "./DoriLevel";
+import type { MountPoint } from "./MountPoint";
+import type { PlanPoint } from "./PlanPoint";
+
+/**
+ * Camera mount and the point it should be aimed at
+ */
+export type AimingRequest = { camera: CameraSystem, mount: MountPoint, 
+/**
+ * Plan position of the target to centre in the image
+ */
+target: PlanPoint, 
+/**
+ * Height of the target above the plane of interest in metres, e.g. 1.6 for faces
+ */
+target_height_m: number, 
+/**
+ * DORI level the target must be seen at
+ */
+required_level?: DoriLevel | null, };
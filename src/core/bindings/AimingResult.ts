@@ -0,0 +1,35 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CameraPose } from "./CameraPose";
+import type { DoriLevel } from "./DoriLevel";
+import type { PlanPoint } from "./PlanPoint";
+
+/**
+ * Bracket angles that centre a target, and what the camera then sees
+ */
+export type AimingResult = { 
+/**
+ * Mount position with the pan and tilt that centre the target
+ */
+pose: CameraPose, 
+/**
+ * Line-of-sight distance from the camera to the target in metres
+ */
+slant_distance_m: number, 
+/**
+ * Horizontal pixel density at the target in px/m
+ */
+target_ppm: number, 
+/**
+ * Highest DORI level reached at the target
+ */
+achieved_level?: DoriLevel | null, 
+/**
+ * Whether the required level is reached, if one was given
+ */
+meets_requirement?: boolean | null, 
+/**
+ * Image corners projected onto the plane of interest: bottom left, bottom right,
+ * top right, top left. Corners at or above the horizon are cut off at the
+ * detection distance.
+ */
+footprint: Array<PlanPoint>, };
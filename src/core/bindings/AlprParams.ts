@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AlprRequirement } from "./AlprRequirement";
+import type { CameraSystem } from "./CameraSystem";
+import type { PlateSpec } from "./PlateSpec";
+import type { ShutterAngle } from "./ShutterAngle";
+
+/**
+ * Input for the ALPR capture distance calculation
+ */
+export type AlprParams = { camera: CameraSystem, plate: PlateSpec, requirement: AlprRequirement, 
+/**
+ * Planned capture distance in metres; enables the required focal length
+ */
+capture_distance_m?: number | null, 
+/**
+ * Vehicle speed in km/h; enables the motion blur check
+ */
+vehicle_speed_kmh?: number | null, 
+/**
+ * Exposure time in seconds for the motion blur check
+ */
+shutter_s?: number | null, 
+/**
+ * Exposure as a shutter angle at a frame rate, used instead of `shutter_s`
+ */
+shutter_angle?: ShutterAngle | null, };
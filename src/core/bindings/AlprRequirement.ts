@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Image resolution an ALPR engine needs on the plate
+ */
+export type AlprRequirement = { "PixelsPerCharacter": number } | { "PixelDensity": number };
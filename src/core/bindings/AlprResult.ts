@@ -0,0 +1,31 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MotionBlurCheck } from "./MotionBlurCheck";
+
+/**
+ * Licence plate capture limits of a camera
+ */
+export type AlprResult = { 
+/**
+ * Required pixel density on the plate in px/m
+ */
+required_ppm: number, 
+/**
+ * Furthest distance at which plates can be read, in metres
+ */
+max_distance_m: number, 
+/**
+ * Focal length needed to read plates at the capture distance, in mm
+ */
+required_focal_length_mm?: number | null, 
+/**
+ * Plate width in pixels at the capture distance
+ */
+plate_width_px?: number | null, 
+/**
+ * Character height in pixels at the capture distance
+ */
+character_height_px?: number | null, 
+/**
+ * Evaluated at the capture distance, or at the maximum distance without one
+ */
+motion_blur?: MotionBlurCheck | null, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Photographic class of a lens, by its 35 mm-equivalent focal length
+ *
+ * The same focal length falls into different classes on different sensor formats:
+ * 8 mm is ultra-wide on full frame but short tele on a 1/4" sensor.
+ */
+export type AngleOfView = "UltraWide" | "Wide" | "Normal" | "ShortTele" | "Tele" | "SuperTele";
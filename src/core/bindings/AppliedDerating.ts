@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Result of a single derating stage, in application order
+ */
+export type AppliedDerating = { label: string, 
+/**
+ * Distance factor contributed by this stage alone
+ */
+distance_factor: number, 
+/**
+ * Distance factor of all stages up to and including this one
+ */
+cumulative_factor: number, };
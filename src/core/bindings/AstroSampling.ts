@@ -0,0 +1,31 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ParameterRange } from "./ParameterRange";
+
+/**
+ * Pixel scale and sky framing of a camera/telescope combination
+ */
+export type AstroSampling = { 
+/**
+ * Sky angle covered by one pixel in arcseconds
+ */
+pixel_scale_arcsec: number, 
+/**
+ * Seeing (star FWHM) in arcseconds
+ */
+seeing_arcsec: number, 
+/**
+ * Pixels across the seeing FWHM
+ */
+pixels_per_fwhm: number, 
+/**
+ * Fewer than 2 pixels across the FWHM, so stars come out blocky
+ */
+undersampled: boolean, 
+/**
+ * More than 3 pixels across the FWHM, so signal is spread without gaining detail
+ */
+oversampled: boolean, 
+/**
+ * Focal lengths that give 2-3 pixels across the FWHM
+ */
+optimal_focal_length_mm: ParameterRange, horizontal_fov_deg: number, vertical_fov_deg: number, diagonal_fov_deg: number, horizontal_fov_arcmin: number, vertical_fov_arcmin: number, };
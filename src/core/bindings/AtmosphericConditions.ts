@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Atmospheric conditions that reduce the contrast of distant targets
+ */
+export type AtmosphericConditions = { 
+/**
+ * Meteorological visibility in meters (fog/haze), `None` for clear air
+ */
+visibility_m?: number | null, 
+/**
+ * Rain rate in millimeters per hour, `None` for no rain
+ */
+rain_rate_mm_h?: number | null, };
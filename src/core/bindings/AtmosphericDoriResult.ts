@@ -0,0 +1,32 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DoriDeratingFactors } from "./DoriDeratingFactors";
+import type { DoriDistances } from "./DoriDistances";
+
+/**
+ * DORI distances derated for atmospheric extinction
+ */
+export type AtmosphericDoriResult = { 
+/**
+ * Extinction coefficient from fog/haze in 1/km
+ */
+fog_extinction_per_km: number, 
+/**
+ * Extinction coefficient from rain in 1/km
+ */
+rain_extinction_per_km: number, 
+/**
+ * Total extinction coefficient in 1/km
+ */
+extinction_per_km: number, 
+/**
+ * Clear-weather DORI distances
+ */
+theoretical: DoriDistances, 
+/**
+ * DORI distances under the given conditions
+ */
+effective: DoriDistances, 
+/**
+ * Ratio of effective to theoretical distance for each tier
+ */
+derating_factors: DoriDeratingFactors, };
@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Aggregated bandwidth for a switch, recorder or the whole system
+ */
+export type BandwidthGroup = { name: string, camera_count: number, 
+/**
+ * Sum of average bitrates in Mbps
+ */
+average_mbps: number, 
+/**
+ * Sum of peak bitrates in Mbps
+ */
+peak_mbps: number, 
+/**
+ * Link capacity in Mbps, if specified
+ */
+capacity_mbps?: number | null, 
+/**
+ * Average bandwidth as a percentage of the capacity
+ */
+utilization_percent?: number | null, };
@@ -0,0 +1,27 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BitrateParams } from "./BitrateParams";
+
+/**
+ * A group of identical camera streams for bandwidth aggregation
+ */
+export type BandwidthStream = { 
+/**
+ * Optional name for identification
+ */
+name?: string | null, 
+/**
+ * Number of cameras with this stream configuration
+ */
+count: number, 
+/**
+ * Stream parameters used for the bitrate estimate
+ */
+stream: BitrateParams, 
+/**
+ * Network switch the cameras are connected to
+ */
+switch?: string | null, 
+/**
+ * Recorder (NVR/VMS server) the cameras stream to
+ */
+recorder?: string | null, };
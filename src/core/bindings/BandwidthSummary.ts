@@ -0,0 +1,24 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BandwidthGroup } from "./BandwidthGroup";
+import type { ValidationWarning } from "./ValidationWarning";
+
+/**
+ * Streaming bandwidth summary for a multi-camera system
+ */
+export type BandwidthSummary = { 
+/**
+ * System-wide totals
+ */
+total: BandwidthGroup, 
+/**
+ * Totals per switch (cameras without a switch are not listed)
+ */
+switches: Array<BandwidthGroup>, 
+/**
+ * Totals per recorder (cameras without a recorder are not listed)
+ */
+recorders: Array<BandwidthGroup>, 
+/**
+ * Warnings for links whose capacity is exceeded
+ */
+warnings: Array<ValidationWarning>, };
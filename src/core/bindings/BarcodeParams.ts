@@ -0,0 +1,28 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CameraSystem } from "./CameraSystem";
+import type { CodeSymbology } from "./CodeSymbology";
+
+/**
+ * Input for the barcode reading distance calculation
+ */
+export type BarcodeParams = { camera: CameraSystem, symbology: CodeSymbology, 
+/**
+ * Width of the narrowest bar or cell in millimeters
+ */
+module_size_mm: number, 
+/**
+ * Pixels per module the decoder needs; defaults to 2 for 1D and 3 for 2D codes
+ */
+pixels_per_module?: number | null, 
+/**
+ * Code width in millimeters, so it must fit in the frame
+ */
+code_width_mm?: number | null, 
+/**
+ * Code height in millimeters, so it must fit in the frame
+ */
+code_height_mm?: number | null, 
+/**
+ * Reading distance in metres to check
+ */
+distance_m?: number | null, };
@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Working distances at which a barcode can be decoded
+ */
+export type BarcodeResult = { 
+/**
+ * Pixels per module used as the decoding threshold
+ */
+required_pixels_per_module: number, 
+/**
+ * Closest distance at which the whole code fits in the frame, in metres
+ */
+near_distance_m: number, 
+/**
+ * Furthest distance at which modules reach the threshold, in metres
+ */
+far_distance_m: number, 
+/**
+ * Pixels per module at the reading distance
+ */
+pixels_per_module?: number | null, 
+/**
+ * Whether the code can be decoded at the reading distance
+ */
+decodable?: boolean | null, };
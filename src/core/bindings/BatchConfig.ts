@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BatchScenario } from "./BatchScenario";
+import type { CameraSystem } from "./CameraSystem";
+
+/**
+ * Cameras and scenarios to evaluate, read from a TOML or JSON file
+ */
+export type BatchConfig = { cameras: Array<CameraSystem>, scenarios: Array<BatchScenario>, };
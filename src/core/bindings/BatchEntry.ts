@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BatchScenario } from "./BatchScenario";
+import type { CameraSystem } from "./CameraSystem";
+import type { DepthOfField } from "./DepthOfField";
+import type { FovResult } from "./FovResult";
+
+/**
+ * Result for one camera in one scenario
+ */
+export type BatchEntry = { camera: CameraSystem, scenario: BatchScenario, 
+/**
+ * Field of view, pixel density and DORI distances
+ */
+fov: FovResult, 
+/**
+ * Depth of field, if an f-number is known
+ */
+dof?: DepthOfField | null, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BatchEntry } from "./BatchEntry";
+
+/**
+ * Results for every camera/scenario combination
+ */
+export type BatchResults = { entries: Array<BatchEntry>, };
@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A viewing scenario evaluated for every camera in a batch
+ */
+export type BatchScenario = { 
+/**
+ * Optional name for identification
+ */
+name?: string | null, 
+/**
+ * Subject distance in meters
+ */
+distance_m: number, 
+/**
+ * Aperture for depth of field; falls back to the camera's f-number
+ */
+f_number?: number | null, 
+/**
+ * Circle of confusion in millimeters; defaults to sensor diagonal / 1500
+ */
+coc_mm?: number | null, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RangeLimit } from "./RangeLimit";
+import type { RangeParameter } from "./RangeParameter";
+
+/**
+ * Limits on both ends of one returned range
+ */
+export type BindingConstraint = { parameter: RangeParameter, min_limited_by: RangeLimit, max_limited_by: RangeLimit, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Result of bitrate estimation for a single camera stream
+ */
+export type BitrateEstimate = { 
+/**
+ * Compressed bits per pixel per frame
+ */
+bits_per_pixel: number, 
+/**
+ * Expected average bitrate in megabits per second
+ */
+average_mbps: number, 
+/**
+ * Expected peak bitrate in megabits per second
+ */
+peak_mbps: number, };
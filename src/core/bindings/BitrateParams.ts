@@ -0,0 +1,28 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SceneActivity } from "./SceneActivity";
+import type { VideoCodec } from "./VideoCodec";
+
+/**
+ * Input parameters for bitrate estimation
+ */
+export type BitrateParams = { 
+/**
+ * Horizontal pixel count of the stream
+ */
+pixel_width: number, 
+/**
+ * Vertical pixel count of the stream
+ */
+pixel_height: number, 
+/**
+ * Frames per second
+ */
+frame_rate: number, 
+/**
+ * Compression codec
+ */
+codec: VideoCodec, 
+/**
+ * Scene activity level
+ */
+activity: SceneActivity, };
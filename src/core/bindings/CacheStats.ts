@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Size and hit rate of the calculation cache
+ */
+export type CacheStats = { 
+/**
+ * Results currently held
+ */
+entries: number, 
+/**
+ * Lookups answered from the cache since it was last cleared
+ */
+hits: bigint, 
+/**
+ * Lookups that had to compute the result since it was last cleared
+ */
+misses: bigint, };
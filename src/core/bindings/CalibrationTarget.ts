@@ -0,0 +1,38 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Recommended calibration board for a camera at a working distance
+ */
+export type CalibrationTarget = { 
+/**
+ * Square size rounded to a whole millimeter
+ */
+square_size_mm: number, 
+/**
+ * ArUco marker size for ChArUco boards
+ */
+marker_size_mm?: number | null, 
+/**
+ * Squares across the board
+ */
+columns: number, 
+/**
+ * Squares down the board
+ */
+rows: number, 
+/**
+ * Inner corners as (columns, rows), the pattern size OpenCV expects for checkerboards
+ */
+inner_corners: [number, number], board_width_mm: number, board_height_mm: number, 
+/**
+ * Pixels across each square with the rounded size
+ */
+pixels_per_square: number, 
+/**
+ * Fraction of the frame width the board fills
+ */
+width_fraction: number, 
+/**
+ * Fraction of the frame height the board fills
+ */
+height_fraction: number, };
@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CalibrationPattern } from "./CalibrationPattern";
+import type { CameraSystem } from "./CameraSystem";
+
+/**
+ * Input for sizing a calibration target
+ */
+export type CalibrationTargetParams = { camera: CameraSystem, pattern: CalibrationPattern, 
+/**
+ * Distance from the camera to the board in millimeters
+ */
+working_distance_mm: number, 
+/**
+ * Pixels across each square (default: 25)
+ */
+pixels_per_square?: number | null, 
+/**
+ * Fraction of the frame width and height the board should fill (default: 0.5)
+ */
+fov_fraction?: number | null, };
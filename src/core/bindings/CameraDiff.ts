@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MetricDelta } from "./MetricDelta";
+
+/**
+ * Percentage differences of every derived metric between two cameras
+ */
+export type CameraDiff = { 
+/**
+ * Working distance both cameras were evaluated at in meters
+ */
+distance_m: number, 
+/**
+ * FOV, resolution and DORI metrics, then depth of field when both cameras
+ * have an f-number
+ */
+metrics: Array<MetricDelta>, };
@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Placement of a camera within the site
+ */
+export type CameraPose = { 
+/**
+ * East offset from the site origin in metres
+ */
+x_m: number, 
+/**
+ * North offset from the site origin in metres
+ */
+y_m: number, 
+/**
+ * Mounting height above ground in metres
+ */
+height_m: number, 
+/**
+ * Pan angle in degrees, clockwise from north
+ */
+pan_deg: number, 
+/**
+ * Tilt angle in degrees, negative is looking down
+ */
+tilt_deg: number, };
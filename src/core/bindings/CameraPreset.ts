@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CameraSystem } from "./CameraSystem";
+
+/**
+ * A user-defined camera model saved for reuse across sessions
+ */
+export type CameraPreset = { 
+/**
+ * Unique preset name
+ */
+name: string, camera: CameraSystem, };
@@ -0,0 +1,70 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { NightMode } from "./NightMode";
+
+/**
+ * Represents a camera sensor specification
+ *
+ * Deserializes from these fields or from spec-sheet notation (see the `spec` module).
+ */
+export type CameraSystem = { 
+/**
+ * Sensor width in millimeters
+ */
+sensor_width_mm: number, 
+/**
+ * Sensor height in millimeters
+ */
+sensor_height_mm: number, 
+/**
+ * Horizontal pixel count
+ */
+pixel_width: number, 
+/**
+ * Vertical pixel count
+ */
+pixel_height: number, 
+/**
+ * Lens focal length in millimeters
+ */
+focal_length_mm: number, 
+/**
+ * Optional name for identification
+ */
+name?: string | null, 
+/**
+ * Lens aperture (f-number), used for depth of field and low-light comparisons
+ */
+f_number?: number | null, 
+/**
+ * Lens transmission stop (T-stop); takes the place of the f-number in
+ * exposure and low-light estimates when specified
+ */
+t_stop?: number | null, 
+/**
+ * Sensor quantum efficiency (0-1), used for low-light comparisons
+ */
+quantum_efficiency?: number | null, 
+/**
+ * Read noise in electrons RMS, used for SNR estimates
+ */
+read_noise_e?: number | null, 
+/**
+ * Full-well capacity in electrons, used for SNR estimates
+ */
+full_well_e?: number | null, 
+/**
+ * Dark current in electrons per pixel per second, used for SNR estimates
+ */
+dark_current_e_s?: number | null, 
+/**
+ * Lens resolving power or MTF cutoff in line pairs per millimeter
+ */
+lens_resolution_lp_mm?: number | null, 
+/**
+ * Specified (wide) dynamic range in dB, used for scene dynamic range checks
+ */
+dynamic_range_db?: number | null, 
+/**
+ * Mono IR night mode of a day/night camera
+ */
+night_mode?: NightMode | null, };
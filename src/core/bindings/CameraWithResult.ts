@@ -0,0 +1,29 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CameraSystem } from "./CameraSystem";
+import type { FovResult } from "./FovResult";
+import type { LowLightScore } from "./LowLightScore";
+import type { NightModeResult } from "./NightModeResult";
+import type { SubjectIsolation } from "./SubjectIsolation";
+import type { SuitabilityScore } from "./SuitabilityScore";
+
+/**
+ * Combined camera system with its calculated FOV result
+ */
+export type CameraWithResult = { camera: CameraSystem, result: FovResult, 
+/**
+ * Low-light sensitivity (only available when the camera has an f-number)
+ */
+low_light?: LowLightScore | null, 
+/**
+ * Weighted suitability score (only available when the comparison was scored)
+ */
+suitability?: SuitabilityScore | null, 
+/**
+ * Background blur for a subject isolation scenario (only available when the
+ * comparison had one and the camera has an f-number)
+ */
+isolation?: SubjectIsolation | null, 
+/**
+ * Results in night mode (only available when the camera has one)
+ */
+night?: NightModeResult | null, };
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CameraSystem } from "./CameraSystem";
+
+/**
+ * A camera body considered by the selection optimizer
+ */
+export type CandidateCamera = { 
+/**
+ * Camera with its own lens; the focal length is the wide end of a varifocal lens
+ */
+camera: CameraSystem, 
+/**
+ * Tele end of the camera's own varifocal lens
+ */
+focal_length_max_mm?: number | null, 
+/**
+ * Unit price, used for cost optimization
+ */
+price?: number | null, };
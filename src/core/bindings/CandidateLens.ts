@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * An interchangeable lens considered by the selection optimizer
+ */
+export type CandidateLens = { name?: string | null, 
+/**
+ * Focal length in millimeters (wide end for a varifocal lens)
+ */
+focal_length_mm: number, 
+/**
+ * Tele end of a varifocal lens
+ */
+focal_length_max_mm?: number | null, f_number?: number | null, 
+/**
+ * Unit price, used for cost optimization
+ */
+price?: number | null, };
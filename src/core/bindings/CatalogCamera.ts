@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CameraSystem } from "./CameraSystem";
+
+/**
+ * A camera model imported from a catalog row
+ */
+export type CatalogCamera = { 
+/**
+ * 1-based spreadsheet row number (the header is row 1)
+ */
+row: number, 
+/**
+ * Camera at the wide end of its lens range
+ */
+camera: CameraSystem, 
+/**
+ * Tele end of a varifocal lens, if the row gives a range
+ */
+focal_length_max_mm?: number | null, };
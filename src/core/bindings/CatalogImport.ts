@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CatalogCamera } from "./CatalogCamera";
+import type { CatalogRowError } from "./CatalogRowError";
+
+/**
+ * Result of importing a camera catalog
+ */
+export type CatalogImport = { cameras: Array<CatalogCamera>, errors: Array<CatalogRowError>, };
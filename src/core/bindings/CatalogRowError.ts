@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A catalog row that could not be imported
+ */
+export type CatalogRowError = { 
+/**
+ * 1-based spreadsheet row number (the header is row 1)
+ */
+row: number, message: string, };
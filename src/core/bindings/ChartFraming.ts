@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How large the test chart should appear in the frame
+ */
+export type ChartFraming = { "Coverage": number } | { "Magnification": number };
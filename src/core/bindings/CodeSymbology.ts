@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Kind of barcode, which sets the axes a module must be resolved on
+ */
+export type CodeSymbology = "Linear" | "Matrix";
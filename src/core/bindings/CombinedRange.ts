@@ -0,0 +1,28 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DriLevel } from "./DriLevel";
+import type { SensorChannel } from "./SensorChannel";
+
+/**
+ * Reach of both channels of a dual-sensor system for one task level
+ */
+export type CombinedRange = { level: DriLevel, 
+/**
+ * Visible channel range (DORI pixel density) in meters
+ */
+visible_m: number, 
+/**
+ * Thermal channel range (Johnson criteria) in meters
+ */
+thermal_m: number, 
+/**
+ * Range up to which both channels perform the task, in meters
+ */
+both_m: number, 
+/**
+ * Range up to which at least one channel performs the task, in meters
+ */
+combined_m: number, 
+/**
+ * Channel with the shorter reach; beyond `both_m` only the other one performs the task
+ */
+limited_by: SensorChannel, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RequirementCheck } from "./RequirementCheck";
+
+/**
+ * Pass/fail results of a camera against a requirement spec
+ */
+export type ComplianceReport = { spec_name?: string | null, camera_name?: string | null, 
+/**
+ * Whether every evaluated requirement passes
+ */
+passed: boolean, checks: Array<RequirementCheck>, };
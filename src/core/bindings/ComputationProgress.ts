@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Progress of a long-running computation, sent to the frontend as an event
+ */
+export type ComputationProgress = { 
+/**
+ * Id the frontend gave the computation when starting it
+ */
+task_id: string, 
+/**
+ * Steps done so far
+ */
+done: number, 
+/**
+ * Total number of steps
+ */
+total: number, };
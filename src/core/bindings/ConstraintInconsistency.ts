@@ -0,0 +1,32 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Disagreement between a fixed FOV, focal length and sensor width
+ *
+ * Each corrected value restores consistency by changing that parameter alone.
+ */
+export type ConstraintInconsistency = { 
+/**
+ * Horizontal FOV the sensor width and focal length actually give, in degrees
+ */
+implied_fov_deg: number, 
+/**
+ * Given FOV minus the implied FOV, in degrees
+ */
+discrepancy_deg: number, 
+/**
+ * Discrepancy relative to the implied FOV, in percent
+ */
+discrepancy_percent: number, 
+/**
+ * FOV to use with the given focal length and sensor width
+ */
+corrected_fov_deg: number, 
+/**
+ * Focal length to use with the given FOV and sensor width
+ */
+corrected_focal_length_mm: number, 
+/**
+ * Sensor width to use with the given FOV and focal length
+ */
+corrected_sensor_width_mm: number, };
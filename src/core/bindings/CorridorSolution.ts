@@ -0,0 +1,28 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Cameras needed to cover a straight corridor or perimeter run
+ *
+ * Distances are along the run, measured from its start.
+ */
+export type CorridorSolution = { camera_count: number, 
+/**
+ * Distance between neighbouring cameras in metres
+ */
+spacing_m: number, 
+/**
+ * Tilt of every camera in degrees, negative is looking down
+ */
+tilt_deg: number, 
+/**
+ * Mounting positions in metres; the first is negative, set back before the run
+ */
+positions_m: Array<number>, 
+/**
+ * Blind spot ahead of each camera before the requirement is met, in metres
+ */
+blind_spot_m: number, 
+/**
+ * Distance ahead of each camera at which the pixel density drops below the requirement
+ */
+reach_m: number, };
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RankedSelection } from "./RankedSelection";
+
+/**
+ * One point on the cost / camera count / coverage quality Pareto front
+ */
+export type CoverageDesign = { selection: RankedSelection, 
+/**
+ * Cameras needed to span the scene width
+ */
+camera_count: number, 
+/**
+ * Price of one camera plus its lens
+ */
+unit_cost: number, total_cost: number, 
+/**
+ * Horizontal pixel density at the scene distance
+ */
+horizontal_ppm: number, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Area a multi-camera design must cover
+ */
+export type CoverageScene = { 
+/**
+ * Total width to cover in meters
+ */
+width_m: number, 
+/**
+ * Distance at which the width must be covered in meters
+ */
+distance_m: number, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PlanPoint } from "./PlanPoint";
+import type { ZoneRequirement } from "./ZoneRequirement";
+
+/**
+ * Area of the site that needs a minimum pixel density
+ */
+export type CoverageZone = { name: string, 
+/**
+ * Zone outline on the site plan
+ */
+polygon: Array<PlanPoint>, requirement: ZoneRequirement, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Depth of field for a camera in a scenario
+ */
+export type DepthOfField = { f_number: number, coc_mm: number, hyperfocal_mm: number, near_mm: number, 
+/**
+ * Far limit in millimeters, `None` if it extends to infinity
+ */
+far_mm?: number | null, 
+/**
+ * Total depth of field in millimeters, `None` if infinite
+ */
+total_mm?: number | null, };
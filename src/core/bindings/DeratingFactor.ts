@@ -0,0 +1,21 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A single stage in a DORI derating stack
+ *
+ * Each stage either raises the pixel density a task needs (e.g. compression
+ * artefacts) or lowers the resolution the camera effectively delivers (e.g. a dirty dome).
+ */
+export type DeratingFactor = { 
+/**
+ * Human-readable description of the stage
+ */
+label: string, 
+/**
+ * Multiplier applied to the required px/m (≥ 1 derates)
+ */
+required_ppm_multiplier: number, 
+/**
+ * Multiplier applied to the effective resolution (≤ 1 derates)
+ */
+resolution_multiplier: number, };
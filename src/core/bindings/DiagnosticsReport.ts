@@ -0,0 +1,28 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LogRecord } from "./LogRecord";
+import type { Project } from "./Project";
+
+/**
+ * Everything needed to reproduce a problem, bundled for a bug report
+ */
+export type DiagnosticsReport = { 
+/**
+ * Schema version the report was written with
+ */
+schema_version: number, 
+/**
+ * Version of the application that wrote the report
+ */
+app_version: string, 
+/**
+ * Milliseconds since the Unix epoch
+ */
+created_at_ms: bigint, 
+/**
+ * The open project at the time of the export
+ */
+project: Project, 
+/**
+ * Recent log events, oldest first
+ */
+logs: Array<LogRecord>, };
@@ -0,0 +1,39 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ResolutionLimit } from "./ResolutionLimit";
+
+/**
+ * Comparison of pixel IFOV against the diffraction-limited (Rayleigh) resolution
+ */
+export type DiffractionLimitResult = { 
+/**
+ * Lens aperture (f-number)
+ */
+f_number: number, 
+/**
+ * Wavelength used for the diffraction limit in nanometers
+ */
+wavelength_nm: number, 
+/**
+ * Entrance pupil diameter in millimeters
+ */
+aperture_diameter_mm: number, 
+/**
+ * Larger of the horizontal and vertical pixel IFOV in microradians
+ */
+pixel_ifov_urad: number, 
+/**
+ * Rayleigh angular resolution (1.22 λ / D) in microradians
+ */
+rayleigh_limit_urad: number, 
+/**
+ * Airy disk diameter on the sensor in micrometers (2.44 λ N)
+ */
+airy_disk_diameter_um: number, 
+/**
+ * Rayleigh limit divided by pixel IFOV (> 1 means optics-limited)
+ */
+rayleigh_to_ifov_ratio: number, 
+/**
+ * Which part of the system limits resolution
+ */
+limited_by: ResolutionLimit, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Per-tier factors (0-1) by which DORI distances are reduced
+ */
+export type DoriDeratingFactors = { detection: number, observation: number, recognition: number, identification: number, };
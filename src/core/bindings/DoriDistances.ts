@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * DORI (Detection, Observation, Recognition, Identification) distances
+ * Standard for surveillance camera performance evaluation
+ */
+export type DoriDistances = { 
+/**
+ * Detection distance: identify that an object is present (25 px/m)
+ */
+detection_m: number, 
+/**
+ * Observation distance: determine general characteristics (62.5 px/m)
+ */
+observation_m: number, 
+/**
+ * Recognition distance: recognize familiar person/object (125 px/m)
+ */
+recognition_m: number, 
+/**
+ * Identification distance: identify specific person beyond reasonable doubt (250 px/m)
+ */
+identification_m: number, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * DORI task level, each with its standard pixel density requirement
+ */
+export type DoriLevel = "Detection" | "Observation" | "Recognition" | "Identification";
@@ -0,0 +1,66 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BindingConstraint } from "./BindingConstraint";
+import type { ConstraintInconsistency } from "./ConstraintInconsistency";
+import type { GoverningTarget } from "./GoverningTarget";
+import type { ParameterRange } from "./ParameterRange";
+import type { RangeParameter } from "./RangeParameter";
+import type { SensorFormat } from "./SensorFormat";
+import type { StandardLens } from "./StandardLens";
+import type { StandardResolution } from "./StandardResolution";
+
+/**
+ * Ranges of camera parameters that satisfy DORI requirements
+ */
+export type DoriParameterRanges = { 
+/**
+ * Range for sensor width in mm (if not constrained)
+ */
+sensor_width_mm?: ParameterRange | null, 
+/**
+ * Range for sensor height in mm (if not constrained)
+ */
+sensor_height_mm?: ParameterRange | null, 
+/**
+ * Range for horizontal pixel count (if not constrained)
+ */
+pixel_width?: ParameterRange | null, 
+/**
+ * Range for vertical pixel count (if not constrained)
+ */
+pixel_height?: ParameterRange | null, 
+/**
+ * Range for focal length in mm (if not constrained)
+ */
+focal_length_mm?: ParameterRange | null, 
+/**
+ * Range for horizontal FOV in degrees (if not constrained)
+ */
+horizontal_fov_deg?: ParameterRange | null, 
+/**
+ * Set when FOV, focal length and sensor width are all fixed but disagree
+ */
+inconsistency?: ConstraintInconsistency | null, 
+/**
+ * What limits each end of each returned range
+ */
+binding_constraints: Array<BindingConstraint>, 
+/**
+ * Standard resolutions whose width falls inside the pixel width range
+ */
+standard_resolutions: Array<StandardResolution>, 
+/**
+ * Standard fixed lenses inside, and varifocal lenses overlapping, the focal length range
+ */
+standard_lenses: Array<StandardLens>, 
+/**
+ * Standard optical formats whose width falls inside the sensor width range
+ */
+standard_sensor_formats: Array<SensorFormat>, 
+/**
+ * The strictest of the given DORI targets, which the ranges are solved for
+ */
+governing_target?: GoverningTarget | null, 
+/**
+ * Parameters with no value inside their bounds that meets the targets
+ */
+infeasible_parameters: Array<RangeParameter>, };
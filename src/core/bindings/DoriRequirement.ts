@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DoriLevel } from "./DoriLevel";
+
+/**
+ * DORI level required out to a distance, e.g. identification at the entrance within 8 m
+ */
+export type DoriRequirement = { 
+/**
+ * Where the requirement applies, e.g. "Entrance"
+ */
+label?: string | null, level: DoriLevel, 
+/**
+ * Distance in metres out to which the level must be reached
+ */
+distance_m: number, };
@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Target DORI distances for inverse calculation
+ */
+export type DoriTargets = { 
+/**
+ * Target detection distance in meters (optional)
+ */
+detection_m?: number | null, 
+/**
+ * Target observation distance in meters (optional)
+ */
+observation_m?: number | null, 
+/**
+ * Target recognition distance in meters (optional)
+ */
+recognition_m?: number | null, 
+/**
+ * Target identification distance in meters (optional)
+ */
+identification_m?: number | null, };
@@ -0,0 +1,21 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DoriLevel } from "./DoriLevel";
+import type { PlacedCamera } from "./PlacedCamera";
+import type { Scene } from "./Scene";
+
+/**
+ * What to include in a coverage drawing
+ */
+export type DrawingRequest = { scene: Scene, cameras: Array<PlacedCamera>, 
+/**
+ * DORI levels to draw range outlines for
+ */
+dori_levels: Array<DoriLevel>, 
+/**
+ * Whether to draw the zone cells no camera covers at the zone's requirement
+ */
+blind_spots: boolean, 
+/**
+ * Blind spot grid spacing in metres; defaults to 0.5 m
+ */
+grid_spacing_m?: number | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Detection, recognition and identification task levels shared by DORI and DRI
+ */
+export type DriLevel = "Detection" | "Recognition" | "Identification";
@@ -0,0 +1,27 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CombinedRange } from "./CombinedRange";
+import type { DoriDistances } from "./DoriDistances";
+import type { JohnsonDriDistances } from "./JohnsonDriDistances";
+import type { NatoTarget } from "./NatoTarget";
+import type { PlanPoint } from "./PlanPoint";
+
+/**
+ * Combined DRI/DORI summary and coverage footprints of a dual-sensor system
+ */
+export type DualSensorEvaluation = { 
+/**
+ * Target the thermal ranges were calculated for
+ */
+target: NatoTarget, visible_dori: DoriDistances, thermal_dri: JohnsonDriDistances, 
+/**
+ * Detection, recognition and identification reach of the combined system
+ */
+ranges: Array<CombinedRange>, 
+/**
+ * Plan outline of the visible detection coverage (only available with a pose)
+ */
+visible_footprint?: Array<PlanPoint> | null, 
+/**
+ * Plan outline of the thermal detection coverage (only available with a pose)
+ */
+thermal_footprint?: Array<PlanPoint> | null, };
@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CameraPose } from "./CameraPose";
+import type { CameraSystem } from "./CameraSystem";
+import type { ThermalCameraSystem } from "./ThermalCameraSystem";
+
+/**
+ * Visible and thermal cameras installed together and evaluated as one system
+ */
+export type DualSensorSystem = { visible: CameraSystem, thermal: ThermalCameraSystem, 
+/**
+ * Shared mounting pose; enables the coverage footprints
+ */
+pose?: CameraPose | null, };
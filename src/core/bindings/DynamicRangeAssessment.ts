@@ -0,0 +1,32 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SceneLuminance } from "./SceneLuminance";
+import type { WdrVerdict } from "./WdrVerdict";
+
+/**
+ * Dynamic range a scene requires and how a camera compares
+ */
+export type DynamicRangeAssessment = { 
+/**
+ * Scene luminances the requirement is based on
+ */
+scene: SceneLuminance, 
+/**
+ * Required dynamic range in dB
+ */
+required_db: number, 
+/**
+ * Required dynamic range in stops
+ */
+required_stops: number, 
+/**
+ * Camera's specified dynamic range in dB, if known
+ */
+camera_db?: number | null, 
+/**
+ * Camera dynamic range minus the requirement in dB
+ */
+margin_db?: number | null, 
+/**
+ * Pass/warn/fail, if the camera's dynamic range is known
+ */
+verdict?: WdrVerdict | null, };
@@ -0,0 +1,25 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AppliedDerating } from "./AppliedDerating";
+import type { DoriDeratingFactors } from "./DoriDeratingFactors";
+import type { DoriDistances } from "./DoriDistances";
+
+/**
+ * DORI distances after applying a derating stack, alongside the theoretical values
+ */
+export type EffectiveDoriDistances = { 
+/**
+ * Theoretical DORI distances for ideal conditions
+ */
+theoretical: DoriDistances, 
+/**
+ * DORI distances after all derating stages
+ */
+effective: DoriDistances, 
+/**
+ * Stages in the order they were applied
+ */
+stages: Array<AppliedDerating>, 
+/**
+ * Atmospheric derating, if conditions were given
+ */
+atmospheric_factors?: DoriDeratingFactors | null, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WorldPoint } from "./WorldPoint";
+
+/**
+ * Area that must not be recorded, e.g. a neighbour's window or a public footpath
+ */
+export type ExclusionZone = { name: string, 
+/**
+ * Outline in site coordinates; vertical for windows, at height 0 for ground areas
+ */
+polygon: Array<WorldPoint>, };
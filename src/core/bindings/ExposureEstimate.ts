@@ -0,0 +1,30 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Exposure settings derived from scene illuminance using the incident-light equation
+ */
+export type ExposureEstimate = { 
+/**
+ * Scene illuminance in lux
+ */
+illuminance_lux: number, 
+/**
+ * Sensor sensitivity as ISO equivalent
+ */
+iso: number, 
+/**
+ * Geometric lens aperture (f-number)
+ */
+f_number: number, 
+/**
+ * T-stop of the lens, used for the exposure instead of the f-number when given
+ */
+t_stop: number | null, 
+/**
+ * Shutter time in seconds required for a correct exposure
+ */
+shutter_s: number, 
+/**
+ * Exposure value (EV) of the aperture/shutter combination, at the T-stop if given
+ */
+exposure_value: number, };
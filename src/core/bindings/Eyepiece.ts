@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Eyepiece for visual observing
+ */
+export type Eyepiece = { 
+/**
+ * Focal length in millimeters
+ */
+focal_length_mm: number, 
+/**
+ * Apparent field of view in degrees
+ */
+apparent_fov_deg: number, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * What a telescope shows through an eyepiece
+ */
+export type EyepieceView = { magnification: number, 
+/**
+ * Sky angle seen through the eyepiece in degrees
+ */
+true_fov_deg: number, 
+/**
+ * Diameter of the light beam leaving the eyepiece in millimeters
+ */
+exit_pupil_mm: number, 
+/**
+ * Magnification is between the minimum and maximum useful magnification
+ */
+within_useful_range: boolean, };
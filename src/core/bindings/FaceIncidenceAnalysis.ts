@@ -0,0 +1,30 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Vertical angle at which a camera looks down onto a face
+ */
+export type FaceIncidenceAnalysis = { 
+/**
+ * Camera mounting height above the ground in metres
+ */
+mounting_height_m: number, 
+/**
+ * Face height above the ground in metres
+ */
+face_height_m: number, 
+/**
+ * Horizontal distance from the camera to the face in metres
+ */
+distance_m: number, 
+/**
+ * Angle below horizontal of the line of sight onto the face in degrees
+ */
+incidence_deg: number, 
+/**
+ * Steepest angle that still allows facial identification in degrees
+ */
+max_incidence_deg: number, 
+/**
+ * The camera looks down too steeply to identify faces
+ */
+exceeds_limit: boolean, };
@@ -0,0 +1,34 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Flight altitude and ground footprint for a ground sample distance
+ */
+export type FlightAltitude = { 
+/**
+ * Ground sample distance in centimeters per pixel
+ */
+gsd_cm: number, 
+/**
+ * Flight altitude above ground in metres
+ */
+altitude_m: number, 
+/**
+ * Ground width across the flight direction in metres
+ */
+swath_width_m: number, 
+/**
+ * Ground length along the flight direction in metres
+ */
+swath_length_m: number, 
+/**
+ * Ground area covered by one image in square metres
+ */
+area_per_image_m2: number, 
+/**
+ * Regulatory altitude ceiling in metres
+ */
+max_altitude_m: number, 
+/**
+ * The altitude is above the ceiling
+ */
+exceeds_ceiling: boolean, };
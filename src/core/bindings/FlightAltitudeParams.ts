@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CameraSystem } from "./CameraSystem";
+
+/**
+ * Input for solving a drone's flight altitude from a ground sample distance
+ */
+export type FlightAltitudeParams = { 
+/**
+ * Nadir-pointing camera
+ */
+camera: CameraSystem, 
+/**
+ * Required ground sample distance in centimeters per pixel
+ */
+gsd_cm: number, 
+/**
+ * Regulatory altitude ceiling above ground in metres (default: 120)
+ */
+max_altitude_m?: number | null, };
@@ -0,0 +1,39 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CameraSystem } from "./CameraSystem";
+
+/**
+ * A camera and aperture mapped onto another sensor format
+ */
+export type FormatEquivalence = { 
+/**
+ * Target sensor diagonal over source sensor diagonal
+ */
+scale_factor: number, 
+/**
+ * Focal length on the target format for the same diagonal FOV
+ */
+equivalent_focal_length_mm: number, 
+/**
+ * F-number on the target format for the same DOF and total light
+ */
+equivalent_f_number: number, 
+/**
+ * ISO multiplier on the target format for the same exposure time and noise
+ */
+iso_multiplier: number, 
+/**
+ * Entrance pupil diameter, shared by both setups, in millimeters
+ */
+entrance_pupil_mm: number, 
+/**
+ * Focal length of the source setup on full frame (35 mm equivalent)
+ */
+full_frame_focal_length_mm: number, 
+/**
+ * F-number of the source setup on full frame
+ */
+full_frame_f_number: number, 
+/**
+ * The source camera rescaled to the target sensor, ready to compare
+ */
+camera: CameraSystem, };
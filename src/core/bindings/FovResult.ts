@@ -0,0 +1,76 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AngleOfView } from "./AngleOfView";
+import type { DoriDistances } from "./DoriDistances";
+
+/**
+ * Results of field-of-view calculations
+ */
+export type FovResult = { 
+/**
+ * Horizontal field of view in degrees
+ */
+horizontal_fov_deg: number, 
+/**
+ * Vertical field of view in degrees
+ */
+vertical_fov_deg: number, 
+/**
+ * Horizontal field of view at specified distance in meters
+ */
+horizontal_fov_m: number, 
+/**
+ * Vertical field of view at specified distance in meters
+ */
+vertical_fov_m: number, 
+/**
+ * Horizontal pixels per meter at specified distance
+ */
+horizontal_ppm: number, 
+/**
+ * Vertical pixels per meter at specified distance
+ */
+vertical_ppm: number, 
+/**
+ * Distance at which calculation was performed in meters
+ */
+distance_m: number, 
+/**
+ * Horizontal instantaneous field of view of a single pixel in microradians
+ */
+horizontal_ifov_urad: number, 
+/**
+ * Vertical instantaneous field of view of a single pixel in microradians
+ */
+vertical_ifov_urad: number, 
+/**
+ * Diagonal field of view in degrees
+ */
+diagonal_fov_deg: number, 
+/**
+ * Horizontal pixels per degree of field of view
+ */
+horizontal_px_per_deg: number, 
+/**
+ * Vertical pixels per degree of field of view
+ */
+vertical_px_per_deg: number, 
+/**
+ * Horizontal pixels per foot at specified distance
+ */
+horizontal_ppf: number, 
+/**
+ * Vertical pixels per foot at specified distance
+ */
+vertical_ppf: number, 
+/**
+ * Megapixels on a 1 m² target facing the camera at specified distance
+ */
+megapixels_per_m2: number, 
+/**
+ * DORI distances (Detection, Observation, Recognition, Identification)
+ */
+dori?: DoriDistances | null, 
+/**
+ * Photographic class of the lens on this sensor format
+ */
+angle_of_view?: AngleOfView | null, };
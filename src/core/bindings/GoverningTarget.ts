@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DoriLevel } from "./DoriLevel";
+
+/**
+ * The DORI target that sets the requirement when several are given
+ */
+export type GoverningTarget = { level: DoriLevel, distance_m: number, 
+/**
+ * Other given targets that do not follow the standard DORI ratios and are
+ * exceeded by meeting this one
+ */
+exceeded: Array<DoriLevel>, };
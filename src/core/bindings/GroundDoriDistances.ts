@@ -0,0 +1,32 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { GroundDoriTier } from "./GroundDoriTier";
+
+/**
+ * DORI distances of a mounted camera converted to ground distances
+ */
+export type GroundDoriDistances = { 
+/**
+ * Camera mounting height above the ground in metres
+ */
+mounting_height_m: number, 
+/**
+ * Target height above the ground in metres
+ */
+target_height_m: number, 
+/**
+ * Camera tilt in degrees, negative is looking down
+ */
+tilt_deg: number, 
+/**
+ * Vertical field of view in degrees
+ */
+vertical_fov_deg: number, 
+/**
+ * Ground distance in metres before which targets are below the frame, None
+ * when the frame never looks down to target height
+ */
+blind_spot_m?: number | null, 
+/**
+ * Tiers from detection to identification
+ */
+tiers: Array<GroundDoriTier>, };
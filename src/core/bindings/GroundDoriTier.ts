@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DoriLevel } from "./DoriLevel";
+
+/**
+ * One DORI tier measured along the floor plan
+ */
+export type GroundDoriTier = { level: DoriLevel, 
+/**
+ * Straight-line distance from the lens in metres
+ */
+slant_m: number, 
+/**
+ * Horizontal distance along the ground in metres, None when the slant
+ * distance is shorter than the height difference to the target
+ */
+ground_m?: number | null, 
+/**
+ * The tier reaches past the blind spot below the frame
+ */
+reachable: boolean, };
@@ -0,0 +1,30 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Input parameters for image preview downsampling calculation
+ */
+export type ImageDownsampleParams = { 
+/**
+ * Horizontal pixels per meter at the working distance
+ */
+horizontal_ppm: number, 
+/**
+ * Vertical pixels per meter at the working distance
+ */
+vertical_ppm: number, 
+/**
+ * Real-world width of the scene shown in the image, in meters
+ */
+image_real_world_width_m: number, 
+/**
+ * Original image width in pixels
+ */
+original_width_px: number, 
+/**
+ * Original image height in pixels
+ */
+original_height_px: number, 
+/**
+ * Maximum display size in pixels (for scaling)
+ */
+max_display_size: number, };
@@ -0,0 +1,42 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Result of image preview downsampling calculation
+ */
+export type ImageDownsampleResult = { 
+/**
+ * Number of horizontal pixels the camera would capture
+ */
+camera_pixels_h: number, 
+/**
+ * Number of vertical pixels the camera would capture
+ */
+camera_pixels_v: number, 
+/**
+ * Scale factor for pixelated display
+ */
+scale: number, 
+/**
+ * Display width in pixels
+ */
+display_width: number, 
+/**
+ * Display height in pixels
+ */
+display_height: number, 
+/**
+ * Scene width in millimeters
+ */
+scene_width_mm: number, 
+/**
+ * Scene height in millimeters
+ */
+scene_height_mm: number, 
+/**
+ * Horizontal downsampling ratio (original:camera)
+ */
+downsample_ratio_h: number, 
+/**
+ * Vertical downsampling ratio (original:camera)
+ */
+downsample_ratio_v: number, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Position in the camera image in pixels, from the top left corner
+ */
+export type ImagePoint = { x_px: number, y_px: number, };
@@ -0,0 +1,27 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CameraSystem } from "./CameraSystem";
+
+/**
+ * A camera and lens at the working distance where the inspected area fills the frame
+ */
+export type InspectionOption = { 
+/**
+ * Camera with the lens focal length applied
+ */
+camera: CameraSystem, 
+/**
+ * Lens-to-object distance in millimeters
+ */
+working_distance_mm: number, 
+/**
+ * Optical magnification (image size / object size)
+ */
+magnification: number, 
+/**
+ * Field of view at the working distance in millimeters
+ */
+field_width_mm: number, field_height_mm: number, 
+/**
+ * Pixels across the smallest feature
+ */
+pixels_per_feature: number, };
@@ -0,0 +1,27 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ParameterRange } from "./ParameterRange";
+
+/**
+ * Smallest feature a machine-vision inspection must detect, over the field it must cover
+ */
+export type InspectionRequirements = { 
+/**
+ * Smallest feature to detect in millimeters, e.g. 0.1 for a fine scratch
+ */
+feature_size_mm: number, 
+/**
+ * Pixels across the smallest feature; defaults to 3
+ */
+pixels_per_feature?: number | null, 
+/**
+ * Width of the inspected area in millimeters
+ */
+field_width_mm: number, 
+/**
+ * Height of the inspected area in millimeters
+ */
+field_height_mm?: number | null, 
+/**
+ * Allowed working distances in millimeters
+ */
+working_distance_mm?: ParameterRange | null, };
@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { InspectionOption } from "./InspectionOption";
+
+/**
+ * Resolution needed for an inspection and the camera/lens combinations that achieve it
+ */
+export type InspectionSolution = { 
+/**
+ * Pixels per feature used for the requirement
+ */
+pixels_per_feature: number, 
+/**
+ * Minimum horizontal pixel count
+ */
+required_pixel_width: number, 
+/**
+ * Minimum vertical pixel count, if a field height was given
+ */
+required_pixel_height?: number | null, 
+/**
+ * Combinations that meet the requirement, by increasing working distance
+ */
+options: Array<InspectionOption>, };
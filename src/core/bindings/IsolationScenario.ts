@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Subject and background distances for comparing background blur
+ */
+export type IsolationScenario = { 
+/**
+ * In-focus subject distance in millimeters
+ */
+subject_distance_mm: number, 
+/**
+ * Background distance in millimeters (default: infinity)
+ */
+background_distance_mm?: number | null, };
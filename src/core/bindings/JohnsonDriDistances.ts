@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * DRI (Detection, Recognition, Identification) ranges according to the Johnson criteria
+ */
+export type JohnsonDriDistances = { 
+/**
+ * Detection range: an object is present (1.5 px across the critical dimension)
+ */
+detection_m: number, 
+/**
+ * Recognition range: the class of object can be determined (6 px)
+ */
+recognition_m: number, 
+/**
+ * Identification range: the object can be described in detail (12 px)
+ */
+identification_m: number, };
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CameraSystem } from "./CameraSystem";
+
+/**
+ * Input for the text legibility calculation
+ */
+export type LegibilityParams = { camera: CameraSystem, 
+/**
+ * Height of the smallest characters to read in millimeters
+ */
+character_height_mm: number, 
+/**
+ * Vertical pixels needed across a character, e.g. 16-20 for OCR
+ */
+pixels_per_character: number, 
+/**
+ * Planned reading distance in metres; enables the minimum focal length
+ */
+read_distance_m?: number | null, };
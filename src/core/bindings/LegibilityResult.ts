@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How far a camera can read text of a given size
+ */
+export type LegibilityResult = { 
+/**
+ * Required vertical pixel density in px/m
+ */
+required_ppm: number, 
+/**
+ * Furthest distance at which the text is legible, in metres
+ */
+max_distance_m: number, 
+/**
+ * Shortest focal length that makes the text legible at the reading distance, in mm
+ */
+min_focal_length_mm?: number | null, 
+/**
+ * Character height in pixels at the reading distance
+ */
+character_height_px?: number | null, };
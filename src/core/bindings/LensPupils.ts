@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Lens pupil model for locating the entrance pupil
+ *
+ * A thin lens has both pupils and principal planes at the lens; real lenses are
+ * described by their pupil magnification and the gap between principal planes.
+ */
+export type LensPupils = { 
+/**
+ * Exit pupil over entrance pupil diameter (default: 1.0)
+ */
+pupil_magnification?: number | null, 
+/**
+ * Distance from the front to the rear principal plane in millimeters, negative
+ * when they are crossed (default: 0)
+ */
+principal_plane_separation_mm?: number | null, };
@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Lens resolving power needed to make full use of a sensor
+ */
+export type LensResolutionRequirement = { 
+/**
+ * Pixel pitch in micrometers
+ */
+pixel_pitch_um: number, 
+/**
+ * Sensor Nyquist frequency in line pairs per millimeter
+ */
+nyquist_lp_mm: number, 
+/**
+ * Minimum contrast (MTF, 0-1) the lens should reach at the Nyquist frequency
+ */
+min_mtf_at_nyquist: number, 
+/**
+ * Half the Nyquist frequency in line pairs per millimeter
+ */
+half_nyquist_lp_mm: number, 
+/**
+ * Minimum contrast (MTF, 0-1) the lens should reach at half the Nyquist frequency
+ */
+min_mtf_at_half_nyquist: number, };
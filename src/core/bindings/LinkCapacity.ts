@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Capacity of a named network link, switch uplink or recorder input
+ */
+export type LinkCapacity = { 
+/**
+ * Name of the switch or recorder
+ */
+name: string, 
+/**
+ * Capacity in Mbps
+ */
+capacity_mbps: number, };
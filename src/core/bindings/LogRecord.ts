@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One log event recorded by the backend
+ */
+export type LogRecord = { 
+/**
+ * Milliseconds since the Unix epoch
+ */
+timestamp_ms: bigint, 
+/**
+ * Severity: "ERROR", "WARN", "INFO", "DEBUG" or "TRACE"
+ */
+level: string, 
+/**
+ * Module that emitted the event
+ */
+target: string, 
+/**
+ * Message followed by the event's fields as `key=value`
+ */
+message: string, };
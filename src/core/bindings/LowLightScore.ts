@@ -0,0 +1,30 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Comparative low-light sensitivity derived from pixel area, aperture and quantum efficiency
+ */
+export type LowLightScore = { 
+/**
+ * Pixel area in square micrometers
+ */
+pixel_area_um2: number, 
+/**
+ * Lens aperture used for the score (the T-stop if the lens specifies one)
+ */
+f_number: number, 
+/**
+ * Quantum efficiency used for the score (1.0 if not specified)
+ */
+quantum_efficiency: number, 
+/**
+ * Light collected per pixel relative to a 1 µm² pixel at f/1.0 with 100% QE
+ */
+score: number, 
+/**
+ * Score as a percentage of the most sensitive camera in a comparison
+ */
+relative_percent?: number | null, 
+/**
+ * Sensitivity rank within a comparison (1 = most sensitive)
+ */
+rank?: number | null, };
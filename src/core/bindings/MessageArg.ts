@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MessageValue } from "./MessageValue";
+
+/**
+ * Named parameter of a message
+ */
+export type MessageArg = { name: string, value: MessageValue, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MessageArg } from "./MessageArg";
+
+/**
+ * Translatable message: a catalog key plus the parameters its template refers to
+ */
+export type MessageKey = { 
+/**
+ * Catalog key, e.g. `camera.sensor_width_too_small`
+ */
+key: string, args: Array<MessageArg>, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Value of a message parameter
+ */
+export type MessageValue = { "Number": number } | { "Text": string } | { "Term": string };
@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Change of one derived metric between two cameras
+ */
+export type MetricDelta = { 
+/**
+ * Metric name, matching the field or column it comes from (e.g. `horizontal_ppm`)
+ */
+metric: string, 
+/**
+ * Value for the reference camera
+ */
+a: number, 
+/**
+ * Value for the compared camera
+ */
+b: number, 
+/**
+ * Change from `a` to `b` in percent of `a` (`None` when `a` is zero or either
+ * value is unbounded, such as a far DOF limit at infinity)
+ */
+change_percent: number | null, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Microscope objective, with the camera coupler between it and the sensor
+ */
+export type MicroscopeObjective = { 
+/**
+ * Objective magnification, e.g. 40 for a 40× objective
+ */
+magnification: number, numerical_aperture: number, 
+/**
+ * Magnification of the camera coupler (default: 1.0)
+ */
+coupler_magnification?: number | null, };
@@ -0,0 +1,38 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Resolution and sampling of a camera on a microscope
+ */
+export type MicroscopyAnalysis = { 
+/**
+ * Objective × coupler magnification onto the sensor
+ */
+total_magnification: number, 
+/**
+ * Wavelength used for the resolution limits in nanometers
+ */
+wavelength_nm: number, 
+/**
+ * Abbe limit λ / (2 NA) in micrometers
+ */
+abbe_resolution_um: number, 
+/**
+ * Rayleigh limit 0.61 λ / NA in micrometers
+ */
+rayleigh_resolution_um: number, 
+/**
+ * Field of view on the sample in millimeters
+ */
+field_width_mm: number, field_height_mm: number, 
+/**
+ * Size of one pixel projected onto the sample in micrometers
+ */
+sample_pixel_size_um: number, 
+/**
+ * Largest pixel pitch on the sensor that still samples the Rayleigh limit at Nyquist
+ */
+max_pixel_pitch_um: number, 
+/**
+ * At least two pixels span the Rayleigh limit
+ */
+nyquist_satisfied: boolean, };
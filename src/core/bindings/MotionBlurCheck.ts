@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Motion blur of a moving plate during the exposure
+ */
+export type MotionBlurCheck = { 
+/**
+ * Blur in pixels at the given shutter, if one was given
+ */
+blur_px?: number | null, 
+/**
+ * Longest exposure that keeps the blur within the limit, in seconds
+ */
+max_shutter_s: number, 
+/**
+ * Whether the given shutter keeps the blur within the limit
+ */
+passes?: boolean | null, 
+/**
+ * Longest shutter angle that keeps the blur within the limit, if a frame rate was given
+ */
+max_shutter_angle_deg?: number | null, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Candidate mounting position for the placement optimizer
+ */
+export type MountPoint = { 
+/**
+ * East offset from the site origin in metres
+ */
+x_m: number, 
+/**
+ * North offset from the site origin in metres
+ */
+y_m: number, 
+/**
+ * Mounting height above the plane of interest in metres
+ */
+height_m: number, };
@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Highest camera position that still sees faces at an identification distance
+ */
+export type MountingHeightLimit = { 
+/**
+ * Horizontal distance at which faces must be identified in metres
+ */
+distance_m: number, 
+/**
+ * Face height above the ground in metres
+ */
+face_height_m: number, 
+/**
+ * Steepest angle onto the face allowed, in degrees
+ */
+max_incidence_deg: number, 
+/**
+ * Highest mounting height above the ground in metres
+ */
+max_mounting_height_m: number, 
+/**
+ * Tilt that centres a face at the distance from that height, negative is looking down
+ */
+tilt_deg: number, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Standard NATO target sizes used for thermal DRI ranges
+ */
+export type NatoTarget = "Human" | "Vehicle";
@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Mono IR night mode of a day/night camera
+ *
+ * At night the IR-cut filter swings out and the image switches to monochrome,
+ * which raises the effective sensitivity; many cameras also bin pixels.
+ */
+export type NightMode = { 
+/**
+ * Pixel binning per axis (1 = full resolution, 2 = 2×2 binning)
+ */
+binning: number, 
+/**
+ * Effective quantum efficiency in night mode, including near-IR response
+ */
+quantum_efficiency?: number | null, };
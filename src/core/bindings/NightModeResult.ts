@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CameraSystem } from "./CameraSystem";
+import type { FovResult } from "./FovResult";
+import type { LowLightScore } from "./LowLightScore";
+
+/**
+ * FOV, DORI and low-light results of a camera in night mode
+ */
+export type NightModeResult = { 
+/**
+ * The camera as it operates in night mode
+ */
+camera: CameraSystem, result: FovResult, 
+/**
+ * Low-light sensitivity in night mode, ranked against the other night modes
+ */
+low_light?: LowLightScore | null, };
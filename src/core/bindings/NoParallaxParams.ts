@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CameraSystem } from "./CameraSystem";
+import type { LensPupils } from "./LensPupils";
+
+/**
+ * Input for locating a lens's no-parallax point
+ */
+export type NoParallaxParams = { camera: CameraSystem, pupils: LensPupils, 
+/**
+ * Focus distance from the entrance pupil in millimeters (default: infinity)
+ */
+focus_distance_mm?: number | null, 
+/**
+ * Nearest subject distance in millimeters, for the parallax error
+ */
+subject_distance_mm: number, 
+/**
+ * Rotation between panorama frames in degrees
+ */
+rotation_deg: number, };
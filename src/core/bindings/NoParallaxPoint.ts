@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Where to rotate a camera for a parallax-free panorama
+ */
+export type NoParallaxPoint = { 
+/**
+ * Entrance pupil (no-parallax point) in front of the sensor at the focus distance, in millimeters
+ */
+entrance_pupil_from_sensor_mm: number, 
+/**
+ * Entrance pupil in front of the sensor when focused at infinity, in millimeters
+ */
+entrance_pupil_at_infinity_mm: number, 
+/**
+ * Parallax between the subject and infinity when rotating about the sensor plane, in degrees
+ */
+parallax_deg: number, 
+/**
+ * The same parallax in pixels on the sensor
+ */
+parallax_px: number, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PlanPoint } from "./PlanPoint";
+
+/**
+ * Wall, racking, column or other structure that blocks the view
+ */
+export type Obstacle = { name?: string | null, 
+/**
+ * Outline on the site plan; a thin wall can be given as a two-point polygon
+ */
+polygon: Array<PlanPoint>, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Device identification from GetDeviceInformation
+ */
+export type OnvifDeviceInfo = { manufacturer?: string | null, model?: string | null, firmware_version?: string | null, };
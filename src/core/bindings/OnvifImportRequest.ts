@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OnvifLensHints } from "./OnvifLensHints";
+
+/**
+ * Connection details and lens hints for importing a camera over ONVIF
+ */
+export type OnvifImportRequest = { 
+/**
+ * Device address, either `host[:port]` or a full device service URL
+ */
+address: string, username?: string | null, password?: string | null, 
+/**
+ * Media profile to import; the first profile is used if not given
+ */
+profile_token?: string | null, 
+/**
+ * Sensor and lens data the device does not report
+ */
+hints: OnvifLensHints, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CameraSystem } from "./CameraSystem";
+import type { OnvifDeviceInfo } from "./OnvifDeviceInfo";
+import type { OnvifProfile } from "./OnvifProfile";
+import type { ValidationWarning } from "./ValidationWarning";
+
+/**
+ * Result of importing a camera from an ONVIF device
+ */
+export type OnvifImportResult = { camera: CameraSystem, device: OnvifDeviceInfo, profile: OnvifProfile, 
+/**
+ * Normalized zoom position (0 = wide, 1 = tele), if the device has PTZ
+ */
+zoom_position?: number | null, 
+/**
+ * Notes on values that had to be assumed
+ */
+warnings: Array<ValidationWarning>, };
@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Sensor and lens data to fill in what ONVIF does not expose
+ *
+ * ONVIF reports the stream resolution and a normalized zoom position, but not
+ * the physical sensor size or focal length.
+ */
+export type OnvifLensHints = { sensor_width_mm?: number | null, sensor_height_mm?: number | null, 
+/**
+ * Focal length at the wide end (zoom position 0)
+ */
+focal_length_min_mm?: number | null, 
+/**
+ * Focal length at the tele end (zoom position 1)
+ */
+focal_length_max_mm?: number | null, };
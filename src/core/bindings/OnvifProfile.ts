@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Video settings of a media profile from GetProfiles
+ */
+export type OnvifProfile = { token: string, name?: string | null, 
+/**
+ * Encoder resolution, falling back to the video source bounds
+ */
+width: number, height: number, encoding?: string | null, frame_rate?: number | null, };
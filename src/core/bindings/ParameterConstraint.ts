@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Fixed constraint for a parameter
+ */
+export type ParameterConstraint = { sensor_width_mm?: number | null, sensor_height_mm?: number | null, pixel_width?: number | null, pixel_height?: number | null, focal_length_mm?: number | null, horizontal_fov_deg?: number | null, 
+/**
+ * Converted to a horizontal FOV at the camera's aspect ratio when none is given
+ */
+vertical_fov_deg?: number | null, 
+/**
+ * Converted to a horizontal FOV at the camera's aspect ratio when neither
+ * a horizontal nor a vertical FOV is given
+ */
+diagonal_fov_deg?: number | null, };
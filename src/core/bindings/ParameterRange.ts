@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Range of possible values for a parameter
+ */
+export type ParameterRange = { min: number, max: number, 
+/**
+ * Sensible default inside the range, for prefilling inputs
+ */
+recommended?: number | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Percentile band of a Monte Carlo output
+ */
+export type PercentileBand = { min: number, p5: number, p50: number, p95: number, max: number, mean: number, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CameraPose } from "./CameraPose";
+import type { CameraSystem } from "./CameraSystem";
+
+/**
+ * A camera at a position and orientation on the site plan
+ */
+export type PlacedCamera = { camera: CameraSystem, pose: CameraPose, };
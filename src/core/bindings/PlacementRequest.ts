@@ -0,0 +1,21 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CandidateCamera } from "./CandidateCamera";
+import type { MountPoint } from "./MountPoint";
+import type { Scene } from "./Scene";
+
+/**
+ * Input for the camera placement optimizer
+ */
+export type PlacementRequest = { scene: Scene, mounts: Array<MountPoint>, 
+/**
+ * Camera/lens options that may be installed at any mount
+ */
+cameras: Array<CandidateCamera>, 
+/**
+ * Upper limit on installed cameras
+ */
+max_cameras?: number | null, 
+/**
+ * Spacing of the coverage sample grid in metres
+ */
+grid_spacing_m?: number | null, };
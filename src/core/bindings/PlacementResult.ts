@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PlacedCamera } from "./PlacedCamera";
+import type { ZoneCoverage } from "./ZoneCoverage";
+
+/**
+ * Cameras chosen by the placement optimizer and the coverage they achieve
+ */
+export type PlacementResult = { cameras: Array<PlacedCamera>, zones: Array<ZoneCoverage>, };
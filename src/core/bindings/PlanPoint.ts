@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Point on the site plan in metres
+ */
+export type PlanPoint = { 
+/**
+ * East offset from the site origin in metres
+ */
+x_m: number, 
+/**
+ * North offset from the site origin in metres
+ */
+y_m: number, };
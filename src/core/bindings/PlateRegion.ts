@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Region with a standard licence plate format
+ */
+export type PlateRegion = "Eu" | "Uk" | "Us";
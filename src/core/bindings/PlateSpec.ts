@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Licence plate and character dimensions
+ */
+export type PlateSpec = { width_mm: number, height_mm: number, character_height_mm: number, };
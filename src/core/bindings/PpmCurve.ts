@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DoriDistances } from "./DoriDistances";
+
+/**
+ * Pixel density versus distance series for charting
+ */
+export type PpmCurve = { 
+/**
+ * Sample distances in meters
+ */
+distances_m: Array<number>, 
+/**
+ * Horizontal pixels per meter at each distance
+ */
+horizontal_ppm: Array<number>, 
+/**
+ * Vertical pixels per meter at each distance
+ */
+vertical_ppm: Array<number>, 
+/**
+ * Distances at which the curve crosses each DORI threshold
+ */
+dori: DoriDistances, };
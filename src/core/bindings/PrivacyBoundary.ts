@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DoriLevel } from "./DoriLevel";
+import type { PlanPoint } from "./PlanPoint";
+
+/**
+ * Area outside of which a camera cannot reach a DORI level, for privacy assessments
+ */
+export type PrivacyBoundary = { level: DoriLevel, 
+/**
+ * Distance from the camera beyond which the level cannot be reached, in metres
+ */
+distance_m: number, 
+/**
+ * Boundary on the site plan: the camera position followed by an arc across
+ * the horizontal FOV at `distance_m`
+ */
+polygon: Array<PlanPoint>, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ImagePoint } from "./ImagePoint";
+
+/**
+ * Privacy mask for one exclusion zone, clipped to the image
+ */
+export type PrivacyMask = { name: string, polygon: Array<ImagePoint>, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PrivacyMask } from "./PrivacyMask";
+
+/**
+ * Privacy masks to configure in a camera or VMS
+ */
+export type PrivacyMaskSet = { image_width: number, image_height: number, 
+/**
+ * Masks of the exclusion zones that are in view
+ */
+masks: Array<PrivacyMask>, };
@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ProjectCamera } from "./ProjectCamera";
+import type { ProjectSettings } from "./ProjectSettings";
+import type { Scene } from "./Scene";
+
+/**
+ * A saved camera design project
+ */
+export type Project = { 
+/**
+ * Schema version the file was written with
+ */
+schema_version: number, name: string, cameras: Array<ProjectCamera>, settings: ProjectSettings, 
+/**
+ * Site plan with coverage zones, if one has been drawn
+ */
+scene?: Scene | null, };
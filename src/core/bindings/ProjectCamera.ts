@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CameraPose } from "./CameraPose";
+import type { CameraSystem } from "./CameraSystem";
+
+/**
+ * A camera as stored in a project
+ */
+export type ProjectCamera = { camera: CameraSystem, 
+/**
+ * Position and orientation on site, if placed
+ */
+pose?: CameraPose | null, 
+/**
+ * Distance to the area of interest in metres
+ */
+target_distance_m?: number | null, };
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CameraWithResult } from "./CameraWithResult";
+import type { ZoneCoverage } from "./ZoneCoverage";
+
+/**
+ * Calculated results for the whole open project, pushed to the frontend on change
+ */
+export type ProjectResults = { 
+/**
+ * FOV, DORI and rankings, in the order of the project's cameras
+ */
+cameras: Array<CameraWithResult>, 
+/**
+ * Zone coverage of the placed cameras; empty without a scene
+ */
+coverage: Array<ZoneCoverage>, };
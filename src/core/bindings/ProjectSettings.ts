@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AtmosphericConditions } from "./AtmosphericConditions";
+
+/**
+ * Project-wide calculation settings
+ */
+export type ProjectSettings = { 
+/**
+ * Default calculation distance in metres for cameras without their own
+ */
+default_distance_m: number, 
+/**
+ * Atmospheric conditions used for derated DORI distances
+ */
+conditions?: AtmosphericConditions | null, };
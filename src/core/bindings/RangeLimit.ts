@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DoriLevel } from "./DoriLevel";
+import type { RangeParameter } from "./RangeParameter";
+
+/**
+ * What sets one end of a returned parameter range
+ */
+export type RangeLimit = { "DoriTarget": DoriLevel } | "FovConstraint" | "FixedParameters" | { "ParameterBounds": RangeParameter };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Parameter of the inverse DORI calculation
+ */
+export type RangeParameter = "SensorWidth" | "SensorHeight" | "PixelWidth" | "PixelHeight" | "FocalLength" | "HorizontalFov";
@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CameraSystem } from "./CameraSystem";
+import type { CandidateLens } from "./CandidateLens";
+import type { DoriDistances } from "./DoriDistances";
+import type { ParameterRange } from "./ParameterRange";
+
+/**
+ * A feasible camera/lens combination
+ */
+export type RankedSelection = { 
+/**
+ * Camera set to the chosen focal length
+ */
+camera: CameraSystem, 
+/**
+ * Interchangeable lens used, if any
+ */
+lens?: CandidateLens | null, 
+/**
+ * Feasible focal length range for this combination in millimeters
+ */
+focal_range: ParameterRange, 
+/**
+ * Smallest relative margin over any requirement at the chosen focal length, in percent
+ */
+headroom_percent?: number | null, horizontal_fov_deg: number, dori: DoriDistances, };
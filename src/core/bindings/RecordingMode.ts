@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How much of the time a camera records
+ */
+export type RecordingMode = "Continuous" | { "Motion": { activity_percent: number, } };
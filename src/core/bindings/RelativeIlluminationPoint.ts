@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Relative illumination of a lens at one point in the field
+ */
+export type RelativeIlluminationPoint = { 
+/**
+ * Image height as a fraction of the sensor half-diagonal (0 = centre, 1 = corner)
+ */
+field_position: number, 
+/**
+ * Illumination as a percentage of the centre
+ */
+percent: number, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Scene size the camera must cover, for solving the working distance
+ */
+export type RequiredFov = { 
+/**
+ * Scene width to cover in meters (optional)
+ */
+width_m?: number | null, 
+/**
+ * Scene height to cover in meters (optional)
+ */
+height_m?: number | null, };
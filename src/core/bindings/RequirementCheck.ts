@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ComplianceStatus } from "./ComplianceStatus";
+
+/**
+ * Result of checking one requirement
+ */
+export type RequirementCheck = { 
+/**
+ * Human-readable requirement, e.g. "Identification at Entrance within 8 m"
+ */
+requirement: string, status: ComplianceStatus, 
+/**
+ * Required value, in `unit`
+ */
+required: number, 
+/**
+ * Value the camera achieves, in `unit`
+ */
+achieved?: number | null, unit: string, 
+/**
+ * How far the achieved value is better (positive) or worse (negative) than required
+ */
+margin_percent?: number | null, };
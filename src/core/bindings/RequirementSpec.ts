@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DoriRequirement } from "./DoriRequirement";
+import type { WidthRequirement } from "./WidthRequirement";
+
+/**
+ * Set of requirements a camera is checked against
+ */
+export type RequirementSpec = { name?: string | null, dori: Array<DoriRequirement>, coverage: Array<WidthRequirement>, 
+/**
+ * Minimum stream frame rate in frames per second
+ */
+min_fps?: number | null, 
+/**
+ * Maximum average stream bitrate in Mbps
+ */
+max_bandwidth_mbps?: number | null, };
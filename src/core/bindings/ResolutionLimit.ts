@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Which part of the camera system limits angular resolution
+ */
+export type ResolutionLimit = "Sensor" | "Optics";
@@ -0,0 +1,30 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Comparison of the sensor Nyquist frequency against the lens resolving power
+ */
+export type SamplingAnalysis = { 
+/**
+ * Horizontal pixel pitch in micrometers
+ */
+pixel_pitch_um: number, 
+/**
+ * Sensor Nyquist frequency in line pairs per millimeter
+ */
+nyquist_lp_mm: number, 
+/**
+ * Lens resolving power in line pairs per millimeter
+ */
+lens_resolution_lp_mm: number, 
+/**
+ * Lens resolving power divided by the Nyquist frequency
+ */
+lens_to_nyquist_ratio: number, 
+/**
+ * The lens resolves detail beyond Nyquist, so moiré/aliasing is likely
+ */
+aliasing_likely: boolean, 
+/**
+ * The lens resolves less than half of Nyquist, so sensor resolution is wasted
+ */
+lens_limited: boolean, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CoverageZone } from "./CoverageZone";
+import type { Obstacle } from "./Obstacle";
+import type { PlanPoint } from "./PlanPoint";
+
+/**
+ * Site plan: room outline, zones with their requirements, and obstacles
+ */
+export type Scene = { 
+/**
+ * Room or site outline; its walls block the view and only the parts of zones
+ * inside it are evaluated
+ */
+room: Array<PlanPoint>, zones: Array<CoverageZone>, 
+/**
+ * Structures that block the view in addition to the room walls
+ */
+obstacles: Array<Obstacle>, };
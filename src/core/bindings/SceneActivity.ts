@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Amount of motion in the scene, which drives inter-frame compression efficiency
+ */
+export type SceneActivity = "Low" | "Medium" | "High";
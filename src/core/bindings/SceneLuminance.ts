@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Brightest and darkest luminance in a scene that must both hold detail
+ */
+export type SceneLuminance = { 
+/**
+ * Brightest area in cd/m²
+ */
+bright_cd_m2: number, 
+/**
+ * Darkest area in cd/m²
+ */
+dark_cd_m2: number, };
@@ -0,0 +1,24 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Relative importance of each criterion when scoring compared cameras
+ *
+ * Weights are relative to each other; a weight of 0 ignores the criterion.
+ */
+export type ScoringWeights = { 
+/**
+ * Weight of the total pixel count
+ */
+resolution: number, 
+/**
+ * Weight of the horizontal field of view (wider is better)
+ */
+fov: number, 
+/**
+ * Weight of the low-light sensitivity
+ */
+low_light: number, 
+/**
+ * Weight of the DORI distances (further is better)
+ */
+dori_reach: number, };
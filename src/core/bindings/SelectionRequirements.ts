@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DoriTargets } from "./DoriTargets";
+
+/**
+ * Requirements a camera/lens combination must meet
+ */
+export type SelectionRequirements = { 
+/**
+ * Minimum DORI distances in meters
+ */
+dori: DoriTargets, 
+/**
+ * Widest acceptable horizontal field of view in degrees
+ */
+max_horizontal_fov_deg?: number | null, 
+/**
+ * Minimum frame width in meters at `near_distance_m`
+ */
+min_frame_width_m?: number | null, 
+/**
+ * Nearest distance in meters that must be covered by `min_frame_width_m`
+ */
+near_distance_m?: number | null, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RankedSelection } from "./RankedSelection";
+
+/**
+ * Feasible combinations, best first
+ */
+export type SelectionResult = { selections: Array<RankedSelection>, 
+/**
+ * Number of camera/lens combinations evaluated
+ */
+evaluated: number, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A standard optical format such as 1/2.8" or APS-C
+ */
+export type SensorFormat = { name: string, sensor_width_mm: number, sensor_height_mm: number, };
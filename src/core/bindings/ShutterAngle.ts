@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Exposure expressed as a rotary shutter angle at a frame rate, as on cinema cameras
+ *
+ * A 180° shutter at 24 fps exposes each frame for 1/48 s.
+ */
+export type ShutterAngle = { 
+/**
+ * Shutter angle in degrees (0-360)
+ */
+angle_deg: number, 
+/**
+ * Frame rate in frames per second
+ */
+frame_rate_fps: number, };
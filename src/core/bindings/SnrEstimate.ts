@@ -0,0 +1,38 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Per-pixel signal, noise and SNR at a given exposure
+ */
+export type SnrEstimate = { 
+/**
+ * Illuminance on the sensor in lux
+ */
+sensor_illuminance_lux: number, 
+/**
+ * Signal in electrons per pixel, limited by the full well
+ */
+signal_e: number, 
+/**
+ * Photon shot noise in electrons RMS
+ */
+shot_noise_e: number, 
+/**
+ * Dark current collected during the exposure in electrons
+ */
+dark_e: number, 
+/**
+ * Total noise in electrons RMS
+ */
+noise_e: number, 
+/**
+ * Signal-to-noise ratio
+ */
+snr: number, 
+/**
+ * Signal-to-noise ratio in dB (`None` without any signal, where it is -∞)
+ */
+snr_db: number | null, 
+/**
+ * Whether the pixel reached its full-well capacity
+ */
+saturated: boolean, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Scene and exposure for a photon-noise SNR estimate
+ */
+export type SnrParams = { 
+/**
+ * Scene illuminance in lux
+ */
+illuminance_lux: number, 
+/**
+ * Exposure time in seconds
+ */
+shutter_s: number, 
+/**
+ * Scene reflectance (0-1), 18% grey if not given
+ */
+reflectance?: number | null, };
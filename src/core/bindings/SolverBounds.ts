@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Plausible bounds on each parameter of the inverse DORI calculation
+ */
+export type SolverBounds = { min_pixel_width: number, max_pixel_width: number, min_sensor_width_mm: number, max_sensor_width_mm: number, min_focal_length_mm: number, max_focal_length_mm: number, 
+/**
+ * Width-to-height ratio for deriving heights when no width and height pair is fixed
+ */
+default_aspect_ratio: number, };
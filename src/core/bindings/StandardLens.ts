@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A standard fixed or varifocal lens
+ */
+export type StandardLens = { 
+/**
+ * e.g. "8 mm" or "2.8-12 mm"
+ */
+name: string, min_focal_length_mm: number, 
+/**
+ * Equal to the minimum for a fixed lens
+ */
+max_focal_length_mm: number, varifocal: boolean, };
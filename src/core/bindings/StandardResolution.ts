@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A purchasable sensor resolution such as 1080p or 4K
+ */
+export type StandardResolution = { name: string, pixel_width: number, pixel_height: number, };
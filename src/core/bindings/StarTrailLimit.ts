@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Longest exposure before stars trail on a fixed tripod
+ */
+export type StarTrailLimit = { 
+/**
+ * Declination of the stars in degrees (0 at the celestial equator)
+ */
+declination_deg: number, 
+/**
+ * Focal length multiplier relative to full frame
+ */
+crop_factor: number, 
+/**
+ * Pixel pitch in micrometers
+ */
+pixel_pitch_um: number, 
+/**
+ * Classic 500 rule: 500 / (focal_length × crop_factor), in seconds
+ */
+rule_500_s: number, 
+/**
+ * NPF rule: (35 × N + 30 × pixel_pitch) / focal_length, in seconds
+ */
+npf_s: number, };
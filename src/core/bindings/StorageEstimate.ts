@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { StreamStorage } from "./StreamStorage";
+
+/**
+ * Storage requirement for a whole system
+ */
+export type StorageEstimate = { 
+/**
+ * Retention period in days
+ */
+retention_days: number, 
+/**
+ * Per-group breakdown
+ */
+streams: Array<StreamStorage>, 
+/**
+ * Total storage in terabytes (10^12 bytes)
+ */
+total_tb: number, };
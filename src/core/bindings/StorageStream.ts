@@ -0,0 +1,24 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BitrateParams } from "./BitrateParams";
+import type { RecordingMode } from "./RecordingMode";
+
+/**
+ * A group of identical camera streams for storage estimation
+ */
+export type StorageStream = { 
+/**
+ * Optional name for identification
+ */
+name?: string | null, 
+/**
+ * Number of cameras with this stream configuration
+ */
+count: number, 
+/**
+ * Stream parameters used for the bitrate estimate
+ */
+stream: BitrateParams, 
+/**
+ * Recording mode
+ */
+recording_mode: RecordingMode, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Storage requirement for a single stream group
+ */
+export type StreamStorage = { name?: string | null, count: number, 
+/**
+ * Average bitrate of a single camera in Mbps
+ */
+average_mbps: number, 
+/**
+ * Fraction of the time the cameras record (0-1)
+ */
+recording_fraction: number, 
+/**
+ * Storage for all cameras in the group in terabytes
+ */
+storage_tb: number, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How strongly a camera blurs the background behind an in-focus subject
+ */
+export type SubjectIsolation = { 
+/**
+ * Blur disc diameter of a background point on the sensor in millimeters
+ */
+blur_disc_mm: number, 
+/**
+ * Blur disc as a percentage of the image width, comparable across formats
+ */
+blur_percent_of_width: number, 
+/**
+ * Blur as a percentage of the most blurred background in the comparison
+ */
+score: number, };
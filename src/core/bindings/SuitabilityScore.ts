@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Weighted suitability of a camera within a comparison
+ *
+ * Each criterion is scored 0-100 relative to the best camera in the comparison.
+ */
+export type SuitabilityScore = { 
+/**
+ * Weighted score from 0 to 100
+ */
+score: number, 
+/**
+ * Rank within the comparison (1 = most suitable)
+ */
+rank: number, resolution: number, fov: number, 
+/**
+ * 0 when the camera has no f-number
+ */
+low_light: number, dori_reach: number, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Rectangular area to survey, flown along its length
+ */
+export type SurveyArea = { 
+/**
+ * Extent across the flight lines in metres
+ */
+width_m: number, 
+/**
+ * Extent along the flight lines in metres
+ */
+length_m: number, };
@@ -0,0 +1,32 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CameraSystem } from "./CameraSystem";
+import type { SurveyArea } from "./SurveyArea";
+
+/**
+ * Input for photogrammetry mission planning
+ */
+export type SurveyParams = { 
+/**
+ * Nadir-pointing camera, long side across the flight direction
+ */
+camera: CameraSystem, 
+/**
+ * Flight altitude above ground in metres
+ */
+altitude_m: number, 
+/**
+ * Ground speed in metres per second
+ */
+ground_speed_m_s: number, 
+/**
+ * Overlap between consecutive images along a line, in percent
+ */
+forward_overlap_percent: number, 
+/**
+ * Overlap between neighbouring flight lines, in percent
+ */
+side_overlap_percent: number, 
+/**
+ * Area to cover; enables the line and image counts
+ */
+area?: SurveyArea | null, };
@@ -0,0 +1,30 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Exposure spacing, line spacing and image count for a survey
+ */
+export type SurveyPlan = { 
+/**
+ * Ground sample distance in centimeters per pixel
+ */
+gsd_cm: number, 
+/**
+ * Image footprint across the flight direction in metres
+ */
+footprint_width_m: number, 
+/**
+ * Image footprint along the flight direction in metres
+ */
+footprint_length_m: number, 
+/**
+ * Distance flown between exposures in metres
+ */
+photo_base_m: number, 
+/**
+ * Time between exposures at the ground speed in seconds
+ */
+shutter_interval_s: number, 
+/**
+ * Distance between neighbouring flight lines in metres
+ */
+line_spacing_m: number, flight_lines?: number | null, images_per_line?: number | null, total_images?: number | null, };
@@ -0,0 +1,44 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DoriLevel } from "./DoriLevel";
+import type { ImagePoint } from "./ImagePoint";
+
+/**
+ * How well a placed camera sees a target at a position on the site
+ */
+export type TargetEvaluation = { 
+/**
+ * Line-of-sight distance from the camera to the target in metres
+ */
+slant_distance_m: number, 
+/**
+ * Horizontal pixel density on the target in px/m, 0 behind the camera
+ */
+horizontal_ppm: number, 
+/**
+ * Vertical pixel density on the target in px/m, 0 behind the camera
+ */
+vertical_ppm: number, 
+/**
+ * Highest DORI level reached, `None` below detection or outside the frame
+ */
+achieved_level?: DoriLevel | null, 
+/**
+ * Pixels across the width of the target
+ */
+pixels_horizontal: number, 
+/**
+ * Pixels along the height of the target, foreshortened by the incidence angle
+ */
+pixels_vertical: number, 
+/**
+ * Angle below horizontal of the line of sight onto the target in degrees
+ */
+incidence_deg: number, 
+/**
+ * Target position in the image, `None` behind the camera
+ */
+image_point?: ImagePoint | null, 
+/**
+ * The target position lies inside the image
+ */
+in_view: boolean, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Width and height of a target, e.g. 0.5 x 1.7 m for a person
+ */
+export type TargetSize = { width_m: number, height_m: number, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Telescope used as the camera's lens or with an eyepiece
+ */
+export type Telescope = { 
+/**
+ * Clear aperture in millimeters
+ */
+aperture_mm: number, 
+/**
+ * Focal length in millimeters, including any reducer or Barlow
+ */
+focal_length_mm: number, name?: string | null, };
@@ -0,0 +1,27 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EyepieceView } from "./EyepieceView";
+
+/**
+ * Optical properties of a telescope
+ */
+export type TelescopeAnalysis = { 
+/**
+ * Focal ratio (f-number)
+ */
+focal_ratio: number, 
+/**
+ * Lowest magnification whose exit pupil still fits a dark-adapted eye
+ */
+min_useful_magnification: number, 
+/**
+ * Highest magnification before the image only gets bigger, not sharper
+ */
+max_useful_magnification: number, 
+/**
+ * Dawes limit for resolving double stars in arcseconds
+ */
+dawes_limit_arcsec: number, 
+/**
+ * View through the eyepiece, if one was given
+ */
+eyepiece?: EyepieceView | null, };
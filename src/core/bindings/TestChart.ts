@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Standard resolution test chart
+ */
+export type TestChart = "Iso12233" | "Usaf1951";
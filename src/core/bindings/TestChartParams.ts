@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CameraSystem } from "./CameraSystem";
+import type { ChartFraming } from "./ChartFraming";
+import type { TestChart } from "./TestChart";
+
+/**
+ * Input for the test chart distance calculation
+ */
+export type TestChartParams = { camera: CameraSystem, chart: TestChart, 
+/**
+ * Width of the chart's active area in millimeters
+ */
+chart_width_mm: number, 
+/**
+ * Height of the chart's active area in millimeters
+ */
+chart_height_mm: number, framing: ChartFraming, };
@@ -0,0 +1,31 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { UsafElement } from "./UsafElement";
+
+/**
+ * Chart placement for verifying a camera's resolution in the lab
+ */
+export type TestChartSetup = { 
+/**
+ * Distance from the lens to the chart in millimeters
+ */
+working_distance_mm: number, magnification: number, 
+/**
+ * Fraction of the frame width the chart fills
+ */
+width_fraction: number, 
+/**
+ * Fraction of the frame height the chart fills
+ */
+height_fraction: number, 
+/**
+ * Sensor Nyquist limit projected onto the chart, in line pairs per mm
+ */
+chart_nyquist_lp_mm: number, 
+/**
+ * Finest USAF 1951 element within the Nyquist limit
+ */
+usaf_limit?: UsafElement | null, 
+/**
+ * Factor converting ISO 12233 chart readings to LW/PH of the full frame
+ */
+iso_reading_scale?: number | null, };
@@ -0,0 +1,30 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Represents a thermal (LWIR/MWIR) camera with a microbolometer or cooled detector
+ */
+export type ThermalCameraSystem = { 
+/**
+ * Detector pixel pitch in micrometers (typically 12, 17 or 25 µm)
+ */
+detector_pitch_um: number, 
+/**
+ * Horizontal detector pixel count
+ */
+pixel_width: number, 
+/**
+ * Vertical detector pixel count
+ */
+pixel_height: number, 
+/**
+ * Lens focal length in millimeters
+ */
+focal_length_mm: number, 
+/**
+ * Noise equivalent temperature difference in millikelvin
+ */
+netd_mk: number, 
+/**
+ * Optional name for identification
+ */
+name?: string | null, };
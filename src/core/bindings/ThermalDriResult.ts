@@ -0,0 +1,32 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JohnsonDriDistances } from "./JohnsonDriDistances";
+import type { NatoTarget } from "./NatoTarget";
+
+/**
+ * Results of thermal DRI calculations for a single NATO target
+ */
+export type ThermalDriResult = { 
+/**
+ * Target the ranges were calculated for
+ */
+target: NatoTarget, 
+/**
+ * Critical dimension of the target in meters
+ */
+critical_dimension_m: number, 
+/**
+ * Horizontal field of view in degrees
+ */
+horizontal_fov_deg: number, 
+/**
+ * Vertical field of view in degrees
+ */
+vertical_fov_deg: number, 
+/**
+ * Instantaneous field of view of a single detector pixel in milliradians
+ */
+ifov_mrad: number, 
+/**
+ * DRI ranges for this target
+ */
+dri: JohnsonDriDistances, };
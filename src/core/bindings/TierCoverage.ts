@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DoriLevel } from "./DoriLevel";
+
+/**
+ * Share of a zone's area that reaches a DORI level
+ */
+export type TierCoverage = { level: DoriLevel, 
+/**
+ * Share of the zone's area (within the room) at or above the level's density
+ */
+covered_percent: number, };
@@ -0,0 +1,43 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Plane of focus and depth of field wedge of a tilted lens
+ */
+export type TiltedFocus = { 
+/**
+ * Distance from the lens to the hinge line, J = f / sin(tilt), in millimeters
+ */
+hinge_distance_mm: number, 
+/**
+ * Angle between the plane of focus and the camera axis in degrees
+ */
+focus_plane_angle_deg: number, 
+/**
+ * Angle between the plane of focus and the sensor plane in degrees
+ */
+focus_plane_to_sensor_deg: number, 
+/**
+ * Angle between the near DOF limit and the camera axis in degrees
+ */
+near_limit_angle_deg: number, 
+/**
+ * Angle between the far DOF limit and the camera axis in degrees; negative when
+ * it tilts away from the hinge
+ */
+far_limit_angle_deg: number, 
+/**
+ * Opening angle of the DOF wedge in degrees
+ */
+wedge_angle_deg: number, 
+/**
+ * Where the near DOF limit crosses the camera axis, in millimeters
+ */
+near_limit_on_axis_mm: number, 
+/**
+ * Where the far DOF limit crosses the camera axis; `None` if it never does
+ */
+far_limit_on_axis_mm?: number | null, 
+/**
+ * Depth of field across the camera axis at the focus distance, in millimeters
+ */
+depth_at_focus_mm: number, };
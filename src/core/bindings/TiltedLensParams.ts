@@ -0,0 +1,21 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Input for the tilted-lens (Scheimpflug) depth of field model
+ *
+ * The lens is tilted about an axis parallel to the sensor; angles are measured in
+ * the plane of the tilt.
+ */
+export type TiltedLensParams = { focal_length_mm: number, 
+/**
+ * Lens tilt relative to the sensor plane in degrees
+ */
+tilt_deg: number, f_number: number, 
+/**
+ * Circle of confusion in millimeters
+ */
+coc_mm: number, 
+/**
+ * Distance along the camera axis at which the plane of focus crosses it, in millimeters
+ */
+focus_distance_mm: number, };
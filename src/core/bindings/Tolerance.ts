@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ToleranceDistribution } from "./ToleranceDistribution";
+
+/**
+ * Tolerance on a single input parameter, in the parameter's own units
+ */
+export type Tolerance = { distribution: ToleranceDistribution, 
+/**
+ * Standard deviation (normal) or half-width (uniform)
+ */
+amount: number, };
@@ -0,0 +1,40 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CameraSystem } from "./CameraSystem";
+import type { Tolerance } from "./Tolerance";
+
+/**
+ * Input for Monte Carlo tolerance analysis
+ */
+export type ToleranceAnalysisParams = { 
+/**
+ * Nominal camera system
+ */
+camera: CameraSystem, 
+/**
+ * Nominal mounting distance in millimeters
+ */
+distance_mm: number, 
+/**
+ * Focal length tolerance in millimeters
+ */
+focal_length_mm?: Tolerance | null, 
+/**
+ * Sensor width tolerance in millimeters
+ */
+sensor_width_mm?: Tolerance | null, 
+/**
+ * Sensor height tolerance in millimeters
+ */
+sensor_height_mm?: Tolerance | null, 
+/**
+ * Mounting distance tolerance in millimeters
+ */
+distance_tolerance_mm?: Tolerance | null, 
+/**
+ * Number of samples to draw
+ */
+samples: number, 
+/**
+ * Random seed, for reproducible runs
+ */
+seed?: number | null, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PercentileBand } from "./PercentileBand";
+
+/**
+ * Spread of FOV and DORI outputs over all Monte Carlo samples
+ */
+export type ToleranceAnalysisResult = { 
+/**
+ * Number of samples evaluated
+ */
+samples: number, horizontal_fov_deg: PercentileBand, vertical_fov_deg: PercentileBand, horizontal_fov_m: PercentileBand, vertical_fov_m: PercentileBand, horizontal_ppm: PercentileBand, detection_m: PercentileBand, observation_m: PercentileBand, recognition_m: PercentileBand, identification_m: PercentileBand, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A result with its propagated uncertainty
+ */
+export type UncertainValue = { value: number, 
+/**
+ * ± uncertainty in the same units as the value
+ */
+uncertainty: number, 
+/**
+ * Uncertainty as a percentage of the value
+ */
+relative_percent: number, };
@@ -0,0 +1,34 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CameraSystem } from "./CameraSystem";
+
+/**
+ * Input for analytic uncertainty propagation
+ *
+ * Uncertainties are ± values in the same units as the nominal value; results carry
+ * the same confidence (e.g. 1σ in gives 1σ out).
+ */
+export type UncertaintyParams = { 
+/**
+ * Nominal camera system
+ */
+camera: CameraSystem, 
+/**
+ * Nominal mounting distance in millimeters
+ */
+distance_mm: number, 
+/**
+ * Focal length uncertainty in millimeters
+ */
+focal_length_mm?: number | null, 
+/**
+ * Sensor width uncertainty in millimeters
+ */
+sensor_width_mm?: number | null, 
+/**
+ * Sensor height uncertainty in millimeters
+ */
+sensor_height_mm?: number | null, 
+/**
+ * Mounting distance uncertainty in millimeters
+ */
+distance_uncertainty_mm?: number | null, };
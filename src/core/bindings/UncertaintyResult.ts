@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { UncertainValue } from "./UncertainValue";
+
+/**
+ * FOV, pixel density and DORI results with error bars
+ */
+export type UncertaintyResult = { horizontal_fov_deg: UncertainValue, vertical_fov_deg: UncertainValue, horizontal_fov_m: UncertainValue, vertical_fov_m: UncertainValue, horizontal_ppm: UncertainValue, vertical_ppm: UncertainValue, detection_m: UncertainValue, observation_m: UncertainValue, recognition_m: UncertainValue, identification_m: UncertainValue, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A USAF 1951 group and element
+ */
+export type UsafElement = { group: number, element: number, 
+/**
+ * Resolution of the element in line pairs per mm
+ */
+line_pairs_per_mm: number, };
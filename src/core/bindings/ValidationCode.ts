@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Stable identifier of a validation check
+ */
+export type ValidationCode = "SensorWidthTooSmall" | "SensorWidthTooLarge" | "SensorHeightTooSmall" | "SensorHeightTooLarge" | "FocalLengthTooShort" | "FocalLengthTooLong" | "PixelWidthTooLow" | "PixelWidthTooHigh" | "PixelHeightTooLow" | "PixelHeightTooHigh" | "PixelPitchTooSmall" | "PixelPitchTooLarge" | "TStopFasterThanFNumber" | "AspectRatioMismatch" | "NonSquarePixels" | "QuantumEfficiencyOutOfRange" | "NegativeSensorValue" | "ReadNoiseAboveFullWell" | "FovTooWide" | "FovTooNarrow" | "PpmTooHigh" | "PpmTooLow" | "DetectionDistanceUnrealistic" | "DoriOrderInvalid" | "LensAliasing" | "LensLimited" | "AstroUndersampled" | "AstroOversampled" | "AltitudeAboveCeiling" | "CornerIlluminationLow" | "FaceIncidenceTooSteep" | "SensorSizeAssumed" | "FocalLengthInterpolated" | "FocalLengthAssumed" | "LinkCapacityExceeded" | "PeakLinkCapacityExceeded" | "RequirementNotMet" | "RequirementNotEvaluated" | "DoriTargetMissed" | "DoriTargetMet" | "ConstraintsInconsistent" | "DoriTargetsInconsistent" | "DoriTargetsInfeasible" | "DoriTargetMissing" | "DoriTargetNotPositive" | "ConstraintNotPositive" | "FovOutOfRange" | "DoriTierUnreachable";
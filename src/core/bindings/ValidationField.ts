@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Input field referenced by a validation warning
+ */
+export type ValidationField = { 
+/**
+ * Field name as serialized, e.g. `sensor_width_mm`
+ */
+field: string, value: number, };
@@ -0,0 +1,30 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MessageKey } from "./MessageKey";
+import type { ValidationCode } from "./ValidationCode";
+import type { ValidationField } from "./ValidationField";
+import type { ValidationSeverity } from "./ValidationSeverity";
+
+/**
+ * Validation warning for camera system
+ */
+export type ValidationWarning = { 
+/**
+ * Stable identifier of the check, for highlighting inputs and in tests
+ */
+code: ValidationCode, message: string, severity: ValidationSeverity, 
+/**
+ * Input fields the warning refers to, with their offending values
+ */
+fields: Array<ValidationField>, 
+/**
+ * Concrete change that resolves the warning, e.g. a focal length to use
+ */
+suggestion?: string | null, 
+/**
+ * Key and parameters `message` was rendered from, for translation
+ */
+message_key?: MessageKey | null, 
+/**
+ * Key and parameters `suggestion` was rendered from, for translation
+ */
+suggestion_key?: MessageKey | null, };
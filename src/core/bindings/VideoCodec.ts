@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Video compression codec
+ */
+export type VideoCodec = "H264" | "H265" | "Mjpeg";
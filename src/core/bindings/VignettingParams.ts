@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CameraSystem } from "./CameraSystem";
+import type { RelativeIlluminationPoint } from "./RelativeIlluminationPoint";
+
+/**
+ * Input for the illumination falloff calculation
+ */
+export type VignettingParams = { camera: CameraSystem, 
+/**
+ * Measured relative illumination of the lens; replaces the cos⁴ model, since
+ * datasheet curves already include it
+ */
+lens_ri_curve?: Array<RelativeIlluminationPoint> | null, 
+/**
+ * Lowest acceptable corner illumination in percent of the centre (default: 50)
+ */
+min_corner_percent?: number | null, };
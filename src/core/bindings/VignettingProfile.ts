@@ -0,0 +1,34 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Illumination falloff from the centre to the corner of the image
+ */
+export type VignettingProfile = { 
+/**
+ * Sampled image heights as fractions of the half-diagonal
+ */
+field_positions: Array<number>, 
+/**
+ * Field angle at each sample in degrees
+ */
+field_angles_deg: Array<number>, 
+/**
+ * Illumination at each sample in percent of the centre
+ */
+relative_illumination_percent: Array<number>, 
+/**
+ * Illumination at the middle of the left/right edges in percent
+ */
+edge_percent: number, 
+/**
+ * Illumination in the corners in percent
+ */
+corner_percent: number, 
+/**
+ * Corner falloff in stops
+ */
+corner_falloff_stops: number, min_corner_percent: number, 
+/**
+ * The corners are darker than the threshold
+ */
+below_threshold: boolean, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SceneLuminance } from "./SceneLuminance";
+import type { WdrScenePreset } from "./WdrScenePreset";
+
+/**
+ * Scene whose dynamic range is assessed, given as a preset or as measured luminances
+ */
+export type WdrScene = { "Preset": WdrScenePreset } | { "Custom": SceneLuminance };
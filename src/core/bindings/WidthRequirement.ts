@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DoriLevel } from "./DoriLevel";
+
+/**
+ * Width the camera must cover at a distance, e.g. observation across a 30 m lot
+ */
+export type WidthRequirement = { 
+/**
+ * Where the requirement applies, e.g. "Car park"
+ */
+label?: string | null, 
+/**
+ * Minimum frame width in metres
+ */
+width_m: number, 
+/**
+ * Distance in metres at which the width is measured
+ */
+distance_m: number, 
+/**
+ * DORI level that must also be reached across the width
+ */
+level?: DoriLevel | null, };
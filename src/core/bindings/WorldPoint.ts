@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Point in site coordinates, with height above the plane of interest
+ */
+export type WorldPoint = { 
+/**
+ * East offset from the site origin in metres
+ */
+x_m: number, 
+/**
+ * North offset from the site origin in metres
+ */
+y_m: number, 
+/**
+ * Height above the plane of interest in metres
+ */
+z_m: number, };
@@ -0,0 +1,27 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How well a zone reaches its required pixel density
+ */
+export type ZoneCoverage = { name: string, 
+/**
+ * Required horizontal pixel density in px/m
+ */
+required_ppm: number, 
+/**
+ * Share of the zone's area (within the room) that meets the requirement
+ */
+covered_percent: number, 
+/**
+ * Lowest pixel density over the zone from the best camera at each point;
+ * 0 where a point is not seen at all
+ */
+worst_ppm: number, 
+/**
+ * How far the worst point is above (positive) or below (negative) the requirement
+ */
+margin_percent: number, 
+/**
+ * Whether the whole zone meets the requirement
+ */
+meets_requirement: boolean, };
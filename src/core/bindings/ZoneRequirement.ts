@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DoriLevel } from "./DoriLevel";
+
+/**
+ * Pixel density a zone must reach, given directly or as a DORI level
+ */
+export type ZoneRequirement = { "PixelDensity": number } | { "Dori": DoriLevel };
@@ -0,0 +1,21 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PercentileBand } from "./PercentileBand";
+import type { TierCoverage } from "./TierCoverage";
+
+/**
+ * Distribution of pixel density over a zone
+ */
+export type ZoneStatistics = { name: string, 
+/**
+ * Number of grid points the zone was sampled at
+ */
+samples: number, 
+/**
+ * Spread of the best pixel density at each point in px/m, 0 where a point is
+ * not seen; `None` when no part of the zone lies inside the room
+ */
+ppm?: PercentileBand | null, 
+/**
+ * Area reaching each level, from detection to identification
+ */
+tiers: Array<TierCoverage>, };
@@ -0,0 +1,34 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * FOV and DORI distance series across a zoom range, for coverage-vs-reach charts
+ */
+export type ZoomCurve = { 
+/**
+ * Sample focal lengths in millimeters
+ */
+focal_lengths_mm: Array<number>, 
+/**
+ * Horizontal field of view in degrees at each focal length
+ */
+horizontal_fov_deg: Array<number>, 
+/**
+ * Vertical field of view in degrees at each focal length
+ */
+vertical_fov_deg: Array<number>, 
+/**
+ * Detection distance in meters at each focal length
+ */
+detection_m: Array<number>, 
+/**
+ * Observation distance in meters at each focal length
+ */
+observation_m: Array<number>, 
+/**
+ * Recognition distance in meters at each focal length
+ */
+recognition_m: Array<number>, 
+/**
+ * Identification distance in meters at each focal length
+ */
+identification_m: Array<number>, };
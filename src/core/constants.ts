@@ -64,6 +64,43 @@ export const CAMERA_PRESETS: Record<string, Partial<CameraSystem>> = {
     focal_length_mm: 25,
     name: 'Micro 4/3 25mm',
   },
+  // Smartphone modules at their binned 12 MP output; names give the 35 mm equivalent
+  'phone-main': {
+    sensor_width_mm: 9.8,
+    sensor_height_mm: 7.35,
+    pixel_width: 4032,
+    pixel_height: 3024,
+    focal_length_mm: 6.86,
+    f_number: 1.8,
+    name: 'Phone main 24mm eq.',
+  },
+  'phone-ultrawide': {
+    sensor_width_mm: 5.6,
+    sensor_height_mm: 4.2,
+    pixel_width: 4032,
+    pixel_height: 3024,
+    focal_length_mm: 2.22,
+    f_number: 2.2,
+    name: 'Phone ultrawide 14mm eq.',
+  },
+  'phone-tele': {
+    sensor_width_mm: 4.0,
+    sensor_height_mm: 3.0,
+    pixel_width: 4032,
+    pixel_height: 3024,
+    focal_length_mm: 9.0,
+    f_number: 2.8,
+    name: 'Phone tele 3x 78mm eq.',
+  },
+  'phone-periscope': {
+    sensor_width_mm: 4.7,
+    sensor_height_mm: 3.52,
+    pixel_width: 4032,
+    pixel_height: 3024,
+    focal_length_mm: 16.3,
+    f_number: 2.8,
+    name: 'Phone periscope 5x 120mm eq.',
+  },
 };
 
 // Visualization colors for camera systems
@@ -1,44 +1,191 @@
 /**
  * Camera system and calculation types
+ *
+ * Backend payload types are generated from the Rust structs by ts-rs into
+ * ./bindings. Run `cargo test` in src-tauri to regenerate them after changing
+ * a Rust type; do not edit the generated files by hand.
  */
 
-export interface CameraSystem {
-  sensor_width_mm: number;
-  sensor_height_mm: number;
-  pixel_width: number;
-  pixel_height: number;
-  focal_length_mm: number;
-  name?: string;
-}
+export type { CameraSystem } from './bindings/CameraSystem';
+export type { FovResult } from './bindings/FovResult';
+export type { AngleOfView } from './bindings/AngleOfView';
+export type { DoriDistances } from './bindings/DoriDistances';
+export type { CameraWithResult } from './bindings/CameraWithResult';
+export type { LowLightScore } from './bindings/LowLightScore';
+export type { NightMode } from './bindings/NightMode';
+export type { NightModeResult } from './bindings/NightModeResult';
+export type { SnrParams } from './bindings/SnrParams';
+export type { SnrEstimate } from './bindings/SnrEstimate';
+export type { ScoringWeights } from './bindings/ScoringWeights';
+export type { SuitabilityScore } from './bindings/SuitabilityScore';
+export type { IsolationScenario } from './bindings/IsolationScenario';
+export type { SubjectIsolation } from './bindings/SubjectIsolation';
+export type { FormatEquivalence } from './bindings/FormatEquivalence';
+export type { MetricDelta } from './bindings/MetricDelta';
+export type { CameraDiff } from './bindings/CameraDiff';
+export type { ValidationWarning } from './bindings/ValidationWarning';
+export type { ValidationSeverity } from './bindings/ValidationSeverity';
+export type { ValidationCode } from './bindings/ValidationCode';
+export type { ValidationField } from './bindings/ValidationField';
+export type { Locale } from './bindings/Locale';
+export type { MessageKey } from './bindings/MessageKey';
+export type { MessageArg } from './bindings/MessageArg';
+export type { MessageValue } from './bindings/MessageValue';
+export type { PpmCurve } from './bindings/PpmCurve';
+export type { ZoomCurve } from './bindings/ZoomCurve';
+export type { RequiredFov } from './bindings/RequiredFov';
+export type { TiltedLensParams } from './bindings/TiltedLensParams';
+export type { TiltedFocus } from './bindings/TiltedFocus';
+export type { LensPupils } from './bindings/LensPupils';
+export type { NoParallaxParams } from './bindings/NoParallaxParams';
+export type { NoParallaxPoint } from './bindings/NoParallaxPoint';
+export type { RelativeIlluminationPoint } from './bindings/RelativeIlluminationPoint';
+export type { VignettingParams } from './bindings/VignettingParams';
+export type { VignettingProfile } from './bindings/VignettingProfile';
+export type { SceneLuminance } from './bindings/SceneLuminance';
+export type { WdrScenePreset } from './bindings/WdrScenePreset';
+export type { WdrScene } from './bindings/WdrScene';
+export type { WdrVerdict } from './bindings/WdrVerdict';
+export type { DynamicRangeAssessment } from './bindings/DynamicRangeAssessment';
 
-export interface FovResult {
-  horizontal_fov_deg: number;
-  vertical_fov_deg: number;
-  horizontal_fov_m: number;
-  vertical_fov_m: number;
-  horizontal_ppm: number;
-  vertical_ppm: number;
-  distance_m: number;
-  dori?: DoriDistances;
-}
+// Types for Monte Carlo tolerance analysis
+export type { Tolerance } from './bindings/Tolerance';
+export type { ToleranceDistribution } from './bindings/ToleranceDistribution';
+export type { ToleranceAnalysisParams } from './bindings/ToleranceAnalysisParams';
+export type { ToleranceAnalysisResult } from './bindings/ToleranceAnalysisResult';
+export type { PercentileBand } from './bindings/PercentileBand';
+export type { UncertaintyParams } from './bindings/UncertaintyParams';
+export type { UncertainValue } from './bindings/UncertainValue';
+export type { UncertaintyResult } from './bindings/UncertaintyResult';
 
-export interface DoriDistances {
-  detection_m: number;
-  observation_m: number;
-  recognition_m: number;
-  identification_m: number;
-}
+// Types for image preview downsampling
+export type { ImageDownsampleParams } from './bindings/ImageDownsampleParams';
+export type { ImageDownsampleResult } from './bindings/ImageDownsampleResult';
 
-export interface CameraWithResult {
-  camera: CameraSystem;
-  result: FovResult;
-}
+// Types for inverse DORI calculation workflow
+export type { DoriTargets } from './bindings/DoriTargets';
+export type { ParameterRange } from './bindings/ParameterRange';
+export type { ParameterConstraint } from './bindings/ParameterConstraint';
+export type { SolverBounds } from './bindings/SolverBounds';
+export type { DoriParameterRanges } from './bindings/DoriParameterRanges';
+export type { GoverningTarget } from './bindings/GoverningTarget';
+export type { ConstraintInconsistency } from './bindings/ConstraintInconsistency';
+export type { BindingConstraint } from './bindings/BindingConstraint';
+export type { RangeLimit } from './bindings/RangeLimit';
+export type { RangeParameter } from './bindings/RangeParameter';
+export type { StandardResolution } from './bindings/StandardResolution';
+export type { StandardLens } from './bindings/StandardLens';
+export type { SensorFormat } from './bindings/SensorFormat';
 
-export interface ValidationWarning {
-  message: string;
-  severity: 'Warning' | 'Error';
-}
+// Types for the project held by the backend
+export type { Project } from './bindings/Project';
+export type { ProjectCamera } from './bindings/ProjectCamera';
+export type { ProjectResults } from './bindings/ProjectResults';
+export type { ProjectSettings } from './bindings/ProjectSettings';
+
+// Types for long-running computations and the calculation cache
+export type { ComputationProgress } from './bindings/ComputationProgress';
+export type { CacheStats } from './bindings/CacheStats';
+
+// Types for diagnostics exported with bug reports
+export type { LogRecord } from './bindings/LogRecord';
+export type { DiagnosticsReport } from './bindings/DiagnosticsReport';
+
+// Types for constraint-based camera selection
+export type { CandidateCamera } from './bindings/CandidateCamera';
+export type { CandidateLens } from './bindings/CandidateLens';
+export type { SelectionRequirements } from './bindings/SelectionRequirements';
+export type { RankedSelection } from './bindings/RankedSelection';
+export type { SelectionResult } from './bindings/SelectionResult';
+export type { CoverageScene } from './bindings/CoverageScene';
+export type { CoverageDesign } from './bindings/CoverageDesign';
+
+// Types for site coverage and camera placement
+export type { PlanPoint } from './bindings/PlanPoint';
+export type { CameraPose } from './bindings/CameraPose';
+export type { PlacedCamera } from './bindings/PlacedCamera';
+export type { Scene } from './bindings/Scene';
+export type { CoverageZone } from './bindings/CoverageZone';
+export type { ZoneRequirement } from './bindings/ZoneRequirement';
+export type { DoriLevel } from './bindings/DoriLevel';
+export type { ZoneCoverage } from './bindings/ZoneCoverage';
+export type { TierCoverage } from './bindings/TierCoverage';
+export type { ZoneStatistics } from './bindings/ZoneStatistics';
+export type { Obstacle } from './bindings/Obstacle';
+export type { MountPoint } from './bindings/MountPoint';
+export type { PlacementRequest } from './bindings/PlacementRequest';
+export type { PlacementResult } from './bindings/PlacementResult';
+export type { CorridorSolution } from './bindings/CorridorSolution';
+export type { AimingRequest } from './bindings/AimingRequest';
+export type { AimingResult } from './bindings/AimingResult';
+export type { TargetSize } from './bindings/TargetSize';
+export type { TargetEvaluation } from './bindings/TargetEvaluation';
+export type { FaceIncidenceAnalysis } from './bindings/FaceIncidenceAnalysis';
+export type { MountingHeightLimit } from './bindings/MountingHeightLimit';
+export type { GroundDoriTier } from './bindings/GroundDoriTier';
+export type { GroundDoriDistances } from './bindings/GroundDoriDistances';
+export type { PrivacyBoundary } from './bindings/PrivacyBoundary';
+export type { WorldPoint } from './bindings/WorldPoint';
+export type { ExclusionZone } from './bindings/ExclusionZone';
+export type { ImagePoint } from './bindings/ImagePoint';
+export type { PrivacyMask } from './bindings/PrivacyMask';
+export type { PrivacyMaskSet } from './bindings/PrivacyMaskSet';
+
+// Types for licence plate, text and barcode reading
+export type { PlateRegion } from './bindings/PlateRegion';
+export type { PlateSpec } from './bindings/PlateSpec';
+export type { AlprRequirement } from './bindings/AlprRequirement';
+export type { AlprParams } from './bindings/AlprParams';
+export type { ShutterAngle } from './bindings/ShutterAngle';
+export type { MotionBlurCheck } from './bindings/MotionBlurCheck';
+export type { AlprResult } from './bindings/AlprResult';
+export type { LegibilityParams } from './bindings/LegibilityParams';
+export type { LegibilityResult } from './bindings/LegibilityResult';
+export type { CodeSymbology } from './bindings/CodeSymbology';
+export type { BarcodeParams } from './bindings/BarcodeParams';
+export type { BarcodeResult } from './bindings/BarcodeResult';
+
+// Types for machine-vision inspection
+export type { InspectionRequirements } from './bindings/InspectionRequirements';
+export type { InspectionOption } from './bindings/InspectionOption';
+export type { InspectionSolution } from './bindings/InspectionSolution';
+export type { TestChart } from './bindings/TestChart';
+export type { ChartFraming } from './bindings/ChartFraming';
+export type { TestChartParams } from './bindings/TestChartParams';
+export type { UsafElement } from './bindings/UsafElement';
+export type { MicroscopeObjective } from './bindings/MicroscopeObjective';
+export type { MicroscopyAnalysis } from './bindings/MicroscopyAnalysis';
+export type { TestChartSetup } from './bindings/TestChartSetup';
+export type { CalibrationPattern } from './bindings/CalibrationPattern';
+export type { CalibrationTargetParams } from './bindings/CalibrationTargetParams';
+export type { CalibrationTarget } from './bindings/CalibrationTarget';
+export type { DrawingFormat } from './bindings/DrawingFormat';
+export type { DrawingRequest } from './bindings/DrawingRequest';
+
+// Types for astrophotography
+export type { AstroSampling } from './bindings/AstroSampling';
+export type { StarTrailLimit } from './bindings/StarTrailLimit';
+export type { Telescope } from './bindings/Telescope';
+export type { Eyepiece } from './bindings/Eyepiece';
+export type { EyepieceView } from './bindings/EyepieceView';
+export type { TelescopeAnalysis } from './bindings/TelescopeAnalysis';
+
+// Types for drone mapping
+export type { FlightAltitudeParams } from './bindings/FlightAltitudeParams';
+export type { FlightAltitude } from './bindings/FlightAltitude';
+export type { SurveyArea } from './bindings/SurveyArea';
+export type { SurveyParams } from './bindings/SurveyParams';
+export type { SurveyPlan } from './bindings/SurveyPlan';
 
+// Types for requirement compliance
+export type { RequirementSpec } from './bindings/RequirementSpec';
+export type { DoriRequirement } from './bindings/DoriRequirement';
+export type { WidthRequirement } from './bindings/WidthRequirement';
+export type { ComplianceStatus } from './bindings/ComplianceStatus';
+export type { RequirementCheck } from './bindings/RequirementCheck';
+export type { ComplianceReport } from './bindings/ComplianceReport';
+
+// Frontend-only types
 export interface ReferenceObject {
   id: string;
   name: string;
@@ -49,56 +196,3 @@ export interface ReferenceObject {
   description: string;
   iconPath?: string; // Optional path to custom SVG/image icon
 }
-
-// Types for image preview downsampling
-export interface ImageDownsampleParams {
-  horizontal_ppm: number;
-  vertical_ppm: number;
-  image_real_world_width_m: number;
-  original_width_px: number;
-  original_height_px: number;
-  max_display_size: number;
-}
-
-export interface ImageDownsampleResult {
-  camera_pixels_h: number;
-  camera_pixels_v: number;
-  scale: number;
-  display_width: number;
-  display_height: number;
-  scene_width_mm: number;
-  scene_height_mm: number;
-  downsample_ratio_h: number;
-  downsample_ratio_v: number;
-}
-
-// Types for inverse DORI calculation workflow
-export interface DoriTargets {
-  detection_m?: number;
-  observation_m?: number;
-  recognition_m?: number;
-  identification_m?: number;
-}
-
-export interface ParameterRange {
-  min: number;
-  max: number;
-}
-
-export interface ParameterConstraint {
-  sensor_width_mm?: number;
-  sensor_height_mm?: number;
-  pixel_width?: number;
-  pixel_height?: number;
-  focal_length_mm?: number;
-  horizontal_fov_deg?: number;
-}
-
-export interface DoriParameterRanges {
-  sensor_width_mm?: ParameterRange;
-  sensor_height_mm?: ParameterRange;
-  pixel_width?: ParameterRange;
-  pixel_height?: ParameterRange;
-  focal_length_mm?: ParameterRange;
-  horizontal_fov_deg?: ParameterRange;
-}
@@ -4,7 +4,14 @@ import { calculateCameraFov } from './services/api';
 import { FEATURES } from './core/settings';
 import { store } from './services/store';
 import { drawVisualization, drawBirdsEyeView } from './ui/visualization';
-import { getCameraFromForm, getDistance, loadSystemToView, loadPreset } from './ui/form';
+import {
+  getCameraFromForm,
+  getDistance,
+  loadSystemToView,
+  loadPreset,
+  applyPixelPitch,
+  clearPixelPitch,
+} from './ui/form';
 import { calculateFocalLengthFromFov } from './services/api';
 import { displaySingleResult } from './ui/results';
 import { showToast } from './ui/toast';
@@ -539,6 +546,7 @@ window.addEventListener('DOMContentLoaded', () => {
     (document.getElementById('sensor-height') as HTMLInputElement).value = '27';
     (document.getElementById('pixel-width') as HTMLInputElement).value = '2000';
     (document.getElementById('pixel-height') as HTMLInputElement).value = '1500';
+    clearPixelPitch();
     (document.getElementById('focal-length') as HTMLInputElement).value = '50';
     (document.getElementById('distance') as HTMLInputElement).value = '25';
     (document.getElementById('hfov-deg') as HTMLInputElement).value = '';
@@ -554,6 +562,15 @@ window.addEventListener('DOMContentLoaded', () => {
     showToast('Form cleared', 'info', 2000);
   });
 
+  // Pixel pitch mode: the sensor size follows pitch × resolution until edited by hand.
+  // Registered before change tracking so that sees the derived sensor size.
+  ['pixel-pitch', 'pixel-width', 'pixel-height'].forEach((inputId) => {
+    document.getElementById(inputId)?.addEventListener('input', applyPixelPitch);
+  });
+  ['sensor-width', 'sensor-height'].forEach((inputId) => {
+    document.getElementById(inputId)?.addEventListener('input', clearPixelPitch);
+  });
+
   // Add change tracking to all form inputs
   const formInputs = [
     'name',
@@ -561,6 +578,7 @@ window.addEventListener('DOMContentLoaded', () => {
     'sensor-height',
     'pixel-width',
     'pixel-height',
+    'pixel-pitch',
     'focal-length',
     'distance',
   ];
@@ -779,6 +797,7 @@ window.addEventListener('DOMContentLoaded', () => {
     'sensor-height',
     'pixel-width',
     'pixel-height',
+    'pixel-pitch',
     'focal-length',
     'distance',
   ];
@@ -3,6 +3,7 @@
  */
 
 import { invoke } from '@tauri-apps/api/core';
+import { listen, type UnlistenFn } from '@tauri-apps/api/event';
 import type {
   CameraSystem,
   FovResult,
@@ -37,15 +38,32 @@ export async function calculateFocalLengthFromFov(
 }
 
 /**
- * Calculate parameter ranges for given DORI requirements
+ * Check DORI targets and constraints for missing, non-positive or impossible values
  */
-export async function calculateDoriRanges(
+export async function validateDoriRequest(
   targets: import('../core/types').DoriTargets,
   constraints: import('../core/types').ParameterConstraint
+): Promise<import('../core/types').ValidationWarning[]> {
+  return await invoke('validate_dori_request', {
+    targets,
+    constraints,
+  });
+}
+
+/**
+ * Calculate parameter ranges for given DORI requirements, optionally within custom
+ * parameter bounds. Rejects with the request's error-level ValidationWarnings if it is
+ * invalid (e.g. no target given)
+ */
+export async function calculateDoriRanges(
+  targets: import('../core/types').DoriTargets,
+  constraints: import('../core/types').ParameterConstraint,
+  bounds?: import('../core/types').SolverBounds
 ): Promise<import('../core/types').DoriParameterRanges> {
   return await invoke('calculate_dori_ranges', {
     targets,
     constraints,
+    bounds: bounds ?? null,
   });
 }
 
@@ -85,3 +103,122 @@ export async function validateCameraSystem(
     result,
   });
 }
+
+/**
+ * Re-render validation warnings in the given language
+ */
+export async function localizeValidationWarnings(
+  warnings: import('../core/types').ValidationWarning[],
+  locale: import('../core/types').Locale
+): Promise<import('../core/types').ValidationWarning[]> {
+  return await invoke('localize_validation_warnings', {
+    warnings,
+    locale,
+  });
+}
+
+/**
+ * Listen for progress of long-running computations started with a task id
+ */
+export async function onComputationProgress(
+  handler: (progress: import('../core/types').ComputationProgress) => void
+): Promise<UnlistenFn> {
+  return await listen<import('../core/types').ComputationProgress>(
+    'computation-progress',
+    (event) => handler(event.payload)
+  );
+}
+
+/**
+ * Ask a running computation to stop; its command then rejects with an error
+ */
+export async function cancelComputation(taskId: string): Promise<void> {
+  await invoke('cancel_computation', { taskId });
+}
+
+/**
+ * Drop cached results of one command, or of every command when none is given
+ */
+export async function clearCalculationCache(
+  command?: string
+): Promise<import('../core/types').CacheStats> {
+  return await invoke('clear_calculation_cache', { command: command ?? null });
+}
+
+/**
+ * Cameras of the project held by the backend
+ */
+export async function listCameras(): Promise<import('../core/types').ProjectCamera[]> {
+  return await invoke('list_cameras');
+}
+
+/**
+ * Add a camera to the backend project, returning the updated list
+ */
+export async function addCamera(
+  camera: import('../core/types').ProjectCamera
+): Promise<import('../core/types').ProjectCamera[]> {
+  return await invoke('add_camera', { camera });
+}
+
+/**
+ * Replace the camera at an index in the backend project, returning the updated list
+ */
+export async function updateCamera(
+  index: number,
+  camera: import('../core/types').ProjectCamera
+): Promise<import('../core/types').ProjectCamera[]> {
+  return await invoke('update_camera', { index, camera });
+}
+
+/**
+ * Remove the camera at an index from the backend project, returning the updated list
+ */
+export async function removeCamera(
+  index: number
+): Promise<import('../core/types').ProjectCamera[]> {
+  return await invoke('remove_camera', { index });
+}
+
+/**
+ * Calculate every camera of the backend project at its target distance
+ */
+export async function recalculateAll(): Promise<import('../core/types').CameraWithResult[]> {
+  return await invoke('recalculate_all');
+}
+
+/**
+ * Replace the site plan of the backend project, or remove it with null
+ */
+export async function setProjectScene(scene: import('../core/types').Scene | null): Promise<void> {
+  await invoke('set_project_scene', { scene });
+}
+
+/**
+ * Listen for recalculated results whenever the backend project changes
+ */
+export async function onProjectUpdated(
+  handler: (results: import('../core/types').ProjectResults) => void
+): Promise<UnlistenFn> {
+  return await listen<import('../core/types').ProjectResults>('project-updated', (event) =>
+    handler(event.payload)
+  );
+}
+
+/**
+ * Save recent backend logs and the open project to a JSON file for a bug report
+ */
+export async function exportDiagnostics(path: string): Promise<void> {
+  await invoke('export_diagnostics', { path });
+}
+
+/**
+ * Percentage change of every derived metric going from camera a to camera b
+ */
+export async function diffCameras(
+  a: import('../core/types').CameraSystem,
+  b: import('../core/types').CameraSystem,
+  distanceMm: number
+): Promise<import('../core/types').CameraDiff> {
+  return await invoke('diff_cameras', { a, b, distanceMm });
+}
@@ -76,6 +76,14 @@ const createMockSystem = (name: string): CameraWithResult => ({
     horizontal_ppm: 111.1,
     vertical_ppm: 111.1,
     distance_m: 25,
+    horizontal_ifov_urad: 360,
+    vertical_ifov_urad: 360,
+    diagonal_fov_deg: 46.8,
+    horizontal_px_per_deg: 50.5,
+    vertical_px_per_deg: 55.6,
+    horizontal_ppf: 33.9,
+    vertical_ppf: 33.9,
+    megapixels_per_m2: 0.0123,
   },
 });
 
@@ -4,6 +4,7 @@
  */
 
 import {
+  validateDoriRequest,
   calculateDoriRanges,
   calculateDoriFromSingleDistance,
   calculateCameraFov,
@@ -13,6 +14,10 @@ import type {
   ParameterConstraint,
   DoriParameterRanges,
   CameraSystem,
+  ParameterRange,
+  RangeLimit,
+  RangeParameter,
+  ValidationWarning,
 } from '../core/types';
 import { store } from '../services/store';
 import { showToast } from './toast';
@@ -393,14 +398,11 @@ async function calculateParameterRanges(): Promise<void> {
     const targets = getDoriTargets();
     const constraints = getConstraints();
 
-    // Validate that at least one target is specified
-    if (
-      !targets.detection_m &&
-      !targets.observation_m &&
-      !targets.recognition_m &&
-      !targets.identification_m
-    ) {
-      alert('Please specify at least one target DORI distance');
+    // Check the request before solving, so bad input is reported next to its field
+    const diagnostics = await validateDoriRequest(targets, constraints);
+    const errors = diagnostics.filter((d) => d.severity === 'Error');
+    if (errors.length > 0) {
+      showRequestErrors(errors);
       return;
     }
 
@@ -414,6 +416,37 @@ async function calculateParameterRanges(): Promise<void> {
     // Display results
     displayParameterRanges(ranges);
 
+    // Fixed FOV, focal length and sensor width that contradict each other
+    const mismatch = ranges.inconsistency;
+    if (mismatch) {
+      showToast(
+        `FOV, focal length and sensor width are off by ${mismatch.discrepancy_deg.toFixed(2)}°. ` +
+          `Use ${mismatch.corrected_fov_deg.toFixed(2)}°, ` +
+          `${mismatch.corrected_focal_length_mm.toFixed(2)} mm focal length or ` +
+          `${mismatch.corrected_sensor_width_mm.toFixed(2)} mm sensor width`,
+        'error',
+        6000
+      );
+    }
+
+    // Targets that cannot be met within the parameter bounds, or that a stricter one exceeds
+    const governing = ranges.governing_target;
+    if (governing && ranges.infeasible_parameters.length > 0) {
+      showToast(
+        `No ${ranges.infeasible_parameters.join(', ')} within bounds reaches ` +
+          `${governing.level} at ${governing.distance_m} m`,
+        'error',
+        6000
+      );
+    } else if (governing && governing.exceeded.length > 0) {
+      showToast(
+        `${governing.level} at ${governing.distance_m} m is the strictest target; ` +
+          `${governing.exceeded.join(', ')} will be exceeded`,
+        'info',
+        6000
+      );
+    }
+
     // Show export button
     const exportBtn = document.getElementById('export-to-comparison-btn') as HTMLButtonElement;
     if (exportBtn) {
@@ -431,43 +464,55 @@ async function calculateParameterRanges(): Promise<void> {
 function displayParameterRanges(ranges: DoriParameterRanges): void {
   // Map backend field names to UI element IDs and parameter names
   const parameterMapping: {
-    [key: string]: { rangeId: string; param: string; unit: string; label: string };
+    [key: string]: {
+      rangeId: string;
+      param: string;
+      unit: string;
+      label: string;
+      parameter: RangeParameter;
+    };
   } = {
     sensor_width_mm: {
       rangeId: 'range-sensor-width',
       param: 'sensor-width',
       unit: 'mm',
       label: 'Sensor Width',
+      parameter: 'SensorWidth',
     },
     sensor_height_mm: {
       rangeId: 'range-sensor-height',
       param: 'sensor-height',
       unit: 'mm',
       label: 'Sensor Height',
+      parameter: 'SensorHeight',
     },
     pixel_width: {
       rangeId: 'range-pixel-width',
       param: 'pixel-width',
       unit: 'px',
       label: 'Pixel Width',
+      parameter: 'PixelWidth',
     },
     pixel_height: {
       rangeId: 'range-pixel-height',
       param: 'pixel-height',
       unit: 'px',
       label: 'Pixel Height',
+      parameter: 'PixelHeight',
     },
     focal_length_mm: {
       rangeId: 'range-focal-length',
       param: 'focal-length',
       unit: 'mm',
       label: 'Focal Length',
+      parameter: 'FocalLength',
     },
     horizontal_fov_deg: {
       rangeId: 'range-horizontal-fov',
       param: 'horizontal-fov',
       unit: '°',
       label: 'Horizontal FOV',
+      parameter: 'HorizontalFov',
     },
   };
 
@@ -475,6 +520,7 @@ function displayParameterRanges(ranges: DoriParameterRanges): void {
   Object.entries(parameterMapping).forEach(([key, mapping]) => {
     const rangeEl = document.getElementById(mapping.rangeId);
     if (!rangeEl) return;
+    rangeEl.title = '';
 
     // Determine current mode
     const paramGroup = document.querySelector(`[data-param="${mapping.param}"]`);
@@ -502,6 +548,19 @@ function displayParameterRanges(ranges: DoriParameterRanges): void {
         rangeEl.textContent = `${range.min.toFixed(range.min < 10 ? 2 : 0)} – ${range.max.toFixed(range.max < 10 ? 2 : 0)} ${mapping.unit}`;
         rangeEl.className = 'param-range-inline range-state';
       }
+      rangeEl.title = describeBindingConstraint(ranges, mapping.parameter);
+      if (key === 'pixel_width' && ranges.standard_resolutions.length > 0) {
+        const names = ranges.standard_resolutions.map((r) => r.name).join(', ');
+        rangeEl.title += `\nStandard resolutions: ${names}`;
+      }
+      if (key === 'focal_length_mm' && ranges.standard_lenses.length > 0) {
+        const names = ranges.standard_lenses.map((l) => l.name).join(', ');
+        rangeEl.title += `\nStandard lenses: ${names}`;
+      }
+      if (key === 'sensor_width_mm' && ranges.standard_sensor_formats.length > 0) {
+        const names = ranges.standard_sensor_formats.map((f) => f.name).join(', ');
+        rangeEl.title += `\nStandard formats: ${names}`;
+      }
     } else if (currentMode === 'fixed' && fixedInput?.value) {
       // Fixed mode with value (green)
       const value = parseFloat(fixedInput.value);
@@ -528,9 +587,45 @@ function displayParameterRanges(ranges: DoriParameterRanges): void {
   });
 }
 
+/**
+ * Show request errors in the range display of the field they refer to,
+ * or as a toast for fields without one such as the DORI targets
+ */
+function showRequestErrors(errors: ValidationWarning[]): void {
+  errors.forEach((error) => {
+    const field = error.fields[0]?.field ?? '';
+    const param = field.replace(/_(mm|deg)$/, '').replace(/_/g, '-');
+    const rangeEl = field ? document.getElementById(`range-${param}`) : null;
+    if (rangeEl) {
+      rangeEl.textContent = error.message;
+      rangeEl.className = 'param-range-inline error-state';
+    } else {
+      showToast(error.message, 'error', 6000);
+    }
+  });
+}
+
+/**
+ * Describe what limits both ends of a calculated range, for the range tooltip
+ */
+function describeBindingConstraint(ranges: DoriParameterRanges, parameter: RangeParameter): string {
+  const binding = ranges.binding_constraints.find((b) => b.parameter === parameter);
+  if (!binding) return '';
+  const min = describeRangeLimit(binding.min_limited_by);
+  const max = describeRangeLimit(binding.max_limited_by);
+  return `Min: ${min}\nMax: ${max}`;
+}
+
+function describeRangeLimit(limit: RangeLimit): string {
+  if (limit === 'FovConstraint') return 'fixed horizontal FOV';
+  if (limit === 'FixedParameters') return 'fixed parameters';
+  if ('DoriTarget' in limit) return `${limit.DoriTarget} distance target`;
+  return `plausible ${limit.ParameterBounds} bounds`;
+}
+
 /**
  * Export a camera configuration to the comparison list
- * Takes the midpoint of each range to create a concrete camera system
+ * Takes the recommended value of each range to create a concrete camera system
  */
 async function exportToComparison(): Promise<void> {
   if (!lastCalculatedRanges || !lastCalculatedTargets) {
@@ -541,23 +636,28 @@ async function exportToComparison(): Promise<void> {
   try {
     const constraints = getConstraints();
 
-    // Build camera system using fixed values or midpoints of ranges
+    // Build camera system using fixed values or the recommended value of each range
     const sensorWidth =
-      constraints.sensor_width_mm || getMidpoint(lastCalculatedRanges.sensor_width_mm);
+      constraints.sensor_width_mm || getRecommended(lastCalculatedRanges.sensor_width_mm);
     const pixelWidth = Math.round(
-      constraints.pixel_width || getMidpoint(lastCalculatedRanges.pixel_width)
+      constraints.pixel_width || getRecommended(lastCalculatedRanges.pixel_width)
     );
 
-    // Calculate height based on standard 4:3 aspect ratio if not specified
-    const aspectRatio = 4 / 3; // Standard aspect ratio
+    // Heights not specified keep the aspect ratio the backend derived them at
     const sensorHeight =
       constraints.sensor_height_mm ||
-      getMidpoint(lastCalculatedRanges.sensor_height_mm) ||
-      sensorWidth / aspectRatio;
+      heightForWidth(
+        lastCalculatedRanges.sensor_height_mm,
+        lastCalculatedRanges.sensor_width_mm,
+        sensorWidth
+      );
     const pixelHeight = Math.round(
       constraints.pixel_height ||
-        getMidpoint(lastCalculatedRanges.pixel_height) ||
-        pixelWidth / aspectRatio
+        heightForWidth(
+          lastCalculatedRanges.pixel_height,
+          lastCalculatedRanges.pixel_width,
+          pixelWidth
+        )
     );
 
     const camera: CameraSystem = {
@@ -566,7 +666,7 @@ async function exportToComparison(): Promise<void> {
       pixel_width: pixelWidth,
       pixel_height: pixelHeight,
       focal_length_mm:
-        constraints.focal_length_mm || getMidpoint(lastCalculatedRanges.focal_length_mm),
+        constraints.focal_length_mm || getRecommended(lastCalculatedRanges.focal_length_mm),
     };
 
     // Use the first specified DORI target as the distance
@@ -607,9 +707,24 @@ async function exportToComparison(): Promise<void> {
 }
 
 /**
- * Get midpoint of a parameter range, or 0 if range is undefined
+ * Get the recommended value of a parameter range, falling back to its midpoint,
+ * or 0 if range is undefined
  */
-function getMidpoint(range: { min: number; max: number } | null | undefined): number {
+function getRecommended(range: ParameterRange | null | undefined): number {
   if (!range) return 0;
-  return (range.min + range.max) / 2;
+  return range.recommended ?? (range.min + range.max) / 2;
+}
+
+/**
+ * Height for a chosen width, at the ratio between the backend's width and height ranges
+ * (a fixed width leaves no width range, and its height range is a single value)
+ */
+function heightForWidth(
+  height: ParameterRange | null | undefined,
+  width: ParameterRange | null | undefined,
+  chosenWidth: number
+): number {
+  if (!height) return 0;
+  if (!width || width.min <= 0) return height.min;
+  return (chosenWidth * height.min) / width.min;
 }
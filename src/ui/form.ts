@@ -82,6 +82,31 @@ export function getCameraFromForm(): CameraSystem {
   };
 }
 
+/**
+ * Derive the sensor size from pixel pitch × resolution when a pitch is entered
+ * Many industrial sensor datasheets give the pitch rather than the sensor size
+ */
+export function applyPixelPitch(): void {
+  const pitchUm = parseFloat((document.getElementById('pixel-pitch') as HTMLInputElement).value);
+  const pixelWidth = parseFloat((document.getElementById('pixel-width') as HTMLInputElement).value);
+  const pixelHeight = parseFloat(
+    (document.getElementById('pixel-height') as HTMLInputElement).value
+  );
+  if (!(pitchUm > 0) || !(pixelWidth > 0) || !(pixelHeight > 0)) return;
+
+  // Rounded to the nanometre, as the backend does
+  const toSensorMm = (pixels: number) => (Math.round(pixels * pitchUm * 1000) / 1e6).toString();
+  (document.getElementById('sensor-width') as HTMLInputElement).value = toSensorMm(pixelWidth);
+  (document.getElementById('sensor-height') as HTMLInputElement).value = toSensorMm(pixelHeight);
+}
+
+/**
+ * Clear the pixel pitch so the sensor size is taken as entered
+ */
+export function clearPixelPitch(): void {
+  (document.getElementById('pixel-pitch') as HTMLInputElement).value = '';
+}
+
 /**
  * Get distance value from form (converts meters to millimeters)
  * @throws Error if distance is invalid
@@ -153,6 +178,7 @@ export function loadSystemToForm(index: number, onUpdate: () => void): void {
   const system = store.getCameraSystem(index);
   if (!system) return;
 
+  clearPixelPitch();
   (document.getElementById('sensor-width') as HTMLInputElement).value =
     system.camera.sensor_width_mm.toString();
   (document.getElementById('sensor-height') as HTMLInputElement).value =
@@ -184,6 +210,7 @@ export function loadSystemToView(index: number): void {
   const system = store.getCameraSystem(index);
   if (!system) return;
 
+  clearPixelPitch();
   (document.getElementById('sensor-width') as HTMLInputElement).value =
     system.camera.sensor_width_mm.toString();
   (document.getElementById('sensor-height') as HTMLInputElement).value =
@@ -207,6 +234,7 @@ export function loadPreset(presetName: string): void {
   const preset = CAMERA_PRESETS[presetName];
   if (!preset) return;
 
+  clearPixelPitch();
   (document.getElementById('sensor-width') as HTMLInputElement).value =
     preset.sensor_width_mm?.toString() || '';
   (document.getElementById('sensor-height') as HTMLInputElement).value =
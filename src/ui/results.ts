@@ -2,7 +2,29 @@
  * Results page rendering for camera FOV calculations
  */
 
-import type { CameraSystem, FovResult } from '../core/types';
+import type { AngleOfView, CameraSystem, FovResult, Locale } from '../core/types';
+
+/**
+ * Backend message language matching the browser language, English by default
+ */
+function uiLocale(): Locale {
+  const language = navigator.language.slice(0, 2).toLowerCase();
+  if (language === 'de') return 'De';
+  if (language === 'fr') return 'Fr';
+  return 'En';
+}
+
+/**
+ * Display names of the lens classes
+ */
+const ANGLE_OF_VIEW_LABELS: Record<AngleOfView, string> = {
+  UltraWide: 'Ultra-wide',
+  Wide: 'Wide',
+  Normal: 'Normal',
+  ShortTele: 'Short tele',
+  Tele: 'Tele',
+  SuperTele: 'Super tele',
+};
 
 /**
  * Render a single camera system result to the results tab
@@ -43,13 +65,19 @@ export function renderSingleResult(
       <div class="result-section">
         <h4>Field of View @ ${result.distance_m.toFixed(2)} m</h4>
         <p>Angular FOV: ${result.horizontal_fov_deg.toFixed(2)}° × ${result.vertical_fov_deg.toFixed(2)}°</p>
+        <p>Diagonal FOV: ${result.diagonal_fov_deg.toFixed(2)}°</p>
+        ${result.angle_of_view ? `<p>Lens Class: ${ANGLE_OF_VIEW_LABELS[result.angle_of_view]}</p>` : ''}
         <p>Linear FOV: ${result.horizontal_fov_m.toFixed(3)} × ${result.vertical_fov_m.toFixed(3)} m</p>
         <p>Linear FOV: ${(result.horizontal_fov_m * 1000).toFixed(2)} × ${(result.vertical_fov_m * 1000).toFixed(2)} mm</p>
       </div>
       <div class="result-section">
         <h4>Spatial Resolution</h4>
         <p>Pixels per meter: ${result.horizontal_ppm.toFixed(1)} × ${result.vertical_ppm.toFixed(1)} px/m</p>
+        <p>Pixels per foot: ${result.horizontal_ppf.toFixed(1)} × ${result.vertical_ppf.toFixed(1)} px/ft</p>
+        <p>Pixels per degree: ${result.horizontal_px_per_deg.toFixed(1)} × ${result.vertical_px_per_deg.toFixed(1)} px/°</p>
         <p>Ground Sample Distance: ${(1000 / result.horizontal_ppm).toFixed(3)} × ${(1000 / result.vertical_ppm).toFixed(3)} mm/pixel</p>
+        <p>IFOV: ${result.horizontal_ifov_urad.toFixed(1)} × ${result.vertical_ifov_urad.toFixed(1)} µrad/pixel</p>
+        <p>Target Coverage: ${result.megapixels_per_m2.toFixed(3)} MP/m²</p>
       </div>
       ${doriSection}
     </div>
@@ -69,15 +97,23 @@ export async function displaySingleResult(
 
   // Validate using Rust backend
   try {
-    const { validateCameraSystem } = await import('../services/api');
+    const { validateCameraSystem, localizeValidationWarnings } = await import('../services/api');
     const { showToast } = await import('./toast');
 
-    const warnings = await validateCameraSystem(camera, result);
+    let warnings = await validateCameraSystem(camera, result);
+    const locale = uiLocale();
+    if (locale !== 'En') {
+      warnings = await localizeValidationWarnings(warnings, locale);
+    }
 
     // Show warnings/errors as toasts
     warnings.forEach((warning) => {
-      const toastType = warning.severity === 'Error' ? 'error' : 'warning';
-      showToast(warning.message, toastType);
+      const toastType =
+        warning.severity === 'Error' ? 'error' : warning.severity === 'Info' ? 'info' : 'warning';
+      const message = warning.suggestion
+        ? `${warning.message}. ${warning.suggestion}`
+        : warning.message;
+      showToast(message, toastType);
     });
   } catch (error) {
     console.error('Error validating camera system:', error);